@@ -0,0 +1,164 @@
+//! `OutputMode::AlphaMask` - a terminal rendered as a mask, composited by
+//! a custom UI shader.
+//!
+//! The terminal is headless: nothing shows it directly. It is rendered
+//! with `output_mode: OutputMode::AlphaMask`, so its texture holds only
+//! the text's coverage - transparent black where nothing is drawn, white
+//! at the glyph's alpha where something is. A `UiMaterial`
+//! (examples/assets/shaders/alpha_mask.wgsl) samples that coverage and
+//! fills the text with an animated gradient; the dark page background
+//! shows through everywhere else. The colors the widgets ask for don't
+//! matter to a mask - only what is drawn, not its color.
+//!
+//! - `Space` switches the terminal back to `OutputMode::Color`, showing
+//!   what the shader sees in each mode (in color mode the opaque cell
+//!   backgrounds cover the whole node).
+//!
+//! Run with: `cargo run --example alpha_mask`
+
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, ShaderType};
+use bevy::shader::ShaderRef;
+use bevy_tui_texture::prelude::*;
+use bevy_tui_texture::Font as TerminalFont;
+use bevy_tui_texture::OutputMode;
+use ratatui::prelude::*;
+use ratatui::style::Color as RatatuiColor;
+use ratatui::widgets::*;
+use std::sync::Arc;
+
+const COLS: u16 = 36;
+const ROWS: u16 = 9;
+
+#[derive(Clone, Copy, Debug, ShaderType)]
+struct MaskSettings {
+    time: f32,
+}
+
+/// Fills the mask's coverage with a gradient - see alpha_mask.wgsl.
+#[derive(Asset, AsBindGroup, Clone, Debug, TypePath)]
+struct TextMaskMaterial {
+    #[uniform(0)]
+    settings: MaskSettings,
+    #[texture(1)]
+    #[sampler(2)]
+    mask: Handle<Image>,
+}
+
+impl UiMaterial for TextMaskMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/alpha_mask.wgsl".into()
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(
+            DefaultPlugins.set(AssetPlugin {
+                file_path: "examples/assets".into(),
+                ..default()
+            }),
+        )
+        .add_plugins(TerminalPlugin::display_only())
+        .add_plugins(UiMaterialPlugin::<TextMaskMaterial>::default())
+        .insert_resource(ClearColor(Color::srgb(0.06, 0.06, 0.1)))
+        .add_systems(Startup, setup)
+        .add_systems(
+            Update,
+            (attach_mask, animate_mask, toggle_mode).in_set(TerminalSystemSet::UserUpdate),
+        )
+        .add_systems(Update, render_terminal.in_set(TerminalSystemSet::Render))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    let font_data = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/assets/fonts/Mplus1Code-Regular.ttf"
+    ));
+    let fonts = Arc::new(Fonts::new(
+        TerminalFont::new(font_data).expect("Failed to parse font"),
+        32,
+    ));
+
+    commands.spawn(TuiRequest::headless(COLS, ROWS, fonts).with_config(TerminalConfig {
+        output_mode: OutputMode::AlphaMask,
+        ..default()
+    }));
+    commands.spawn(Camera2d);
+}
+
+/// Once the terminal exists, show its mask through the material, centered
+/// at the texture's own size.
+fn attach_mask(
+    mut commands: Commands,
+    terminals: Query<&Tui, Added<Tui>>,
+    mut materials: ResMut<Assets<TextMaskMaterial>>,
+) {
+    let Ok(tui) = terminals.single() else {
+        return;
+    };
+    let metrics = tui.metrics();
+    let material = materials.add(TextMaskMaterial {
+        settings: MaskSettings { time: 0.0 },
+        mask: tui.image_handle().clone(),
+    });
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .with_child((
+            MaterialNode(material),
+            Node {
+                width: Val::Px(metrics.texture_width as f32),
+                height: Val::Px(metrics.texture_height as f32),
+                ..default()
+            },
+        ));
+}
+
+fn animate_mask(time: Res<Time>, mut materials: ResMut<Assets<TextMaskMaterial>>) {
+    for (_, material) in materials.iter_mut() {
+        material.settings.time = time.elapsed_secs();
+    }
+}
+
+fn toggle_mode(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut terminals: Query<&mut Tui>,
+    mut showing_color: Local<bool>,
+) {
+    if !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+    *showing_color = !*showing_color;
+    let mode = if *showing_color { OutputMode::Color } else { OutputMode::AlphaMask };
+    for mut tui in &mut terminals {
+        tui.set_output_mode(mode);
+    }
+}
+
+fn render_terminal(mut terminals: Query<&mut Tui>) {
+    for mut tui in &mut terminals {
+        tui.draw(|frame| {
+            let block = Block::bordered()
+                .border_type(BorderType::Rounded)
+                .title(" alpha mask ")
+                .border_style(Style::default().fg(RatatuiColor::Yellow));
+            let text = vec![
+                Line::from(""),
+                Line::from("TEXT AS A STENCIL").bold().centered(),
+                Line::from(""),
+                Line::from("the gradient comes from the shader;").centered(),
+                Line::from("the terminal only says where.").centered(),
+                Line::from(""),
+                Line::from("Space: mask / color".fg(RatatuiColor::DarkGray)).centered(),
+            ];
+            frame.render_widget(Paragraph::new(text).block(block), frame.area());
+        });
+    }
+}
@@ -0,0 +1,28 @@
+// Text mask compositing - used by examples/alpha_mask.rs
+//
+// Samples a terminal rendered with `OutputMode::AlphaMask` and uses its
+// alpha as the coverage of an animated gradient: the text shows the
+// gradient, everything else stays see-through.
+
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+struct MaskSettings {
+    time: f32,
+};
+
+@group(1) @binding(0) var<uniform> settings: MaskSettings;
+@group(1) @binding(1) var mask_texture: texture_2d<f32>;
+@group(1) @binding(2) var mask_sampler: sampler;
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    // Coverage lives in every channel of a mask; alpha is the one that
+    // isn't touched by the texture's sRGB decode.
+    let coverage = textureSample(mask_texture, mask_sampler, in.uv).a;
+
+    // A diagonal rainbow sweeping across the text.
+    let phase = (in.uv.x + in.uv.y) * 3.0 - settings.time;
+    let gradient = 0.5 + 0.5 * cos(vec3<f32>(phase, phase + 2.094, phase + 4.188));
+
+    return vec4<f32>(gradient, coverage);
+}
@@ -0,0 +1,121 @@
+//! `TerminalConfig::background_gradient` — a vertical fade behind the text.
+//!
+//! Every cell left at the default (`Color::Reset`) background takes its
+//! row's color from a top-to-bottom gradient, while explicitly colored
+//! cells (the status bar here) draw over it as usual. The terminal's
+//! corners are rounded (`TerminalConfig::corner_radius`): outside them the
+//! texture is transparent, so the window's clear color shows through.
+//! Press `G` to toggle the gradient via `Tui::set_background_gradient`, `R`
+//! to toggle the rounded corners via `Tui::set_corner_radius`, and `S` to
+//! save a screenshot of the window to `background_gradient.png`.
+//!
+//! Run with: `cargo run --example background_gradient`
+
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{save_to_disk, Screenshot};
+use bevy_tui_texture::prelude::*;
+use bevy_tui_texture::Font as TerminalFont;
+use ratatui::prelude::*;
+use ratatui::style::Color as RatatuiColor;
+use ratatui::widgets::*;
+use std::sync::Arc;
+
+const GRADIENT: ([u8; 3], [u8; 3]) = ([20, 30, 80], [90, 20, 60]);
+const CORNER_RADIUS: u32 = 14;
+
+#[derive(Component)]
+struct GradientTerminal;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(TerminalPlugin::default())
+        // Light enough for the rounded corners to stand out.
+        .insert_resource(ClearColor(Color::srgb(0.55, 0.58, 0.65)))
+        .add_systems(Startup, setup)
+        .add_systems(Update, handle_keys.in_set(TerminalSystemSet::UserUpdate))
+        .add_systems(Update, render_terminal.in_set(TerminalSystemSet::Render))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    let font_data = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/assets/fonts/Mplus1Code-Regular.ttf"
+    ));
+    let fonts = Arc::new(Fonts::new(
+        TerminalFont::new(font_data).expect("Failed to parse font"),
+        16,
+    ));
+
+    commands.spawn((
+        TuiRequest::ui(60, 20, fonts).with_config(TerminalConfig {
+            background_gradient: Some(GRADIENT),
+            corner_radius: CORNER_RADIUS,
+            keyboard: false,
+            mouse: false,
+            ..default()
+        }),
+        Node::default(),
+        GradientTerminal,
+    ));
+    commands.spawn(Camera2d);
+}
+
+/// `G` toggles the gradient, `R` the rounded corners, `S` saves a
+/// screenshot. Plain bevy keyboard input - the terminal itself has
+/// `keyboard: false`.
+fn handle_keys(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut enabled: Local<Option<bool>>,
+    mut square: Local<bool>,
+    mut screens: Query<&mut Tui, With<GradientTerminal>>,
+) {
+    if keys.just_pressed(KeyCode::KeyS) {
+        commands
+            .spawn(Screenshot::primary_window())
+            .observe(save_to_disk("background_gradient.png"));
+    }
+    let Ok(mut term) = screens.single_mut() else {
+        return;
+    };
+    if keys.just_pressed(KeyCode::KeyR) {
+        *square = !*square;
+        term.set_corner_radius(if *square { 0 } else { CORNER_RADIUS });
+    }
+    if keys.just_pressed(KeyCode::KeyG) {
+        let on = !enabled.unwrap_or(true);
+        *enabled = Some(on);
+        term.set_background_gradient(on.then_some(GRADIENT));
+    }
+}
+
+fn render_terminal(mut screens: Query<&mut Tui, With<GradientTerminal>>) {
+    let Ok(mut term) = screens.single_mut() else {
+        return;
+    };
+    term.draw(|frame| {
+        let [body, status] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let lines = [
+            "SYSTEM ONLINE",
+            "",
+            "> reactor output ..... 98%",
+            "> hull integrity ..... 100%",
+            "> shields ............ nominal",
+        ];
+        frame.render_widget(
+            Paragraph::new(lines.map(Line::from).to_vec())
+                .fg(RatatuiColor::Cyan)
+                .block(Block::bordered().title(" status ")),
+            body,
+        );
+        frame.render_widget(
+            Paragraph::new(" G: gradient   R: rounded corners   S: screenshot")
+                .fg(RatatuiColor::Black)
+                .bg(RatatuiColor::Gray),
+            status,
+        );
+    });
+}
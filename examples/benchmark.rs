@@ -106,10 +106,16 @@ fn render_benchmark(
     let elapsed = time.elapsed_secs();
     if elapsed - state.last_report_secs >= 2.0 {
         state.last_report_secs = elapsed;
+        // What the last frame cost the GPU, to set the timings against.
+        let stats = term.render_stats();
         println!(
-            "[benchmark] t={elapsed:>6.1}s  fps={fps:>6.1}  frame_time_ms={:>6.2}  frames={}",
+            "[benchmark] t={elapsed:>6.1}s  fps={fps:>6.1}  frame_time_ms={:>6.2}  frames={}  \
+             bg_quads={}  fg_quads={}  draw_calls={}",
             if fps > 0.0 { 1000.0 / fps } else { 0.0 },
-            state.frame_count
+            state.frame_count,
+            stats.bg_quads,
+            stats.fg_quads,
+            stats.draw_calls
         );
     }
 
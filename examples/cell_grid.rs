@@ -0,0 +1,107 @@
+//! `TerminalConfig::cell_grid` — 1px separators between every cell.
+//!
+//! A spreadsheet-style table drawn with plain text only (no box-drawing
+//! characters anywhere): the grid lines are a global overlay painted in the
+//! background pass. Press `G` to toggle the grid at runtime via
+//! `Tui::set_cell_grid`.
+//!
+//! Run with: `cargo run --example cell_grid`
+
+use bevy::prelude::*;
+use bevy_tui_texture::prelude::*;
+use bevy_tui_texture::Font as TerminalFont;
+use ratatui::prelude::*;
+use ratatui::style::Color as RatatuiColor;
+use ratatui::widgets::*;
+use std::sync::Arc;
+
+const GRID_COLOR: [u8; 3] = [60, 60, 70];
+
+#[derive(Component)]
+struct GridTerminal;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(TerminalPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, toggle_grid.in_set(TerminalSystemSet::UserUpdate))
+        .add_systems(Update, render_terminal.in_set(TerminalSystemSet::Render))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    let font_data = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/assets/fonts/Mplus1Code-Regular.ttf"
+    ));
+    let fonts = Arc::new(Fonts::new(
+        TerminalFont::new(font_data).expect("Failed to parse font"),
+        16,
+    ));
+
+    commands.spawn((
+        TuiRequest::ui(48, 14, fonts).with_config(TerminalConfig {
+            cell_grid: Some(GRID_COLOR),
+            keyboard: false,
+            mouse: false,
+            ..default()
+        }),
+        Node::default(),
+        GridTerminal,
+    ));
+    commands.spawn(Camera2d);
+}
+
+/// `G` toggles the overlay. Plain bevy keyboard input - the terminal itself
+/// has `keyboard: false`, so no focus is involved.
+fn toggle_grid(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut enabled: Local<Option<bool>>,
+    mut screens: Query<&mut Tui, With<GridTerminal>>,
+) {
+    if !keys.just_pressed(KeyCode::KeyG) {
+        return;
+    }
+    let Ok(mut term) = screens.single_mut() else {
+        return;
+    };
+    let on = !enabled.unwrap_or(true);
+    *enabled = Some(on);
+    term.set_cell_grid(on.then_some(GRID_COLOR));
+}
+
+fn render_terminal(mut screens: Query<&mut Tui, With<GridTerminal>>) {
+    let Ok(mut term) = screens.single_mut() else {
+        return;
+    };
+    term.draw(|frame| {
+        let header = Row::new(["Item", "Qty", "Price"])
+            .style(Style::default().fg(RatatuiColor::Yellow).bold());
+        let rows = [
+            ["Apples", "12", "3.40"],
+            ["Bread", "1", "2.10"],
+            ["Cheese", "3", "9.75"],
+            ["Dates", "40", "6.00"],
+        ]
+        .map(Row::new);
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(16),
+                Constraint::Length(8),
+                Constraint::Length(8),
+            ],
+        )
+        .header(header)
+        .column_spacing(0);
+
+        let [table_area, help_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        frame.render_widget(table, table_area);
+        frame.render_widget(
+            Paragraph::new("press G to toggle the grid").fg(RatatuiColor::DarkGray),
+            help_area,
+        );
+    });
+}
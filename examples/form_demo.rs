@@ -110,8 +110,8 @@ fn handle_events(
     let Ok((entity, term)) = terminals.single() else {
         return;
     };
-    for event in events.read().filter(|e| e.target == entity) {
-        match &event.input {
+    for input in events.for_terminal(entity) {
+        match input {
             InputEvent::Mouse(m) => match m.kind {
                 MouseEventKind::Down(MouseButton::Left) => {
                     if let Some(w) = term.hit_regions().hit_at::<Widget>((m.column, m.row)) {
@@ -0,0 +1,127 @@
+//! `TerminalConfig::hdr` - a terminal rendered into an `Rgba16Float`
+//! texture, with text bright enough to bloom.
+//!
+//! - the terminal is headless and shown as a 2D `Sprite`, so it is drawn
+//!   in the camera's main pass and bloom sees it (UI nodes are drawn after
+//!   post-processing and would not glow),
+//! - `text_brightness: 4.0` scales every text color past `1.0`; the float
+//!   texture keeps those values, and the camera's `Bloom` (which makes it
+//!   an HDR camera) spreads them into a glow. Cell backgrounds stay as
+//!   they are, so only the text glows,
+//! - `H` switches the texture between HDR and plain `Rgba8Unorm`: the
+//!   8-bit texture clamps the same text back to `1.0` and the glow is gone,
+//! - `Up`/`Down` change the text brightness.
+//!
+//! Run with: `cargo run --example hdr_bloom`
+
+use bevy::post_process::bloom::Bloom;
+use bevy::prelude::*;
+use bevy_tui_texture::prelude::*;
+use bevy_tui_texture::Font as TerminalFont;
+use ratatui::prelude::*;
+use ratatui::style::Color as RatatuiColor;
+use ratatui::widgets::*;
+use std::sync::Arc;
+
+const COLS: u16 = 40;
+const ROWS: u16 = 10;
+const BRIGHTNESS: f32 = 4.0;
+
+fn main() {
+    App::new()
+        .add_plugins(
+            DefaultPlugins.set(AssetPlugin {
+                file_path: "examples/assets".into(),
+                ..default()
+            }),
+        )
+        .add_plugins(TerminalPlugin::display_only())
+        .insert_resource(ClearColor(Color::BLACK))
+        .add_systems(Startup, setup)
+        .add_systems(
+            Update,
+            (attach_sprite, adjust).in_set(TerminalSystemSet::UserUpdate),
+        )
+        .add_systems(Update, render_terminal.in_set(TerminalSystemSet::Render))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    let font_data = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/assets/fonts/Mplus1Code-Regular.ttf"
+    ));
+    let fonts = Arc::new(Fonts::new(
+        TerminalFont::new(font_data).expect("Failed to parse font"),
+        24,
+    ));
+
+    commands.spawn(TuiRequest::headless(COLS, ROWS, fonts).with_config(TerminalConfig {
+        hdr: true,
+        text_brightness: BRIGHTNESS,
+        ..default()
+    }));
+    // `Bloom` requires `Hdr`, so this is an HDR camera.
+    commands.spawn((Camera2d, Bloom::NATURAL));
+}
+
+/// Once the terminal exists, show its image as a sprite.
+fn attach_sprite(mut commands: Commands, terminals: Query<&Tui, Added<Tui>>) {
+    for tui in &terminals {
+        commands.spawn(Sprite::from_image(tui.image_handle().clone()));
+    }
+}
+
+fn adjust(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut terminals: Query<&mut Tui>,
+    mut images: ResMut<Assets<Image>>,
+    mut sdr: Local<bool>,
+    mut brightness: Local<Option<f32>>,
+) {
+    let brightness = brightness.get_or_insert(BRIGHTNESS);
+    let mut changed = false;
+    if keys.just_pressed(KeyCode::ArrowUp) {
+        *brightness += 0.5;
+        changed = true;
+    }
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        *brightness = (*brightness - 0.5).max(0.0);
+        changed = true;
+    }
+    let toggled = keys.just_pressed(KeyCode::KeyH);
+    if toggled {
+        *sdr = !*sdr;
+    }
+    for mut tui in &mut terminals {
+        if changed {
+            tui.set_text_brightness(*brightness);
+        }
+        if toggled {
+            tui.set_hdr(!*sdr, &mut images);
+        }
+    }
+}
+
+fn render_terminal(mut terminals: Query<&mut Tui>) {
+    for mut tui in &mut terminals {
+        tui.draw(|frame| {
+            let block = Block::bordered()
+                .border_type(BorderType::Double)
+                .title(" hdr bloom ")
+                .border_style(Style::default().fg(RatatuiColor::Cyan));
+            let text = vec![
+                Line::from(""),
+                Line::from("NEON SIGN".fg(RatatuiColor::Magenta)).bold().centered(),
+                Line::from(""),
+                Line::from("text past 1.0, kept by a float texture".fg(RatatuiColor::Yellow))
+                    .centered(),
+                Line::from("and spread by the camera's bloom".fg(RatatuiColor::Green)).centered(),
+                Line::from(""),
+                Line::from("H: hdr / 8-bit   Up/Down: brightness".fg(RatatuiColor::DarkGray))
+                    .centered(),
+            ];
+            frame.render_widget(Paragraph::new(text).block(block), frame.area());
+        });
+    }
+}
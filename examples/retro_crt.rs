@@ -636,6 +636,10 @@ fn setup(
     // the glTF loads (claim_object2_screen below), found by querying the
     // `MainScreen` marker rather than a resource. `initial_draw` shows a
     // loading splash until the per-frame draw system takes over.
+    // In the browser, rebuilding the widget catalog every animation frame
+    // is the demo's main CPU cost: there the screen is capped at 30
+    // renders a second (`0.0` = uncapped natively), and `render_terminal`
+    // skips its draw on frames that wouldn't render.
     commands.spawn((
         TuiRequest::headless(32, 24, fonts).with_config(TerminalConfig {
             initial_draw: Some(Box::new(|frame| {
@@ -662,6 +666,7 @@ fn setup(
             ..default()
         }),
         MainScreen,
+        TerminalRenderRate(if cfg!(target_arch = "wasm32") { 30.0 } else { 0.0 }),
     ));
     commands.insert_resource(AppState::default());
 }
@@ -1061,11 +1066,11 @@ fn update_blur_uniforms(
 /// Zero render-resource parameters: the plugin's `gpu_flush_system` owns
 /// the GPU render + async copy + material touch.
 fn render_terminal(
-    mut screens: Query<&mut Tui, With<MainScreen>>,
+    mut screens: Query<(&mut Tui, Option<&TerminalRenderRate>), With<MainScreen>>,
     mut app_state: ResMut<AppState>,
     time: Res<Time>,
 ) {
-    let Ok(mut term) = screens.single_mut() else {
+    let Ok((mut term, render_rate)) = screens.single_mut() else {
         return;
     };
     app_state.frame_count += 1;
@@ -1087,6 +1092,11 @@ fn render_terminal(
         app_state.button_clicked = false;
     }
 
+    // Nothing drawn now would reach the screen before the next due render.
+    if !term.render_due(render_rate, time.elapsed()) {
+        return;
+    }
+
     // UV calibration: CRT_CALIBRATE=1 shows four quadrants + a counter
     // (used to measure the screen's UV orientation and visible region)
     if std::env::var("CRT_CALIBRATE").is_ok() {
@@ -0,0 +1,157 @@
+//! `TerminalConfig::color_quantize` — render any content in a retro palette.
+//!
+//! A colorful dashboard (a hue sweep, gauges, styled text) starts in
+//! green-phosphor monochrome. The widgets themselves only ever use ordinary
+//! ratatui colors; the palette is a last step in the backend. Switch it at
+//! runtime via `Tui::set_color_quantize`:
+//!
+//! - `1` full color, `2` CGA 16-color, `3` green phosphor, `4` amber phosphor.
+//!
+//! Run with: `cargo run --example retro_palette`
+
+use bevy::prelude::*;
+use bevy_tui_texture::prelude::*;
+use bevy_tui_texture::Font as TerminalFont;
+use ratatui::prelude::*;
+use ratatui::style::Color as RatatuiColor;
+use ratatui::widgets::*;
+use std::sync::Arc;
+
+#[derive(Component)]
+struct RetroTerminal;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(TerminalPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, switch_palette.in_set(TerminalSystemSet::UserUpdate))
+        .add_systems(Update, render_terminal.in_set(TerminalSystemSet::Render))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    let font_data = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/assets/fonts/Mplus1Code-Regular.ttf"
+    ));
+    let fonts = Arc::new(Fonts::new(
+        TerminalFont::new(font_data).expect("Failed to parse font"),
+        20,
+    ));
+
+    commands.spawn((
+        TuiRequest::ui(56, 18, fonts).with_config(TerminalConfig {
+            color_quantize: Some(Palette::green_phosphor()),
+            keyboard: false,
+            mouse: false,
+            ..default()
+        }),
+        Node::default(),
+        RetroTerminal,
+    ));
+    commands.spawn(Camera2d);
+}
+
+/// Number keys pick the palette. Plain bevy keyboard input - the terminal
+/// itself has `keyboard: false`, so no focus is involved.
+fn switch_palette(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut screens: Query<&mut Tui, With<RetroTerminal>>,
+) {
+    let palette = if keys.just_pressed(KeyCode::Digit1) {
+        None
+    } else if keys.just_pressed(KeyCode::Digit2) {
+        Some(Palette::cga())
+    } else if keys.just_pressed(KeyCode::Digit3) {
+        Some(Palette::green_phosphor())
+    } else if keys.just_pressed(KeyCode::Digit4) {
+        Some(Palette::amber_phosphor())
+    } else {
+        return;
+    };
+    if let Ok(mut term) = screens.single_mut() {
+        term.set_color_quantize(palette);
+    }
+}
+
+fn render_terminal(time: Res<Time>, mut screens: Query<&mut Tui, With<RetroTerminal>>) {
+    let Ok(mut term) = screens.single_mut() else {
+        return;
+    };
+    let t = time.elapsed_secs();
+    term.draw(|frame| {
+        let block = Block::bordered()
+            .title(" SYSTEM MONITOR ")
+            .border_style(Style::default().fg(RatatuiColor::LightCyan));
+        let inner = block.inner(frame.area());
+        frame.render_widget(block, frame.area());
+
+        let [sweep_area, gauges_area, text_area, help_area] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(6),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(inner);
+
+        // A full hue sweep, to show how arbitrary RGB lands on the palette.
+        let width = sweep_area.width.max(1);
+        let sweep: Vec<Span> = (0..width)
+            .map(|x| {
+                let [r, g, b] = hue(x as f32 / width as f32);
+                Span::styled(" ", Style::default().bg(RatatuiColor::Rgb(r, g, b)))
+            })
+            .collect();
+        frame.render_widget(
+            Paragraph::new(vec![Line::from(sweep.clone()), Line::from(sweep)]),
+            sweep_area,
+        );
+
+        let gauges = Layout::vertical([Constraint::Length(2); 3]).split(gauges_area);
+        for (i, (label, color)) in [
+            ("CPU", RatatuiColor::LightRed),
+            ("MEM", RatatuiColor::Yellow),
+            ("NET", RatatuiColor::Blue),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let ratio = 0.5 + 0.45 * (t * (0.7 + i as f32 * 0.4)).sin();
+            frame.render_widget(
+                Gauge::default()
+                    .label(format!("{label} {:>3.0}%", ratio * 100.0))
+                    .gauge_style(Style::default().fg(color).bg(RatatuiColor::DarkGray))
+                    .ratio(ratio as f64),
+                gauges[i],
+            );
+        }
+
+        let text = vec![
+            Line::from(vec![
+                Span::styled("READY", Style::default().fg(RatatuiColor::Green).bold()),
+                Span::raw("  all systems nominal"),
+            ]),
+            Line::from(Span::styled(
+                "WARNING: reactor temperature rising",
+                Style::default().fg(RatatuiColor::Black).bg(RatatuiColor::LightYellow),
+            )),
+            Line::from(Span::styled("> _", Style::default().fg(RatatuiColor::Gray))),
+        ];
+        frame.render_widget(Paragraph::new(text), text_area);
+        frame.render_widget(
+            Paragraph::new("1 color  2 CGA  3 green  4 amber").fg(RatatuiColor::DarkGray),
+            help_area,
+        );
+    });
+}
+
+/// Fully saturated color at hue `h` (0..1).
+fn hue(h: f32) -> [u8; 3] {
+    let channel = |offset: f32| {
+        let k = (h * 6.0 + offset) % 6.0;
+        let v = 1.0 - (k.min(4.0 - k).clamp(0.0, 1.0));
+        (v * 255.0).round() as u8
+    };
+    [channel(5.0), channel(3.0), channel(1.0)]
+}
@@ -0,0 +1,136 @@
+//! `BevyTerminalBackend::set_grid` vs drawing the same grid through a
+//! ratatui `Frame` - a CPU-only benchmark, no window or GPU.
+//!
+//! Each iteration steps a Game of Life board and pushes the whole board
+//! into the backend, then flushes (shaping the changed rows), the same
+//! work `Tui::set_grid`/`Tui::draw` do every frame:
+//!
+//! - `frame`: write every cell into the `Frame`'s buffer, let ratatui diff
+//!   it against the previous frame and hand the changed cells to the
+//!   backend,
+//! - `set_grid`: hand the backend the board's cells directly; unchanged
+//!   rows are skipped.
+//!
+//! Run with: `cargo run --release --example set_grid_benchmark`
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use ratatui::backend::Backend;
+use ratatui::buffer::Cell;
+use ratatui::style::Color;
+
+use bevy_tui_texture::{BevyTerminalBackend, Font, Fonts, TerminalBuilder};
+
+const COLS: u16 = 160;
+const ROWS: u16 = 50;
+const ITERATIONS: u32 = 500;
+
+fn main() {
+    let font_data = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/assets/fonts/Mplus1Code-Regular.ttf"
+    ));
+    let fonts = Arc::new(Fonts::new(Font::new(font_data).expect("Failed to load font"), 16));
+    let backend = || -> BevyTerminalBackend {
+        TerminalBuilder::new(fonts.clone()).with_dimensions(COLS, ROWS).build()
+    };
+
+    let frame = {
+        let mut terminal = ratatui::Terminal::new(backend()).expect("terminal");
+        run(|cells| {
+            terminal
+                .draw(|frame| {
+                    let buffer = frame.buffer_mut();
+                    for (cell, target) in cells.iter().zip(buffer.content.iter_mut()) {
+                        *target = cell.clone();
+                    }
+                })
+                .expect("draw failed");
+        })
+    };
+
+    let set_grid = {
+        let mut backend = backend();
+        run(|cells| {
+            backend.set_grid(cells, COLS, ROWS).expect("set_grid failed");
+            backend.flush().expect("flush failed");
+        })
+    };
+
+    println!("{COLS}x{ROWS} board, {ITERATIONS} generations");
+    println!("  frame:    {:>8.1} µs/generation", per_iteration(frame));
+    println!("  set_grid: {:>8.1} µs/generation", per_iteration(set_grid));
+}
+
+/// Time `push` over `ITERATIONS` Game of Life generations.
+fn run(mut push: impl FnMut(&[Cell])) -> Duration {
+    let mut board = Board::glider_gun();
+    let mut cells = vec![Cell::default(); COLS as usize * ROWS as usize];
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        board.write_cells(&mut cells);
+        push(&cells);
+        board.step();
+    }
+    start.elapsed()
+}
+
+fn per_iteration(total: Duration) -> f64 {
+    total.as_secs_f64() * 1e6 / ITERATIONS as f64
+}
+
+struct Board {
+    alive: Vec<bool>,
+}
+
+impl Board {
+    /// Gosper's glider gun, so the board keeps changing without filling up.
+    fn glider_gun() -> Self {
+        #[rustfmt::skip]
+        const GUN: [(usize, usize); 36] = [
+            (1, 5), (1, 6), (2, 5), (2, 6), (11, 5), (11, 6), (11, 7), (12, 4),
+            (12, 8), (13, 3), (13, 9), (14, 3), (14, 9), (15, 6), (16, 4), (16, 8),
+            (17, 5), (17, 6), (17, 7), (18, 6), (21, 3), (21, 4), (21, 5), (22, 3),
+            (22, 4), (22, 5), (23, 2), (23, 6), (25, 1), (25, 2), (25, 6), (25, 7),
+            (35, 3), (35, 4), (36, 3), (36, 4),
+        ];
+        let mut alive = vec![false; COLS as usize * ROWS as usize];
+        for (x, y) in GUN {
+            alive[y * COLS as usize + x] = true;
+        }
+        Self { alive }
+    }
+
+    fn step(&mut self) {
+        let (cols, rows) = (COLS as isize, ROWS as isize);
+        let next = (0..rows)
+            .flat_map(|y| (0..cols).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let neighbours = (-1..=1)
+                    .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+                    .filter(|&(dx, dy)| (dx, dy) != (0, 0))
+                    .filter(|&(dx, dy)| {
+                        let (nx, ny) = (x + dx, y + dy);
+                        (0..cols).contains(&nx)
+                            && (0..rows).contains(&ny)
+                            && self.alive[(ny * cols + nx) as usize]
+                    })
+                    .count();
+                matches!((self.alive[(y * cols + x) as usize], neighbours), (true, 2) | (_, 3))
+            })
+            .collect();
+        self.alive = next;
+    }
+
+    fn write_cells(&self, cells: &mut [Cell]) {
+        for (cell, &alive) in cells.iter_mut().zip(&self.alive) {
+            cell.reset();
+            if alive {
+                cell.set_char('█').set_fg(Color::LightGreen);
+            } else {
+                cell.set_char('·').set_fg(Color::DarkGray);
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! `TerminalContent` — terminals drawn by the plugin, no render system.
+//!
+//! Two terminals show the same score. The left one is drawn the usual way:
+//! a system in `TerminalSystemSet::Render` queries `&mut Tui` and calls
+//! `draw`. The right one carries a `TerminalContent` instead; the plugin
+//! draws it every frame. Its renderer owns the spinner's state, and
+//! `update_content` pushes each new score in with `TerminalContent::set` -
+//! the renderer can't borrow the `Score` resource, so it gets a copy.
+//!
+//! - `Space` adds a point.
+//!
+//! Run with: `cargo run --example terminal_content`
+
+use bevy::prelude::*;
+use bevy_tui_texture::prelude::*;
+use bevy_tui_texture::Font as TerminalFont;
+use ratatui::prelude::*;
+use ratatui::style::Color as RatatuiColor;
+use ratatui::widgets::*;
+use std::sync::Arc;
+
+#[derive(Resource, Default)]
+struct Score(u32);
+
+#[derive(Component)]
+struct ManualTerminal;
+
+#[derive(Component)]
+struct DeclarativeTerminal;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(TerminalPlugin::display_only())
+        .init_resource::<Score>()
+        .add_systems(Startup, setup)
+        .add_systems(
+            Update,
+            (score_points, update_content.run_if(resource_changed::<Score>))
+                .chain()
+                .in_set(TerminalSystemSet::UserUpdate),
+        )
+        // Only the manual terminal needs a render system.
+        .add_systems(Update, render_manual.in_set(TerminalSystemSet::Render))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    let font_data = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/assets/fonts/Mplus1Code-Regular.ttf"
+    ));
+    let fonts = Arc::new(Fonts::new(
+        TerminalFont::new(font_data).expect("Failed to parse font"),
+        20,
+    ));
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::SpaceEvenly,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                TuiRequest::ui(30, 8, fonts.clone()),
+                Node::default(),
+                ManualTerminal,
+            ));
+            parent.spawn((
+                TuiRequest::ui(30, 8, fonts),
+                Node::default(),
+                // Replaced by `update_content` on the first frame, when
+                // `Score` counts as changed.
+                TerminalContent::new(scoreboard(0)),
+                DeclarativeTerminal,
+            ));
+        });
+    commands.spawn(Camera2d);
+}
+
+fn score_points(keys: Res<ButtonInput<KeyCode>>, mut score: ResMut<Score>) {
+    if keys.just_pressed(KeyCode::Space) {
+        score.0 += 1;
+    }
+}
+
+/// The manual approach: draw from a system, with full access to the ECS.
+fn render_manual(score: Res<Score>, mut screens: Query<&mut Tui, With<ManualTerminal>>) {
+    let Ok(mut term) = screens.single_mut() else {
+        return;
+    };
+    term.draw(|frame| {
+        let block = Block::bordered().title(" manual draw system ");
+        let text = vec![
+            Line::from(format!("score: {}", score.0)).bold(),
+            Line::from(""),
+            Line::from("Space adds a point".fg(RatatuiColor::DarkGray)),
+        ];
+        frame.render_widget(Paragraph::new(text).block(block), frame.area());
+    });
+}
+
+/// The declarative approach: hand the terminal a new renderer whenever the
+/// state it shows changes.
+fn update_content(
+    score: Res<Score>,
+    mut screens: Query<&mut TerminalContent, With<DeclarativeTerminal>>,
+) {
+    if let Ok(mut content) = screens.single_mut() {
+        content.set(scoreboard(score.0));
+    }
+}
+
+/// A renderer showing `score`, with an animation whose state lives in the
+/// closure itself.
+fn scoreboard(score: u32) -> impl FnMut(&mut Frame) + Send + Sync + 'static {
+    const SPINNER: [char; 4] = ['|', '/', '-', '\\'];
+    let mut frames = 0usize;
+    move |frame: &mut Frame| {
+        frames += 1;
+        let block = Block::bordered()
+            .title(" TerminalContent ")
+            .border_style(Style::default().fg(RatatuiColor::LightGreen));
+        let text = vec![
+            Line::from(format!("score: {score}")).bold(),
+            Line::from(""),
+            Line::from(format!("{} drawn by the plugin", SPINNER[frames / 8 % SPINNER.len()])),
+        ];
+        frame.render_widget(Paragraph::new(text).block(block), frame.area());
+    }
+}
@@ -0,0 +1,160 @@
+//! `TerminalGlow` — an emissive 3D terminal feeding bevy's bloom.
+//!
+//! - the screen is a `TuiRequest::world_quad` with `TerminalConfig::glow`
+//!   set, so its material takes the terminal image as `emissive_texture`,
+//! - the camera has `Bloom`, so text brighter than `1.0` bleeds light like
+//!   an old CRT,
+//! - `pulse_glow` animates the intensity every frame (a slow breathing
+//!   pulse plus a little flicker) just by writing the `TerminalGlow`
+//!   component,
+//! - press `Space` to switch the screen off and on (`TerminalGlow(0.0)`).
+//!
+//! Run with: `cargo run --example terminal_glow`
+//! (use `cargo run`, not the bare binary: asset/font paths resolve via
+//! `CARGO_MANIFEST_DIR`.)
+
+use bevy::post_process::bloom::Bloom;
+use bevy::prelude::*;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color as TuiColor, Modifier, Style};
+use ratatui::widgets::{Block, Paragraph};
+
+use bevy_tui_texture::prelude::*;
+
+const CAMERA_POS: Vec3 = Vec3::new(0.0, 1.0, 6.0);
+const SCREEN_POS: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+/// Peak intensity of the pulse - well above `1.0` so bloom picks it up.
+const GLOW: f32 = 2.5;
+
+fn main() {
+    App::new()
+        .add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: Some(Window {
+                        title: "TerminalGlow — emissive screen with bloom".to_string(),
+                        ..default()
+                    }),
+                    ..default()
+                })
+                .set(AssetPlugin {
+                    file_path: "examples/assets".into(),
+                    ..default()
+                }),
+        )
+        .add_plugins(TerminalPlugin::default())
+        .init_resource::<Power>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, (toggle_power, pulse_glow).chain())
+        .add_systems(Update, update_screen.in_set(TerminalSystemSet::UserUpdate))
+        .run();
+}
+
+/// Marker for the glowing screen entity.
+#[derive(Component)]
+struct Screen;
+
+/// Whether the screen is switched on.
+#[derive(Resource)]
+struct Power(bool);
+
+impl Default for Power {
+    fn default() -> Self {
+        Power(true)
+    }
+}
+
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Bloom needs an HDR camera; `Bloom` requires it, so no extra setup.
+    commands.spawn((
+        Camera3d::default(),
+        Bloom::NATURAL,
+        Transform::from_translation(CAMERA_POS).looking_at(SCREEN_POS, Vec3::Y),
+    ));
+    commands.spawn((
+        PointLight {
+            intensity: 200_000.0,
+            ..default()
+        },
+        Transform::from_xyz(3.0, 4.0, 3.0),
+    ));
+    // A dim floor for the glow to sit over.
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(12.0, 12.0))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgb(0.08, 0.08, 0.1),
+            ..default()
+        })),
+    ));
+
+    commands.spawn((
+        TuiRequest::world_quad(
+            32,
+            10,
+            TuiFontSource::Asset {
+                handle: asset_server.load("fonts/Mplus1Code-Regular.ttf"),
+                size_px: 32,
+            },
+            1.6,
+        )
+        .with_config(TerminalConfig {
+            glow: Some(GLOW),
+            ..default()
+        }),
+        Transform::from_translation(SCREEN_POS),
+        Screen,
+    ));
+}
+
+fn toggle_power(keys: Res<ButtonInput<KeyCode>>, mut power: ResMut<Power>) {
+    if keys.just_pressed(KeyCode::Space) {
+        power.0 = !power.0;
+    }
+}
+
+/// Breathing pulse plus flicker, written straight into `TerminalGlow`; the
+/// plugin pushes each change into the screen's material.
+fn pulse_glow(time: Res<Time>, power: Res<Power>, mut screens: Query<&mut TerminalGlow, With<Screen>>) {
+    let t = time.elapsed_secs();
+    let intensity = if power.0 {
+        let pulse = 0.8 + 0.2 * (t * 1.5).sin();
+        let flicker = 1.0 - 0.05 * (t * 37.0).sin().max(0.0);
+        GLOW * pulse * flicker
+    } else {
+        0.0
+    };
+    for mut glow in &mut screens {
+        glow.0 = intensity;
+    }
+}
+
+fn update_screen(power: Res<Power>, mut screens: Query<&mut Tui, With<Screen>>) {
+    let Ok(mut tui) = screens.single_mut() else {
+        return;
+    };
+    let state = if power.0 { "ON " } else { "OFF" };
+    tui.draw(|frame| {
+        let [body, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let green = Style::default().fg(TuiColor::Rgb(90, 255, 120));
+        frame.render_widget(
+            Paragraph::new(vec![
+                "READY.".into(),
+                "> RUN GLOW".into(),
+                format!("> POWER {state}").into(),
+            ])
+            .style(green.add_modifier(Modifier::BOLD))
+            .block(Block::bordered().title(" TerminalGlow ").style(green)),
+            body,
+        );
+        frame.render_widget(
+            Paragraph::new(" Space: power").style(Style::default().fg(TuiColor::Gray)),
+            footer,
+        );
+    });
+}
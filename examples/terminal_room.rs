@@ -0,0 +1,114 @@
+//! A "terminal room" — six `TuiKind::WorldQuad` terminals lining the inside
+//! of a cube, placed with `RoomFace::transform`.
+//!
+//! - the camera stands in the middle of the room and slowly looks around,
+//! - each face's grid is picked to be (close to) square, so the walls meet
+//!   at the room's edges,
+//! - hover any wall with the mouse: hit-testing works as on any other world
+//!   quad, and the hovered cell is shown on that wall.
+//!
+//! Run with: `cargo run --example terminal_room`
+
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use bevy_tui_texture::Font as TerminalFont;
+use bevy_tui_texture::prelude::*;
+use ratatui::prelude::*;
+use ratatui::style::Color as RatatuiColor;
+use ratatui::widgets::*;
+
+/// Distance from the room's center to each wall.
+const HALF_EXTENT: f32 = 4.0;
+const ROWS: u16 = 16;
+
+/// Which wall a terminal is, plus the last cell the mouse was over.
+#[derive(Component)]
+struct Wall {
+    face: RoomFace,
+    hovered: Option<(u16, u16)>,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Terminal room — RoomFace".to_string(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(TerminalPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, look_around)
+        .add_systems(Update, track_hover.in_set(TerminalSystemSet::UserUpdate))
+        .add_systems(Update, draw_walls.in_set(TerminalSystemSet::Render))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn((Camera3d::default(), Transform::default()));
+
+    let font_data = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/assets/fonts/Mplus1Code-Regular.ttf"
+    ));
+    let fonts = Arc::new(Fonts::new(
+        TerminalFont::new(font_data).expect("Failed to parse font"),
+        24,
+    ));
+    // Enough columns for a square texture, so the quads close the cube.
+    let cols = (ROWS as u32 * fonts.height_px()).div_ceil(fonts.min_width_px()) as u16;
+
+    for face in RoomFace::ALL {
+        commands.spawn((
+            TuiRequest::world_quad(cols, ROWS, fonts.clone(), 2.0 * HALF_EXTENT),
+            face.transform(HALF_EXTENT),
+            Wall {
+                face,
+                hovered: None,
+            },
+        ));
+    }
+}
+
+/// Slow yaw with a gentle nod, so the floor and ceiling come into view.
+fn look_around(time: Res<Time>, mut cameras: Query<&mut Transform, With<Camera3d>>) {
+    let t = time.elapsed_secs();
+    for mut transform in &mut cameras {
+        transform.rotation =
+            Quat::from_rotation_y(t * 0.25) * Quat::from_rotation_x((t * 0.4).sin() * 0.9);
+    }
+}
+
+fn track_hover(mut events: MessageReader<TerminalEvent>, mut walls: Query<&mut Wall>) {
+    for event in events.read() {
+        let InputEvent::Mouse(mouse) = &event.input else {
+            continue;
+        };
+        let Ok(mut wall) = walls.get_mut(event.target) else {
+            continue;
+        };
+        wall.hovered = Some((mouse.column, mouse.row));
+    }
+}
+
+fn draw_walls(mut walls: Query<(&mut Tui, &Wall)>) {
+    for (mut tui, wall) in &mut walls {
+        let hovered = match wall.hovered {
+            Some((col, row)) => format!("mouse at {col},{row}"),
+            None => "hover me".to_string(),
+        };
+        tui.draw(|frame| {
+            let text = Paragraph::new(vec![
+                Line::from(format!("{:?}", wall.face)).bold(),
+                Line::from(""),
+                Line::from(hovered),
+            ])
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(RatatuiColor::LightGreen))
+            .block(Block::bordered().border_style(Style::default().fg(RatatuiColor::Cyan)));
+            frame.render_widget(text, frame.area());
+        });
+    }
+}
@@ -7,6 +7,9 @@
 //! everywhere except the bordered panel (which sets an explicit
 //! background) and the text itself.
 //!
+//! Press `D` to toggle `TerminalDebug::transparency_checkerboard`, which
+//! paints the transparent cells with a gray checkerboard instead.
+//!
 //! Run with: `cargo run --example transparent_world_quad`
 
 use bevy::prelude::*;
@@ -34,7 +37,8 @@ fn main() {
         }))
         .add_plugins(TerminalPlugin::default())
         .add_systems(Startup, setup)
-        .add_systems(Update, rotate_cube)
+        .init_resource::<TerminalDebug>()
+        .add_systems(Update, (rotate_cube, toggle_checkerboard))
         .add_systems(Update, render_terminal.in_set(TerminalSystemSet::Render))
         .run();
 }
@@ -95,6 +99,12 @@ fn rotate_cube(time: Res<Time>, mut cubes: Query<&mut Transform, With<Spinning>>
     }
 }
 
+fn toggle_checkerboard(keys: Res<ButtonInput<KeyCode>>, mut debug: ResMut<TerminalDebug>) {
+    if keys.just_pressed(KeyCode::KeyD) {
+        debug.transparency_checkerboard = !debug.transparency_checkerboard;
+    }
+}
+
 fn render_terminal(mut screens: Query<&mut Tui, With<ScreenTerminal>>) {
     let Ok(mut term) = screens.single_mut() else {
         return;
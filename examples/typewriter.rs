@@ -0,0 +1,136 @@
+//! `TypewriterTerminal` — dialogue revealed character by character.
+//!
+//! A dialogue box types out one line at a time. `Space` skips to the end
+//! of the current line, or advances to the next once it is fully shown;
+//! `TypewriterFinished` switches the prompt on when a line completes.
+//!
+//! Run with: `cargo run --example typewriter`
+
+use bevy::prelude::*;
+use bevy_tui_texture::prelude::*;
+use bevy_tui_texture::Font as TerminalFont;
+use ratatui::style::{Color as RatatuiColor, Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use std::sync::Arc;
+
+const CHARS_PER_SECOND: f32 = 30.0;
+
+#[derive(Component)]
+struct DialogueBox;
+
+#[derive(Component)]
+struct Prompt;
+
+#[derive(Resource, Default)]
+struct Dialogue {
+    line: usize,
+}
+
+fn dialogue_line(index: usize) -> Option<Text<'static>> {
+    let lines: [Text<'static>; 3] = [
+        Text::from(vec![
+            Line::from(Span::styled("ARCHIVIST", Style::default().fg(RatatuiColor::Yellow).bold())),
+            Line::from("You made it. The storm knocked out every relay between here and the coast."),
+        ]),
+        Text::from(vec![
+            Line::from(Span::styled("ARCHIVIST", Style::default().fg(RatatuiColor::Yellow).bold())),
+            Line::from("All but one. Its last message is still in the buffer - "),
+            Line::from("THE SIGNAL IS NOT ORIGINATING FROM THE TOWER.".red()),
+        ]),
+        Text::from(vec![
+            Line::from(Span::styled("ARCHIVIST", Style::default().fg(RatatuiColor::Yellow).bold())),
+            Line::from("We leave at dawn."),
+        ]),
+    ];
+    lines.into_iter().nth(index)
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(TerminalPlugin::display_only())
+        .init_resource::<Dialogue>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, advance_dialogue.in_set(TerminalSystemSet::UserUpdate))
+        .add_systems(Update, show_prompt.in_set(TerminalSystemSet::Render))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    let font_data = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/assets/fonts/Mplus1Code-Regular.ttf"
+    ));
+    let fonts = Arc::new(Fonts::new(
+        TerminalFont::new(font_data).expect("Failed to parse font"),
+        20,
+    ));
+
+    commands.spawn(Camera2d);
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::End,
+            align_items: AlignItems::Center,
+            padding: UiRect::bottom(Val::Px(40.0)),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                TuiRequest::ui(48, 5, fonts.clone()),
+                Node::default(),
+                DialogueBox,
+                TypewriterTerminal::new(
+                    dialogue_line(0).expect("the dialogue has a first line"),
+                    CHARS_PER_SECOND,
+                ),
+            ));
+            parent.spawn((TuiRequest::ui(48, 1, fonts), Node::default(), Prompt));
+        });
+}
+
+/// `Space`: skip the running line, or start the next finished one.
+fn advance_dialogue(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut dialogue: ResMut<Dialogue>,
+    mut boxes: Query<&mut TypewriterTerminal, With<DialogueBox>>,
+) {
+    if !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+    let Ok(mut typewriter) = boxes.single_mut() else {
+        return;
+    };
+    if !typewriter.is_finished() {
+        typewriter.skip();
+        return;
+    }
+    dialogue.line += 1;
+    if let Some(text) = dialogue_line(dialogue.line) {
+        typewriter.set_text(text);
+    }
+}
+
+/// Redraws the prompt line when a line finishes or the next one starts.
+fn show_prompt(
+    mut finished: MessageReader<TypewriterFinished>,
+    dialogue: Res<Dialogue>,
+    boxes: Query<&TypewriterTerminal, (With<DialogueBox>, Changed<TypewriterTerminal>)>,
+    mut prompts: Query<&mut Tui, With<Prompt>>,
+) {
+    let line_done = finished.read().count() > 0;
+    if !line_done && boxes.is_empty() {
+        return;
+    }
+    let Ok(mut prompt) = prompts.single_mut() else {
+        return;
+    };
+    let text = match (line_done, dialogue_line(dialogue.line + 1).is_some()) {
+        (false, _) => "",
+        (true, true) => "                              [Space] continue",
+        (true, false) => "                                         [End]",
+    };
+    prompt.draw(|frame| frame.render_widget(text.dark_gray(), frame.area()));
+}
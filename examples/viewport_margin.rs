@@ -0,0 +1,110 @@
+//! `TerminalConfig::viewport` — an undrawn margin inside the texture.
+//!
+//! The terminal's texture is 48x14 cells, but `Viewport::Shrink` takes an
+//! inset off its right and bottom edges: ratatui's `frame.area()` only
+//! covers what is left, and the margin shows the `initial_fill` color.
+//! Press `M` to toggle the margin at runtime via `Tui::set_viewport`.
+//!
+//! Run with: `cargo run --example viewport_margin`
+
+use bevy::prelude::*;
+use bevy_tui_texture::prelude::*;
+use bevy_tui_texture::{Font as TerminalFont, Viewport};
+use ratatui::prelude::*;
+use ratatui::style::Color as RatatuiColor;
+use ratatui::widgets::*;
+use std::sync::Arc;
+
+/// Margin color - everything outside the shrunk viewport.
+const MARGIN_FILL: [u8; 4] = [40, 20, 60, 255];
+
+#[derive(Component)]
+struct MarginTerminal;
+
+/// The shrunk viewport, kept around for the runtime toggle.
+#[derive(Resource)]
+struct MarginViewport(Viewport);
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(TerminalPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, toggle_margin.in_set(TerminalSystemSet::UserUpdate))
+        .add_systems(Update, render_terminal.in_set(TerminalSystemSet::Render))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    let font_data = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/assets/fonts/Mplus1Code-Regular.ttf"
+    ));
+    let fonts = Arc::new(Fonts::new(
+        TerminalFont::new(font_data).expect("Failed to parse font"),
+        16,
+    ));
+    let viewport = margin_viewport(&fonts);
+    commands.insert_resource(MarginViewport(viewport));
+
+    commands.spawn((
+        TuiRequest::ui(48, 14, fonts).with_config(TerminalConfig {
+            viewport,
+            initial_fill: MARGIN_FILL,
+            keyboard: false,
+            mouse: false,
+            ..default()
+        }),
+        Node::default(),
+        MarginTerminal,
+    ));
+    commands.spawn(Camera2d);
+}
+
+/// Four columns and two rows of margin. The inset is in pixels, so it is
+/// derived from the font's cell size.
+fn margin_viewport(fonts: &Fonts) -> Viewport {
+    Viewport::Shrink {
+        width: 4 * fonts.min_width_px(),
+        height: 2 * fonts.height_px(),
+    }
+}
+
+/// `M` toggles between the shrunk and the full viewport. Plain bevy
+/// keyboard input - the terminal itself has `keyboard: false`.
+fn toggle_margin(
+    keys: Res<ButtonInput<KeyCode>>,
+    margin: Res<MarginViewport>,
+    mut shrunk: Local<Option<bool>>,
+    mut screens: Query<&mut Tui, With<MarginTerminal>>,
+) {
+    if !keys.just_pressed(KeyCode::KeyM) {
+        return;
+    }
+    let Ok(mut term) = screens.single_mut() else {
+        return;
+    };
+    let on = !shrunk.unwrap_or(true);
+    *shrunk = Some(on);
+    term.set_viewport(if on { margin.0 } else { Viewport::Full });
+}
+
+fn render_terminal(mut screens: Query<&mut Tui, With<MarginTerminal>>) {
+    let Ok(mut term) = screens.single_mut() else {
+        return;
+    };
+    term.draw(|frame| {
+        let area = frame.area();
+        let text = format!(
+            "frame.area() is {}x{} cells\n\npress M to toggle the margin",
+            area.width, area.height
+        );
+        frame.render_widget(
+            Paragraph::new(text)
+                .fg(RatatuiColor::White)
+                .bg(RatatuiColor::Rgb(20, 30, 50))
+                .block(Block::bordered().title("viewport")),
+            area,
+        );
+    });
+}
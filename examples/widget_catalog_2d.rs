@@ -163,8 +163,8 @@ fn handle_terminal_events(
         Err(_) => return,
     };
 
-    for event in events.read().filter(|e| e.target == terminal_entity) {
-        match &event.input {
+    for input in events.for_terminal(terminal_entity) {
+        match input {
             InputEvent::Mouse(m) if matches!(m.kind, MouseEventKind::Moved | MouseEventKind::Drag(_)) => {
                 state.mouse_position = Some((m.column, m.row));
             }
@@ -175,7 +175,7 @@ fn handle_terminal_events(
 
                 info!(
                     "2D Mouse Press: col={}, row={}, target={:?}",
-                    col, row, event.target
+                    col, row, terminal_entity
                 );
 
                 // Tab detection (still needs manual calculation as tabs are not stored)
@@ -15,6 +15,7 @@ use std::time::Duration;
 use rand::Rng as _;
 use tracing::info;
 
+use bevy::image::{ImageSampler, ImageSamplerDescriptor};
 use bevy::prelude::*;
 use bevy::window::WindowResolution;
 use ratatui::prelude::*;
@@ -108,10 +109,23 @@ fn setup_terminal(mut commands: Commands) {
     // rotation formula vs. the old Y-normal `SimpleTerminal3D` plane).
     // height chosen to match the original pixel-sized plane
     // (ROWS * char_height_px) so the on-screen scale is unchanged.
+    //
+    // As the plane swings edge-on, the terminal image is squeezed along
+    // one axis; with the default sampler the hard edges between cell
+    // backgrounds shimmer as it turns. An anisotropic sampler (wgpu wants
+    // every filter linear for it) smooths that out.
     let char_height_px = fonts.height_px();
+    let texture_sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
+        anisotropy_clamp: 16,
+        ..ImageSamplerDescriptor::linear()
+    });
     let terminal_entity = commands
         .spawn((
-            TuiRequest::world_quad(COLS, ROWS, fonts, ROWS as f32 * char_height_px as f32),
+            TuiRequest::world_quad(COLS, ROWS, fonts, ROWS as f32 * char_height_px as f32)
+                .with_config(TerminalConfig {
+                    texture_sampler,
+                    ..default()
+                }),
             Transform::from_translation(Vec3::ZERO),
             RotatingPlane,
         ))
@@ -151,8 +165,8 @@ fn handle_terminal_events(
         Err(_) => return,
     };
 
-    for event in events.read().filter(|e| e.target == terminal_entity) {
-        match &event.input {
+    for input in events.for_terminal(terminal_entity) {
+        match input {
             InputEvent::Mouse(m) if matches!(m.kind, MouseEventKind::Moved | MouseEventKind::Drag(_)) => {
                 ui_state.mouse_position = Some((m.column, m.row));
             }
@@ -163,7 +177,7 @@ fn handle_terminal_events(
 
                 info!(
                     "3D Mouse Press: col={}, row={}, target={:?}",
-                    col, row, event.target
+                    col, row, terminal_entity
                 );
 
                 // Tab detection (manual calculation as tabs are not stored)
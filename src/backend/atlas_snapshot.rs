@@ -0,0 +1,57 @@
+//! Portable glyph atlas contents, for skipping glyph warmup at startup.
+//!
+//! [`BevyTerminalBackend::export_atlas`](super::bevy_backend::BevyTerminalBackend::export_atlas)
+//! captures rasterized glyphs as an [`AtlasSnapshot`];
+//! [`BevyTerminalBackend::import_atlas`](super::bevy_backend::BevyTerminalBackend::import_atlas)
+//! puts them back into another process's atlas without rasterizing
+//! anything. The typical use is a build step (or a first launch) that
+//! calls `populate_programmatic_glyphs` plus whatever warmup draws the app
+//! wants, exports, and embeds the result - every later launch (or WASM
+//! cold start) imports it instead.
+//!
+//! A snapshot stores glyphs by font *position* in the `Fonts` fallback
+//! order (last-resort first, then regular, bold, italic, bold-italic), plus
+//! a per-font fingerprint - a hash of the font file's contents - and the
+//! cell size. Font ids themselves are per-process hashes, so they can't be
+//! stored. Importing into a `Fonts` built from different font files, or at
+//! another size, restores nothing.
+//!
+//! With the `serde` feature, `AtlasSnapshot` implements `Serialize` and
+//! `Deserialize`.
+
+/// Rasterized glyphs exported from one terminal's atlas - see the
+/// [module docs](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AtlasSnapshot {
+    /// `Fonts::min_width_px` / `height_px` at export time.
+    pub(crate) cell_width: u32,
+    pub(crate) cell_height: u32,
+    /// Content hash (`Font::fingerprint`) of each font, in fallback order.
+    pub(crate) fonts: Vec<u64>,
+    pub(crate) glyphs: Vec<AtlasSnapshotGlyph>,
+}
+
+/// One glyph's atlas key (with its font as an index into
+/// `AtlasSnapshot::fonts`) and its RGBA8 pixels, `width x cell_height`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct AtlasSnapshotGlyph {
+    pub(crate) style: u16,
+    pub(crate) glyph: u32,
+    pub(crate) font: u32,
+    pub(crate) width: u32,
+    pub(crate) pixels: Vec<u32>,
+}
+
+impl AtlasSnapshot {
+    /// Number of glyphs in the snapshot.
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    /// `true` if the snapshot holds no glyphs.
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+}
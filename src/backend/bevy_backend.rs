@@ -1,18 +1,97 @@
+use std::borrow::Cow;
 use std::sync::Arc;
+use std::time::Duration;
 
+use crate::backend::atlas_snapshot::AtlasSnapshot;
+use crate::backend::programmatic_glyphs::PowerlineStyle;
 use crate::backend::rasterize::rasterize_glyph;
+use crate::backend::c2c;
 use crate::backend::TextBgVertexMember;
 use crate::backend::TextVertexMember;
 use crate::backend::Viewport;
-use crate::colors::Rgb;
-use crate::fonts::Fonts;
-use crate::utils::text_atlas::Key;
+use crate::backend::Padding;
+use crate::backend::ChromeConfig;
+use crate::backend::CursorStyle;
+use crate::backend::RenderStats;
+use crate::backend::TextExtractOptions;
+use crate::backend::OutputMode;
+use crate::colors::{Palette, Rgb};
+use crate::fonts::{FallbackFont, Fonts};
+use crate::utils::text_atlas::{Entry, Key};
 use ratatui::buffer::Cell;
 use ratatui::text::Line;
 use rustybuzz::UnicodeBuffer;
 
 const NULL_CELL: Cell = Cell::new("");
 
+/// A hook editing each cell on its way into the backend, given the cell's
+/// `(x, y)` - see [`BevyTerminalBackend::set_cell_filter`].
+pub type CellFilter = Box<dyn Fn(&mut Cell, u16, u16) + Send + Sync>;
+
+/// Stand-in symbol for cells whose content can't be rendered in one cell
+/// (see `is_renderable_symbol`).
+const REPLACEMENT_SYMBOL: &str = "\u{FFFD}";
+
+/// The Unicode Control Pictures glyph (`␀`..`␟`, `␡` for DEL) standing
+/// for a cell whose symbol is a lone C0 control character or DEL, or
+/// `None` for any other symbol - see
+/// [`TerminalBuilder::with_control_pictures`].
+fn control_picture(symbol: &str) -> Option<char> {
+    let mut chars = symbol.chars();
+    let (Some(ch), None) = (chars.next(), chars.next()) else {
+        return None;
+    };
+    match ch {
+        '\0'..='\u{1f}' => char::from_u32(0x2400 + ch as u32),
+        '\u{7f}' => Some('\u{2421}'),
+        _ => None,
+    }
+}
+
+/// Symbol of a cell holding a literal tab. Never stored in the grid:
+/// `draw()` expands it into blank cells up to the next tab stop instead -
+/// see `BevyTerminalBackend::lay_out_tab_row`.
+const TAB_SYMBOL: &str = "\t";
+
+/// Screen column of each cell in a row once tabs are expanded, plus one
+/// trailing entry for the column just past the last cell. A tab cell
+/// reaches to the next multiple of `tab_width` (terminal tab stops) and
+/// every cell after it shifts right by however much it grew, possibly past
+/// the row's end. `None` when the row holds no tab, in which case every
+/// cell sits at its own index.
+fn tab_expanded_columns(row_cells: &[Cell], tab_width: u16) -> Option<Vec<usize>> {
+    if !row_cells.iter().any(|cell| cell.symbol() == TAB_SYMBOL) {
+        return None;
+    }
+    let tab_width = tab_width.max(1) as usize;
+    let mut columns = Vec::with_capacity(row_cells.len() + 1);
+    let mut column = 0;
+    for cell in row_cells {
+        columns.push(column);
+        column = if cell.symbol() == TAB_SYMBOL {
+            (column / tab_width + 1) * tab_width
+        } else {
+            column + 1
+        };
+    }
+    columns.push(column);
+    Some(columns)
+}
+
+/// `true` if `symbol` is something the render path can place in a single
+/// cell: empty (a wide glyph's continuation), or exactly one extended
+/// grapheme cluster - a base character plus whatever Unicode attaches to
+/// it (combining and spacing marks, selectors, ZWJ sequences, skin-tone
+/// modifiers, regional-indicator pairs). Control characters and runs of
+/// several clusters (`"ab"`, from a widget writing a whole string into one
+/// cell) are not - they would be shaped as several glyphs stacked on one
+/// cell and, worse, widen the cell over its neighbours.
+fn is_renderable_symbol(symbol: &str) -> bool {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    !symbol.chars().any(char::is_control) && symbol.graphemes(true).nth(1).is_none()
+}
+
 /// Cached geometry for one row, from the last flush that (re)generated it.
 /// Reused verbatim by a later flush when the row is clean (`dirty_rows[y]
 /// == false`) and the atlas hasn't reassigned any slot since - see
@@ -53,12 +132,27 @@ pub struct BevyTerminalBackend {
     pub(super) cells: Vec<Cell>,
     pub(super) dirty_rows: Vec<bool>,
     /// Set by `draw()` when ratatui's internal buffer diff contained at
-    /// least one changed cell; cleared at the start of every `draw()` call.
+    /// least one changed cell (or an `invalidate_all` is pending); cleared
+    /// at the start of every `draw()` call.
     /// Lets `Tui::draw` skip marking itself dirty (and thus skip the GPU
     /// render + copy) when a redraw produces byte-identical content.
     pub(super) cells_changed_last_draw: bool,
     pub(super) cursor: (u16, u16),
+    /// Whether ratatui last asked for the cursor to be shown - the block
+    /// cursor is drawn at `cursor` only while this is set.
+    pub(super) cursor_visible: bool,
+    /// Colors of the block cursor - see [`TerminalBuilder::with_cursor_style`].
+    pub(super) cursor_style: CursorStyle,
     pub(super) viewport: Viewport,
+    /// Pixel margin around the grid - see [`TerminalBuilder::with_padding`].
+    /// Applied in `take_draw_payload`, not `shape_row`: the cached row
+    /// geometry stays in grid space and is translated as it is copied out.
+    pub(super) padding: Padding,
+    /// Border drawn around the content - see [`TerminalBuilder::with_chrome`].
+    pub(super) chrome: Option<ChromeConfig>,
+    /// `chrome`'s ring of cells and the grid size it was laid out for;
+    /// rebuilt when either changes.
+    chrome_ring: Option<((u16, u16), Vec<(u16, u16, Cell)>)>,
     /// Rows needing re-render, accumulated across flushes since the last
     /// `take_draw_payload`: each `flush()` unions ratatui's dirty rows in;
     /// `take_draw_payload` consumes and clears it. Rows reshaped in
@@ -76,6 +170,11 @@ pub struct BevyTerminalBackend {
     /// produces a full payload (`TerminalDrawPayload::load_previous ==
     /// false`) instead of one covering only dirty rows.
     pub(super) full_redraw_needed: bool,
+    /// Set by [`BevyTerminalBackend::invalidate_all`]: the next `draw()`
+    /// reports `cells_changed_last_draw` even if ratatui's diff is empty,
+    /// so the following `flush()` (and `Tui::flush`) rebuild every row
+    /// from `cells` instead of early-returning on an unchanged frame.
+    pub(super) reshape_pending: bool,
 
     // ====== Font management (Arc, no lifetime) ======
     // `cached` (Atlas), `plan_cache`, and `pending_cache_updates` used to
@@ -130,6 +229,145 @@ pub struct BevyTerminalBackend {
     /// color whenever a draw payload's vertex data is empty. Carried on
     /// every `TerminalDrawPayload` as `clear_color` (see `take_draw_payload`).
     pub(super) initial_fill: [u8; 4],
+    /// Color of the optional 1px separators drawn between every pair of
+    /// adjacent cells ("table mode"), or `None` for no grid. Emitted by
+    /// `shape_row` as part of each row's background quads, so it is a
+    /// global overlay independent of any box-drawing glyphs in the cells.
+    pub(super) cell_grid: Option<Rgb>,
+    /// `(top, bottom)` colors blended down the grid in place of `reset_bg`,
+    /// or `None` for the flat `reset_bg`. Applies only to cells whose
+    /// effective background is `Color::Reset` (after `REVERSED`), so
+    /// explicitly colored cells still draw over it. Stepped per row - each
+    /// row's cells get the color at the row's vertical center - because
+    /// bg vertex colors are flat-interpolated; like `transparent_reset_bg`
+    /// this is purely vertex-color packing, with no shader change. The
+    /// alpha still follows `transparent_reset_bg`, and the padding
+    /// letterbox (`take_draw_payload`) keeps the flat `reset_bg`.
+    pub(super) background_gradient: Option<(Rgb, Rgb)>,
+    /// Columns between tab stops - see [`TerminalBuilder::with_tab_width`].
+    pub(super) tab_width: u16,
+    /// Whether `draw()` shows lone control characters as their Control
+    /// Pictures glyph instead of U+FFFD - see
+    /// [`TerminalBuilder::with_control_pictures`].
+    pub(super) control_pictures: bool,
+    /// Horizontal and vertical scale of every glyph quad within its cell -
+    /// see [`TerminalBuilder::with_glyph_scale`].
+    pub(super) glyph_scale: (f32, f32),
+    /// Pixels of breathing room between each glyph and its cell's edges -
+    /// see [`TerminalBuilder::with_cell_inset`].
+    pub(super) cell_inset: u32,
+    /// Whether cells with a not-fully-opaque background show a checkerboard
+    /// instead - see [`TerminalBuilder::with_transparency_checkerboard`].
+    pub(super) transparency_checkerboard: bool,
+    /// Modifiers OR'd into every cell's own when its row is shaped - see
+    /// [`TerminalBuilder::with_default_modifier`].
+    pub(super) default_modifier: ratatui::style::Modifier,
+    /// Underline color for cells that don't carry their own, or `None`
+    /// for the text color - see [`TerminalBuilder::with_underline_color`].
+    pub(super) underline_color: Option<Rgb>,
+    /// Strikethrough color, or `None` for the text color - see
+    /// [`TerminalBuilder::with_strikethrough_color`].
+    pub(super) strikethrough_color: Option<Rgb>,
+    /// Override for the 16 ANSI colors (named `Color`s and
+    /// `Color::Indexed(0..16)`), or `None` for the built-in table - see
+    /// [`BevyTerminalBackend::set_palette`].
+    pub(super) palette: Option<[Rgb; 16]>,
+    /// Palette every resolved color is snapped to, or `None` for full
+    /// color - see [`TerminalBuilder::with_color_quantize`].
+    pub(super) color_quantize: Option<Palette>,
+    /// Whether shaping applies the font's ligatures - see
+    /// [`TerminalBuilder::with_ligatures`].
+    pub(super) ligatures: bool,
+    /// Applied to every incoming cell before it is stored - see
+    /// [`BevyTerminalBackend::set_cell_filter`].
+    pub(super) cell_filter: Option<CellFilter>,
+    /// Glyph and color drawn in blank cells, or `None` to leave them
+    /// blank - see [`TerminalBuilder::with_empty_cell`].
+    pub(super) empty_cell: Option<(char, Rgb)>,
+    /// Most glyphs one `flush()` rasterizes, or `None` for no limit - see
+    /// [`TerminalBuilder::with_glyph_budget`].
+    pub(super) glyph_budget: Option<u32>,
+    /// Full color or coverage only - see
+    /// [`TerminalBuilder::with_output_mode`].
+    pub(super) output_mode: OutputMode,
+    /// Radius, in pixels, of the texture's rounded corners (`0`: square) -
+    /// see [`TerminalBuilder::with_corner_radius`].
+    pub(super) corner_radius: u32,
+    /// Factor text colors are scaled by in the compositor (`1.0`: as
+    /// given) - see [`TerminalBuilder::with_text_brightness`].
+    pub(super) text_brightness: f32,
+    /// Glyphs rasterized by the current `flush()`, against `glyph_budget`.
+    glyphs_rasterized_this_flush: u32,
+    /// The last `flush()` ran out of `glyph_budget` and left some glyphs
+    /// blank - their rows are marked dirty and `reshape_pending` is set.
+    glyphs_deferred: bool,
+    /// Set by [`BevyTerminalBackend::invalidate_all`] until the next
+    /// `flush()` ends: the glyphs that flush has re-rasterized so far. A
+    /// glyph already in the shared atlas is rasterized again into its own
+    /// slot the first time this terminal meets it, instead of being reused.
+    rerasterize: Option<std::collections::HashSet<Key>>,
+    /// Requested MSAA sample count, `1` for none - see
+    /// [`TerminalBuilder::with_msaa`].
+    pub(super) msaa_samples: u32,
+    /// How long a newly changed cell takes to fade its foreground in from
+    /// transparent, or `None` for no fade - see
+    /// [`TerminalBuilder::with_cell_fade`].
+    pub(super) cell_fade: Option<Duration>,
+    /// When each cell (same indexing as `cells`) last received new
+    /// content, while it is still fading in; `None` once fully shown.
+    /// Empty unless `cell_fade` is set.
+    pub(super) cell_changed_at: Vec<Option<FadeStart>>,
+    /// Cells ratatui placed under the right half of a character drawn
+    /// wider than ratatui measures it (ambiguous width 2, width
+    /// overrides), keyed by index into `cells`. They stay covered while
+    /// that character is there and come back when it is replaced -
+    /// ratatui's diff never resends them, as it never knew they were
+    /// covered.
+    pub(super) widened_over: std::collections::HashMap<usize, Cell>,
+    /// Rows holding a literal tab, keyed by row: their cells as ratatui
+    /// drew them. `cells` holds these rows laid out - tabs expanded into
+    /// blanks, the rest shifted right - so everything reading the grid
+    /// (shaping, hit-testing, `get_text`) sees what is on screen, and each
+    /// change to the row is laid out again from this copy.
+    pub(super) tab_rows: std::collections::HashMap<usize, Vec<Cell>>,
+    /// How long each on and off phase of `Modifier::RAPID_BLINK` and
+    /// `Modifier::SLOW_BLINK` cells lasts - see
+    /// [`TerminalBuilder::with_blink_rates`].
+    pub(super) blink_rates: (Duration, Duration),
+    /// Whether rapid / slow blinking cells are in their "on" phase.
+    show_fast: bool,
+    show_slow: bool,
+    /// When the rapid / slow phase last flipped, or `None` until the first
+    /// [`BevyTerminalBackend::tick_blink`] starts the clocks.
+    /// Both are app time, as passed to `tick_blink`.
+    last_fast_toggle: Option<Duration>,
+    last_slow_toggle: Option<Duration>,
+    /// Geometry of the programmatic powerline separators - see
+    /// [`TerminalBuilder::with_powerline_style`].
+    pub(super) powerline_style: PowerlineStyle,
+    /// Supersampling factor for programmatic glyphs, `1` for none - see
+    /// [`TerminalBuilder::with_programmatic_supersampling`].
+    pub(super) programmatic_supersample: u32,
+    /// Whether `shape_row` records the characters it could not draw into
+    /// `missing_glyphs` - see [`TerminalBuilder::with_missing_glyph_tracking`].
+    pub(super) track_missing_glyphs: bool,
+    /// Every character that shaped to `.notdef` (glyph id 0) in all fonts
+    /// of the fallback chain, or whose programmatic glyph failed to render,
+    /// since tracking was enabled or last cleared. Empty while
+    /// `track_missing_glyphs` is off.
+    pub(super) missing_glyphs: std::collections::HashSet<char>,
+    /// Counts of the payload the last `take_draw_payload` built - see
+    /// [`Self::render_stats`].
+    last_render_stats: RenderStats,
+    /// App time of the most recent [`BevyTerminalBackend::tick_fades`] -
+    /// every fade factor computed by the following `flush()` is relative
+    /// to it, so one frame's rows all agree on the fade progress.
+    pub(super) fade_clock: Option<Duration>,
+    /// Running count of glyphs this backend rasterized into the shared
+    /// atlas (cache misses in `shape_row`), read by the `debug_stats`
+    /// overlay.
+    #[cfg(feature = "debug_stats")]
+    pub(super) glyphs_rasterized: u64,
 }
 
 /// Builder for BevyTerminalBackend. Fully synchronous, requires Device/Queue at build().
@@ -140,8 +378,34 @@ pub struct TerminalBuilder {
     reset_fg: Rgb,
     reset_bg: Rgb,
     viewport: Viewport,
+    padding: Padding,
+    chrome: Option<ChromeConfig>,
     transparent_reset_bg: bool,
     initial_fill: [u8; 4],
+    cell_grid: Option<Rgb>,
+    background_gradient: Option<(Rgb, Rgb)>,
+    msaa_samples: u32,
+    cell_fade: Option<Duration>,
+    blink_rates: (Duration, Duration),
+    powerline_style: PowerlineStyle,
+    programmatic_supersample: u32,
+    track_missing_glyphs: bool,
+    cursor_style: CursorStyle,
+    tab_width: u16,
+    control_pictures: bool,
+    glyph_scale: (f32, f32),
+    cell_inset: u32,
+    transparency_checkerboard: bool,
+    default_modifier: ratatui::style::Modifier,
+    underline_color: Option<Rgb>,
+    strikethrough_color: Option<Rgb>,
+    color_quantize: Option<Palette>,
+    ligatures: bool,
+    empty_cell: Option<(char, Rgb)>,
+    glyph_budget: Option<u32>,
+    output_mode: OutputMode,
+    corner_radius: u32,
+    text_brightness: f32,
 }
 
 impl TerminalBuilder {
@@ -154,8 +418,34 @@ impl TerminalBuilder {
             reset_fg: [255, 255, 255], // WHITE
             reset_bg: [0, 0, 0],       // BLACK
             viewport: Viewport::Full,
+            padding: Padding::default(),
+            chrome: None,
             transparent_reset_bg: false,
             initial_fill: [0, 0, 0, 255],
+            cell_grid: None,
+            background_gradient: None,
+            msaa_samples: 1,
+            cell_fade: None,
+            blink_rates: (Duration::from_millis(250), Duration::from_millis(500)),
+            powerline_style: PowerlineStyle::default(),
+            programmatic_supersample: 1,
+            track_missing_glyphs: false,
+            cursor_style: CursorStyle::default(),
+            tab_width: 8,
+            control_pictures: false,
+            glyph_scale: (1.0, 1.0),
+            cell_inset: 0,
+            transparency_checkerboard: false,
+            default_modifier: ratatui::style::Modifier::empty(),
+            underline_color: None,
+            strikethrough_color: None,
+            color_quantize: None,
+            ligatures: true,
+            empty_cell: None,
+            glyph_budget: None,
+            output_mode: OutputMode::Color,
+            corner_radius: 0,
+            text_brightness: 1.0,
         }
     }
 
@@ -184,6 +474,31 @@ impl TerminalBuilder {
         self
     }
 
+    /// Pixel padding around the cell grid, CSS order - see [`Padding`].
+    /// The grid's origin moves to `(left, top)` and the payload's screen
+    /// size grows by the padding, so the destination texture must be
+    /// sized to match ([`Padding::texture_size`];
+    /// `TerminalTexture::with_padding` does this). Positive padding is
+    /// filled with the reset background on every full redraw; negative
+    /// padding pushes the grid's outer pixels off the texture, letting
+    /// edge-to-edge borders bleed. Ratatui's grid size is unaffected.
+    /// Default none.
+    pub fn with_padding(mut self, top: i32, right: i32, bottom: i32, left: i32) -> Self {
+        self.padding = Padding::new(top, right, bottom, left);
+        self
+    }
+
+    /// Draw a border (with an optional title) around the content - see
+    /// [`ChromeConfig`]. Every `draw()` stamps it into the grid's outer
+    /// ring of cells, so ratatui must only draw inside it, into
+    /// [`BevyTerminalBackend::content_area`]: build the `ratatui::Terminal`
+    /// with `Viewport::Fixed(backend.content_area())` (`Tui` does this for
+    /// `TerminalConfig::chrome`). The texture keeps its size. Default none.
+    pub fn with_chrome(mut self, chrome: ChromeConfig) -> Self {
+        self.chrome = Some(chrome);
+        self
+    }
+
     /// If `true`, cells whose effective background is `Color::Reset` render
     /// with alpha 0 instead of opaque `reset_bg` - see the field doc on
     /// `BevyTerminalBackend::transparent_reset_bg`. Default `false`.
@@ -200,6 +515,289 @@ impl TerminalBuilder {
         self
     }
 
+    /// Draw 1px grid lines in `color` between every pair of adjacent cells
+    /// (spreadsheet/table style), or `None` (the default) for no grid -
+    /// see the field doc on `BevyTerminalBackend::cell_grid`.
+    pub fn with_cell_grid(mut self, color: Option<Rgb>) -> Self {
+        self.cell_grid = color;
+        self
+    }
+
+    /// Fill reset-background cells with a vertical gradient from `top` (the
+    /// first row) to `bottom` (the last row) instead of the flat
+    /// `reset_bg` - see the field doc on
+    /// `BevyTerminalBackend::background_gradient`.
+    pub fn with_background_gradient(mut self, top: Rgb, bottom: Rgb) -> Self {
+        self.background_gradient = Some((top, bottom));
+        self
+    }
+
+    /// Render with `samples`x multisample anti-aliasing, resolved into the
+    /// single-sample destination texture - smooths the edges of
+    /// programmatic diagonal/arc glyphs and the cell grid. Default `1`
+    /// (off); `0` is treated as `1`. The render world validates the count
+    /// against the adapter on first use and falls back to the largest
+    /// supported count below it (logging a warning), so any value is safe
+    /// to pass. Typical values: `4` (supported everywhere MSAA is), `8`.
+    pub fn with_msaa(mut self, samples: u32) -> Self {
+        self.msaa_samples = samples.max(1);
+        self
+    }
+
+    /// Record every character this terminal fails to draw - one no font in
+    /// the fallback chain covers (it renders as the `.notdef` box), or a
+    /// programmatic glyph that failed to render - in
+    /// [`BevyTerminalBackend::missing_glyphs`], for "this font is missing
+    /// N glyphs" diagnostics. Default `false`.
+    pub fn with_missing_glyph_tracking(mut self, enabled: bool) -> Self {
+        self.track_missing_glyphs = enabled;
+        self
+    }
+
+    /// Fade every cell that receives new, non-blank content in over
+    /// `duration`: its foreground alpha ramps linearly from 0 to 1
+    /// (backgrounds appear immediately). `Duration::ZERO` disables the
+    /// fade, the default.
+    ///
+    /// Fades advance in [`BevyTerminalBackend::tick_fades`], which `Tui`
+    /// calls every frame with the app's [`Time`](bevy::time::Time), so
+    /// they pause and scale with it - a bare backend has to call it
+    /// itself. While any cell is mid-fade each tick rebuilds that cell's
+    /// row; once every fade has finished, frames are free again.
+    pub fn with_cell_fade(mut self, duration: Duration) -> Self {
+        self.cell_fade = (!duration.is_zero()).then_some(duration);
+        self
+    }
+
+    /// How long each on and off phase of blinking text lasts: `fast` for
+    /// cells with `Modifier::RAPID_BLINK`, `slow` for `Modifier::SLOW_BLINK`
+    /// (a cell with both blinks fast). In the off phase the glyph and its
+    /// decorations are left out; the background stays. Default 250 ms and
+    /// 500 ms. A very long duration (`Duration::MAX`) keeps that kind of
+    /// text steadily shown, i.e. disables its blinking.
+    ///
+    /// Phases advance in [`BevyTerminalBackend::tick_blink`], which `Tui`
+    /// calls every frame with the app's [`Time`](bevy::time::Time) - a bare
+    /// backend has to call it itself.
+    pub fn with_blink_rates(mut self, fast: Duration, slow: Duration) -> Self {
+        self.blink_rates = (fast, slow);
+        self
+    }
+
+    /// Shape, reach and stroke weight of the programmatically drawn
+    /// powerline separators (U+E0B0–U+E0BF), to match the surrounding
+    /// font - rounded arrows, a steeper slope, heavier lines. Default
+    /// [`PowerlineStyle::default`], the built-in geometry.
+    pub fn with_powerline_style(mut self, style: PowerlineStyle) -> Self {
+        self.powerline_style = style;
+        self
+    }
+
+    /// Rasterize programmatic glyphs (box drawing, blocks, braille,
+    /// powerline) at `factor` times the cell size and box-filter them
+    /// down, anti-aliasing the rounded corners, diagonals and separator
+    /// curves that otherwise come out stair-stepped at small sizes. Clamped
+    /// to `1..=4`; default `1` (render at cell size, pixel-crisp). Stroke
+    /// widths are rounded at the larger size, so a line can come out up to
+    /// half a pixel lighter or heavier than at `1`.
+    pub fn with_programmatic_supersampling(mut self, factor: u32) -> Self {
+        self.programmatic_supersample = factor.clamp(1, 4);
+        self
+    }
+
+    /// Colors of the block cursor drawn while ratatui's cursor is visible.
+    /// Default [`CursorStyle::default`]: both colors unset, so the cursor
+    /// inverts the cell under it.
+    pub fn with_cursor_style(mut self, style: CursorStyle) -> Self {
+        self.cursor_style = style;
+        self
+    }
+
+    /// Columns between tab stops. A cell holding a literal tab (`"\t"`)
+    /// becomes blank cells in its style up to the next multiple of this,
+    /// and the rest of its row shifts right to follow, like a terminal's
+    /// horizontal tab. The expansion happens in the backend's grid, so
+    /// hit-testing, [`BevyTerminalBackend::get_text`] and overlays see the
+    /// cells where they are drawn; cells shifted past the row's end are
+    /// not drawn (logged at debug level). Default `8`; `0` is treated as
+    /// `1`.
+    pub fn with_tab_width(mut self, tab_width: u16) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// Show a cell holding a lone C0 control character (U+0000..U+001F)
+    /// or DEL as its Unicode Control Pictures glyph - `"\u{3}"` as `␃`,
+    /// DEL as `␡` - like a terminal displaying a raw byte stream, instead
+    /// of the U+FFFD every other unrenderable symbol becomes. Tabs keep
+    /// their tab-stop behavior either way (see [`Self::with_tab_width`]).
+    /// The pictures come from the fonts, so pick one covering U+2400..
+    /// Default `false`.
+    pub fn with_control_pictures(mut self, enabled: bool) -> Self {
+        self.control_pictures = enabled;
+        self
+    }
+
+    /// Draw glyphs `x` times their normal width and `y` times their normal
+    /// height, centered in their cells - e.g. `(0.8, 1.0)` for condensed
+    /// text. Only the glyph quads change: the grid, cell backgrounds,
+    /// cursor and hit-testing keep the font's cell size, so this is not a
+    /// font-size change. Glyphs are stretched from their atlas bitmaps
+    /// (no re-rasterization), so strong scales look soft; above `1.0` they
+    /// spill into neighbouring cells. Underlines and strikethroughs ride on
+    /// the glyph quad and scale with it. Non-positive or non-finite values
+    /// are treated as `1.0`. Default `(1.0, 1.0)`.
+    pub fn with_glyph_scale(mut self, x: f32, y: f32) -> Self {
+        self.glyph_scale = (x, y);
+        self
+    }
+
+    /// Leave `px` pixels of breathing room between every glyph and the
+    /// edges of its cell: each glyph is drawn into its cell rectangle
+    /// shrunk by `px` on all four sides, centered, while backgrounds,
+    /// the cursor, the grid and hit-testing keep the full cell. Unlike row
+    /// spacing this also opens up space between columns, so dense text -
+    /// tables, logs, a packed dashboard - reads lighter at the same cell
+    /// count; the price is smaller, slightly softer glyphs (they are
+    /// shrunk from their atlas bitmaps, like [`Self::with_glyph_scale`],
+    /// which applies on top), and a glyph loses relatively more height
+    /// than width in a tall, narrow cell. Box-drawing, block and other
+    /// programmatic glyphs keep the whole cell, so borders and bars still
+    /// join up. Underlines and strikethroughs ride on the glyph quad and
+    /// shrink with it. Capped so a glyph stays at least one pixel wide
+    /// and tall. Default `0`.
+    pub fn with_cell_inset(mut self, px: u32) -> Self {
+        self.cell_inset = px;
+        self
+    }
+
+    /// Debug aid: paint a gray checkerboard (two squares per cell side,
+    /// like an image editor) over every cell whose background isn't fully
+    /// opaque - the `transparent_reset_bg` cells - so what would be see-
+    /// through is told apart from black at a glance. Text still draws on
+    /// top. Only the cells are affected, not the padding letterbox, and
+    /// never in [`OutputMode::AlphaMask`]. Default `false`; usually toggled app-wide through
+    /// [`TerminalDebug`](crate::debug::TerminalDebug).
+    pub fn with_transparency_checkerboard(mut self, enabled: bool) -> Self {
+        self.transparency_checkerboard = enabled;
+        self
+    }
+
+    /// Modifiers applied to every cell on top of its own - e.g.
+    /// `Modifier::BOLD` for an all-bold theme. They are OR'd with the
+    /// cell's modifiers, so a cell can add to the default but never remove
+    /// from it (a ratatui `Cell` only records the modifiers it has, not
+    /// ones it removed). Only the modifiers the renderer honours have a
+    /// visible effect: `REVERSED`, plus `BOLD`/`ITALIC` with the
+    /// `bold_italic_fonts` feature. Default `Modifier::empty()`.
+    pub fn with_default_modifier(mut self, modifier: ratatui::style::Modifier) -> Self {
+        self.default_modifier = modifier;
+        self
+    }
+
+    /// Color of `Modifier::UNDERLINED` lines, independent of the text -
+    /// e.g. red for spellcheck, blue for links. A cell's own ratatui
+    /// `underline_color` wins when the `underline_color` feature is on
+    /// and it isn't `Color::Reset`. Default `None`: the text color.
+    pub fn with_underline_color(mut self, color: Option<Rgb>) -> Self {
+        self.underline_color = color;
+        self
+    }
+
+    /// Color of `Modifier::CROSSED_OUT` lines, independent of the text
+    /// (ratatui has no per-cell strikethrough color). Default `None`: the
+    /// text color.
+    pub fn with_strikethrough_color(mut self, color: Option<Rgb>) -> Self {
+        self.strikethrough_color = color;
+        self
+    }
+
+    /// Snap every color the terminal renders to `palette` (e.g.
+    /// [`Palette::cga`] or [`Palette::green_phosphor`]) as a last step,
+    /// after the theme palette, reset colors, `REVERSED` and the other
+    /// color options have resolved it - cell colors, cursor, decorations,
+    /// the cell grid and the texture's fill alike. Alpha is untouched.
+    /// Default: full color.
+    pub fn with_color_quantize(mut self, palette: Palette) -> Self {
+        self.color_quantize = Some(palette);
+        self
+    }
+
+    /// Whether shaping applies the font's ligatures (OpenType `liga`,
+    /// `clig` and `calt`) - e.g. Fira Code's or JetBrains Mono's `=>` and
+    /// `!=`. A ligature is drawn from its first character's cell. Off
+    /// keeps every character its own glyph, as many prefer for code.
+    /// Default `true`. Rows taken by the `ascii_fast_shaping` feature's
+    /// fast path are never shaped, so never ligated, either way.
+    pub fn with_ligatures(mut self, ligatures: bool) -> Self {
+        self.ligatures = ligatures;
+        self
+    }
+
+    /// Draw `ch` in `color` in every blank cell (a space, not `REVERSED`)
+    /// instead of leaving it empty - e.g. a faint `·` marking out the grid
+    /// like a design tool. Only the rendering changes: the cell's
+    /// background, ratatui's buffer and [`BevyTerminalBackend::get_text`]
+    /// are unaffected. `ch` should be one cell wide. Combines with
+    /// [`Self::with_cell_grid`]'s lines. Default `None`: blank.
+    pub fn with_empty_cell(mut self, empty_cell: Option<(char, Rgb)>) -> Self {
+        self.empty_cell = empty_cell;
+        self
+    }
+
+    /// Rasterize at most `glyphs` new glyphs per `flush()` (at least one).
+    /// The first display of content with many glyphs the atlas hasn't seen
+    /// - a page of CJK text, say - otherwise rasterizes them all in one
+    /// frame and hitches. Over budget, the remaining new glyphs are left
+    /// blank (the cell background still draws) and filled in by the
+    /// following flushes, `glyphs` at a time; a [`Tui`](crate::Tui) keeps
+    /// flushing on its own until they are all in, whether or not anything
+    /// is drawn meanwhile. Glyphs already in the atlas are never held
+    /// back. Default: no budget.
+    pub fn with_glyph_budget(mut self, glyphs: u32) -> Self {
+        self.glyph_budget = Some(glyphs.max(1));
+        self
+    }
+
+    /// Render the terminal's colors ([`OutputMode::Color`], the default)
+    /// or only its text coverage ([`OutputMode::AlphaMask`]): transparent
+    /// black wherever nothing is drawn, white at the glyph's alpha
+    /// wherever something is - text, decorations, grid lines, the cursor.
+    /// A mask lets a custom shader or material use the text as a stencil
+    /// (e.g. text cut out of a video) without keying out a background
+    /// color. The texture stays `Rgba8Unorm`; see [`OutputMode`] for what
+    /// its channels hold.
+    pub fn with_output_mode(mut self, mode: OutputMode) -> Self {
+        self.output_mode = mode;
+        self
+    }
+
+    /// Round the texture's corners to a `px` radius: everything outside the
+    /// rounded rectangle is transparent (antialiased along the curve), so
+    /// the texture drops straight into a UI `ImageNode` with rounded
+    /// corners - no `BorderRadius` clipping or mask needed. The whole
+    /// texture is rounded, padding included, and the radius is capped at
+    /// half its shorter side. Only shows against what's behind the
+    /// texture if that alpha reaches the screen: a UI image or an
+    /// alpha-blended material. Default `0`: square corners.
+    pub fn with_corner_radius(mut self, px: u32) -> Self {
+        self.corner_radius = px;
+        self
+    }
+
+    /// Scale every text color (glyphs, underlines and strikethroughs) by
+    /// `factor` as it is composited, e.g. `4.0` for text bright enough to
+    /// bloom. Values above `1.0` only survive into an HDR texture
+    /// ([`TerminalTexture::with_hdr`](crate::setup::TerminalTexture::with_hdr));
+    /// an `Rgba8Unorm` one clamps them back to the plain colors. Cell
+    /// backgrounds and the cursor are left as they are, so text glows
+    /// against a dark screen instead of the whole screen glowing. Negative
+    /// factors are treated as `0.0`. Default `1.0`.
+    pub fn with_text_brightness(mut self, factor: f32) -> Self {
+        self.text_brightness = factor.max(0.0);
+        self
+    }
+
     /// Build the BevyTerminalBackend.
     ///
     /// This is synchronous (unlike the original async Builder).
@@ -218,9 +816,15 @@ impl TerminalBuilder {
             dirty_rows: vec![],
             cells_changed_last_draw: false,
             cursor: (0, 0),
+            cursor_visible: false,
+            cursor_style: self.cursor_style,
             viewport: self.viewport,
+            padding: self.padding,
+            chrome: self.chrome,
+            chrome_ring: None,
             rows_dirty_since_take: vec![],
             full_redraw_needed: true,
+            reshape_pending: false,
             fonts: self.fonts,
             buffer: UnicodeBuffer::new(),
             row: String::new(),
@@ -232,10 +836,168 @@ impl TerminalBuilder {
             reset_bg: self.reset_bg,
             transparent_reset_bg: self.transparent_reset_bg,
             initial_fill: self.initial_fill,
+            cell_grid: self.cell_grid,
+            background_gradient: self.background_gradient,
+            tab_width: self.tab_width,
+            control_pictures: self.control_pictures,
+            glyph_scale: sanitize_glyph_scale(self.glyph_scale),
+            cell_inset: self.cell_inset,
+            transparency_checkerboard: self.transparency_checkerboard,
+            default_modifier: self.default_modifier,
+            underline_color: self.underline_color,
+            strikethrough_color: self.strikethrough_color,
+            palette: None,
+            color_quantize: self.color_quantize,
+            ligatures: self.ligatures,
+            cell_filter: None,
+            empty_cell: self.empty_cell,
+            glyph_budget: self.glyph_budget,
+            output_mode: self.output_mode,
+            corner_radius: self.corner_radius,
+            text_brightness: self.text_brightness,
+            glyphs_rasterized_this_flush: 0,
+            glyphs_deferred: false,
+            rerasterize: None,
+            msaa_samples: self.msaa_samples,
+            cell_fade: self.cell_fade,
+            cell_changed_at: vec![],
+            widened_over: std::collections::HashMap::new(),
+            tab_rows: std::collections::HashMap::new(),
+            blink_rates: self.blink_rates,
+            show_fast: true,
+            show_slow: true,
+            last_fast_toggle: None,
+            last_slow_toggle: None,
+            powerline_style: self.powerline_style,
+            programmatic_supersample: self.programmatic_supersample,
+            track_missing_glyphs: self.track_missing_glyphs,
+            missing_glyphs: std::collections::HashSet::new(),
+            last_render_stats: RenderStats::default(),
+            fade_clock: None,
+            #[cfg(feature = "debug_stats")]
+            glyphs_rasterized: 0,
         }
     }
 }
 
+/// When a cell's fade-in started - see
+/// [`BevyTerminalBackend::tick_fades`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum FadeStart {
+    /// Drawn since the last tick, which starts the fade.
+    Pending,
+    /// App time the fade started at.
+    At(Duration),
+}
+
+/// Flip a blink phase (`shown`) once `rate` has passed since `last`, the
+/// time of its previous flip - see [`BevyTerminalBackend::tick_blink`].
+/// Returns whether it flipped. The first call only starts the clock.
+fn advance_blink_phase(
+    last: &mut Option<Duration>,
+    shown: &mut bool,
+    rate: Duration,
+    now: Duration,
+) -> bool {
+    let Some(since) = *last else {
+        *last = Some(now);
+        return false;
+    };
+    if now.saturating_sub(since) < rate {
+        return false;
+    }
+    *shown = !*shown;
+    *last = Some(now);
+    true
+}
+
+/// Screen and atlas corners of one glyph quad - see `push_text_quad`.
+struct GlyphQuad {
+    min: [f32; 2],
+    max: [f32; 2],
+    uv_min: [f32; 2],
+    uv_max: [f32; 2],
+}
+
+/// A glyph scale with each non-positive or non-finite factor replaced by
+/// `1.0` - see [`TerminalBuilder::with_glyph_scale`].
+fn sanitize_glyph_scale((x, y): (f32, f32)) -> (f32, f32) {
+    let valid = |factor: f32| if factor.is_finite() && factor > 0.0 { factor } else { 1.0 };
+    (valid(x), valid(y))
+}
+
+/// Appends the four corners of a glyph quad - the text counterpart of
+/// `push_bg_quad`, plus the decoration band `underline_pos` (packed
+/// `y_min << 16 | y_max` in atlas texels, `0` for none) drawn in
+/// `underline_color`.
+fn push_text_quad(
+    vertices: &mut Vec<TextVertexMember>,
+    quad: &GlyphQuad,
+    fg_color: u32,
+    underline_pos: u32,
+    underline_color: u32,
+) {
+    let corner = |x: usize, y: usize| TextVertexMember {
+        vertex: [[quad.min[0], quad.max[0]][x], [quad.min[1], quad.max[1]][y]],
+        uv: [[quad.uv_min[0], quad.uv_max[0]][x], [quad.uv_min[1], quad.uv_max[1]][y]],
+        fg_color,
+        underline_pos,
+        underline_color,
+    };
+    vertices.extend_from_slice(&[corner(0, 0), corner(1, 0), corner(0, 1), corner(1, 1)]);
+}
+
+/// Packed opaque white: full coverage in [`OutputMode::AlphaMask`].
+const MASK_COVERAGE: u32 = 0xFFFF_FFFF;
+
+/// Light and dark squares of the transparency checkerboard.
+const CHECKERBOARD_LIGHT: u32 = 0xCCCC_CCFF;
+const CHECKERBOARD_DARK: u32 = 0x8888_88FF;
+
+/// Append one cell's worth of transparency checkerboard spanning
+/// `min..max`: a light quad with dark top-left and bottom-right quarters.
+/// Every cell gets the same pattern, so side by side they alternate.
+fn push_checkerboard(vertices: &mut Vec<TextBgVertexMember>, min: [f32; 2], max: [f32; 2]) {
+    let mid = [(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0];
+    push_bg_quad(vertices, min, max, CHECKERBOARD_LIGHT);
+    push_bg_quad(vertices, min, mid, CHECKERBOARD_DARK);
+    push_bg_quad(vertices, mid, max, CHECKERBOARD_DARK);
+}
+
+/// Append the four edges of a hollow box spanning `min..max` (pixels) as
+/// bg quads - the placeholder for a glyph the atlas rejected. The stroke
+/// scales with the box, at least a pixel.
+fn push_box_outline(vertices: &mut Vec<TextBgVertexMember>, min: [f32; 2], max: [f32; 2], color: u32) {
+    let stroke = ((max[1] - min[1]) / 16.0).floor().max(1.0);
+    push_bg_quad(vertices, min, [max[0], min[1] + stroke], color);
+    push_bg_quad(vertices, [min[0], max[1] - stroke], max, color);
+    push_bg_quad(vertices, [min[0], min[1] + stroke], [min[0] + stroke, max[1] - stroke], color);
+    push_bg_quad(vertices, [max[0] - stroke, min[1] + stroke], [max[0], max[1] - stroke], color);
+}
+
+/// Append one axis-aligned background quad spanning `min..max` (pixels) in
+/// the TL, TR, BL, BR corner order every bg/fg quad in this file uses.
+fn push_bg_quad(vertices: &mut Vec<TextBgVertexMember>, min: [f32; 2], max: [f32; 2], color: u32) {
+    vertices.extend_from_slice(&[
+        TextBgVertexMember {
+            vertex: [min[0], min[1]],
+            bg_color: color,
+        },
+        TextBgVertexMember {
+            vertex: [max[0], min[1]],
+            bg_color: color,
+        },
+        TextBgVertexMember {
+            vertex: [min[0], max[1]],
+            bg_color: color,
+        },
+        TextBgVertexMember {
+            vertex: [max[0], max[1]],
+            bg_color: color,
+        },
+    ]);
+}
+
 /// Convert tiny-skia Pixmap to Vec<u32> in RGBA8 format
 fn pixmap_to_rgba8(pixmap: tiny_skia::Pixmap) -> Vec<u32> {
     pixmap
@@ -262,6 +1024,278 @@ impl BevyTerminalBackend {
         self.cells_changed_last_draw
     }
 
+    /// Replace the whole drawable grid in one call, for apps that keep
+    /// their own full grid (a roguelike map, a game-of-life board) and
+    /// would otherwise fill a ratatui `Frame` cell by cell only to have it
+    /// diffed again. `cells` is row-major, `cols * rows` long, and
+    /// `(cols, rows)` must be the backend's drawable size (the grid, less
+    /// any [`Viewport::Shrink`] inset); anything else is rejected with
+    /// [`TerminalError::GridSize`](crate::TerminalError::GridSize) and
+    /// nothing is changed.
+    ///
+    /// Cells go through the same handling as [`ratatui::backend::Backend::draw`]
+    /// (unrenderable symbols, wide characters, fades); rows equal to what
+    /// the backend already holds are skipped, so only changed rows are
+    /// reshaped. Like `draw`, this only updates the grid - call
+    /// `flush()` afterwards, as ratatui does. [`Tui::set_grid`](crate::Tui::set_grid)
+    /// does both.
+    pub fn set_grid(&mut self, cells: &[Cell], cols: u16, rows: u16) -> Result<(), crate::TerminalError> {
+        let bounds = ratatui::backend::Backend::size(self)?;
+        if (cols, rows) != (bounds.width, bounds.height) || cells.len() != cols as usize * rows as usize {
+            return Err(crate::TerminalError::GridSize {
+                cols,
+                rows,
+                cells: cells.len(),
+                expected_cols: bounds.width,
+                expected_rows: bounds.height,
+            });
+        }
+
+        let width = cols as usize;
+        let filtered: Option<Vec<Cell>> = self.cell_filter.is_some().then(|| {
+            cells
+                .iter()
+                .enumerate()
+                .filter_map(|(i, cell)| {
+                    self.filtered_cell(cell, (i % width) as u16, (i / width) as u16)
+                })
+                .collect()
+        });
+        let cells = filtered.as_deref().unwrap_or(cells);
+
+        let fading = self.begin_draw(bounds);
+        for y in 0..rows as usize {
+            let start = y * width;
+            let row = &cells[start..start + width];
+            // Rows holding a tab compare against (and go through) their
+            // ratatui copy - see `lay_out_tab_row`.
+            let unchanged = match self.tab_rows.get(&y) {
+                Some(drawn) => drawn.as_slice() == row,
+                None => self.row_holds(start, row),
+            };
+            if unchanged {
+                continue;
+            }
+            let tabbed = self.tab_rows.contains_key(&y)
+                || row.iter().any(|cell| cell.symbol() == TAB_SYMBOL);
+            self.cells_changed_last_draw = true;
+            self.dirty_rows[y] = true;
+            let mut x = 0;
+            while x < width {
+                // Cells under a wide character stay covered, as they do
+                // when ratatui's diff skips them.
+                if tabbed {
+                    self.store_tab_row_cell(x, y, width, &row[x]);
+                    x += unicode_width::UnicodeWidthStr::width(row[x].symbol()).max(1);
+                } else {
+                    x += self.store_cell(start + x, &row[x], fading);
+                }
+            }
+            self.lay_out_tab_row(y, fading);
+        }
+
+        self.end_draw(fading, width);
+        Ok(())
+    }
+
+    /// Set or clear (`None`) a hook that edits each cell on its way in -
+    /// uppercasing, redacting, restyling, ... - called with the cell and
+    /// its `(x, y)` from `draw()` and [`Self::set_grid`], before symbol
+    /// substitution, dirty tracking and the backend's own copy see it.
+    ///
+    /// It runs once for every cell handed in: every cell ratatui's diff
+    /// reports changed, or the whole grid for `set_grid` - so keep it
+    /// cheap, and deterministic in `(cell, x, y)`: ratatui diffs the
+    /// unfiltered frames, so a cell it considers unchanged keeps whatever
+    /// the filter made of it last time. For the same reason cells already
+    /// drawn keep the previous filter's output until they are drawn again
+    /// (clear the ratatui `Terminal` to resend them all -
+    /// [`Tui::set_cell_filter`](crate::setup::Tui::set_cell_filter) does).
+    /// The filter shouldn't change a cell's display width: ratatui has
+    /// already laid out the cells after a wide character.
+    pub fn set_cell_filter(&mut self, filter: Option<CellFilter>) {
+        self.cell_filter = filter;
+    }
+
+    /// `cell` as `cell_filter` rewrites it at `(x, y)`, or `None` without
+    /// a filter.
+    fn filtered_cell(&self, cell: &Cell, x: u16, y: u16) -> Option<Cell> {
+        let filter = self.cell_filter.as_ref()?;
+        let mut cell = cell.clone();
+        filter(&mut cell, x, y);
+        Some(cell)
+    }
+
+    /// Size the per-cell state to `bounds` and start a new draw - shared
+    /// by `Backend::draw` and [`Self::set_grid`]. Returns whether changed
+    /// cells fade in.
+    fn begin_draw(&mut self, bounds: ratatui::layout::Size) -> bool {
+        self.cells
+            .resize(bounds.height as usize * bounds.width as usize, Cell::EMPTY);
+        self.dirty_rows.resize(bounds.height as usize, true);
+        self.row_geometry
+            .resize_with(bounds.height as usize, RowGeometry::default);
+        self.cells_changed_last_draw = std::mem::take(&mut self.reshape_pending);
+        let fading = self.cell_fade.is_some();
+        if fading {
+            self.cell_changed_at.resize(self.cells.len(), None);
+        }
+        fading
+    }
+
+    /// Whether the grid from `start` on already holds what storing `row`
+    /// there would leave - compared the way `store_cell` stores it: after
+    /// symbol substitution, and skipping the cells a wide character covers.
+    fn row_holds(&self, start: usize, row: &[Cell]) -> bool {
+        use unicode_width::UnicodeWidthStr;
+
+        let mut x = 0;
+        while x < row.len() {
+            let index = start + x;
+            let cell = self.renderable_cell(&row[x]);
+            if let Some(hidden) = self.widened_over.get(&index) {
+                if *hidden != *cell {
+                    return false;
+                }
+                x += 1;
+            } else {
+                if self.cells[index] != *cell {
+                    return false;
+                }
+                x += cell.symbol().width().max(1);
+            }
+        }
+        true
+    }
+
+    /// `cell` with the stand-in `store_cell` keeps for a symbol the render
+    /// path can't draw: its Control Picture (with `control_pictures` on)
+    /// or U+FFFD.
+    fn renderable_cell<'a>(&self, cell: &'a Cell) -> Cow<'a, Cell> {
+        if is_renderable_symbol(cell.symbol()) {
+            return Cow::Borrowed(cell);
+        }
+        let mut cell = cell.clone();
+        match control_picture(cell.symbol()).filter(|_| self.control_pictures) {
+            Some(picture) => cell.set_char(picture),
+            None => cell.set_symbol(REPLACEMENT_SYMBOL),
+        };
+        Cow::Owned(cell)
+    }
+
+    /// Store `cell` at `index`, standing in for symbols the render path
+    /// can't draw and blanking the cells a wide character covers. Returns
+    /// the cell's width as ratatui lays it out (at least 1) - cells past
+    /// that which it covers anyway are set aside in `widened_over`. The
+    /// caller marks the row dirty.
+    fn store_cell(&mut self, index: usize, cell: &Cell, fading: bool) -> usize {
+        use unicode_width::UnicodeWidthStr;
+
+        if let Some(hidden) = self.widened_over.get_mut(&index) {
+            *hidden = cell.clone();
+            return 1;
+        }
+        let row_end = match ratatui::backend::Backend::size(self).map_or(0, |size| size.width as usize) {
+            0 => self.cells.len(),
+            width => ((index / width + 1) * width).min(self.cells.len()),
+        };
+        let old_end = (index + self.fonts.text_width_cells(self.cells[index].symbol()).max(1)).min(row_end);
+
+        self.cells[index] = self.renderable_cell(cell).into_owned();
+        let cell = &self.cells[index];
+        if fading {
+            let blank = cell.symbol().trim().is_empty();
+            self.cell_changed_at[index] = (!blank).then_some(FadeStart::Pending);
+        }
+
+        let laid_out = cell.symbol().width().max(1);
+        let end = (index + self.fonts.text_width_cells(cell.symbol()).max(1)).min(row_end);
+        let start = (index + 1).min(end);
+        let covered = (index + laid_out).clamp(start, end);
+        self.cells[start..covered].fill(NULL_CELL);
+        for continuation in start..covered {
+            self.widened_over.remove(&continuation);
+        }
+        for hidden in covered..end {
+            let neighbour = std::mem::replace(&mut self.cells[hidden], NULL_CELL);
+            self.widened_over.entry(hidden).or_insert(neighbour);
+        }
+        for uncovered in end..old_end {
+            if let Some(neighbour) = self.widened_over.remove(&uncovered) {
+                self.store_cell(uncovered, &neighbour, fading);
+            }
+        }
+        laid_out
+    }
+
+    /// Record `cell` at `(x, y)` in the ratatui copy of a row holding a
+    /// tab (`tab_rows`), starting that copy from the grid if the row held
+    /// none so far, and blanking the cells a wide character covers there.
+    /// The caller lays the row out again with [`Self::lay_out_tab_row`].
+    fn store_tab_row_cell(&mut self, x: usize, y: usize, width: usize, cell: &Cell) {
+        use unicode_width::UnicodeWidthStr;
+
+        let row_start = y * width;
+        let row = self.tab_rows.entry(y).or_insert_with(|| {
+            (row_start..row_start + width)
+                .map(|index| self.widened_over.get(&index).unwrap_or(&self.cells[index]).clone())
+                .collect()
+        });
+        row[x] = cell.clone();
+        let end = (x + cell.symbol().width().max(1)).min(width);
+        row[x + 1..end].fill(NULL_CELL);
+    }
+
+    /// Write row `y` of the grid from its ratatui copy in `tab_rows`: each
+    /// tab becomes blank cells in its style up to the next tab stop (see
+    /// `tab_expanded_columns`), and the cells after it move right to
+    /// follow. Forgets the copy once the row holds no tab - the grid then
+    /// matches it cell for cell again. The caller marks the row dirty.
+    fn lay_out_tab_row(&mut self, y: usize, fading: bool) {
+        let Some(row) = self.tab_rows.get(&y).cloned() else {
+            return;
+        };
+        let width = row.len();
+        let row_start = y * width;
+        let columns = tab_expanded_columns(&row, self.tab_width);
+        let mut dropped = 0;
+        for (x, cell) in row.iter().enumerate() {
+            let (col0, col1) = columns
+                .as_ref()
+                .map_or((x, x + 1), |columns| (columns[x], columns[x + 1]));
+            if col0 >= width {
+                dropped += usize::from(!cell.symbol().trim().is_empty());
+                continue;
+            }
+            if cell.symbol() == TAB_SYMBOL {
+                let mut blank = cell.clone();
+                blank.set_symbol(" ");
+                for col in col0..col1.min(width) {
+                    if self.cells[row_start + col] != blank {
+                        self.store_cell(row_start + col, &blank, fading);
+                    }
+                }
+            } else if self.cells[row_start + col0] != *cell {
+                self.store_cell(row_start + col0, cell, fading);
+            }
+        }
+        if columns.is_none() {
+            self.tab_rows.remove(&y);
+        }
+        if dropped > 0 {
+            tracing::debug!(
+                row = y,
+                dropped,
+                "tab expansion shifted cells past the end of the row"
+            );
+        }
+    }
+
+    /// Finish a draw started with [`Self::begin_draw`].
+    fn end_draw(&mut self, fading: bool, width: usize) {
+        self.stamp_chrome(fading, width);
+    }
+
     /// Update the grid dimensions used by `Backend::size()`/`window_size()`.
     /// Pure bookkeeping - the actual cell/dirty-tracking buffers (`cells`,
     /// `dirty_rows`) are resized lazily inside `draw()` based on the bounds
@@ -283,6 +1317,9 @@ impl BevyTerminalBackend {
         // IMPROVEMENT.md A2's invalidation list).
         self.dirty_rows.clear();
         self.row_geometry.clear();
+        self.cell_changed_at.clear();
+        self.widened_over.clear();
+        self.tab_rows.clear();
 
         // The destination texture is about to be recreated at the new
         // size (see `Tui::apply_pending_resize`) - nothing rendered to it
@@ -299,6 +1336,11 @@ impl BevyTerminalBackend {
     /// here (there is no GPU resource to upload to yet; the render world does that on this
     /// terminal's first extract, exactly like any other glyph). Glyphs not yet implemented are
     /// silently skipped (not an error).
+    ///
+    /// The atlas is shared by every terminal holding the same `Arc<Fonts>`, so this only does
+    /// the work once per `Fonts` and powerline style/supersampling combination: later terminals
+    /// find the glyphs already in place and return straight away. Glyphs evicted from the atlas
+    /// since are rasterized again on demand, like any other cache miss.
     pub fn populate_programmatic_glyphs(&mut self) {
         use crate::backend::programmatic_glyphs::{
             all_programmatic_glyphs, render_programmatic_glyph,
@@ -308,7 +1350,19 @@ impl BevyTerminalBackend {
 
         let width = self.fonts.min_width_px();
         let height = self.fonts.height_px();
-        let font_id = self.fonts.last_resort_id();
+
+        let settings = (self.powerline_style, self.programmatic_supersample);
+        let already_populated = self.fonts.with_shared_cpu_state(|shared| {
+            if shared.programmatic_populated.contains(&settings) {
+                return true;
+            }
+            shared.programmatic_populated.push(settings);
+            false
+        });
+        if already_populated {
+            tracing::debug!("Programmatic glyphs already populated in this Fonts' shared atlas");
+            return;
+        }
 
         tracing::debug!(
             "Pre-populating {} programmatic glyphs ({}x{} px)...",
@@ -321,7 +1375,13 @@ impl BevyTerminalBackend {
         let mut skipped_count = 0;
         for unicode_char in all_programmatic_glyphs() {
             // Render glyph to bitmap using tiny-skia
-            let pixmap = match render_programmatic_glyph(unicode_char, width, height) {
+            let pixmap = match render_programmatic_glyph(
+                unicode_char,
+                width,
+                height,
+                &self.powerline_style,
+                self.programmatic_supersample,
+            ) {
                 Some(p) => p,
                 None => {
                     // Skip glyphs not yet implemented
@@ -337,16 +1397,24 @@ impl BevyTerminalBackend {
             let key = Key {
                 style: Modifier::empty(),
                 glyph: unicode_char as u32,
-                font: font_id,
+                font: self.programmatic_font_id(unicode_char),
             };
 
             // Get atlas slot (this allocates space in the shared atlas -
             // shared with every other terminal using this same `Fonts`,
             // IMPROVEMENT.md C3) and queue the bitmap for GPU upload.
-            self.fonts.with_shared_cpu_state(|shared| {
+            let stored = self.fonts.with_shared_cpu_state(|shared| {
+                if !shared.cached.accepts(width, height) {
+                    return false;
+                }
                 let rect = shared.cached.get(&key, width, height);
                 shared.pending_cache_updates.push((*rect, bitmap));
+                true
             });
+            if !stored {
+                skipped_count += 1;
+                continue;
+            }
 
             populated_count += 1;
         }
@@ -358,6 +1426,119 @@ impl BevyTerminalBackend {
         );
     }
 
+    /// Capture this terminal's rasterized glyphs as a portable
+    /// [`AtlasSnapshot`] - see [`crate::backend::atlas_snapshot`].
+    ///
+    /// Only glyphs not yet uploaded are captured, not the whole atlas: the
+    /// atlas pixels themselves only ever live on the GPU, so the snapshot
+    /// is built from the rasterizations still queued for upload. Once the
+    /// render world has drained that queue - from the terminal's first
+    /// rendered frame on - those glyphs are no longer in it and an export
+    /// misses them. In practice, call this before anything renders (right
+    /// after [`Self::populate_programmatic_glyphs`] and any warmup draws),
+    /// e.g. in a build script or a headless app without a render world,
+    /// where nothing is ever drained. The queue itself is left untouched.
+    pub fn export_atlas(&self) -> AtlasSnapshot {
+        use crate::backend::atlas_snapshot::AtlasSnapshotGlyph;
+
+        let font_ids: Vec<u64> = self
+            .fonts
+            .fonts_in_fallback_order()
+            .map(crate::fonts::Font::id)
+            .collect();
+        let glyphs = self.fonts.with_shared_cpu_state(|shared| {
+            // A slot evicted and reassigned before the queue was drained
+            // shows up twice - only its last upload matches the key that
+            // owns it now, so walk newest first and skip repeats.
+            let mut seen = std::collections::HashSet::new();
+            let mut glyphs: Vec<_> = shared
+                .pending_cache_updates
+                .iter()
+                .rev()
+                .filter(|(rect, _)| seen.insert((rect.x, rect.y)))
+                .filter_map(|(rect, pixels)| {
+                    let key = shared.cached.key_at(rect)?;
+                    let font = font_ids.iter().position(|&id| id == key.font)?;
+                    Some(AtlasSnapshotGlyph {
+                        style: key.style.bits(),
+                        glyph: key.glyph,
+                        font: font as u32,
+                        width: rect.width,
+                        pixels: pixels.clone(),
+                    })
+                })
+                .collect();
+            glyphs.reverse();
+            glyphs
+        });
+
+        AtlasSnapshot {
+            cell_width: self.fonts.min_width_px(),
+            cell_height: self.fonts.height_px(),
+            fonts: self
+                .fonts
+                .fonts_in_fallback_order()
+                .map(crate::fonts::Font::fingerprint)
+                .collect(),
+            glyphs,
+        }
+    }
+
+    /// Restore glyphs from an [`AtlasSnapshot`] into this terminal's
+    /// (shared) atlas, queueing their pixels for upload exactly as if they
+    /// had just been rasterized - an alternative to
+    /// [`Self::populate_programmatic_glyphs`] that costs a memcpy per glyph
+    /// instead of a rasterization. Glyphs already in the atlas are left
+    /// alone. Returns the number of glyphs restored: `0`, with a warning,
+    /// when the snapshot was taken with other fonts or another font size.
+    pub fn import_atlas(&mut self, snapshot: &AtlasSnapshot) -> usize {
+        use crate::utils::text_atlas::Key;
+        use ratatui::style::Modifier;
+
+        let fonts: Vec<&crate::fonts::Font> = self.fonts.fonts_in_fallback_order().collect();
+        let width = self.fonts.min_width_px();
+        let height = self.fonts.height_px();
+        let matches = snapshot.cell_width == width
+            && snapshot.cell_height == height
+            && snapshot.fonts.len() == fonts.len()
+            && fonts
+                .iter()
+                .zip(&snapshot.fonts)
+                .all(|(font, fingerprint)| font.fingerprint() == *fingerprint);
+        if !matches {
+            tracing::warn!("import_atlas: snapshot was taken with different fonts or font size, ignored");
+            return 0;
+        }
+
+        self.fonts.with_shared_cpu_state(|shared| {
+            let mut restored = 0;
+            for glyph in &snapshot.glyphs {
+                // Same shapes `shape_row` allocates: one or two cells wide.
+                let valid_width = glyph.width == width || glyph.width == 2 * width;
+                let Some(font) = fonts.get(glyph.font as usize) else {
+                    continue;
+                };
+                if !valid_width || glyph.pixels.len() != (glyph.width * height) as usize {
+                    continue;
+                }
+                if !shared.cached.accepts(glyph.width, height) {
+                    continue;
+                }
+                let key = Key {
+                    style: Modifier::from_bits_truncate(glyph.style),
+                    glyph: glyph.glyph,
+                    font: font.id(),
+                };
+                let entry = shared.cached.get(&key, glyph.width, height);
+                if !entry.cached() {
+                    shared.pending_cache_updates.push((*entry, glyph.pixels.clone()));
+                    restored += 1;
+                }
+            }
+            restored
+        })
+    }
+
     /// Build the CPU-computed draw payload for the render world to consume:
     /// the background/foreground vertex data for whichever rows need
     /// re-rendering, plus this backend's font identity (`font_key`, see
@@ -385,7 +1566,11 @@ impl BevyTerminalBackend {
     ///   skips emitting a bg quad for any cell whose color already equals
     ///   `initial_fill` (nothing to draw under `LoadOp::Clear`, but under
     ///   `LoadOp::Load` a previous frame's different pixels there would
-    ///   otherwise linger).
+    ///   otherwise linger). The payload also carries a scissor rect
+    ///   spanning its first through last dirty row, so the pass writes no
+    ///   pixel outside that band (partial present). The clear color plays
+    ///   no part here: `LoadOp::Load` never clears, and the row-clear quads
+    ///   are what paint `initial_fill` inside the band.
     ///
     /// Glyph rasterizations are NOT part of this payload - they queue in
     /// the shared `Fonts::with_shared_cpu_state`, keyed by font rather than
@@ -414,6 +1599,8 @@ impl BevyTerminalBackend {
         let mut text_vertices = Vec::new();
         let row_width_px = bounds.width as f32 * self.fonts.min_width_px() as f32;
         let cell_height_px = self.fonts.height_px() as f32;
+        // First and last dirty row of a partial payload, for its scissor.
+        let mut dirty_span: Option<(usize, usize)> = None;
 
         for y in 0..height {
             let redraw = full
@@ -426,12 +1613,13 @@ impl BevyTerminalBackend {
                 continue;
             }
             if !full {
+                dirty_span = Some(dirty_span.map_or((y, y), |(first, _)| (first, y)));
                 // Row-clear quad: TL, TR, BL, BR corner order, matching
                 // `shape_row`'s own quads - REPLACE-blended, so this is
                 // pixel-identical to clearing just this row.
                 let y0 = y as f32 * cell_height_px;
                 let y1 = y0 + cell_height_px;
-                let color = u32::from_be_bytes(self.initial_fill);
+                let color = u32::from_be_bytes(self.fill());
                 bg_vertices.extend_from_slice(&[
                     TextBgVertexMember {
                         vertex: [0.0, y0],
@@ -464,15 +1652,83 @@ impl BevyTerminalBackend {
         self.rows_dirty_since_take.iter_mut().for_each(|d| *d = false);
         self.full_redraw_needed = false;
 
-        crate::backend::TerminalDrawPayload {
-            screen_width_px: bounds.width as f32 * self.fonts.min_width_px() as f32,
-            screen_height_px: bounds.height as f32 * self.fonts.height_px() as f32,
-            clear_color: self.initial_fill,
+        // Everything above is in grid space; move it to the grid's origin
+        // inside the padded texture.
+        let padding = self.padding;
+        let origin = [padding.left as f32, padding.top as f32];
+        if origin != [0.0, 0.0] {
+            for v in &mut bg_vertices {
+                v.vertex = [v.vertex[0] + origin[0], v.vertex[1] + origin[1]];
+            }
+            for v in &mut text_vertices {
+                v.vertex = [v.vertex[0] + origin[0], v.vertex[1] + origin[1]];
+            }
+        }
+        let (screen_width, screen_height) = padding.texture_size(
+            bounds.width as u32 * self.fonts.min_width_px(),
+            bounds.height as u32 * self.fonts.height_px(),
+        );
+        // Letterbox: a full payload repaints the padding first, in the
+        // color reset cells get, before the rows draw over the grid area.
+        // Rounded corners can't mask the render pass's clear, so with a
+        // corner radius the clear is transparent and the fill is painted as
+        // a quad the shaders round like any other.
+        let letterboxed = [padding.top, padding.right, padding.bottom, padding.left]
+            .iter()
+            .any(|&side| side > 0);
+        let rounded = self.corner_radius > 0;
+        if full && (letterboxed || rounded) {
+            let [r, g, b] = self.quantized(self.reset_bg);
+            let alpha = if self.transparent_reset_bg { 0 } else { 255 };
+            let color = if self.alpha_mask() {
+                0
+            } else if letterboxed {
+                u32::from_be_bytes([r, g, b, alpha])
+            } else {
+                u32::from_be_bytes(self.fill())
+            };
+            let mut fill = Vec::with_capacity(4 + bg_vertices.len());
+            push_bg_quad(
+                &mut fill,
+                [0.0, 0.0],
+                [screen_width as f32, screen_height as f32],
+                color,
+            );
+            fill.append(&mut bg_vertices);
+            bg_vertices = fill;
+        }
+
+        let row_height = self.fonts.height_px();
+        let scissor = dirty_span.map(|(first, last)| {
+            // Negative padding can push the span's top/left off the
+            // texture; the render world clamps the far edges.
+            let x = padding.left as i64;
+            let y = padding.top as i64 + first as i64 * row_height as i64;
+            let width = (bounds.width as u32 * self.fonts.min_width_px()) as i64 + x.min(0);
+            let height = ((last - first + 1) as u32 * row_height) as i64 + y.min(0);
+            [
+                x.max(0) as u32,
+                y.max(0) as u32,
+                width.max(0) as u32,
+                height.max(0) as u32,
+            ]
+        });
+
+        let payload = crate::backend::TerminalDrawPayload {
+            screen_width_px: screen_width as f32,
+            screen_height_px: screen_height as f32,
+            corner_radius: self.corner_radius as f32,
+            text_brightness: self.text_brightness,
+            clear_color: if rounded { [0; 4] } else { self.fill() },
             font_key: self.fonts.identity(),
             load_previous: !full,
+            sample_count: self.msaa_samples,
+            scissor,
             bg_vertices,
             text_vertices,
-        }
+        };
+        self.last_render_stats = payload.render_stats();
+        payload
     }
 
     /// Forces the next [`Self::take_draw_payload`] call to produce a full
@@ -515,519 +1771,1699 @@ impl BevyTerminalBackend {
         Some((self.cols, self.rows))
     }
 
-    /// Get the text content of the terminal.
-    pub fn get_text(&self) -> Vec<Line<'static>> {
-        // TODO: Implement text extraction
-        vec![]
+    /// The column the character covering `(col, row)` starts in. A wide
+    /// (e.g. CJK) character occupies its own cell plus empty continuation
+    /// cells to its right (`NULL_CELL`); for those this walks left to the
+    /// character's own cell. `col` unchanged for every other cell, and for
+    /// positions outside the grid.
+    pub fn glyph_origin_column(&self, col: u16, row: u16) -> u16 {
+        let Ok(bounds) = ratatui::backend::Backend::size(self) else {
+            return col;
+        };
+        if col >= bounds.width || row >= bounds.height {
+            return col;
+        }
+        let row_start = row as usize * bounds.width as usize;
+        let mut origin = col;
+        while origin > 0
+            && self
+                .cells
+                .get(row_start + origin as usize)
+                .is_some_and(|cell| cell.symbol().is_empty())
+        {
+            origin -= 1;
+        }
+        origin
     }
 
-    /// Update fonts used by the backend.
-    pub fn update_fonts(&mut self, new_fonts: Arc<Fonts>) {
-        // Invalidate this backend's own caches and mark all dirty. No
-        // atlas invalidation needed here: the atlas now lives in
-        // `Fonts::with_shared_cpu_state` (IMPROVEMENT.md C3), keyed by the
-        // `Fonts` instance itself - switching to a different `Arc<Fonts>`
-        // means the next flush naturally looks up (or lazily creates) that
-        // OTHER font's own shared state, leaving whatever the old `Fonts`
-        // owned untouched (and simply dropped once its last `Arc` clone
-        // goes away, same as any other Rust value).
-        self.dirty_rows.clear();
-        self.row_geometry.clear();
-        self.rows_dirty_since_take.clear();
-        self.full_redraw_needed = true;
-        #[cfg(feature = "ascii_fast_shaping")]
-        self.ascii_glyph_cache.clear();
-        self.fonts = new_fonts;
+    /// Symbol of the cell at column `x`, row `y` as last drawn. Test-only
+    /// accessor for callers outside the `backend` module, same reasoning
+    /// as `TerminalDrawPayload::bg_quad_colors`.
+    #[cfg(test)]
+    pub(crate) fn cell_symbol(&self, x: u16, y: u16) -> &str {
+        let index = y as usize * self.cols as usize + x as usize;
+        self.cells.get(index).map_or("", Cell::symbol)
     }
 
-    /// Shapes and rasterizes-as-needed a single row (index `y`, of a grid
-    /// `width` cells wide), returning its background and foreground
-    /// vertex geometry. Factored out of `flush()` so the same per-row
-    /// logic can (re)build either a freshly-dirty row or a clean row
-    /// whose cache turned out to be stale (see IMPROVEMENT.md A2) -
-    /// callers append the result to both this frame's output and this
-    /// row's `row_geometry` cache entry. An empty row (no glyphs) returns
-    /// two empty `Vec`s, which is a perfectly valid cache entry - nothing
-    /// distinguishes "cached and empty" from "never cached", since a
-    /// dirty row is always (re)shaped before ever being read as clean.
-    fn shape_row(
-        &mut self,
-        y: usize,
-        width: usize,
-        shared: &mut crate::fonts::SharedFontCpuState,
-    ) -> (Vec<TextBgVertexMember>, Vec<TextVertexMember>) {
-        use crate::backend::c2c;
-        use rustybuzz::shape_with_plan;
-        use rustybuzz::ttf_parser::GlyphId;
-
-        let mut bg_vertices = Vec::new();
-        let mut text_vertices = Vec::new();
+    /// Get the text content of the terminal, one [`Line`] per row with a
+    /// span per run of equally styled cells, trailing blank cells trimmed
+    /// - [`Self::get_text_with`] with the default [`TextExtractOptions`].
+    pub fn get_text(&self) -> Vec<Line<'static>> {
+        self.get_text_with(TextExtractOptions::default())
+    }
 
-        // Packed the same way as `bg_color_u32` below - a bg quad whose
-        // color exactly matches this is redundant (IMPROVEMENT.md B3):
-        // the render pass already clears the target to `initial_fill`, so
-        // drawing an identical quad on top changes nothing. This also
-        // correctly keeps (never skips) an alpha-0 `transparent_reset_bg`
-        // quad whenever `initial_fill`'s own alpha isn't 0 - the packed
-        // values simply won't be equal in that case.
-        let initial_fill_u32 = u32::from_be_bytes(self.initial_fill);
+    /// [`Self::get_text`] with explicit [`TextExtractOptions`]. The cells
+    /// are those of the last `draw()` (or [`Self::set_grid`]), after
+    /// symbol substitution; a wide character's continuation cells add
+    /// nothing. Empty before the first draw.
+    pub fn get_text_with(&self, options: TextExtractOptions) -> Vec<Line<'static>> {
+        let Ok(bounds) = ratatui::backend::Backend::size(self) else {
+            return vec![];
+        };
+        (0..bounds.height)
+            .map_while(|row| self.extract_row(bounds.width, row, 0, bounds.width, options))
+            .map(|cells| {
+                let mut spans: Vec<ratatui::text::Span<'static>> = vec![];
+                for cell in cells {
+                    match spans.last_mut() {
+                        Some(span) if span.style == cell.style() => {
+                            span.content.to_mut().push_str(cell.symbol());
+                        }
+                        _ => spans.push(ratatui::text::Span::styled(
+                            cell.symbol().to_string(),
+                            cell.style(),
+                        )),
+                    }
+                }
+                Line::from(spans)
+            })
+            .collect()
+    }
 
-        let row_start = y * width;
-        let row_end = (row_start + width).min(self.cells.len());
-        let row_cells = &self.cells[row_start..row_end];
+    /// Plain text of the cells from `start` to `end` (`(col, row)`, both
+    /// inclusive) in reading order, the way a mouse selection covers
+    /// them: the first row from `start`'s column, the last up to `end`'s,
+    /// every row in between whole. Rows are joined with `\n`. `start` and
+    /// `end` may be given in either order; positions past the grid are
+    /// clamped to it. Empty before the first draw.
+    pub fn text_in_range(&self, start: (u16, u16), end: (u16, u16), options: TextExtractOptions) -> String {
+        let Ok(bounds) = ratatui::backend::Backend::size(self) else {
+            return String::new();
+        };
+        let cells = bounds.width as usize * bounds.height as usize;
+        if cells == 0 || self.cells.len() < cells {
+            return String::new();
+        }
+        let clamp = |(col, row): (u16, u16)| (col.min(bounds.width - 1), row.min(bounds.height - 1));
+        let (start, end) = (clamp(start), clamp(end));
+        let ((first_col, first_row), (last_col, last_row)) = if (start.1, start.0) <= (end.1, end.0) {
+            (start, end)
+        } else {
+            (end, start)
+        };
 
-        // Build row string for shaping
-        self.row.clear();
-        self.rowmap.clear();
-        for (x, cell) in row_cells.iter().enumerate() {
-            let symbol = cell.symbol();
-            self.row.push_str(symbol);
-            // Map each byte to its cell index
-            for _ in 0..symbol.len() {
-                self.rowmap.push(x as u16);
+        let mut text = String::new();
+        for row in first_row..=last_row {
+            let from = if row == first_row { first_col } else { 0 };
+            let to = if row == last_row { last_col + 1 } else { bounds.width };
+            if row != first_row {
+                text.push('\n');
+            }
+            for cell in self.extract_row(bounds.width, row, from, to, options).unwrap_or_default() {
+                text.push_str(cell.symbol());
             }
         }
+        text
+    }
 
-        if self.row.is_empty() {
-            return (bg_vertices, text_vertices);
+    /// Cells `from..to` of `row` in a grid `width` cells wide, minus the
+    /// trailing blank ones if `options` asks for it - a space on an
+    /// effective reset background, or a wide character's continuation
+    /// cell. `None` before the cells cover the row (no draw yet).
+    fn extract_row(
+        &self,
+        width: u16,
+        row: u16,
+        from: u16,
+        to: u16,
+        options: TextExtractOptions,
+    ) -> Option<&[Cell]> {
+        let row_start = row as usize * width as usize;
+        let mut cells = self.cells.get(row_start + from as usize..row_start + to as usize)?;
+        if options.trim_trailing {
+            while let [rest @ .., last] = cells {
+                let blank = matches!(last.symbol(), " " | "")
+                    && last.bg == ratatui::style::Color::Reset
+                    && !last.modifier.contains(ratatui::style::Modifier::REVERSED);
+                if !blank {
+                    break;
+                }
+                cells = rest;
+            }
         }
+        Some(cells)
+    }
 
-        // For now, use font_for_cell on the first cell
-        #[cfg(feature = "bold_italic_fonts")]
-        let (font, _cell_fake_bold, _cell_fake_italic) =
-            self.fonts.font_for_cell(&row_cells[0]);
+    /// Update fonts used by the backend.
+    pub fn update_fonts(&mut self, new_fonts: Arc<Fonts>) {
+        // Invalidate this backend's own caches and mark all dirty. No
+        // atlas invalidation needed here: the atlas now lives in
+        // `Fonts::with_shared_cpu_state` (IMPROVEMENT.md C3), keyed by the
+        // `Fonts` instance itself - switching to a different `Arc<Fonts>`
+        // means the next flush naturally looks up (or lazily creates) that
+        // OTHER font's own shared state, leaving whatever the old `Fonts`
+        // owned untouched (and simply dropped once its last `Arc` clone
+        // goes away, same as any other Rust value).
+        self.invalidate_geometry();
+        // Widths may differ under the new fonts - uncover whatever the old
+        // ones widened over.
+        for (index, cell) in self.widened_over.drain() {
+            self.cells[index] = cell;
+        }
+        self.fonts = new_fonts;
+    }
 
-        #[cfg(not(feature = "bold_italic_fonts"))]
-        let (font, _cell_fake_bold, _cell_fake_italic) = {
-            let (f, _, _) = self.fonts.font_for_cell(&row_cells[0]);
-            (f, false, false) // Disable fake styling when feature is off
-        };
+    /// Repaint everything: the single entry point for runtime changes
+    /// (fonts, colors, palette, ...) that ratatui's own buffer diff cannot
+    /// see. Marks every row dirty, drops this backend's cached per-row
+    /// geometry, and re-rasterizes every glyph this terminal draws - the
+    /// next `draw()` + `flush()` then rebuild the whole grid from the
+    /// backend's own cell copy (even when the frame's diff is empty) and
+    /// the next payload is a full one.
+    ///
+    /// The atlas is shared by every terminal holding the same `Arc<Fonts>`
+    /// (IMPROVEMENT.md C3), so it is not emptied: other terminals' queued
+    /// uploads and already-built payloads keep pointing at valid slots.
+    /// Each glyph is instead rasterized again into the slot it already
+    /// owns, which leaves its key - and so every other terminal's view of
+    /// it - unchanged. Shape plans are left alone - a plan depends only on
+    /// the (immutable) face, direction and script, so it is never stale.
+    pub fn invalidate_all(&mut self) {
+        self.invalidate_geometry();
+        self.rerasterize = Some(std::collections::HashSet::new());
+    }
 
-        // ASCII fast path (IMPROVEMENT.md A3, opt-in via the
-        // `ascii_fast_shaping` feature - compiled out entirely otherwise,
-        // and requires `bold_italic_fonts` to be off, since that feature
-        // makes per-cell font selection meaningful while this path assumes
-        // the whole row shares `font`, exactly like the slow path already
-        // does whenever `bold_italic_fonts` is off - see `cell_font`
-        // below). Builds synthetic glyph info/position pairs straight from
-        // the font's cmap instead of invoking rustybuzz at all, then feeds
-        // them into the SAME per-glyph loop below as real shaped output -
-        // no duplication of the atlas/vertex-emission logic, so there is
-        // exactly one code path that can get that part wrong. Bails to the
-        // full shaping path (`None`) the moment any cell isn't a single
-        // ASCII printable byte or the font lacks that glyph.
-        #[cfg(all(feature = "ascii_fast_shaping", not(feature = "bold_italic_fonts")))]
-        let fast_path: Option<(Vec<rustybuzz::GlyphInfo>, Vec<rustybuzz::GlyphPosition>)> = 'fast: {
-            use rustybuzz::ttf_parser::Face;
+    /// Turn the cell grid overlay on (`Some(color)`) or off (`None`) at
+    /// runtime - see [`TerminalBuilder::with_cell_grid`]. Every row is
+    /// rebuilt on the next `draw()` + `flush()`, even if its content is
+    /// unchanged.
+    pub fn set_cell_grid(&mut self, color: Option<Rgb>) {
+        if self.cell_grid != color {
+            self.cell_grid = color;
+            self.invalidate_geometry();
+        }
+    }
 
-            let mut infos = Vec::with_capacity(row_cells.len());
-            let mut positions = Vec::with_capacity(row_cells.len());
-            for (x, cell) in row_cells.iter().enumerate() {
-                let bytes = cell.symbol().as_bytes();
-                // Exactly one ASCII printable byte - this single check also
-                // excludes an empty symbol (NULL_CELL wide-char
-                // continuation) and any multi-byte UTF-8 grapheme cluster.
-                if bytes.len() != 1 || !(0x20..=0x7E).contains(&bytes[0]) {
-                    break 'fast None;
-                }
-                let ch = bytes[0] as char;
+    /// Set or clear (`None`) the reset-background gradient at runtime -
+    /// see [`TerminalBuilder::with_background_gradient`]. Every row is
+    /// rebuilt on the next `draw()` + `flush()`.
+    pub fn set_background_gradient(&mut self, gradient: Option<(Rgb, Rgb)>) {
+        if self.background_gradient != gradient {
+            self.background_gradient = gradient;
+            self.invalidate_geometry();
+        }
+    }
 
-                let cache_key = (font.id(), ch);
-                let glyph_id = *self
-                    .ascii_glyph_cache
-                    .entry(cache_key)
-                    .or_insert_with(|| Face::glyph_index(font.font(), ch));
-                let Some(glyph_id) = glyph_id else {
-                    break 'fast None; // font lacks this glyph - defer to full shaping
-                };
+    /// Change the block cursor's colors at runtime - see
+    /// [`TerminalBuilder::with_cursor_style`]. Only the cursor's row is
+    /// rebuilt, on the next `draw()` + `flush()`.
+    pub fn set_cursor_style(&mut self, style: CursorStyle) {
+        if self.cursor_style != style {
+            self.cursor_style = style;
+            if self.cursor_visible {
+                self.reshape_pending = true;
+                self.mark_cursor_row_dirty();
+            }
+        }
+    }
 
-                // Struct-literal update syntax (`..Default::default()`)
-                // isn't usable here: `GlyphInfo` has private fields not
-                // nameable from outside rustybuzz, and Rust's privacy
-                // rules block `..` on a literal that can't name every
-                // field, even though `Default` alone would fill them. Build
-                // the default first, then set only the two public fields.
-                let mut glyph_info = rustybuzz::GlyphInfo::default();
-                glyph_info.glyph_id = glyph_id.0 as u32;
-                glyph_info.cluster = x as u32;
-                infos.push(glyph_info);
-                positions.push(rustybuzz::GlyphPosition::default());
+    /// Change the tab stop spacing at runtime - see
+    /// [`TerminalBuilder::with_tab_width`]. Every row is rebuilt on the
+    /// next `draw()` + `flush()`.
+    pub fn set_tab_width(&mut self, tab_width: u16) {
+        if self.tab_width != tab_width {
+            self.tab_width = tab_width;
+            let rows: Vec<usize> = self.tab_rows.keys().copied().collect();
+            for y in rows {
+                self.lay_out_tab_row(y, None);
             }
-            Some((infos, positions))
+            self.invalidate_geometry();
+        }
+    }
+
+    /// Change the terminal-wide default modifiers at runtime - see
+    /// [`TerminalBuilder::with_default_modifier`]. Every row is rebuilt on
+    /// the next `draw()` + `flush()`, restyling content already drawn.
+    pub fn set_default_modifier(&mut self, modifier: ratatui::style::Modifier) {
+        if self.default_modifier != modifier {
+            self.default_modifier = modifier;
+            self.invalidate_geometry();
+        }
+    }
+
+    /// Change the terminal-wide underline color at runtime - see
+    /// [`TerminalBuilder::with_underline_color`]. Every row is rebuilt on
+    /// the next `draw()` + `flush()`.
+    pub fn set_underline_color(&mut self, color: Option<Rgb>) {
+        if self.underline_color != color {
+            self.underline_color = color;
+            self.invalidate_geometry();
+        }
+    }
+
+    /// Change the strikethrough color at runtime - see
+    /// [`TerminalBuilder::with_strikethrough_color`]. Every row is rebuilt
+    /// on the next `draw()` + `flush()`.
+    pub fn set_strikethrough_color(&mut self, color: Option<Rgb>) {
+        if self.strikethrough_color != color {
+            self.strikethrough_color = color;
+            self.invalidate_geometry();
+        }
+    }
+
+    /// Packed `(underline, strikethrough)` colors for `cell`, falling back
+    /// to its already-packed text color `fg`. Alpha is copied from `fg`, so
+    /// the lines fade with the glyph.
+    fn decoration_colors(&self, cell: &Cell, fg: u32) -> (u32, u32) {
+        let pack = |color: Rgb| (self.opaque(color) & 0xFFFF_FF00) | (fg & 0xFF);
+        #[cfg(feature = "underline_color")]
+        let own_underline = Some(cell.underline_color)
+            .filter(|color| *color != ratatui::style::Color::Reset)
+            .map(|color| crate::backend::c2c(color, self.reset_fg, self.palette.as_ref()));
+        #[cfg(not(feature = "underline_color"))]
+        let own_underline = {
+            let _ = cell;
+            None
         };
-        #[cfg(not(all(feature = "ascii_fast_shaping", not(feature = "bold_italic_fonts"))))]
-        let fast_path: Option<(Vec<rustybuzz::GlyphInfo>, Vec<rustybuzz::GlyphPosition>)> = None;
+        let underline = own_underline.or(self.underline_color).map_or(fg, pack);
+        let strikethrough = self.strikethrough_color.map_or(fg, pack);
+        (underline, strikethrough)
+    }
 
-        let (infos, positions): (Vec<rustybuzz::GlyphInfo>, Vec<rustybuzz::GlyphPosition>) =
-            if let Some(fast) = fast_path {
-                fast
-            } else {
-                // Shape the row
-                let mut buffer = std::mem::take(&mut self.buffer);
-                buffer.clear();
-                for (idx, ch) in self.row.char_indices() {
-                    buffer.add(ch, idx as u32);
-                }
+    /// Queue the cursor's current row for reshaping. Called whenever the
+    /// cursor moves, shows, hides or changes color - ratatui does all of
+    /// those between its `draw()` and `flush()` calls, so the row is
+    /// rebuilt by the same frame's `flush()`.
+    fn mark_cursor_row_dirty(&mut self) {
+        if let Some(dirty) = self.dirty_rows.get_mut(self.cursor.1 as usize) {
+            *dirty = true;
+            self.cells_changed_last_draw = true;
+        }
+    }
 
-                let glyph_buffer =
-                    shape_with_plan(font.font(), shared.plan_cache.get(font, &mut buffer), buffer);
-                let infos = glyph_buffer.glyph_infos().to_vec();
-                let positions = glyph_buffer.glyph_positions().to_vec();
-                self.buffer = glyph_buffer.clear();
-                (infos, positions)
-            };
+    /// Change the viewport after build - see [`Viewport`]. The cell grid
+    /// ratatui sees changes size, so the caller must also resize the
+    /// `ratatui::Terminal` to the new [`ratatui::backend::Backend::size`]
+    /// (`Tui::set_viewport` does); the next payload repaints the texture
+    /// in full, with the uncovered margin in the initial fill color.
+    pub fn set_viewport(&mut self, viewport: Viewport) {
+        if self.viewport != viewport {
+            self.viewport = viewport;
+            // Same invalidation as a grid resize: row width and count may
+            // both have changed.
+            self.resize(self.cols, self.rows);
+        }
+    }
 
-        // Process shaped (or synthesized) glyphs
-        let metrics = font.font();
-        let advance_scale = self.fonts.height_px() as f32 / metrics.height() as f32;
+    /// Change the padding after build - see [`TerminalBuilder::with_padding`].
+    /// The texture size changes with it, so the caller must also recreate
+    /// the destination image (`TerminalTexture::with_padding` does); the
+    /// next payload repaints it in full. Row geometry is kept - only its
+    /// translation changes.
+    pub fn set_padding(&mut self, padding: Padding) {
+        if self.padding != padding {
+            self.padding = padding;
+            self.full_redraw_needed = true;
+        }
+    }
 
-        for (info, pos) in infos.iter().zip(positions.iter()) {
-            let cluster = info.cluster as usize;
-            if cluster >= self.rowmap.len() {
+    /// Current padding - see [`TerminalBuilder::with_padding`].
+    pub fn padding(&self) -> Padding {
+        self.padding
+    }
+
+    /// Change the chrome after build, or remove it with `None` - see
+    /// [`TerminalBuilder::with_chrome`]. The area ratatui may draw into
+    /// changes with it, so the caller must also fit the `ratatui::Terminal`
+    /// to the new [`Self::content_area`] (`Tui::set_chrome` does); every
+    /// row is rebuilt on the next `draw()` + `flush()`.
+    pub fn set_chrome(&mut self, chrome: Option<ChromeConfig>) {
+        if self.chrome != chrome {
+            self.chrome = chrome;
+            self.chrome_ring = None;
+            self.invalidate_geometry();
+        }
+    }
+
+    /// Current chrome - see [`TerminalBuilder::with_chrome`].
+    pub fn chrome(&self) -> Option<&ChromeConfig> {
+        self.chrome.as_ref()
+    }
+
+    /// The part of the grid ratatui draws into: all of it, or with a
+    /// [`ChromeConfig`] the cells inside its border (empty when the grid
+    /// is too small to have any).
+    pub fn content_area(&self) -> ratatui::layout::Rect {
+        let size = ratatui::backend::Backend::size(self).unwrap_or_default();
+        let area = ratatui::layout::Rect::new(0, 0, size.width, size.height);
+        if self.chrome.is_some() {
+            area.inner(ratatui::layout::Margin::new(1, 1))
+        } else {
+            area
+        }
+    }
+
+    /// A 1x1 backend on the same fonts, holding this one's place while the
+    /// `ratatui::Terminal` around it is rebuilt.
+    pub(crate) fn stand_in(&self) -> Self {
+        TerminalBuilder::new(Arc::clone(&self.fonts))
+            .with_dimensions(1, 1)
+            .build()
+    }
+
+    /// Write the chrome's ring into the grid wherever it differs from what
+    /// is there. Ratatui never draws over it, but a clear blanks it, and a
+    /// resize or [`Self::set_grid`] replaces it.
+    fn stamp_chrome(&mut self, fading: bool, width: usize) {
+        let Some(chrome) = &self.chrome else {
+            return;
+        };
+        if width == 0 {
+            return;
+        }
+        let size = (width as u16, (self.cells.len() / width) as u16);
+        let ring = match self.chrome_ring.take() {
+            Some((built_for, ring)) if built_for == size => ring,
+            _ => chrome.ring(size.0, size.1),
+        };
+        // Skips the cells a wide title character covers, as ratatui's own
+        // diff does.
+        let mut next_free = 0;
+        for (x, y, cell) in &ring {
+            let index = *y as usize * width + *x as usize;
+            if index < next_free || index >= self.cells.len() {
                 continue;
             }
-
-            let cell_idx = self.rowmap[cluster] as usize;
-            if cell_idx >= row_cells.len() {
+            if self.cells[index] == *cell {
+                next_free = index + self.fonts.text_width_cells(cell.symbol()).max(1);
                 continue;
             }
+            next_free = index + self.store_cell(index, cell, fading);
+            self.dirty_rows[*y as usize] = true;
+            self.cells_changed_last_draw = true;
+        }
+        self.chrome_ring = Some((size, ring));
+    }
 
-            let cell = &row_cells[cell_idx];
-            let _glyph_id = GlyphId(info.glyph_id as u16);
+    /// Change the powerline separator geometry at runtime - see
+    /// [`TerminalBuilder::with_powerline_style`]. Every row is rebuilt on
+    /// the next `draw()` + `flush()`; separators in the new style are
+    /// rasterized on first use.
+    pub fn set_powerline_style(&mut self, style: PowerlineStyle) {
+        if self.powerline_style != style {
+            self.powerline_style = style;
+            self.invalidate_geometry();
+        }
+    }
 
-            // Use per-cell font selection for proper styling
-            #[cfg(feature = "bold_italic_fonts")]
-            let (cell_font, cell_fake_bold, cell_fake_italic) = self.fonts.font_for_cell(cell);
+    /// Change the programmatic glyph supersampling factor at runtime - see
+    /// [`TerminalBuilder::with_programmatic_supersampling`]. Every row is
+    /// rebuilt on the next `draw()` + `flush()`; glyphs at the new factor
+    /// are rasterized on first use.
+    pub fn set_programmatic_supersampling(&mut self, factor: u32) {
+        let factor = factor.clamp(1, 4);
+        if self.programmatic_supersample != factor {
+            self.programmatic_supersample = factor;
+            self.invalidate_geometry();
+        }
+    }
 
-            #[cfg(not(feature = "bold_italic_fonts"))]
-            let (cell_font, cell_fake_bold, cell_fake_italic) = (font, false, false);
+    /// Atlas font id for the programmatic glyph `ch`: the last-resort
+    /// font's, with the powerline style mixed in for separators and the
+    /// supersampling factor for every glyph, so glyphs drawn differently
+    /// never share an atlas slot.
+    fn programmatic_font_id(&self, ch: char) -> u64 {
+        use crate::backend::programmatic_glyphs::is_powerline_glyph;
 
-            // Calculate character width using unicode-width for precise glyph width
-            use unicode_width::UnicodeWidthChar;
-            let ch = cell.symbol().chars().next().unwrap_or(' ');
-            let ch_width = ch.width().unwrap_or(1).max(1) as u32;
-            let glyph_width_px = ch_width * self.fonts.min_width_px();
+        let mut font_id = self.fonts.last_resort_id();
+        if is_powerline_glyph(ch) {
+            font_id ^= self.powerline_style.cache_salt();
+        }
+        if self.programmatic_supersample > 1 {
+            font_id ^= u64::from(self.programmatic_supersample).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        }
+        font_id
+    }
 
-            // Check if this character is an emoji
-            #[cfg(feature = "emoji")]
-            fn is_emoji(ch: char) -> bool {
-                use unicode_properties::UnicodeEmoji;
-                // Simplify emoji detection - just check if it's an emoji character
-                ch.is_emoji_char()
-            }
+    /// Change the MSAA sample count after build - see
+    /// [`TerminalBuilder::with_msaa`]. The render world switches to the new
+    /// multisampled target with this terminal's next payload and repaints
+    /// it in full.
+    pub fn set_msaa(&mut self, samples: u32) {
+        let samples = samples.max(1);
+        if self.msaa_samples != samples {
+            self.msaa_samples = samples;
+            self.full_redraw_needed = true;
+            self.reshape_pending = true;
+        }
+    }
 
-            #[cfg(not(feature = "emoji"))]
-            fn is_emoji(_ch: char) -> bool {
-                false
+    /// Turn control-character pictures on or off at runtime - see
+    /// [`TerminalBuilder::with_control_pictures`]. The substitution happens
+    /// as cells are drawn, so only content drawn afterwards changes.
+    pub fn set_control_pictures(&mut self, enabled: bool) {
+        self.control_pictures = enabled;
+    }
+
+    /// Turn the transparency checkerboard on or off at runtime - see
+    /// [`TerminalBuilder::with_transparency_checkerboard`]. Every row is
+    /// rebuilt on the next `draw()` + `flush()`.
+    pub fn set_transparency_checkerboard(&mut self, enabled: bool) {
+        if self.transparency_checkerboard != enabled {
+            self.transparency_checkerboard = enabled;
+            self.invalidate_geometry();
+        }
+    }
+
+    /// Change the glyph scale at runtime - see
+    /// [`TerminalBuilder::with_glyph_scale`]. Every row is rebuilt on the
+    /// next `draw()` + `flush()`.
+    pub fn set_glyph_scale(&mut self, x: f32, y: f32) {
+        let scale = sanitize_glyph_scale((x, y));
+        if self.glyph_scale != scale {
+            self.glyph_scale = scale;
+            self.invalidate_geometry();
+        }
+    }
+
+    /// Change the cell inset at runtime - see
+    /// [`TerminalBuilder::with_cell_inset`]. Every row is rebuilt on the
+    /// next `draw()` + `flush()` if it actually changed.
+    pub fn set_cell_inset(&mut self, px: u32) {
+        if self.cell_inset != px {
+            self.cell_inset = px;
+            self.invalidate_geometry();
+        }
+    }
+
+    /// Turn missing-glyph tracking on or off at runtime - see
+    /// [`TerminalBuilder::with_missing_glyph_tracking`]. Turning it on
+    /// rebuilds every row on the next `draw()` + `flush()`, so content
+    /// already on screen is checked too; turning it off clears the set.
+    pub fn set_missing_glyph_tracking(&mut self, enabled: bool) {
+        if self.track_missing_glyphs != enabled {
+            self.track_missing_glyphs = enabled;
+            if enabled {
+                self.invalidate_geometry();
+            } else {
+                self.missing_glyphs.clear();
             }
+        }
+    }
 
-            let is_emoji = is_emoji(ch);
+    /// Characters this terminal could not draw since tracking was enabled
+    /// or [`Self::clear_missing_glyphs`] was last called - see
+    /// [`TerminalBuilder::with_missing_glyph_tracking`]. Always empty
+    /// while tracking is off. A row is only checked when it is reshaped,
+    /// so a character shows up here after the `flush()` that draws it.
+    pub fn missing_glyphs(&self) -> &std::collections::HashSet<char> {
+        &self.missing_glyphs
+    }
 
-            // Check if this is a programmatic glyph that was pre-rendered
-            use crate::backend::programmatic_glyphs::is_programmatic_glyph;
-            let is_programmatic = is_programmatic_glyph(ch);
+    /// Forget every character recorded in [`Self::missing_glyphs`]. Ones
+    /// no font covers are reported again the next time their row is
+    /// redrawn; a failed programmatic glyph only on its first attempt.
+    pub fn clear_missing_glyphs(&mut self) {
+        self.missing_glyphs.clear();
+    }
 
-            // Create cache key
-            // For programmatic glyphs: use Unicode codepoint + last_resort font (matches populate_programmatic_glyphs)
-            // For font glyphs: use shaped glyph ID + actual font
+    /// Requested MSAA sample count, `1` for none - see
+    /// [`TerminalBuilder::with_msaa`].
+    pub fn msaa_samples(&self) -> u32 {
+        self.msaa_samples
+    }
 
-            #[cfg(feature = "bold_italic_fonts")]
-            let style = cell.modifier
-                & (ratatui::style::Modifier::BOLD | ratatui::style::Modifier::ITALIC);
+    /// Quad and draw-call counts of the last payload handed to the
+    /// renderer - see [`RenderStats`]. Cheap: computed once per payload
+    /// from the vertex counts. Default (all zero) before the first one.
+    pub fn render_stats(&self) -> RenderStats {
+        self.last_render_stats
+    }
 
-            #[cfg(not(feature = "bold_italic_fonts"))]
-            let style = ratatui::style::Modifier::empty();
+    /// `true` while at least one cell is still fading in (see
+    /// [`TerminalBuilder::with_cell_fade`]).
+    pub fn is_fading(&self) -> bool {
+        self.cell_changed_at.iter().any(Option::is_some)
+    }
 
-            let key = if is_programmatic {
-                Key {
-                    style,
-                    glyph: ch as u32,
-                    font: self.fonts.last_resort_id(),
-                }
-            } else {
-                Key {
-                    style,
-                    glyph: info.glyph_id,
-                    font: cell_font.id(),
+    /// Advance the fade-ins to `now`, the app time elapsed so far (e.g.
+    /// [`Time::elapsed`](bevy::time::Time::elapsed)): cells drawn since the
+    /// last tick start fading from `now`, finished fades are retired, and
+    /// every row with a fading cell is marked for the next `draw()` +
+    /// `flush()` to rebuild with the new alpha, even if ratatui's diff
+    /// leaves it alone. A fade finishing this tick marks its row once
+    /// more, so the cell ends on exactly full alpha. Returns whether any
+    /// row was marked.
+    pub fn tick_fades(&mut self, now: Duration) -> bool {
+        let Some(fade) = self.cell_fade else {
+            return false;
+        };
+        let width = ratatui::backend::Backend::size(self).map_or(0, |size| size.width as usize);
+        if width == 0 {
+            return false;
+        }
+        self.fade_clock = Some(now);
+        let mut marked = false;
+        for (index, changed_at) in self.cell_changed_at.iter_mut().enumerate() {
+            match *changed_at {
+                None => continue,
+                Some(FadeStart::Pending) => *changed_at = Some(FadeStart::At(now)),
+                Some(FadeStart::At(started)) if now.saturating_sub(started) >= fade => {
+                    *changed_at = None;
                 }
-            };
+                Some(FadeStart::At(_)) => {}
+            }
+            if index / width < self.dirty_rows.len() {
+                self.dirty_rows[index / width] = true;
+                marked = true;
+            }
+        }
+        if marked {
+            self.reshape_pending = true;
+            self.cells_changed_last_draw = true;
+        }
+        marked
+    }
 
-            let cached = shared
-                .cached
-                .get(&key, glyph_width_px, self.fonts.height_px());
+    /// End every running fade at once, its row repainted at full alpha on
+    /// the next `draw()` + `flush()`.
+    pub(crate) fn finish_fades(&mut self) {
+        let width = ratatui::backend::Backend::size(self).map_or(0, |size| size.width as usize);
+        for (index, changed_at) in std::mem::take(&mut self.cell_changed_at).into_iter().enumerate() {
+            if changed_at.is_some() && width > 0 && index / width < self.dirty_rows.len() {
+                self.dirty_rows[index / width] = true;
+                self.reshape_pending = true;
+            }
+        }
+    }
 
-            // If not cached, render the glyph
-            if !cached.cached() {
-                if is_programmatic {
-                    // Render programmatic glyph on-demand if not pre-cached
-                    use crate::backend::programmatic_glyphs::render_programmatic_glyph;
+    /// Advance the blink phases to `now`, the app time elapsed so far (e.g.
+    /// [`Time::elapsed`](bevy::time::Time::elapsed)): a phase flips once its
+    /// duration (see [`TerminalBuilder::with_blink_rates`]) has passed since
+    /// the last flip, and every row holding a cell of that blink kind is
+    /// marked for the next `draw()` + `flush()` to rebuild, even if
+    /// ratatui's diff leaves it alone. The first call only starts the
+    /// clocks. Returns whether any row was marked.
+    pub fn tick_blink(&mut self, now: Duration) -> bool {
+        let fast = advance_blink_phase(
+            &mut self.last_fast_toggle,
+            &mut self.show_fast,
+            self.blink_rates.0,
+            now,
+        );
+        let slow = advance_blink_phase(
+            &mut self.last_slow_toggle,
+            &mut self.show_slow,
+            self.blink_rates.1,
+            now,
+        );
+        self.mark_blinking_rows(fast, slow)
+    }
 
-                    if let Some(pixmap) =
-                        render_programmatic_glyph(ch, glyph_width_px, self.fonts.height_px())
-                    {
-                        let bitmap = pixmap_to_rgba8(pixmap);
-                        shared.pending_cache_updates.push((*cached, bitmap));
-                    } else {
-                        tracing::warn!(
-                            "Failed to render programmatic glyph '{}' (U+{:04X})",
-                            ch,
-                            ch as u32
-                        );
-                    }
-                } else {
-                    // Calculate glyph bearing offset to apply during rasterization
-                    let bearing_offset_x = pos.x_offset as f32 * advance_scale;
+    /// Turn the per-cell fade-in on (`duration`) or off (`Duration::ZERO`)
+    /// at runtime - see [`TerminalBuilder::with_cell_fade`]. Only content
+    /// changed afterwards fades in; fades still running end at once, their
+    /// rows repainted at full alpha on the next `draw()` + `flush()`.
+    pub fn set_cell_fade(&mut self, duration: Duration) {
+        let fade = (!duration.is_zero()).then_some(duration);
+        if self.cell_fade == fade {
+            return;
+        }
+        self.cell_fade = fade;
+        self.finish_fades();
+    }
 
-                    // Don't apply fake styling to emoji characters to avoid distortion
-                    let final_fake_italic = cell_fake_italic && !is_emoji;
-                    let final_fake_bold = cell_fake_bold && !is_emoji;
+    /// Change the blink rates at runtime - see
+    /// [`TerminalBuilder::with_blink_rates`]. Blinking text comes back on
+    /// and both phases restart on the next [`Self::tick_blink`].
+    pub fn set_blink_rates(&mut self, fast: Duration, slow: Duration) {
+        if self.blink_rates == (fast, slow) {
+            return;
+        }
+        self.blink_rates = (fast, slow);
+        let (fast_was_off, slow_was_off) = (!self.show_fast, !self.show_slow);
+        self.show_fast = true;
+        self.show_slow = true;
+        self.last_fast_toggle = None;
+        self.last_slow_toggle = None;
+        self.mark_blinking_rows(fast_was_off, slow_was_off);
+    }
 
-                    let (rect, image) = rasterize_glyph(
-                        cached,
-                        metrics,
-                        info,
-                        final_fake_italic, // Don't distort emoji
-                        final_fake_bold,   // Don't distort emoji
-                        advance_scale,
-                        glyph_width_px,   // Use actual glyph width
-                        bearing_offset_x, // Apply offset in atlas
-                    );
+    /// Mark dirty every row with a rapid (`fast`) or slow (`slow`) blinking
+    /// cell. Returns whether any row was marked.
+    fn mark_blinking_rows(&mut self, fast: bool, slow: bool) -> bool {
+        use ratatui::style::Modifier;
 
-                    shared.pending_cache_updates.push((rect, image));
-                }
+        if !fast && !slow {
+            return false;
+        }
+        let width = ratatui::backend::Backend::size(self).map_or(0, |size| size.width as usize);
+        if width == 0 {
+            return false;
+        }
+        let mut marked = false;
+        for (index, cell) in self.cells.iter().enumerate() {
+            let modifier = cell.modifier | self.default_modifier;
+            let blinks = if modifier.contains(Modifier::RAPID_BLINK) {
+                fast
+            } else {
+                slow && modifier.contains(Modifier::SLOW_BLINK)
+            };
+            if blinks && index / width < self.dirty_rows.len() {
+                self.dirty_rows[index / width] = true;
+                marked = true;
             }
+        }
+        if marked {
+            self.reshape_pending = true;
+            self.cells_changed_last_draw = true;
+        }
+        marked
+    }
 
-            // Calculate screen position - align to cell grid since offset is already in atlas
-            let screen_x = cell_idx as f32 * self.fonts.min_width_px() as f32;
-            let screen_y = y as f32 * self.fonts.height_px() as f32;
+    /// Whether `modifier`'s blink kind is in its off phase, so the cell's
+    /// glyph is left out.
+    fn blinked_off(&self, modifier: ratatui::style::Modifier) -> bool {
+        use ratatui::style::Modifier;
 
-            // Get colors
-            let reverse = cell.modifier.contains(ratatui::style::Modifier::REVERSED);
-            // The color actually being used *as the background* -
-            // `cell.fg` when reversed, matching the swap below. Checked
-            // against `Color::Reset` before `c2c()` resolves it to an
-            // opaque RGB, since that resolution is exactly what erases
-            // the "this cell has no explicit background" information
-            // `transparent_reset_bg` needs.
-            let bg_source = if reverse { cell.fg } else { cell.bg };
-            let bg_color = if reverse {
-                c2c(cell.fg, self.reset_fg)
-            } else {
-                c2c(cell.bg, self.reset_bg)
-            };
-            let fg_color = if reverse {
-                c2c(cell.bg, self.reset_bg)
-            } else {
-                c2c(cell.fg, self.reset_fg)
-            };
+        if modifier.contains(Modifier::RAPID_BLINK) {
+            !self.show_fast
+        } else {
+            modifier.contains(Modifier::SLOW_BLINK) && !self.show_slow
+        }
+    }
 
-            let bg_alpha = if self.transparent_reset_bg
-                && matches!(bg_source, ratatui::style::Color::Reset)
-            {
-                0
-            } else {
-                255
-            };
-            let [r, g, b] = bg_color;
-            let bg_color_u32 = u32::from_be_bytes([r, g, b, bg_alpha]);
-
-            let [r, g, b] = fg_color;
-            let fg_color_u32 = u32::from_be_bytes([r, g, b, 255]);
-
-            // Render at actual glyph width (no compression)
-            let render_width_px = glyph_width_px as f32;
-
-            // Background vertices - skipped when this cell's background
-            // exactly matches the render pass's own clear color (see
-            // `initial_fill_u32` above); the fg (text) quad below is
-            // unaffected, so bg/fg quad counts intentionally diverge here.
-            if bg_color_u32 != initial_fill_u32 {
-                bg_vertices.push(TextBgVertexMember {
-                    vertex: [screen_x, screen_y],
-                    bg_color: bg_color_u32,
-                });
-                bg_vertices.push(TextBgVertexMember {
-                    vertex: [screen_x + render_width_px, screen_y],
-                    bg_color: bg_color_u32,
-                });
-                bg_vertices.push(TextBgVertexMember {
-                    vertex: [screen_x, screen_y + self.fonts.height_px() as f32],
-                    bg_color: bg_color_u32,
-                });
-                bg_vertices.push(TextBgVertexMember {
-                    vertex: [
-                        screen_x + render_width_px,
-                        screen_y + self.fonts.height_px() as f32,
-                    ],
-                    bg_color: bg_color_u32,
-                });
-            }
+    /// Foreground alpha (0-255) for the cell at `index` as of the last
+    /// [`Self::tick_fades`] - 255 unless it is mid-fade, 0 if it was drawn
+    /// since.
+    fn fade_alpha(&self, index: usize) -> u8 {
+        let Some(fade) = self.cell_fade else {
+            return 255;
+        };
+        let started = match self.cell_changed_at.get(index) {
+            Some(Some(FadeStart::At(started))) => *started,
+            Some(Some(FadeStart::Pending)) => return 0,
+            _ => return 255,
+        };
+        let now = self.fade_clock.unwrap_or(started);
+        let progress = now.saturating_sub(started).as_secs_f32() / fade.as_secs_f32();
+        (progress.clamp(0.0, 1.0) * 255.0) as u8
+    }
 
-            // Text vertices - 1:1 mapping between atlas and screen
-            let uv_x = cached.x as f32;
-            let uv_y = cached.y as f32;
-            let uv_w = cached.width as f32; // Matches glyph_width_px
-            let uv_h = cached.height as f32;
+    /// Change the colors `Color::Reset` resolves to (foreground and
+    /// background) after build - see [`TerminalBuilder::with_reset_fg`] /
+    /// [`TerminalBuilder::with_reset_bg`]. Every row is rebuilt on the next
+    /// `draw()` + `flush()` if either color actually changed.
+    pub fn set_reset_colors(&mut self, fg: Rgb, bg: Rgb) {
+        if (self.reset_fg, self.reset_bg) != (fg, bg) {
+            self.reset_fg = fg;
+            self.reset_bg = bg;
+            self.invalidate_geometry();
+        }
+    }
 
-            text_vertices.push(TextVertexMember {
-                vertex: [screen_x, screen_y],
-                uv: [uv_x, uv_y],
-                fg_color: fg_color_u32,
-                underline_pos: 0,
-                underline_color: fg_color_u32,
-            });
-            text_vertices.push(TextVertexMember {
-                vertex: [screen_x + render_width_px, screen_y],
-                uv: [uv_x + uv_w, uv_y],
-                fg_color: fg_color_u32,
-                underline_pos: 0,
-                underline_color: fg_color_u32,
-            });
-            text_vertices.push(TextVertexMember {
-                vertex: [screen_x, screen_y + self.fonts.height_px() as f32],
-                uv: [uv_x, uv_y + uv_h],
-                fg_color: fg_color_u32,
-                underline_pos: 0,
-                underline_color: fg_color_u32,
-            });
-            text_vertices.push(TextVertexMember {
-                vertex: [
-                    screen_x + render_width_px,
-                    screen_y + self.fonts.height_px() as f32,
-                ],
-                uv: [uv_x + uv_w, uv_y + uv_h],
-                fg_color: fg_color_u32,
-                underline_pos: 0,
-                underline_color: fg_color_u32,
-            });
+    /// Override the 16 ANSI colors - ratatui's named colors (`Color::Red`,
+    /// `Color::LightBlue`, ...) and `Color::Indexed(0..16)`, in xterm slot
+    /// order - or restore the built-in table with `None`. `Color::Rgb` and
+    /// `Indexed(16..)` are unaffected. Every row is rebuilt on the next
+    /// `draw()` + `flush()` if the palette actually changed.
+    pub fn set_palette(&mut self, palette: Option<[Rgb; 16]>) {
+        if self.palette != palette {
+            self.palette = palette;
+            self.invalidate_geometry();
         }
+    }
 
-        (bg_vertices, text_vertices)
+    /// Set or clear (`None`) the palette every color is snapped to at
+    /// runtime - see [`TerminalBuilder::with_color_quantize`]. Every row is
+    /// rebuilt on the next `draw()` + `flush()` if it actually changed.
+    pub fn set_color_quantize(&mut self, palette: Option<Palette>) {
+        if self.color_quantize != palette {
+            self.color_quantize = palette;
+            self.invalidate_geometry();
+        }
     }
-}
 
-// ====== Backend trait implementation ======
+    /// Set or clear (`None`) the glyph drawn in blank cells at runtime -
+    /// see [`TerminalBuilder::with_empty_cell`]. Every row is rebuilt on
+    /// the next `draw()` + `flush()` if it actually changed.
+    pub fn set_empty_cell(&mut self, empty_cell: Option<(char, Rgb)>) {
+        if self.empty_cell != empty_cell {
+            self.empty_cell = empty_cell;
+            self.invalidate_geometry();
+        }
+    }
 
-impl ratatui::backend::Backend for BevyTerminalBackend {
-    type Error = std::io::Error;
-    fn draw<'a, I>(&mut self, content: I) -> std::io::Result<()>
-    where
-        I: Iterator<Item = (u16, u16, &'a Cell)>,
-    {
-        use unicode_width::UnicodeWidthStr;
+    /// Set or clear (`None`) the per-flush glyph budget at runtime - see
+    /// [`TerminalBuilder::with_glyph_budget`]. Applies from the next
+    /// `flush()`.
+    pub fn set_glyph_budget(&mut self, glyphs: Option<u32>) {
+        self.glyph_budget = glyphs.map(|glyphs| glyphs.max(1));
+    }
 
-        let bounds = self.size()?;
+    /// Whether the last `flush()` ran out of its glyph budget and left
+    /// glyphs for later - see [`TerminalBuilder::with_glyph_budget`].
+    pub fn has_deferred_glyphs(&self) -> bool {
+        self.glyphs_deferred
+    }
 
-        self.cells
-            .resize(bounds.height as usize * bounds.width as usize, Cell::EMPTY);
-        self.dirty_rows.resize(bounds.height as usize, true);
-        self.row_geometry
-            .resize_with(bounds.height as usize, RowGeometry::default);
-        self.cells_changed_last_draw = false;
+    /// Rasterize the next batch of glyphs a budgeted `flush()` put off, as
+    /// an unchanged frame's `draw()` + `flush()` would. `false` (and
+    /// nothing done) when there are none.
+    pub(crate) fn rasterize_deferred_glyphs(&mut self) -> bool {
+        if !self.glyphs_deferred {
+            return false;
+        }
+        self.reshape_marked_rows()
+    }
 
-        for (x, y, cell) in content {
-            self.cells_changed_last_draw = true;
-            let index = y as usize * bounds.width as usize + x as usize;
+    /// Rebuild the rows marked dirty outside a draw (deferred glyphs, a
+    /// blink phase flip), as an unchanged frame's `draw()` + `flush()`
+    /// would. `false` if that failed.
+    pub(crate) fn reshape_marked_rows(&mut self) -> bool {
+        let empty = std::iter::empty::<(u16, u16, &Cell)>();
+        ratatui::backend::Backend::draw(self, empty)
+            .and_then(|()| ratatui::backend::Backend::flush(self))
+            .is_ok()
+    }
 
-            self.cells[index] = cell.clone();
+    /// Change the corner radius at runtime - see
+    /// [`TerminalBuilder::with_corner_radius`]. The whole texture is
+    /// repainted on the next `draw()` + `flush()` if it actually changed.
+    pub fn set_corner_radius(&mut self, px: u32) {
+        if self.corner_radius != px {
+            self.corner_radius = px;
+            self.invalidate_geometry();
+        }
+    }
 
-            let width = cell.symbol().width().max(1);
-            let start = (index + 1).min(self.cells.len());
-            let end = (index + width).min(self.cells.len());
-            self.cells[start..end].fill(NULL_CELL);
-            self.dirty_rows[y as usize] = true;
+    /// Change the text brightness at runtime - see
+    /// [`TerminalBuilder::with_text_brightness`]. The whole texture is
+    /// repainted on the next `draw()` + `flush()` if it actually changed.
+    pub fn set_text_brightness(&mut self, factor: f32) {
+        let factor = factor.max(0.0);
+        if self.text_brightness != factor {
+            self.text_brightness = factor;
+            self.invalidate_geometry();
         }
+    }
 
-        Ok(())
+    /// The text brightness factor - see
+    /// [`TerminalBuilder::with_text_brightness`].
+    pub fn text_brightness(&self) -> f32 {
+        self.text_brightness
     }
 
-    fn hide_cursor(&mut self) -> std::io::Result<()> {
-        Ok(())
+    /// Switch between color and coverage output at runtime - see
+    /// [`TerminalBuilder::with_output_mode`]. Every row is rebuilt on the
+    /// next `draw()` + `flush()` if it actually changed.
+    pub fn set_output_mode(&mut self, mode: OutputMode) {
+        if self.output_mode != mode {
+            self.output_mode = mode;
+            self.invalidate_geometry();
+        }
     }
 
-    fn show_cursor(&mut self) -> std::io::Result<()> {
-        Ok(())
+    /// Turn ligatures on or off at runtime - see
+    /// [`TerminalBuilder::with_ligatures`]. Every row is reshaped on the
+    /// next `draw()` + `flush()` if it actually changed.
+    pub fn set_ligatures(&mut self, ligatures: bool) {
+        if self.ligatures != ligatures {
+            self.ligatures = ligatures;
+            self.invalidate_geometry();
+        }
     }
 
-    fn get_cursor_position(&mut self) -> std::io::Result<ratatui::layout::Position> {
-        Ok(ratatui::layout::Position::new(self.cursor.0, self.cursor.1))
+    /// `color` as drawn under `color_quantize`.
+    fn quantized(&self, color: Rgb) -> Rgb {
+        self.color_quantize.as_ref().map_or(color, |palette| palette.quantize(color))
     }
 
-    fn set_cursor_position<Pos: Into<ratatui::layout::Position>>(
-        &mut self,
-        position: Pos,
-    ) -> std::io::Result<()> {
-        let bounds = self.size()?;
-        let pos: ratatui::layout::Position = position.into();
-        self.cursor = (pos.x.min(bounds.width - 1), pos.y.min(bounds.height - 1));
-        Ok(())
+    /// Whether this backend renders [`OutputMode::AlphaMask`].
+    fn alpha_mask(&self) -> bool {
+        self.output_mode == OutputMode::AlphaMask
     }
 
-    fn clear(&mut self) -> std::io::Result<()> {
-        self.cells.clear();
-        self.dirty_rows.clear();
-        self.row_geometry.clear();
-        self.cursor = (0, 0);
+    /// `color`, opaque, packed as the vertex formats expect - white in
+    /// [`OutputMode::AlphaMask`], where every drawn shape is full coverage.
+    fn opaque(&self, color: Rgb) -> u32 {
+        if self.alpha_mask() {
+            return MASK_COVERAGE;
+        }
+        let [r, g, b] = self.quantized(color);
+        u32::from_be_bytes([r, g, b, 255])
+    }
 
-        // The destination texture's content is no longer meaningful once
-        // the terminal's own cell grid has been cleared - force the next
-        // payload to be a full one (`LoadOp::Clear`) rather than a
-        // dirty-rows-only partial.
+    /// `initial_fill` as drawn under `color_quantize`, alpha kept -
+    /// transparent black in [`OutputMode::AlphaMask`].
+    fn fill(&self) -> [u8; 4] {
+        if self.alpha_mask() {
+            return [0; 4];
+        }
+        let [r, g, b, a] = self.initial_fill;
+        let [r, g, b] = self.quantized([r, g, b]);
+        [r, g, b, a]
+    }
+
+    /// Total glyphs this backend has rasterized so far (feature
+    /// `debug_stats`).
+    #[cfg(feature = "debug_stats")]
+    pub(crate) fn glyphs_rasterized(&self) -> u64 {
+        self.glyphs_rasterized
+    }
+
+    /// `(used, capacity)` slots of the glyph atlas shared by every backend
+    /// using this backend's `Fonts` (feature `debug_stats`).
+    #[cfg(feature = "debug_stats")]
+    pub(crate) fn atlas_occupancy(&self) -> (u32, u32) {
+        self.fonts
+            .with_shared_cpu_state(|shared| shared.cached.occupancy())
+    }
+
+    /// `(used, capacity)` bytes of the glyph atlas shared by every backend
+    /// using this backend's `Fonts`. Glyphs are cached in fixed-size
+    /// slots; once `used` reaches `capacity`, each new glyph evicts the
+    /// least recently used one, which is rasterized again on its next use.
+    /// A full atlas is not an error, but one churning every frame (many
+    /// distinct CJK glyphs, several styles) pays for that rasterization
+    /// each time. `capacity` is fixed by the font's cell size.
+    pub fn atlas_usage(&self) -> (u64, u64) {
+        self.fonts
+            .with_shared_cpu_state(|shared| shared.cached.usage_bytes())
+    }
+
+    /// Backend-local half of [`Self::invalidate_all`], shared with
+    /// [`Self::update_fonts`] (which switches to a different `Fonts`, so
+    /// has no atlas of its own to clear).
+    fn invalidate_geometry(&mut self) {
+        self.dirty_rows.iter_mut().for_each(|d| *d = true);
+        self.row_geometry.clear();
         self.rows_dirty_since_take.clear();
         self.full_redraw_needed = true;
+        self.reshape_pending = true;
+        #[cfg(feature = "ascii_fast_shaping")]
+        self.ascii_glyph_cache.clear();
+    }
 
-        Ok(())
+    /// Resolve `cell`'s (background, foreground) colors, packed as the
+    /// vertex formats expect (`u32::from_be_bytes([r, g, b, a])`), with
+    /// `REVERSED` and `transparent_reset_bg` applied, and the block cursor
+    /// on top when it is visible at column `x` of row `y`.
+    fn cell_colors_at(&self, cell: &Cell, x: usize, y: usize) -> (u32, u32) {
+        let (bg, fg) = self.cell_colors(cell, y);
+        if !self.cursor_visible || (x, y) != (self.cursor.0 as usize, self.cursor.1 as usize) {
+            return (bg, fg);
+        }
+        if self.alpha_mask() {
+            // A solid block: the glyph would otherwise come out black in
+            // the rgb channels, no longer matching the alpha.
+            return (MASK_COVERAGE, MASK_COVERAGE);
+        }
+        let pack = |color: Rgb| self.opaque(color);
+        // Inversion keeps the glyph readable: the fg is always opaque, and
+        // the bg's alpha (0 under `transparent_reset_bg`) is forced back
+        // to 255 so the text isn't drawn in an invisible color.
+        let cursor_bg = self.cursor_style.color.map_or(fg, pack);
+        let cursor_fg = self.cursor_style.text_color.map_or(bg | 0xFF, pack);
+        (cursor_bg, cursor_fg)
     }
 
-    fn size(&self) -> std::io::Result<ratatui::layout::Size> {
-        let (inset_width, inset_height) = match self.viewport {
-            Viewport::Full => (0, 0),
-            Viewport::Shrink { width, height } => (width, height),
+    /// Resolve `cell`'s (background, foreground) colors, packed as the
+    /// vertex formats expect (`u32::from_be_bytes([r, g, b, a])`), with
+    /// `REVERSED`, `transparent_reset_bg` and the `background_gradient`
+    /// color of row `y` applied.
+    fn cell_colors(&self, cell: &Cell, y: usize) -> (u32, u32) {
+        if self.alpha_mask() {
+            return (0, MASK_COVERAGE);
+        }
+        let reverse = cell.modifier.contains(ratatui::style::Modifier::REVERSED);
+        // The color actually being used *as the background* - `cell.fg`
+        // when reversed, matching the swap below. Checked against
+        // `Color::Reset` before `c2c()` resolves it to an opaque RGB, since
+        // that resolution is exactly what erases the "this cell has no
+        // explicit background" information `transparent_reset_bg` needs.
+        let bg_source = if reverse { cell.fg } else { cell.bg };
+        let palette = self.palette.as_ref();
+        let reset_bg = self.reset_bg_for_row(y);
+        let bg_color = if reverse {
+            c2c(cell.fg, self.reset_fg, palette)
+        } else {
+            c2c(cell.bg, reset_bg, palette)
+        };
+        let fg_color = if reverse {
+            c2c(cell.bg, reset_bg, palette)
+        } else {
+            c2c(cell.fg, self.reset_fg, palette)
         };
 
-        // Calculate drawable area based on cols/rows
-        let pixel_width = self.cols as u32 * self.fonts.min_width_px();
-        let pixel_height = self.rows as u32 * self.fonts.height_px();
+        let bg_alpha =
+            if self.transparent_reset_bg && matches!(bg_source, ratatui::style::Color::Reset) {
+                0
+            } else {
+                255
+            };
+        let [r, g, b] = self.quantized(bg_color);
+        let bg_color_u32 = u32::from_be_bytes([r, g, b, bg_alpha]);
 
-        let width = pixel_width - inset_width;
-        let height = pixel_height - inset_height;
+        let [r, g, b] = self.quantized(fg_color);
+        let fg_color_u32 = u32::from_be_bytes([r, g, b, 255]);
 
-        Ok(ratatui::layout::Size {
-            width: (width / self.fonts.min_width_px()) as u16,
-            height: (height / self.fonts.height_px()) as u16,
+        (bg_color_u32, fg_color_u32)
+    }
+
+    /// The color a `Color::Reset` background resolves to on row `y`:
+    /// `reset_bg`, or the `background_gradient` sampled at the row's
+    /// vertical center.
+    fn reset_bg_for_row(&self, y: usize) -> Rgb {
+        let Some((top, bottom)) = self.background_gradient else {
+            return self.reset_bg;
+        };
+        let t = (y as f32 + 0.5) / self.rows.max(1) as f32;
+        std::array::from_fn(|i| {
+            let (a, b) = (top[i] as f32, bottom[i] as f32);
+            (a + (b - a) * t.clamp(0.0, 1.0)).round() as u8
         })
     }
 
-    fn window_size(&mut self) -> std::io::Result<ratatui::backend::WindowSize> {
-        let (inset_width, inset_height) = match self.viewport {
-            Viewport::Full => (0, 0),
-            Viewport::Shrink { width, height } => (width, height),
+    /// Shapes and rasterizes-as-needed a single row (index `y`, of a grid
+    /// `width` cells wide), returning its background and foreground
+    /// vertex geometry. Factored out of `flush()` so the same per-row
+    /// logic can (re)build either a freshly-dirty row or a clean row
+    /// whose cache turned out to be stale (see IMPROVEMENT.md A2) -
+    /// callers append the result to both this frame's output and this
+    /// row's `row_geometry` cache entry. An empty row (no glyphs) still
+    /// returns its background quads (any cell whose background differs
+    /// from `initial_fill`), and otherwise two empty `Vec`s, which is a
+    /// perfectly valid cache entry - nothing distinguishes "cached and
+    /// empty" from "never cached", since a dirty row is always (re)shaped
+    /// before ever being read as clean.
+    fn shape_row(
+        &mut self,
+        y: usize,
+        width: usize,
+        shared: &mut crate::fonts::SharedFontCpuState,
+    ) -> (Vec<TextBgVertexMember>, Vec<TextVertexMember>) {
+        use rustybuzz::shape_with_plan;
+        use rustybuzz::ttf_parser::GlyphId;
+
+        let mut bg_vertices = Vec::new();
+        let mut text_vertices = Vec::new();
+
+        // Packed the same way as `bg_color_u32` below - a bg quad whose
+        // color exactly matches this is redundant (IMPROVEMENT.md B3):
+        // the render pass already clears the target to `initial_fill`, so
+        // drawing an identical quad on top changes nothing. This also
+        // correctly keeps (never skips) an alpha-0 `transparent_reset_bg`
+        // quad whenever `initial_fill`'s own alpha isn't 0 - the packed
+        // values simply won't be equal in that case.
+        let initial_fill_u32 = u32::from_be_bytes(self.fill());
+
+        let row_start = y * width;
+        let row_end = (row_start + width).min(self.cells.len());
+        // The default modifier and the empty-cell glyph are applied here
+        // rather than stored into `cells` by `draw()`, so changing them
+        // restyles unchanged content (and text extraction never sees the
+        // placeholder glyphs).
+        let styled_row: Vec<Cell>;
+        let row_cells = if self.default_modifier.is_empty() && self.empty_cell.is_none() {
+            &self.cells[row_start..row_end]
+        } else {
+            styled_row = self.cells[row_start..row_end]
+                .iter()
+                .map(|cell| {
+                    let mut cell = cell.clone();
+                    cell.modifier |= self.default_modifier;
+                    if let Some((ch, [r, g, b])) = self.empty_cell {
+                        let reverse = cell.modifier.contains(ratatui::style::Modifier::REVERSED);
+                        if cell.symbol() == " " && !reverse {
+                            cell.set_char(ch).set_fg(ratatui::style::Color::Rgb(r, g, b));
+                        }
+                    }
+                    cell
+                })
+                .collect();
+            &styled_row[..]
         };
 
-        let pixel_width = self.cols as u32 * self.fonts.min_width_px();
-        let pixel_height = self.rows as u32 * self.fonts.height_px();
+        // Build row string for shaping
+        self.row.clear();
+        self.rowmap.clear();
+        for (x, cell) in row_cells.iter().enumerate() {
+            let symbol = cell.symbol();
+            self.row.push_str(symbol);
+            // Map each byte to its cell index
+            for _ in 0..symbol.len() {
+                self.rowmap.push(x as u16);
+            }
+        }
 
-        let width = pixel_width - inset_width;
-        let height = pixel_height - inset_height;
+        // Backgrounds are emitted per CELL, independently of shaping: a
+        // cell that produces no glyph at all (an empty symbol, e.g. a
+        // `NULL_CELL` left behind by a wide glyph that has since been
+        // overwritten, or a row made up entirely of them) must still paint
+        // its background, or clearing a previously-colored region would
+        // leave whatever the row-clear quad / `LoadOp::Clear` put there
+        // (`initial_fill`) instead of the terminal's default background.
+        // A wide glyph's quad spans its continuation cells, which are
+        // skipped here rather than painted with their own (reset) style.
+        {
+            let cell_width_px = self.fonts.min_width_px() as f32;
+            let cell_height_px = self.fonts.height_px() as f32;
+            let screen_y = y as f32 * cell_height_px;
+
+            let grid_color = self.cell_grid.map(|color| self.opaque(color));
+            let y1 = screen_y + cell_height_px;
+
+            let mut x = 0;
+            while x < row_cells.len() {
+                let cell = &row_cells[x];
+                let span = self
+                    .fonts
+                    .text_width_cells(cell.symbol())
+                    .max(1)
+                    .min(row_cells.len() - x);
+                let (col0, col1) = (x, x + span);
+                let (bg_color_u32, _) = self.cell_colors_at(cell, x, y);
+                let x0 = col0 as f32 * cell_width_px;
+
+                // Skipped when this cell's background exactly matches the
+                // render pass's own clear color (see `initial_fill_u32`
+                // above); bg/fg quad counts intentionally diverge here.
+                let checkerboard = self.transparency_checkerboard && !self.alpha_mask();
+                if checkerboard && bg_color_u32 & 0xFF != 0xFF {
+                    for col in col0..col1 {
+                        let cell_x = col as f32 * cell_width_px;
+                        push_checkerboard(
+                            &mut bg_vertices,
+                            [cell_x, screen_y],
+                            [cell_x + cell_width_px, y1],
+                        );
+                    }
+                } else if bg_color_u32 != initial_fill_u32 {
+                    let x1 = col1 as f32 * cell_width_px;
+                    push_bg_quad(&mut bg_vertices, [x0, screen_y], [x1, y1], bg_color_u32);
+                }
 
-        Ok(ratatui::backend::WindowSize {
-            columns_rows: ratatui::layout::Size {
-                width: (width / self.fonts.min_width_px()) as u16,
-                height: (height / self.fonts.height_px()) as u16,
-            },
-            pixels: ratatui::layout::Size {
-                width: width as u16,
-                height: height as u16,
-            },
-        })
-    }
+                // Vertical separator along this cell's left edge - pushed
+                // after the cell's own quad so REPLACE blending keeps it on
+                // top. None inside a wide glyph's span.
+                if let Some(color) = grid_color.filter(|_| x > 0) {
+                    push_bg_quad(&mut bg_vertices, [x0, screen_y], [x0 + 1.0, y1], color);
+                }
 
-    /// Maintain the per-row geometry cache for GPU rendering (IMPROVEMENT.md
-    /// A2) and accumulate which rows need re-rendering
-    /// (`rows_dirty_since_take` - Phase 2 partial redraw). Concatenating
-    /// `row_geometry` into an actual draw payload - deciding full vs.
-    /// partial, synthesizing row-clear quads - happens later, in
-    /// `take_draw_payload`; this method only keeps `row_geometry` correct
+                x += span;
+            }
+
+            // Horizontal separator along this row's top edge - kept inside
+            // the row's own pixel span, so a partial redraw's row-clear
+            // quad (`take_draw_payload`) and this row's geometry still
+            // cover exactly the same pixels.
+            if let Some(color) = grid_color.filter(|_| y > 0) {
+                let row_width_px = row_cells.len() as f32 * cell_width_px;
+                push_bg_quad(
+                    &mut bg_vertices,
+                    [0.0, screen_y],
+                    [row_width_px, screen_y + 1.0],
+                    color,
+                );
+            }
+        }
+
+        if self.row.is_empty() {
+            return (bg_vertices, text_vertices);
+        }
+
+        // For now, use font_for_cell on the first cell
+        #[cfg(feature = "bold_italic_fonts")]
+        let (font, _cell_fake_bold, _cell_fake_italic) =
+            self.fonts.font_for_cell(&row_cells[0]);
+
+        #[cfg(not(feature = "bold_italic_fonts"))]
+        let (font, _cell_fake_bold, _cell_fake_italic) = {
+            let (f, _, _) = self.fonts.font_for_cell(&row_cells[0]);
+            (f, false, false) // Disable fake styling when feature is off
+        };
+
+        // ASCII fast path (IMPROVEMENT.md A3, opt-in via the
+        // `ascii_fast_shaping` feature - compiled out entirely otherwise,
+        // and requires `bold_italic_fonts` to be off, since that feature
+        // makes per-cell font selection meaningful while this path assumes
+        // the whole row shares `font`, exactly like the slow path already
+        // does whenever `bold_italic_fonts` is off - see `cell_font`
+        // below). Builds synthetic glyph info/position pairs straight from
+        // the font's cmap instead of invoking rustybuzz at all, then feeds
+        // them into the SAME per-glyph loop below as real shaped output -
+        // no duplication of the atlas/vertex-emission logic, so there is
+        // exactly one code path that can get that part wrong. Bails to the
+        // full shaping path (`None`) the moment any cell isn't a single
+        // ASCII printable byte or the font lacks that glyph.
+        #[cfg(all(feature = "ascii_fast_shaping", not(feature = "bold_italic_fonts")))]
+        let fast_path: Option<(Vec<rustybuzz::GlyphInfo>, Vec<rustybuzz::GlyphPosition>)> = 'fast: {
+            use rustybuzz::ttf_parser::Face;
+
+            let mut infos = Vec::with_capacity(row_cells.len());
+            let mut positions = Vec::with_capacity(row_cells.len());
+            for (x, cell) in row_cells.iter().enumerate() {
+                let bytes = cell.symbol().as_bytes();
+                // Exactly one ASCII printable byte - this single check also
+                // excludes an empty symbol (NULL_CELL wide-char
+                // continuation) and any multi-byte UTF-8 grapheme cluster.
+                if bytes.len() != 1 || !(0x20..=0x7E).contains(&bytes[0]) {
+                    break 'fast None;
+                }
+                let ch = bytes[0] as char;
+
+                let cache_key = (font.id(), ch);
+                let glyph_id = *self
+                    .ascii_glyph_cache
+                    .entry(cache_key)
+                    .or_insert_with(|| Face::glyph_index(font.font(), ch));
+                let Some(glyph_id) = glyph_id else {
+                    break 'fast None; // font lacks this glyph - defer to full shaping
+                };
+
+                // Struct-literal update syntax (`..Default::default()`)
+                // isn't usable here: `GlyphInfo` has private fields not
+                // nameable from outside rustybuzz, and Rust's privacy
+                // rules block `..` on a literal that can't name every
+                // field, even though `Default` alone would fill them. Build
+                // the default first, then set only the two public fields.
+                let mut glyph_info = rustybuzz::GlyphInfo::default();
+                glyph_info.glyph_id = glyph_id.0 as u32;
+                glyph_info.cluster = x as u32;
+                infos.push(glyph_info);
+                positions.push(rustybuzz::GlyphPosition::default());
+            }
+            Some((infos, positions))
+        };
+        #[cfg(not(all(feature = "ascii_fast_shaping", not(feature = "bold_italic_fonts"))))]
+        let fast_path: Option<(Vec<rustybuzz::GlyphInfo>, Vec<rustybuzz::GlyphPosition>)> = None;
+
+        let (infos, positions): (Vec<rustybuzz::GlyphInfo>, Vec<rustybuzz::GlyphPosition>) =
+            if let Some(fast) = fast_path {
+                fast
+            } else {
+                // Shape the row
+                let mut buffer = std::mem::take(&mut self.buffer);
+                buffer.clear();
+                for (idx, ch) in self.row.char_indices() {
+                    buffer.add(ch, idx as u32);
+                }
+
+                let plan = shared.plan_cache.get(font, &mut buffer, self.ligatures);
+                let glyph_buffer = shape_with_plan(font.font(), plan, buffer);
+                let infos = glyph_buffer.glyph_infos().to_vec();
+                let positions = glyph_buffer.glyph_positions().to_vec();
+                self.buffer = glyph_buffer.clear();
+                (infos, positions)
+            };
+
+        // Process shaped (or synthesized) glyphs
+        let metrics = font.font();
+        let advance_scale = self.fonts.height_px() as f32 / metrics.height() as f32;
+
+        // Shaping position within the current cell, in font units: every
+        // glyph is drawn from its cell's origin, but combining marks are
+        // positioned by the shaper relative to the pen *after* their base
+        // glyph, so they need the pen's travel through the cell added back
+        // to land on the base rather than a cell to its left.
+        let mut pen_cell = usize::MAX;
+        let mut pen_in_cell = 0;
+        let mut marks_in_cell = 0;
+
+        for (info, pos) in infos.iter().zip(positions.iter()) {
+            let cluster = info.cluster as usize;
+            if cluster >= self.rowmap.len() {
+                continue;
+            }
+
+            let cell_idx = self.rowmap[cluster] as usize;
+            if cell_idx >= row_cells.len() {
+                continue;
+            }
+
+            let cell = &row_cells[cell_idx];
+
+            // A zero-advance glyph following another in the same cell is a
+            // combining mark (rustybuzz merges marks into their base's
+            // cluster): acute accents, enclosing circles (U+20DD) and the
+            // like, overlaid on the base glyph's cell.
+            if pen_cell != cell_idx {
+                pen_cell = cell_idx;
+                pen_in_cell = 0;
+                marks_in_cell = 0;
+            }
+            let is_mark = pen_in_cell != 0 && pos.x_advance == 0;
+            let mark_pen = if is_mark { pen_in_cell } else { 0 };
+            pen_in_cell += pos.x_advance;
+            let _glyph_id = GlyphId(info.glyph_id as u16);
+
+            // Use per-cell font selection for proper styling
+            #[cfg(feature = "bold_italic_fonts")]
+            let (cell_font, cell_fake_bold, cell_fake_italic) = self.fonts.font_for_cell(cell);
+
+            #[cfg(not(feature = "bold_italic_fonts"))]
+            let (cell_font, cell_fake_bold, cell_fake_italic) = (font, false, false);
+
+            // Character width in cells: unicode-width, adjusted by the
+            // fonts' ambiguous-width setting and overrides. A mark takes
+            // its base's width, but is looked up (fallback fonts, missing
+            // glyph tracking) as the mark character itself.
+            let base_ch = cell.symbol().chars().next().unwrap_or(' ');
+            let ch_width = self.fonts.char_width_cells(base_ch).max(1) as u32;
+            let glyph_width_px = ch_width * self.fonts.min_width_px();
+            let ch = if is_mark {
+                use unicode_width::UnicodeWidthChar;
+
+                marks_in_cell += 1;
+                cell.symbol()
+                    .chars()
+                    .skip(1)
+                    .filter(|ch| ch.width() == Some(0))
+                    .nth(marks_in_cell - 1)
+                    .unwrap_or(base_ch)
+            } else {
+                base_ch
+            };
+
+            // Check if this character is an emoji
+            #[cfg(feature = "emoji")]
+            fn is_emoji(ch: char) -> bool {
+                use unicode_properties::UnicodeEmoji;
+                // Simplify emoji detection - just check if it's an emoji character
+                ch.is_emoji_char()
+            }
+
+            #[cfg(not(feature = "emoji"))]
+            fn is_emoji(_ch: char) -> bool {
+                false
+            }
+
+            let is_emoji = is_emoji(ch);
+
+            // Check if this is a programmatic glyph that was pre-rendered
+            use crate::backend::programmatic_glyphs::is_programmatic_glyph;
+            let is_programmatic = is_programmatic_glyph(ch);
+
+            // A character pinned to a font by `Fonts::set_range_font` is
+            // drawn from that font. Otherwise a glyph the row's shaping
+            // font lacks (`.notdef`, id 0) is looked up again along the
+            // whole fallback chain - plus, with the `system_fonts` feature,
+            // the fonts installed on the OS - and drawn from whichever font
+            // covers it.
+            let fallback = if is_programmatic {
+                None
+            } else if let Some(range_font) = self.fonts.range_font_for(ch) {
+                Some(FallbackFont::Own(range_font))
+                    .filter(|range_font| range_font.id() != cell_font.id())
+            } else if info.glyph_id == 0 {
+                self.fonts
+                    .fallback_font_for(ch)
+                    .filter(|fallback| fallback.id() != cell_font.id())
+            } else {
+                None
+            };
+            let (cell_font, glyph_face, glyph_info, glyph_advance_scale, x_offset) = match fallback.as_deref() {
+                Some(fallback) => {
+                    let mut glyph_info = *info;
+                    glyph_info.glyph_id = fallback
+                        .font()
+                        .glyph_index(ch)
+                        .map_or(0, |glyph| glyph.0 as u32);
+                    let scale = self.fonts.height_px() as f32 / fallback.font().height() as f32;
+                    (fallback, fallback.font(), glyph_info, scale, 0)
+                }
+                None => (cell_font, metrics, *info, advance_scale, pos.x_offset),
+            };
+            if self.track_missing_glyphs && glyph_info.glyph_id == 0 && !is_programmatic {
+                self.missing_glyphs.insert(ch);
+            }
+            // Where the glyph sits horizontally in its atlas entry, in
+            // pixels: the shaper's offset, plus for a mark the base
+            // glyph's advance (see `pen_in_cell`).
+            let bearing_offset_x =
+                x_offset as f32 * glyph_advance_scale + mark_pen as f32 * advance_scale;
+
+            // Create cache key
+            // For programmatic glyphs: use Unicode codepoint + last_resort font (matches populate_programmatic_glyphs)
+            // For font glyphs: use shaped glyph ID + actual font
+
+            #[cfg(feature = "bold_italic_fonts")]
+            let style = cell.modifier
+                & (ratatui::style::Modifier::BOLD | ratatui::style::Modifier::ITALIC);
+
+            #[cfg(not(feature = "bold_italic_fonts"))]
+            let style = ratatui::style::Modifier::empty();
+
+            let key = if is_programmatic {
+                Key {
+                    style,
+                    glyph: ch as u32,
+                    font: self.programmatic_font_id(ch),
+                }
+            } else if is_mark {
+                // Rasterized shifted onto its base, so a mark over a
+                // different base advance needs an entry of its own.
+                let shift = (bearing_offset_x * 4.0).round() as i64 as u64;
+                Key {
+                    style,
+                    glyph: glyph_info.glyph_id,
+                    font: cell_font.id() ^ shift.wrapping_mul(0xD6E8_FEB8_6659_FD93),
+                }
+            } else {
+                Key {
+                    style,
+                    glyph: glyph_info.glyph_id,
+                    font: cell_font.id(),
+                }
+            };
+
+            // Blinking text in its off phase: the background above is
+            // all that shows.
+            if self.blinked_off(cell.modifier) {
+                continue;
+            }
+
+            // A glyph the atlas can't hold (an extreme font size, or one
+            // over `Fonts::set_max_glyph_px`) is never rasterized: the cell
+            // gets a hollow box in its text color instead, drawn in the bg
+            // pass on top of the cell backgrounds pushed above. Blank cells
+            // have nothing to stand in for and stay blank.
+            if !shared.cached.accepts(glyph_width_px, self.fonts.height_px()) {
+                if ch.is_whitespace() {
+                    continue;
+                }
+                let (_, fg_color_u32) = self.cell_colors_at(cell, cell_idx, y);
+                let min = [
+                    cell_idx as f32 * self.fonts.min_width_px() as f32,
+                    y as f32 * self.fonts.height_px() as f32,
+                ];
+                let max = [min[0] + glyph_width_px as f32, min[1] + self.fonts.height_px() as f32];
+                push_box_outline(&mut bg_vertices, min, max, fg_color_u32);
+                if self.track_missing_glyphs {
+                    self.missing_glyphs.insert(ch);
+                }
+                continue;
+            }
+
+            // Over this flush's glyph budget, a glyph not in the atlas yet
+            // is left out for now: the row stays dirty for the next flush.
+            let over_budget = self
+                .glyph_budget
+                .is_some_and(|budget| self.glyphs_rasterized_this_flush >= budget);
+            if over_budget && shared.cached.try_get(&key).is_none() {
+                self.dirty_rows[y] = true;
+                self.reshape_pending = true;
+                self.glyphs_deferred = true;
+                continue;
+            }
+
+            let cached = shared
+                .cached
+                .get(&key, glyph_width_px, self.fonts.height_px());
+            // After `invalidate_all`, the first use of a cached glyph is
+            // rasterized again, into the slot it already has.
+            let cached = match cached {
+                Entry::Cached(rect)
+                    if self.rerasterize.as_mut().is_some_and(|seen| seen.insert(key)) =>
+                {
+                    Entry::Uncached(rect)
+                }
+                entry => entry,
+            };
+
+            // If not cached, render the glyph
+            if !cached.cached() {
+                self.glyphs_rasterized_this_flush += 1;
+                #[cfg(feature = "debug_stats")]
+                {
+                    self.glyphs_rasterized += 1;
+                }
+                if is_programmatic {
+                    // Render programmatic glyph on-demand if not pre-cached
+                    use crate::backend::programmatic_glyphs::render_programmatic_glyph;
+
+                    if let Some(pixmap) = render_programmatic_glyph(
+                        ch,
+                        glyph_width_px,
+                        self.fonts.height_px(),
+                        &self.powerline_style,
+                        self.programmatic_supersample,
+                    ) {
+                        let bitmap = pixmap_to_rgba8(pixmap);
+                        shared.pending_cache_updates.push((*cached, bitmap));
+                    } else {
+                        tracing::warn!(
+                            "Failed to render programmatic glyph '{}' (U+{:04X})",
+                            ch,
+                            ch as u32
+                        );
+                        if self.track_missing_glyphs {
+                            self.missing_glyphs.insert(ch);
+                        }
+                    }
+                } else {
+                    // Don't apply fake styling to emoji characters to avoid distortion
+                    let final_fake_italic = cell_fake_italic && !is_emoji;
+                    let final_fake_bold = cell_fake_bold && !is_emoji;
+
+                    let (rect, image) = rasterize_glyph(
+                        cached,
+                        glyph_face,
+                        &glyph_info,
+                        final_fake_italic, // Don't distort emoji
+                        final_fake_bold,   // Don't distort emoji
+                        glyph_advance_scale,
+                        glyph_width_px,   // Use actual glyph width
+                        bearing_offset_x, // Apply offset in atlas
+                    );
+
+                    shared.pending_cache_updates.push((rect, image));
+                }
+            }
+
+            // Calculate screen position - align to cell grid since offset is already in atlas
+            let screen_x = cell_idx as f32 * self.fonts.min_width_px() as f32;
+            let screen_y = y as f32 * self.fonts.height_px() as f32;
+
+            let (_, fg_color_u32) = self.cell_colors_at(cell, cell_idx, y);
+            let fade_alpha = self.fade_alpha(row_start + cell_idx) as u32;
+            let fg_color_u32 = if fade_alpha == 255 {
+                fg_color_u32
+            } else {
+                (fg_color_u32 & 0xFFFF_FF00) | fade_alpha
+            };
+
+            // Render at actual glyph width less the cell inset, times the
+            // glyph scale; the quad stays centered on the unscaled one.
+            // Programmatic glyphs keep the whole cell so borders join.
+            let inset = if is_programmatic { 0 } else { self.cell_inset };
+            let inset_width_px = glyph_width_px.saturating_sub(2 * inset).max(1);
+            let inset_height_px = self.fonts.height_px().saturating_sub(2 * inset).max(1);
+            let (scale_x, scale_y) = self.glyph_scale;
+            let render_width_px = inset_width_px as f32 * scale_x;
+            let render_height_px = inset_height_px as f32 * scale_y;
+            let screen_x = screen_x + (glyph_width_px as f32 - render_width_px) / 2.0;
+            let screen_y = screen_y + (self.fonts.height_px() as f32 - render_height_px) / 2.0;
+
+            // Text vertices - 1:1 mapping between atlas and screen
+            let uv_x = cached.x as f32;
+            let uv_y = cached.y as f32;
+            let uv_w = cached.width as f32; // Matches glyph_width_px
+            let uv_h = cached.height as f32;
+            let quad = GlyphQuad {
+                min: [screen_x, screen_y],
+                max: [screen_x + render_width_px, screen_y + render_height_px],
+                uv_min: [uv_x, uv_y],
+                uv_max: [uv_x + uv_w, uv_y + uv_h],
+            };
+
+            // Underline and strikethrough are drawn by the fg shader as a
+            // solid band of `underline_color` across the glyph quad, rows
+            // `underline_pos` (atlas texels). One band per quad: the
+            // underline rides on the glyph's own quad, a strikethrough gets
+            // a second quad whose text color is fully transparent, so only
+            // its band shows.
+            let (underline_color, strikethrough_color) =
+                self.decoration_colors(cell, fg_color_u32);
+            let band = |(top, thickness): (u32, u32)| {
+                let scale = cached.height as f32 / self.fonts.height_px().max(1) as f32;
+                let y_min = cached.y + (top as f32 * scale) as u32;
+                let y_max = y_min + ((thickness as f32 * scale) as u32).max(1);
+                (y_min << 16) | y_max
+            };
+            // A mark's quad covers its base's: the base already drew the
+            // cell's decorations.
+            let underline_pos = if cell.modifier.contains(ratatui::style::Modifier::UNDERLINED) && !is_mark {
+                band(self.fonts.underline_band_px())
+            } else {
+                0
+            };
+            push_text_quad(&mut text_vertices, &quad, fg_color_u32, underline_pos, underline_color);
+            if cell.modifier.contains(ratatui::style::Modifier::CROSSED_OUT) && !is_mark {
+                push_text_quad(
+                    &mut text_vertices,
+                    &quad,
+                    fg_color_u32 & 0xFFFF_FF00,
+                    band(self.fonts.strikethrough_band_px()),
+                    strikethrough_color,
+                );
+            }
+        }
+
+        (bg_vertices, text_vertices)
+    }
+}
+
+// ====== Backend trait implementation ======
+
+impl ratatui::backend::Backend for BevyTerminalBackend {
+    type Error = std::io::Error;
+    fn draw<'a, I>(&mut self, content: I) -> std::io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let bounds = self.size()?;
+        let fading = self.begin_draw(bounds);
+        let width = bounds.width as usize;
+        let mut tab_rows_drawn = Vec::new();
+
+        for (x, y, cell) in content {
+            // Only a misbehaving caller sends positions outside the grid -
+            // drop them rather than write into (or past) another row.
+            if x >= bounds.width || y >= bounds.height {
+                continue;
+            }
+            self.cells_changed_last_draw = true;
+            let (x, y) = (x as usize, y as usize);
+            let filtered = self.filtered_cell(cell, x as u16, y as u16);
+            let cell = filtered.as_ref().unwrap_or(cell);
+            if cell.symbol() == TAB_SYMBOL || self.tab_rows.contains_key(&y) {
+                self.store_tab_row_cell(x, y, width, cell);
+                if tab_rows_drawn.last() != Some(&y) {
+                    tab_rows_drawn.push(y);
+                }
+            } else {
+                self.store_cell(y * width + x, cell, fading);
+            }
+            self.dirty_rows[y] = true;
+        }
+        for y in tab_rows_drawn {
+            self.lay_out_tab_row(y, fading);
+        }
+
+        self.end_draw(fading, width);
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> std::io::Result<()> {
+        if self.cursor_visible {
+            self.mark_cursor_row_dirty();
+            self.cursor_visible = false;
+        }
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> std::io::Result<()> {
+        if !self.cursor_visible {
+            self.cursor_visible = true;
+            self.mark_cursor_row_dirty();
+        }
+        Ok(())
+    }
+
+    fn get_cursor_position(&mut self) -> std::io::Result<ratatui::layout::Position> {
+        Ok(ratatui::layout::Position::new(self.cursor.0, self.cursor.1))
+    }
+
+    fn set_cursor_position<Pos: Into<ratatui::layout::Position>>(
+        &mut self,
+        position: Pos,
+    ) -> std::io::Result<()> {
+        let bounds = self.size()?;
+        let pos: ratatui::layout::Position = position.into();
+
+        // A zero-sized grid (e.g. `with_dimensions(0, _)`, or a raw-backend
+        // user positioning the cursor before anything sized it) has no valid
+        // cell to clamp into - `bounds.width - 1` would underflow to
+        // `u16::MAX` and, in debug builds, panic.
+        if bounds.width == 0 || bounds.height == 0 {
+            self.cursor = (0, 0);
+            return Ok(());
+        }
+
+        let cursor = (
+            pos.x.min(bounds.width.saturating_sub(1)),
+            pos.y.min(bounds.height.saturating_sub(1)),
+        );
+        if cursor != self.cursor && self.cursor_visible {
+            // Both the row it leaves and the row it enters.
+            self.mark_cursor_row_dirty();
+            self.cursor = cursor;
+            self.mark_cursor_row_dirty();
+        }
+        self.cursor = cursor;
+        Ok(())
+    }
+
+    fn clear(&mut self) -> std::io::Result<()> {
+        self.cells.clear();
+        self.dirty_rows.clear();
+        self.row_geometry.clear();
+        self.cell_changed_at.clear();
+        self.widened_over.clear();
+        self.tab_rows.clear();
+        self.cursor = (0, 0);
+
+        // The destination texture's content is no longer meaningful once
+        // the terminal's own cell grid has been cleared - force the next
+        // payload to be a full one (`LoadOp::Clear`) rather than a
+        // dirty-rows-only partial.
+        self.rows_dirty_since_take.clear();
+        self.full_redraw_needed = true;
+
+        Ok(())
+    }
+
+    fn size(&self) -> std::io::Result<ratatui::layout::Size> {
+        let (inset_width, inset_height) = match self.viewport {
+            Viewport::Full => (0, 0),
+            Viewport::Shrink { width, height } => (width, height),
+        };
+
+        // Calculate drawable area based on cols/rows
+        let pixel_width = self.cols as u32 * self.fonts.min_width_px();
+        let pixel_height = self.rows as u32 * self.fonts.height_px();
+
+        let width = pixel_width.saturating_sub(inset_width);
+        let height = pixel_height.saturating_sub(inset_height);
+
+        Ok(ratatui::layout::Size {
+            width: (width / self.fonts.min_width_px()) as u16,
+            height: (height / self.fonts.height_px()) as u16,
+        })
+    }
+
+    fn window_size(&mut self) -> std::io::Result<ratatui::backend::WindowSize> {
+        let (inset_width, inset_height) = match self.viewport {
+            Viewport::Full => (0, 0),
+            Viewport::Shrink { width, height } => (width, height),
+        };
+
+        let pixel_width = self.cols as u32 * self.fonts.min_width_px();
+        let pixel_height = self.rows as u32 * self.fonts.height_px();
+
+        let width = pixel_width.saturating_sub(inset_width);
+        let height = pixel_height.saturating_sub(inset_height);
+
+        Ok(ratatui::backend::WindowSize {
+            columns_rows: ratatui::layout::Size {
+                width: (width / self.fonts.min_width_px()) as u16,
+                height: (height / self.fonts.height_px()) as u16,
+            },
+            pixels: ratatui::layout::Size {
+                width: width as u16,
+                height: height as u16,
+            },
+        })
+    }
+
+    /// Maintain the per-row geometry cache for GPU rendering (IMPROVEMENT.md
+    /// A2) and accumulate which rows need re-rendering
+    /// (`rows_dirty_since_take` - Phase 2 partial redraw). Concatenating
+    /// `row_geometry` into an actual draw payload - deciding full vs.
+    /// partial, synthesizing row-clear quads - happens later, in
+    /// `take_draw_payload`; this method only keeps `row_geometry` correct
     /// and notes which rows changed.
     ///
     /// Two-pass, row-incremental design (IMPROVEMENT.md A2):
@@ -1081,591 +3517,2609 @@ impl ratatui::backend::Backend for BevyTerminalBackend {
             return Ok(());
         }
 
-        let bounds = self.size()?;
-        let width = bounds.width as usize;
-        let height = bounds.height as usize;
+        let bounds = self.size()?;
+        let width = bounds.width as usize;
+        let height = bounds.height as usize;
+        self.glyphs_rasterized_this_flush = 0;
+        self.glyphs_deferred = false;
+
+        // `pending_cache_updates` must NOT be cleared here: its entries are
+        // one-shot atlas uploads for slots the `Atlas` LRU already
+        // considers cached, queued by a *previous* flush (or by
+        // `populate_programmatic_glyphs` at creation) and not yet drained
+        // by `take_draw_payload`. Clearing them here destroys the only
+        // copy of those glyphs' pixels - the glyph is never re-rasterized
+        // (the LRU says it's cached) and its slot stays garbage forever,
+        // i.e. permanently invisible characters. This is exactly what
+        // happens when `Terminal::draw` runs more than once between
+        // payload-takes: creation-time populate + a setup-time initial
+        // draw + the first per-frame draw are three flushes before the
+        // first `gpu_flush_system` ever runs.
+
+        // Track which rows actually changed content this flush,
+        // accumulated across flushes since the last `take_draw_payload` -
+        // `take` unions this into the row-clear set for the next partial
+        // payload. See the field doc on `rows_dirty_since_take` for why
+        // pass 2's eviction-driven reshapes below do NOT feed into this.
+        self.rows_dirty_since_take.resize(height, false);
+
+        // Snapshot which rows ratatui's diff actually marked dirty coming
+        // INTO this flush - `dirty_rows[y]` is cleared as each such row is
+        // processed below, so this stable copy is what tells pass 2 which
+        // rows still need looking at (a row just cleared by pass 1 must
+        // not be reconsidered by pass 2 as "was already clean").
+        let was_dirty = self.dirty_rows[..height].to_vec();
+        for (y, &dirty) in was_dirty.iter().enumerate() {
+            if dirty {
+                self.rows_dirty_since_take[y] = true;
+            }
+        }
+
+        // Cloned (not borrowed) so the closure below can hold it alongside
+        // an independent `&mut self` for `self.shape_row(...)` - `self.fonts`
+        // itself can't be borrowed for the `with_shared_cpu_state` receiver
+        // while a closure captures all of `self` mutably for the `shape_row`
+        // calls (IMPROVEMENT.md C3: the atlas/plan cache live in `Fonts`,
+        // shared by every terminal using the same `Arc<Fonts>`).
+        let fonts = Arc::clone(&self.fonts);
+        fonts.with_shared_cpu_state(|shared| {
+            let generation_before_flush = shared.cached.generation();
+
+            // Pass 1: regenerate every row that actually changed.
+            for (y, &dirty) in was_dirty.iter().enumerate() {
+                if !dirty {
+                    continue;
+                }
+                // Cleared first: `shape_row` marks the row dirty again if
+                // it had to defer glyphs over the budget.
+                self.dirty_rows[y] = false;
+                let (bg_vertices, text_vertices) = self.shape_row(y, width, shared);
+                self.row_geometry[y] = RowGeometry {
+                    bg_vertices,
+                    text_vertices,
+                    atlas_generation: shared.cached.generation(),
+                };
+            }
+
+            // If shaping this frame's dirty rows evicted anything from the
+            // atlas, no previously-cached row is safe to reuse blindly this
+            // frame (see the doc comment above) - fall back to reshaping
+            // every remaining row too, just this once.
+            let atlas_evicted_this_flush = shared.cached.generation() != generation_before_flush;
+
+            // Pass 2: rows that were clean coming into this flush.
+            for (y, &dirty) in was_dirty.iter().enumerate() {
+                if dirty {
+                    continue; // already handled in pass 1
+                }
+
+                let cache_valid = !atlas_evicted_this_flush
+                    && self.row_geometry[y].atlas_generation == generation_before_flush;
+
+                if cache_valid {
+                    continue;
+                }
+
+                let (bg_vertices, text_vertices) = self.shape_row(y, width, shared);
+                self.row_geometry[y] = RowGeometry {
+                    bg_vertices,
+                    text_vertices,
+                    atlas_generation: shared.cached.generation(),
+                };
+            }
+        });
+        self.rerasterize = None;
+
+        Ok(())
+    }
+
+    fn clear_region(
+        &mut self,
+        _clear_type: ratatui::backend::ClearType,
+    ) -> Result<(), Self::Error> {
+        // For now, just delegate to clear() for all clear types
+        self.clear()
+    }
+}
+
+// ============================================================================
+// Test: cell-level transparency (P2-2). Pure CPU - drives ratatui's
+// `Backend::draw()` directly and inspects the packed vertex color, no
+// GPU/App needed. `bg_color`'s alpha byte is what `composite_bg.wgsl`
+// outputs unmodified (`BlendState::REPLACE`), so this is the whole story:
+// no shader/pipeline test is needed on top of this.
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::{Font, Fonts};
+    use ratatui::backend::Backend as RatatuiBackend;
+    use ratatui::buffer::Cell;
+    use ratatui::style::Color;
+
+    fn test_fonts() -> Arc<Fonts> {
+        let font_data = include_bytes!("../../examples/assets/fonts/Mplus1Code-Regular.ttf");
+        let font = Font::new(font_data).expect("failed to load test font");
+        Arc::new(Fonts::new(font, 16))
+    }
+
+    #[test]
+    fn transparent_reset_bg_zeroes_alpha_only_for_reset_backgrounds() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(2, 1)
+            .with_transparent_reset_bg(true)
+            .build();
+
+        let mut reset_cell = Cell::default();
+        reset_cell.set_symbol("a");
+        reset_cell.bg = Color::Reset;
+
+        let mut colored_cell = Cell::default();
+        colored_cell.set_symbol("b");
+        colored_cell.bg = Color::Rgb(10, 20, 30);
+
+        RatatuiBackend::draw(
+            &mut backend,
+            [(0u16, 0u16, &reset_cell), (1u16, 0u16, &colored_cell)].into_iter(),
+        )
+        .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+
+        // First-ever take on this backend is always full (no synthesized
+        // row-clear quad), so this is exactly one quad per cell.
+        assert!(payload.is_full(), "the first take on a fresh backend must be full");
+        assert_eq!(
+            payload.bg_vertices.len(),
+            8,
+            "one quad (4 vertices) per cell, 2 cells"
+        );
+        for vertex in &payload.bg_vertices[0..4] {
+            assert_eq!(
+                vertex.bg_color & 0xFF,
+                0,
+                "a Color::Reset background must pack alpha 0 when \
+                 transparent_reset_bg is enabled"
+            );
+        }
+        for vertex in &payload.bg_vertices[4..8] {
+            assert_eq!(
+                vertex.bg_color & 0xFF,
+                255,
+                "an explicit background color must stay fully opaque"
+            );
+        }
+    }
+
+    #[test]
+    fn transparency_checkerboard_covers_only_transparent_cells() {
+        let fonts = test_fonts();
+        let (cell_width, cell_height) = (fonts.min_width_px() as f32, fonts.height_px() as f32);
+        let mut backend = TerminalBuilder::new(fonts)
+            .with_dimensions(2, 1)
+            .with_transparent_reset_bg(true)
+            .with_transparency_checkerboard(true)
+            .build();
+        let mut opaque = Cell::default();
+        opaque.set_bg(ratatui::style::Color::Rgb(0, 0, 128));
+        RatatuiBackend::draw(&mut backend, [(1u16, 0u16, &opaque)].into_iter()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+
+        let quads: Vec<([f32; 2], [f32; 2], u32)> = backend
+            .take_draw_payload()
+            .bg_vertices
+            .chunks_exact(4)
+            .map(|quad| (quad[0].vertex, quad[3].vertex, quad[0].bg_color))
+            .collect();
+        let half = [cell_width / 2.0, cell_height / 2.0];
+        assert_eq!(
+            quads,
+            [
+                ([0.0, 0.0], [cell_width, cell_height], CHECKERBOARD_LIGHT),
+                ([0.0, 0.0], half, CHECKERBOARD_DARK),
+                (half, [cell_width, cell_height], CHECKERBOARD_DARK),
+                ([cell_width, 0.0], [2.0 * cell_width, cell_height], 0x0000_80FF),
+            ],
+            "the reset cell is checkered, the blue one keeps its color"
+        );
+
+        backend.set_transparency_checkerboard(false);
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+        assert!(!payload.bg_vertices.is_empty());
+        assert!(
+            payload
+                .bg_vertices
+                .iter()
+                .all(|v| v.bg_color != CHECKERBOARD_LIGHT && v.bg_color != CHECKERBOARD_DARK),
+            "switched off, the checkerboard is gone"
+        );
+    }
+
+    #[test]
+    fn transparent_reset_bg_disabled_keeps_reset_cells_opaque() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 1)
+            .with_transparent_reset_bg(false) // the default
+            // A `reset_bg` distinct from the default `initial_fill` (opaque
+            // black) - otherwise this cell's resolved background exactly
+            // matches the render pass's own clear color and B3's redundant-
+            // quad skip (IMPROVEMENT.md B3) correctly omits it entirely,
+            // which would starve this test of the vertices it inspects.
+            .with_reset_bg([10, 20, 30])
+            .build();
+
+        let mut reset_cell = Cell::default();
+        reset_cell.set_symbol("a");
+        reset_cell.bg = Color::Reset;
+
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &reset_cell)].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+
+        assert!(!payload.bg_vertices.is_empty(), "flush must generate vertices");
+        for vertex in &payload.bg_vertices {
+            assert_eq!(
+                vertex.bg_color & 0xFF,
+                255,
+                "without transparent_reset_bg, Reset backgrounds render \
+                 opaque (using reset_bg), matching pre-P2-2 behavior"
+            );
+        }
+    }
+
+    #[test]
+    fn reversed_modifier_checks_fg_for_reset_transparency() {
+        // With Modifier::REVERSED, cell.fg becomes the effective background
+        // (see the color-swap in flush()) - transparency must follow that
+        // swap, not check cell.bg unconditionally.
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 1)
+            .with_transparent_reset_bg(true)
+            .build();
+
+        let mut cell = Cell::default();
+        cell.set_symbol("a");
+        cell.modifier.insert(ratatui::style::Modifier::REVERSED);
+        cell.fg = Color::Reset; // effective background under REVERSED
+        cell.bg = Color::Rgb(1, 2, 3); // effective foreground under REVERSED - must not matter
+
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+
+        assert!(!payload.bg_vertices.is_empty(), "flush must generate vertices");
+        for vertex in &payload.bg_vertices {
+            assert_eq!(
+                vertex.bg_color & 0xFF,
+                0,
+                "reversed cell.fg == Reset must still zero the background alpha"
+            );
+        }
+    }
+
+    // ========================================================================
+    // Test: A1 - flush() early-out on an unchanged frame (IMPROVEMENT.md A1).
+    // ========================================================================
+
+    #[test]
+    fn flush_skips_vertex_regeneration_on_byte_identical_redraw() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(2, 1)
+            .build();
+
+        let mut cell = Cell::default();
+        cell.set_symbol("a");
+        cell.bg = Color::Rgb(10, 20, 30);
+
+        // First draw: content changes (empty -> "a"), so this flush must do
+        // real work and produce vertices.
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+        assert!(
+            !payload.bg_vertices.is_empty(),
+            "first draw onto an empty buffer must generate vertices"
+        );
+
+        // Second draw: byte-identical content. Note ratatui's own
+        // `Terminal::flush` (ratatui-core `terminal/buffers.rs`) is what
+        // diffs the previous/current buffers and passes only the CHANGED
+        // cells to `Backend::draw` - an identical redraw means that diff is
+        // empty, so `Backend::draw` is called with an empty iterator (not
+        // the same cell tuple again; feeding the same tuple twice would
+        // incorrectly mark it changed both times, since this trait method
+        // itself performs no diffing - it trusts the caller already diffed).
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
+        assert!(
+            !backend.cells_changed_last_draw(),
+            "an identical redraw (empty diff) must not report a cell change"
+        );
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+
+        let payload = backend.take_draw_payload();
+        assert!(
+            payload.bg_vertices.is_empty(),
+            "flush must skip regenerating bg vertices for an unchanged frame"
+        );
+        assert!(
+            payload.text_vertices.is_empty(),
+            "flush must skip regenerating text vertices for an unchanged frame"
+        );
+    }
+
+    // ========================================================================
+    // Test: A2 - row-incremental flush (IMPROVEMENT.md A2). Vertex order
+    // is not part of the contract (two-pass processing visits rows in a
+    // different order than a straight 0..height scan - see the doc
+    // comment on `flush`), so vertices are compared as an order-independent
+    // multiset via their raw bytes (`TextBgVertexMember`/`TextVertexMember`
+    // are `bytemuck::Pod`, so this is a byte-exact comparison, not a
+    // fuzzy one).
+    // ========================================================================
+
+    fn sorted_bytes<T: bytemuck::Pod>(items: &[T]) -> Vec<Vec<u8>> {
+        let mut bytes: Vec<Vec<u8>> = items.iter().map(|i| bytemuck::bytes_of(i).to_vec()).collect();
+        bytes.sort();
+        bytes
+    }
+
+    #[test]
+    fn row_incremental_flush_matches_a_from_scratch_flush_of_the_same_content() {
+        // Deliberately a SINGLE backend (one `Atlas`) throughout - a glyph's
+        // UV rect depends on its insertion order into the atlas, not just
+        // its identity, so two independently-built backends would assign
+        // "A"/"B"/"C"/"D"/"Z" to different slots and legitimately produce
+        // different (but each internally-correct) UV bytes. Comparing across
+        // two atlases would be testing atlas insertion order, not row-cache
+        // correctness. Instead: incrementally flush on this backend, save
+        // the result, then force the SAME backend (same atlas, same
+        // established slot assignments - no new glyphs are introduced by
+        // this step, so no new allocation) through a full reshape of every
+        // row and compare against the saved result.
+        fn cell_with_symbol(symbol: &str) -> Cell {
+            let mut cell = Cell::default();
+            cell.set_symbol(symbol);
+            cell
+        }
+
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 4)
+            .build();
+
+        let row_a = cell_with_symbol("A");
+        let row_b = cell_with_symbol("B");
+        let row_c = cell_with_symbol("C");
+        let row_d = cell_with_symbol("D");
+        RatatuiBackend::draw(
+            &mut backend,
+            [
+                (0u16, 0u16, &row_a),
+                (0u16, 1u16, &row_b),
+                (0u16, 2u16, &row_c),
+                (0u16, 3u16, &row_d),
+            ]
+            .into_iter(),
+        )
+        .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let _ = backend.take_draw_payload();
+
+        // Change ONLY row 2's cell and flush again - the row cache should
+        // reuse rows 0, 1, 3 from `row_geometry` and reshape only row 2
+        // (allocating "Z" a fresh atlas slot).
+        let row_c_changed = cell_with_symbol("Z");
+        RatatuiBackend::draw(&mut backend, [(0u16, 2u16, &row_c_changed)].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+
+        // Snapshot the row cache produced incrementally. `row_geometry` -
+        // what IMPROVEMENT.md A2 caches - is what this test verifies;
+        // `take_draw_payload`'s full/partial concatenation and row-clear-quad
+        // synthesis (Phase 2) are orthogonal to row-cache correctness, so
+        // comparing `row_geometry` directly (rather than a concatenated
+        // payload) keeps this test decoupled from that later concern.
+        let incremental_rows: Vec<RowGeometry> = backend.row_geometry.clone();
+        let _ = backend.take_draw_payload();
+
+        // Force every row to be reshaped from scratch, on this same
+        // backend. "A", "B", "Z", "D" are all already in the atlas from
+        // the two flushes above, so this introduces no new glyphs and
+        // therefore no new allocations - `Atlas::get` hits the existing
+        // LRU entries and returns the SAME UV rects it already handed out,
+        // making this a true apples-to-apples comparison against the
+        // incremental flush above.
+        backend.dirty_rows.iter_mut().for_each(|dirty| *dirty = true);
+        backend.cells_changed_last_draw = true;
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+
+        for (y, (incremental, reshaped)) in incremental_rows
+            .iter()
+            .zip(backend.row_geometry.iter())
+            .enumerate()
+        {
+            assert_eq!(
+                sorted_bytes(&incremental.bg_vertices),
+                sorted_bytes(&reshaped.bg_vertices),
+                "row {y} bg vertices from an incremental (row-cached) flush must \
+                 match a full reshape of the same content on the same atlas state"
+            );
+            assert_eq!(
+                sorted_bytes(&incremental.text_vertices),
+                sorted_bytes(&reshaped.text_vertices),
+                "row {y} text vertices from an incremental (row-cached) flush must \
+                 match a full reshape of the same content on the same atlas state"
+            );
+        }
+    }
+
+    // ========================================================================
+    // Phase 2 tests: partial redraw (full vs. dirty-rows-only payloads).
+    // ========================================================================
+
+    #[test]
+    fn imported_atlas_renders_identically_without_rasterizing() {
+        let mut cell = Cell::default();
+        cell.set_symbol("─");
+        // Draws one box-drawing cell, returning the glyph uploads it queued
+        // and its text vertex bytes.
+        fn draw_box_cell(backend: &mut BevyTerminalBackend, cell: &Cell) -> (usize, Vec<u8>) {
+            RatatuiBackend::draw(backend, [(0u16, 0u16, cell)].into_iter()).expect("draw failed");
+            RatatuiBackend::flush(backend).expect("flush failed");
+            let uploads = backend.take_shared_glyph_uploads().len();
+            let payload = backend.take_draw_payload();
+            (uploads, bytemuck::cast_slice::<_, u8>(&payload.text_vertices).to_vec())
+        }
+
+        let mut source = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 1)
+            .build();
+        source.populate_programmatic_glyphs();
+        let snapshot = source.export_atlas();
+        assert!(!snapshot.is_empty());
+        let source_uploads = source.take_shared_glyph_uploads();
+        assert_eq!(snapshot.len(), source_uploads.len());
+
+        // A fresh `Fonts` (new font id) - only the snapshot carries over.
+        let mut target = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 1)
+            .build();
+        assert_eq!(target.import_atlas(&snapshot), snapshot.len());
+        assert_eq!(
+            target.take_shared_glyph_uploads(),
+            source_uploads,
+            "imported glyphs must land in the same slots with the same pixels"
+        );
+        assert_eq!(target.import_atlas(&snapshot), 0, "already-cached glyphs are skipped");
+
+        let (source_new, source_vertices) = draw_box_cell(&mut source, &cell);
+        let (target_new, target_vertices) = draw_box_cell(&mut target, &cell);
+        assert_eq!((source_new, target_new), (0, 0), "both atlases already hold the glyph");
+        assert_eq!(target_vertices, source_vertices);
+    }
+
+    #[test]
+    fn atlas_snapshot_from_another_font_size_is_ignored() {
+        let mut source = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 1)
+            .build();
+        source.populate_programmatic_glyphs();
+        let snapshot = source.export_atlas();
+
+        let font_data = include_bytes!("../../examples/assets/fonts/Mplus1Code-Regular.ttf");
+        let font = Font::new(font_data).expect("failed to load test font");
+        let mut target = TerminalBuilder::new(Arc::new(Fonts::new(font, 24)))
+            .with_dimensions(1, 1)
+            .build();
+        assert_eq!(target.import_atlas(&snapshot), 0);
+        assert!(target.take_shared_glyph_uploads().is_empty());
+    }
+
+    #[test]
+    fn atlas_snapshot_from_another_font_file_is_ignored() {
+        let mut source = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 1)
+            .build();
+        source.populate_programmatic_glyphs();
+        let mut snapshot = source.export_atlas();
+        // As if exported with another file for the regular font.
+        snapshot.fonts[1] ^= 1;
+
+        let mut target = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 1)
+            .build();
+        assert_eq!(target.import_atlas(&snapshot), 0);
+        assert!(target.take_shared_glyph_uploads().is_empty());
+    }
+
+    #[test]
+    fn is_renderable_symbol_accepts_single_grapheme_clusters_only() {
+        let attached = ["e\u{301}", "👍🏽", "👨\u{200D}👩\u{200D}👧", "🇯🇵", "❤\u{FE0F}"];
+        for ok in ["", " ", "a", "─", "漢"].into_iter().chain(attached) {
+            assert!(is_renderable_symbol(ok), "{ok:?}");
+        }
+        for bad in ["\n", "\t", "\u{1b}", "\0", "a\u{7f}", "ab", "漢字", "a\nb"] {
+            assert!(!is_renderable_symbol(bad), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn is_renderable_symbol_accepts_clusters_with_spacing_marks() {
+        // Thai KO KAI + SARA AM and Devanagari KA + VOWEL SIGN I: the marks
+        // are not zero-width but still belong to the base's cluster.
+        for ok in ["กำ", "कि"] {
+            assert!(is_renderable_symbol(ok), "{ok:?}");
+        }
+        for bad in ["กำก", "किक"] {
+            assert!(!is_renderable_symbol(bad), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn odd_symbols_are_replaced_and_never_widen_a_cell() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(4, 2)
+            .build();
+        let symbols = ["\u{1b}", "abc", "\n", "a"];
+        let cells: Vec<Cell> = symbols
+            .iter()
+            .map(|symbol| {
+                let mut cell = Cell::default();
+                cell.set_symbol(symbol);
+                cell
+            })
+            .collect();
+        RatatuiBackend::draw(
+            &mut backend,
+            cells.iter().enumerate().map(|(x, cell)| (x as u16, 0u16, cell)),
+        )
+        .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+
+        let row: Vec<&str> = backend.cells[..4].iter().map(Cell::symbol).collect();
+        assert_eq!(row, ["\u{FFFD}", "\u{FFFD}", "\u{FFFD}", "a"]);
+        let payload = backend.take_draw_payload();
+        assert_eq!(payload.text_quad_count(), 4, "one glyph quad per cell");
+    }
+
+    #[test]
+    fn control_pictures_replace_lone_control_characters() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(5, 1)
+            .with_control_pictures(true)
+            .build();
+        let symbols = ["\u{3}", "\0", "\u{7f}", "\t", "\u{3}a"];
+        let cells: Vec<Cell> = symbols
+            .iter()
+            .map(|symbol| {
+                let mut cell = Cell::default();
+                cell.set_symbol(symbol);
+                cell
+            })
+            .collect();
+        let content = || cells.iter().enumerate().map(|(x, cell)| (x as u16, 0u16, cell));
+        RatatuiBackend::draw(&mut backend, content()).expect("draw failed");
+
+        let row: Vec<&str> = backend.cells[..5].iter().map(Cell::symbol).collect();
+        assert_eq!(
+            row,
+            ["\u{2403}", "\u{2400}", "\u{2421}", "\t", "\u{FFFD}"],
+            "lone controls become pictures; tabs and multi-char symbols don't"
+        );
+
+        backend.set_control_pictures(false);
+        RatatuiBackend::draw(&mut backend, content()).expect("draw failed");
+        assert_eq!(backend.cells[0].symbol(), "\u{FFFD}");
+    }
+
+    #[test]
+    fn ambiguous_width_setting_widens_glyph_and_covers_next_cell() {
+        let font_data = include_bytes!("../../examples/assets/fonts/Mplus1Code-Regular.ttf");
+        let mut fonts = Fonts::new(Font::new(font_data).expect("failed to load test font"), 16);
+        fonts.set_ambiguous_width(2);
+        let cell_width = fonts.min_width_px() as f32;
+        let mut backend = TerminalBuilder::new(Arc::new(fonts))
+            .with_dimensions(3, 1)
+            .build();
+
+        let mut quote = Cell::default();
+        quote.set_symbol("\u{2018}");
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &quote)].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+
+        assert_eq!(backend.cells[1].symbol(), "", "continuation cell");
+        let payload = backend.take_draw_payload();
+        let glyph = &payload.text_vertices[..4];
+        assert_eq!(glyph[1].vertex[0] - glyph[0].vertex[0], 2.0 * cell_width);
+    }
+
+    #[test]
+    fn cell_under_a_widened_glyph_comes_back_when_the_glyph_is_replaced() {
+        let font_data = include_bytes!("../../examples/assets/fonts/Mplus1Code-Regular.ttf");
+        let mut fonts = Fonts::new(Font::new(font_data).expect("failed to load test font"), 16);
+        fonts.set_ambiguous_width(2);
+        let mut backend = TerminalBuilder::new(Arc::new(fonts))
+            .with_dimensions(3, 1)
+            .build();
+        let cell = |symbol: &str| {
+            let mut cell = Cell::default();
+            cell.set_symbol(symbol);
+            cell
+        };
+        let (quote, b, c, a) = (cell("\u{2018}"), cell("b"), cell("c"), cell("a"));
+
+        // Ratatui measures the quote as one cell, so it sends `b` as well.
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &quote), (1, 0, &b)].into_iter())
+            .expect("draw failed");
+        assert_eq!(backend.cells[1].symbol(), "", "covered by the widened quote");
+
+        // A change to the covered cell alone stays covered...
+        RatatuiBackend::draw(&mut backend, [(1u16, 0u16, &c)].into_iter()).expect("draw failed");
+        assert_eq!(backend.cells[1].symbol(), "");
+
+        // ...and is shown once the quote goes, though ratatui's diff only
+        // resends the quote's own cell.
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &a)].into_iter()).expect("draw failed");
+        assert_eq!(backend.cells[0].symbol(), "a");
+        assert_eq!(backend.cells[1].symbol(), "c");
+        assert!(backend.widened_over.is_empty());
+    }
+
+    #[test]
+    fn oversized_glyphs_become_placeholder_boxes() {
+        let font_data = include_bytes!("../../examples/assets/fonts/Mplus1Code-Regular.ttf");
+        // Cells taller than the whole glyph atlas.
+        let fonts = Fonts::new(Font::new(font_data).expect("failed to load test font"), 4096);
+        let (cell_width, cell_height) = (fonts.min_width_px() as f32, fonts.height_px() as f32);
+        let mut backend = TerminalBuilder::new(Arc::new(fonts))
+            .with_dimensions(2, 1)
+            .with_missing_glyph_tracking(true)
+            .build();
+
+        let mut cell = Cell::default();
+        cell.set_symbol("a");
+        RatatuiBackend::draw(&mut backend, [(1u16, 0u16, &cell)].into_iter()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+
+        let payload = backend.take_draw_payload();
+        assert!(payload.text_vertices.is_empty(), "nothing is rasterized into the atlas");
+        let outline: Vec<[f32; 2]> = payload.bg_vertices.iter().map(|v| v.vertex).collect();
+        assert_eq!(outline.len(), 16, "four edges of one box");
+        let (min_x, max_x) = outline.iter().fold((f32::MAX, f32::MIN), |(lo, hi), v| (lo.min(v[0]), hi.max(v[0])));
+        assert_eq!((min_x, max_x), (cell_width, 2.0 * cell_width), "the box covers the glyph's cell");
+        assert!(outline.iter().all(|v| (0.0..=cell_height).contains(&v[1])));
+        assert!(backend.missing_glyphs().contains(&'a'));
+    }
+
+    #[test]
+    fn set_grid_replaces_the_grid_and_dirties_only_changed_rows() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(3, 2)
+            .build();
+        let mut grid: Vec<Cell> = ["a", "b", "c", "d", "e", "f"]
+            .iter()
+            .map(|symbol| {
+                let mut cell = Cell::default();
+                cell.set_symbol(symbol);
+                cell
+            })
+            .collect();
+
+        assert!(matches!(
+            backend.set_grid(&grid, 2, 3),
+            Err(crate::TerminalError::GridSize { expected_cols: 3, expected_rows: 2, .. })
+        ));
+        assert!(backend.set_grid(&grid[..5], 3, 2).is_err(), "cell count must be cols * rows");
+
+        backend.set_grid(&grid, 3, 2).expect("set_grid failed");
+        assert_eq!(backend.cells, grid);
+        assert!(backend.cells_changed_last_draw());
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        backend.take_draw_payload();
+
+        backend.set_grid(&grid, 3, 2).expect("set_grid failed");
+        assert!(!backend.cells_changed_last_draw(), "an identical grid changes nothing");
+
+        grid[4].set_symbol("x");
+        backend.set_grid(&grid, 3, 2).expect("set_grid failed");
+        assert_eq!(backend.dirty_rows, [false, true]);
+        assert_eq!(backend.cells[4].symbol(), "x");
+    }
+
+    #[test]
+    fn set_grid_matches_drawing_the_same_cells() {
+        let grid: Vec<Cell> = ["a", "漢", "", "\u{1}", "b", "c"]
+            .iter()
+            .map(|symbol| {
+                let mut cell = Cell::default();
+                cell.set_symbol(symbol);
+                cell
+            })
+            .collect();
+
+        let mut drawn = TerminalBuilder::new(test_fonts()).with_dimensions(3, 2).build();
+        RatatuiBackend::draw(
+            &mut drawn,
+            grid.iter().enumerate().map(|(i, cell)| ((i % 3) as u16, (i / 3) as u16, cell)),
+        )
+        .expect("draw failed");
+        let mut set = TerminalBuilder::new(test_fonts()).with_dimensions(3, 2).build();
+        set.set_grid(&grid, 3, 2).expect("set_grid failed");
+
+        assert_eq!(set.cells, drawn.cells);
+        assert_eq!(set.cells[3].symbol(), REPLACEMENT_SYMBOL, "control characters are replaced");
+        RatatuiBackend::flush(&mut drawn).expect("flush failed");
+        RatatuiBackend::flush(&mut set).expect("flush failed");
+        assert_eq!(
+            bytemuck::cast_slice::<_, u8>(&set.take_draw_payload().text_vertices),
+            bytemuck::cast_slice::<_, u8>(&drawn.take_draw_payload().text_vertices),
+        );
+
+        // Substituted symbols and wide characters don't make an unchanged
+        // row look changed.
+        set.set_grid(&grid, 3, 2).expect("set_grid failed");
+        assert!(!set.cells_changed_last_draw());
+        assert_eq!(set.dirty_rows, [false, false]);
+    }
+
+    /// A 5x2 backend showing `"hi   "` over `"a b  "`, the last cell of
+    /// the second row on a blue background.
+    fn backend_with_trailing_spaces() -> BevyTerminalBackend {
+        let mut grid: Vec<Cell> = "hi   a b  "
+            .chars()
+            .map(|c| {
+                let mut cell = Cell::default();
+                cell.set_char(c);
+                cell
+            })
+            .collect();
+        grid[9].set_bg(Color::Blue);
+        let mut backend = TerminalBuilder::new(test_fonts()).with_dimensions(5, 2).build();
+        backend.set_grid(&grid, 5, 2).expect("set_grid failed");
+        backend
+    }
+
+    #[test]
+    fn get_text_trims_trailing_blanks_by_default() {
+        let backend = backend_with_trailing_spaces();
+        let text: Vec<String> = backend.get_text().iter().map(Line::to_string).collect();
+        // A space on a colored background isn't blank.
+        assert_eq!(text, ["hi", "a b  "]);
+
+        let keep = TextExtractOptions { trim_trailing: false };
+        let text: Vec<String> = backend.get_text_with(keep).iter().map(Line::to_string).collect();
+        assert_eq!(text, ["hi   ", "a b  "]);
+    }
+
+    #[test]
+    fn get_text_collapses_equal_styles_and_skips_wide_continuations() {
+        let mut grid: Vec<Cell> = "ab\u{4E16} c "
+            .chars()
+            .map(|c| {
+                let mut cell = Cell::default();
+                cell.set_char(c);
+                cell
+            })
+            .collect();
+        grid[0].set_fg(Color::Red);
+        grid[1].set_fg(Color::Red);
+        let mut backend = TerminalBuilder::new(test_fonts()).with_dimensions(6, 1).build();
+        backend.set_grid(&grid, 6, 1).expect("set_grid failed");
+
+        let text = backend.get_text();
+        let spans: Vec<(&str, Color)> = text[0]
+            .spans
+            .iter()
+            .map(|span| (span.content.as_ref(), span.style.fg.unwrap_or(Color::Reset)))
+            .collect();
+        // The space under the wide character's right half is its
+        // continuation, not text.
+        assert_eq!(spans, [("ab", Color::Red), ("\u{4E16}c", Color::Reset)]);
+    }
+
+    #[test]
+    fn text_in_range_follows_the_selection_and_trim_option() {
+        let backend = backend_with_trailing_spaces();
+        let trim = TextExtractOptions::default();
+        let keep = TextExtractOptions { trim_trailing: false };
+
+        assert_eq!(backend.text_in_range((1, 0), (2, 1), trim), "i\na b");
+        assert_eq!(backend.text_in_range((1, 0), (2, 1), keep), "i   \na b");
+        // Reversed ends and out-of-grid positions.
+        assert_eq!(backend.text_in_range((9, 9), (0, 0), trim), "hi\na b  ");
+        assert_eq!(backend.text_in_range((0, 0), (9, 9), keep), "hi   \na b  ");
+    }
+
+    #[test]
+    fn get_text_is_empty_before_the_first_draw() {
+        let backend = TerminalBuilder::new(test_fonts()).with_dimensions(5, 2).build();
+        assert!(backend.get_text().is_empty());
+        assert_eq!(backend.text_in_range((0, 0), (4, 1), TextExtractOptions::default()), "");
+    }
+
+    #[test]
+    fn cell_filter_rewrites_cells_from_draw_and_set_grid() {
+        let mut backend = TerminalBuilder::new(test_fonts()).with_dimensions(3, 2).build();
+        backend.set_cell_filter(Some(Box::new(|cell: &mut Cell, x: u16, y: u16| {
+            if y == 1 {
+                cell.set_char('*');
+            } else if x == 0 {
+                let upper = cell.symbol().to_uppercase();
+                cell.set_symbol(&upper);
+            }
+        })));
+
+        let cells: Vec<Cell> = "abcdef"
+            .chars()
+            .map(|c| {
+                let mut cell = Cell::default();
+                cell.set_char(c);
+                cell
+            })
+            .collect();
+        RatatuiBackend::draw(
+            &mut backend,
+            cells.iter().enumerate().map(|(i, cell)| ((i % 3) as u16, (i / 3) as u16, cell)),
+        )
+        .expect("draw failed");
+        let symbols = |backend: &BevyTerminalBackend| {
+            backend.cells.iter().map(Cell::symbol).collect::<String>()
+        };
+        assert_eq!(symbols(&backend), "Abc***");
+
+        // set_grid compares the filtered cells: row 1 is unchanged.
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let mut next = cells.clone();
+        next[0].set_char('x');
+        next[4].set_char('y');
+        backend.set_grid(&next, 3, 2).expect("set_grid failed");
+        assert_eq!(symbols(&backend), "Xbc***");
+        assert_eq!(backend.dirty_rows, [true, false]);
+
+        backend.set_cell_filter(None);
+        backend.set_grid(&next, 3, 2).expect("set_grid failed");
+        assert_eq!(symbols(&backend), "xbcdyf");
+    }
+
+    #[test]
+    fn combining_marks_are_drawn_over_their_base_glyph() {
+        // U+0301 COMBINING ACUTE ACCENT has no advance; the shaper places
+        // it relative to the pen after the `x`. Enclosing marks (U+20DD)
+        // take the same path, but the test fonts don't have them.
+        fn draw_symbol(backend: &mut BevyTerminalBackend, symbol: &str) -> Vec<TextVertexMember> {
+            let mut cell = Cell::default();
+            cell.set_symbol(symbol);
+            cell.modifier = ratatui::style::Modifier::UNDERLINED;
+            RatatuiBackend::draw(backend, [(0u16, 0u16, &cell)].into_iter()).expect("draw failed");
+            RatatuiBackend::flush(backend).expect("flush failed");
+            backend.take_draw_payload().text_vertices
+        }
+
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(2, 1)
+            .build();
+        draw_symbol(&mut backend, "x");
+        backend.take_shared_glyph_uploads();
+
+        let vertices = draw_symbol(&mut backend, "x\u{301}");
+        let quads: Vec<&[TextVertexMember]> = vertices.chunks_exact(4).collect();
+        assert_eq!(quads.len(), 2, "base and mark each get a quad");
+        assert_eq!(quads[0][0].vertex, quads[1][0].vertex, "the mark shares its base's cell");
+        assert_eq!(quads[0][3].vertex, quads[1][3].vertex);
+        assert_ne!(quads[0][0].underline_pos, 0);
+        assert_eq!(quads[1][0].underline_pos, 0, "the underline is drawn once, by the base");
+
+        let uploads = backend.take_shared_glyph_uploads();
+        assert_eq!(uploads.len(), 1, "only the mark is new");
+        assert!(
+            uploads[0].1.iter().any(|&pixel| pixel >> 24 != 0),
+            "the accent must land inside the cell, not to its left"
+        );
+    }
+
+    // The ASCII fast path never shapes, so never ligates.
+    #[cfg(not(all(feature = "ascii_fast_shaping", not(feature = "bold_italic_fonts"))))]
+    #[test]
+    fn ligatures_form_only_when_enabled() {
+        // M+ 1Code ligates its own name into a single logo glyph.
+        fn glyph_quads(backend: &mut BevyTerminalBackend) -> usize {
+            let cells: Vec<Cell> = "mplusfonts"
+                .chars()
+                .map(|c| {
+                    let mut cell = Cell::default();
+                    cell.set_char(c);
+                    cell
+                })
+                .collect();
+            backend.set_grid(&cells, 10, 1).expect("set_grid failed");
+            RatatuiBackend::flush(backend).expect("flush failed");
+            backend.take_draw_payload().text_vertices.len() / 4
+        }
+
+        let mut backend = TerminalBuilder::new(test_fonts()).with_dimensions(10, 1).build();
+        assert_eq!(glyph_quads(&mut backend), 1, "ligatures are on by default");
+
+        backend.set_ligatures(false);
+        assert_eq!(glyph_quads(&mut backend), 10, "one glyph per character");
+
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(10, 1)
+            .with_ligatures(false)
+            .build();
+        assert_eq!(glyph_quads(&mut backend), 10);
+    }
+
+    #[test]
+    fn glyph_scale_shrinks_glyph_quads_but_not_the_cell_stride() {
+        let fonts = test_fonts();
+        let cell_width = fonts.min_width_px() as f32;
+        let cell_height = fonts.height_px() as f32;
+        let mut backend = TerminalBuilder::new(fonts)
+            .with_dimensions(3, 1)
+            .with_glyph_scale(0.5, 0.75)
+            .build();
+        let cells: Vec<Cell> = ["a", "b"]
+            .iter()
+            .map(|symbol| {
+                let mut cell = Cell::default();
+                cell.set_symbol(symbol);
+                cell.set_bg(ratatui::style::Color::Red);
+                cell
+            })
+            .collect();
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cells[0]), (1, 0, &cells[1])].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+
+        let payload = backend.take_draw_payload();
+        let quads: Vec<[f32; 4]> = payload
+            .text_vertices
+            .chunks_exact(4)
+            .map(|quad| [quad[0].vertex[0], quad[0].vertex[1], quad[3].vertex[0], quad[3].vertex[1]])
+            .collect();
+        let inset_x = cell_width / 4.0;
+        let inset_y = cell_height / 8.0;
+        assert_eq!(
+            quads,
+            [
+                [inset_x, inset_y, cell_width - inset_x, cell_height - inset_y],
+                [cell_width + inset_x, inset_y, 2.0 * cell_width - inset_x, cell_height - inset_y],
+            ],
+            "half-width, three-quarter-height glyphs centered in unchanged cells"
+        );
+        let uv_width = payload.text_vertices[3].uv[0] - payload.text_vertices[0].uv[0];
+        assert_eq!(uv_width, cell_width, "the whole atlas bitmap is sampled");
+        assert!(
+            payload
+                .bg_vertices
+                .chunks_exact(4)
+                .any(|quad| quad[0].vertex == [cell_width, 0.0] && quad[3].vertex == [2.0 * cell_width, cell_height]),
+            "backgrounds still fill whole cells"
+        );
+
+        backend.set_glyph_scale(0.0, f32::NAN);
+        assert_eq!(backend.glyph_scale, (1.0, 1.0), "invalid factors fall back to 1.0");
+    }
+
+    #[test]
+    fn cell_inset_shrinks_glyphs_but_not_backgrounds_or_box_drawing() {
+        let fonts = test_fonts();
+        let cell_width = fonts.min_width_px() as f32;
+        let cell_height = fonts.height_px() as f32;
+        let mut backend = TerminalBuilder::new(fonts)
+            .with_dimensions(3, 1)
+            .with_cell_inset(2)
+            .build();
+        let cells: Vec<Cell> = ["a", "─"]
+            .iter()
+            .map(|symbol| {
+                let mut cell = Cell::default();
+                cell.set_symbol(symbol);
+                cell.set_bg(ratatui::style::Color::Red);
+                cell
+            })
+            .collect();
+        let draw = |backend: &mut BevyTerminalBackend| {
+            RatatuiBackend::draw(backend, [(0u16, 0u16, &cells[0]), (1, 0, &cells[1])].into_iter())
+                .expect("draw failed");
+            RatatuiBackend::flush(backend).expect("flush failed");
+            let payload = backend.take_draw_payload();
+            let quads: Vec<[f32; 4]> = payload
+                .text_vertices
+                .chunks_exact(4)
+                .map(|q| [q[0].vertex[0], q[0].vertex[1], q[3].vertex[0], q[3].vertex[1]])
+                .collect();
+            (quads, payload)
+        };
+
+        let (quads, payload) = draw(&mut backend);
+        assert_eq!(
+            quads,
+            [
+                [2.0, 2.0, cell_width - 2.0, cell_height - 2.0],
+                [cell_width, 0.0, 2.0 * cell_width, cell_height],
+            ],
+            "the letter is inset on all four sides, the box-drawing line spans its cell"
+        );
+        assert!(
+            payload
+                .bg_vertices
+                .chunks_exact(4)
+                .any(|q| q[0].vertex == [0.0, 0.0] && q[3].vertex == [cell_width, cell_height]),
+            "backgrounds still fill whole cells"
+        );
+
+        // An inset bigger than the cell leaves a one-pixel glyph, centered.
+        backend.set_cell_inset(1000);
+        let (quads, payload) = draw(&mut backend);
+        assert!(payload.is_full(), "a new inset rebuilds every row");
+        assert_eq!(
+            quads[0],
+            [
+                (cell_width - 1.0) / 2.0,
+                (cell_height - 1.0) / 2.0,
+                (cell_width + 1.0) / 2.0,
+                (cell_height + 1.0) / 2.0,
+            ]
+        );
+    }
+
+    #[test]
+    fn fullwidth_forms_take_two_cells_and_halfwidth_katakana_one() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(4, 1)
+            .build();
+        let cell_width = backend.fonts.min_width_px() as f32;
+
+        // Laid out the way ratatui does it: the fullwidth '！' at column 0
+        // owns column 1, the halfwidth 'ｱ' and 'ｲ' sit in 2 and 3.
+        let cells: Vec<Cell> = ["！", "ｱ", "ｲ"]
+            .iter()
+            .map(|symbol| {
+                let mut cell = Cell::default();
+                cell.set_symbol(symbol);
+                cell
+            })
+            .collect();
+        RatatuiBackend::draw(
+            &mut backend,
+            [(0u16, 0u16, &cells[0]), (2, 0, &cells[1]), (3, 0, &cells[2])].into_iter(),
+        )
+        .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+
+        let row: Vec<&str> = backend.cells[..4].iter().map(Cell::symbol).collect();
+        assert_eq!(row, ["！", "", "ｱ", "ｲ"], "'！' covers its continuation cell");
+        let payload = backend.take_draw_payload();
+        assert_eq!(payload.text_quad_count(), 3);
+        let quads: Vec<(f32, f32)> = payload
+            .text_vertices
+            .chunks_exact(4)
+            .map(|quad| (quad[0].vertex[0], quad[1].vertex[0] - quad[0].vertex[0]))
+            .collect();
+        assert_eq!(
+            quads,
+            [
+                (0.0, 2.0 * cell_width),
+                (2.0 * cell_width, cell_width),
+                (3.0 * cell_width, cell_width),
+            ],
+            "glyph quads must match the cells ratatui allocated"
+        );
+    }
+
+    #[test]
+    fn out_of_bounds_draw_positions_are_ignored() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(2, 2)
+            .build();
+        let mut cell = Cell::default();
+        cell.set_symbol("x");
+        RatatuiBackend::draw(
+            &mut backend,
+            [(2u16, 0u16, &cell), (0u16, 2u16, &cell), (u16::MAX, 0u16, &cell)].into_iter(),
+        )
+        .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        assert!(backend.cells.iter().all(|cell| cell.symbol() == " "));
+    }
+
+    #[test]
+    fn programmatic_glyphs_are_populated_once_per_shared_fonts() {
+        let fonts = test_fonts();
+        let mut first = TerminalBuilder::new(fonts.clone()).with_dimensions(1, 1).build();
+        let mut second = TerminalBuilder::new(fonts.clone()).with_dimensions(1, 1).build();
+
+        first.populate_programmatic_glyphs();
+        let populated = first.take_shared_glyph_uploads().len();
+        assert!(populated > 0);
+        second.populate_programmatic_glyphs();
+        assert!(
+            second.take_shared_glyph_uploads().is_empty(),
+            "the second terminal reuses the glyphs already in the shared atlas"
+        );
+
+        second.set_programmatic_supersampling(2);
+        second.populate_programmatic_glyphs();
+        assert_eq!(
+            second.take_shared_glyph_uploads().len(),
+            populated,
+            "other settings still get their own glyphs"
+        );
+
+        let mut other = TerminalBuilder::new(test_fonts()).with_dimensions(1, 1).build();
+        other.populate_programmatic_glyphs();
+        assert_eq!(other.take_shared_glyph_uploads().len(), populated, "a separate Fonts has its own atlas");
+    }
+
+    #[test]
+    fn supersampling_change_rasterizes_programmatic_glyphs_under_new_keys() {
+        let mut cell = Cell::default();
+        cell.set_symbol("╭");
+        fn draw_cell(backend: &mut BevyTerminalBackend, cell: &Cell) -> usize {
+            RatatuiBackend::draw(backend, [(0u16, 0u16, cell)].into_iter()).expect("draw failed");
+            RatatuiBackend::flush(backend).expect("flush failed");
+            backend.take_draw_payload();
+            backend.take_shared_glyph_uploads().len()
+        }
+
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 1)
+            .build();
+        backend.populate_programmatic_glyphs();
+        backend.take_shared_glyph_uploads();
+        assert_eq!(draw_cell(&mut backend, &cell), 0, "1x is pre-populated");
+
+        backend.set_programmatic_supersampling(9);
+        assert_eq!(backend.programmatic_supersample, 4, "clamped to 4x");
+        assert_eq!(draw_cell(&mut backend, &cell), 1, "4x corner is its own glyph");
+        assert_eq!(draw_cell(&mut backend, &cell), 0);
+
+        backend.set_programmatic_supersampling(1);
+        assert_eq!(draw_cell(&mut backend, &cell), 0, "1x glyph is still cached");
+    }
+
+    #[test]
+    fn powerline_style_change_rasterizes_separators_under_new_keys() {
+        let mut cell = Cell::default();
+        cell.set_symbol("\u{E0B0}");
+        fn draw_cell(backend: &mut BevyTerminalBackend, cell: &Cell) -> usize {
+            RatatuiBackend::draw(backend, [(0u16, 0u16, cell)].into_iter()).expect("draw failed");
+            RatatuiBackend::flush(backend).expect("flush failed");
+            backend.take_draw_payload();
+            backend.take_shared_glyph_uploads().len()
+        }
+
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 1)
+            .build();
+        backend.populate_programmatic_glyphs();
+        backend.take_shared_glyph_uploads();
+        assert_eq!(draw_cell(&mut backend, &cell), 0, "default style is pre-populated");
+
+        backend.set_powerline_style(PowerlineStyle {
+            shape: crate::backend::PowerlineShape::Rounded,
+            ..PowerlineStyle::default()
+        });
+        assert_eq!(draw_cell(&mut backend, &cell), 1, "rounded separator is its own glyph");
+        assert_eq!(draw_cell(&mut backend, &cell), 0);
+
+        backend.set_powerline_style(PowerlineStyle::default());
+        assert_eq!(draw_cell(&mut backend, &cell), 0, "default glyph is still cached");
+    }
+
+    #[test]
+    fn glyph_origin_column_snaps_wide_glyph_continuations() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(4, 1)
+            .build();
+        assert_eq!(backend.glyph_origin_column(2, 0), 2, "nothing drawn yet");
+
+        let mut wide = Cell::default();
+        wide.set_symbol("中");
+        RatatuiBackend::draw(&mut backend, [(1u16, 0u16, &wide)].into_iter()).expect("draw failed");
+
+        assert_eq!(backend.glyph_origin_column(2, 0), 1, "continuation cell snaps left");
+        assert_eq!(backend.glyph_origin_column(1, 0), 1);
+        assert_eq!(backend.glyph_origin_column(0, 0), 0);
+        assert_eq!(backend.glyph_origin_column(3, 0), 3);
+        assert_eq!(backend.glyph_origin_column(9, 0), 9, "out of range is passed through");
+    }
+
+    #[test]
+    fn padding_letterboxes_and_offsets_the_grid() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(2, 1)
+            .with_reset_bg([9, 9, 9])
+            .with_padding(2, 3, 4, 5)
+            .build();
+        let (cw, ch) = (backend.fonts.min_width_px(), backend.fonts.height_px());
+
+        let mut cell = Cell::default();
+        cell.set_symbol("a");
+        cell.bg = Color::Rgb(10, 20, 30);
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+
+        let (width, height) = ((2 * cw + 8) as f32, (ch + 6) as f32);
+        assert_eq!((payload.screen_width_px, payload.screen_height_px), (width, height));
+        let fill: Vec<_> = payload.bg_vertices[..4].iter().map(|v| (v.vertex, v.bg_color)).collect();
+        let reset = u32::from_be_bytes([9, 9, 9, 255]);
+        assert_eq!(
+            fill,
+            [([0.0, 0.0], reset), ([width, 0.0], reset), ([0.0, height], reset), ([width, height], reset)],
+            "a full payload starts by filling the whole texture with the reset background"
+        );
+        assert_eq!(payload.bg_vertices[4].vertex, [5.0, 2.0], "the first cell starts at (left, top)");
+        assert!(payload.text_vertices.iter().all(|v| v.vertex[0] >= 5.0 && v.vertex[1] >= 2.0));
+
+        cell.set_symbol("b");
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+        assert!(!payload.is_full());
+        assert_eq!(payload.scissor, Some([5, 2, 2 * cw, ch]), "the scissor follows the grid origin");
+        assert_eq!(payload.bg_vertices[0].vertex, [5.0, 2.0], "no letterbox fill on a partial payload");
+    }
+
+    #[test]
+    fn negative_padding_bleeds_the_grid_off_the_texture() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(2, 1)
+            .with_padding(-1, -1, -1, -1)
+            .build();
+        let (cw, ch) = (backend.fonts.min_width_px(), backend.fonts.height_px());
+
+        let mut cell = Cell::default();
+        cell.set_symbol("a");
+        cell.bg = Color::Rgb(10, 20, 30);
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+
+        assert_eq!(
+            (payload.screen_width_px, payload.screen_height_px),
+            ((2 * cw - 2) as f32, (ch - 2) as f32)
+        );
+        assert_eq!(payload.bg_vertices[0].vertex, [-1.0, -1.0], "nothing to letterbox");
+
+        cell.set_symbol("b");
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+        assert_eq!(payload.scissor, Some([0, 0, 2 * cw - 1, ch - 1]));
+    }
+
+    #[test]
+    fn first_take_is_full() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(2, 1)
+            .build();
+
+        let mut cell = Cell::default();
+        cell.set_symbol("a");
+        cell.bg = Color::Rgb(10, 20, 30);
+
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+
+        assert!(payload.is_full(), "the very first take must be a full payload");
+    }
+
+    #[test]
+    fn status_line_update_reshapes_only_its_own_row() {
+        let (cols, rows) = (100u16, 30u16);
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(cols, rows)
+            .build();
+        let mut cell = Cell::default();
+        cell.set_symbol("x");
+        let everywhere = (0..rows).flat_map(|y| (0..cols).map(move |x| (x, y)));
+        RatatuiBackend::draw(&mut backend, everywhere.map(|(x, y)| (x, y, &cell)))
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        backend.take_draw_payload();
+        let cached: Vec<*const TextVertexMember> = backend
+            .row_geometry
+            .iter()
+            .map(|row| row.text_vertices.as_ptr())
+            .collect();
+
+        let mut status = Cell::default();
+        status.set_symbol("S");
+        RatatuiBackend::draw(&mut backend, std::iter::once((0, rows - 1, &status)))
+            .expect("draw failed");
+        assert_eq!(backend.dirty_rows.iter().filter(|&&dirty| dirty).count(), 1);
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+
+        // Every other row still holds the very vertices shaped the first
+        // time - reused, not regenerated.
+        let last = rows as usize - 1;
+        for (y, row) in backend.row_geometry.iter().enumerate().take(last) {
+            assert_eq!(row.text_vertices.as_ptr(), cached[y], "row {y} was reshaped");
+        }
+        assert!(!backend.take_draw_payload().is_full());
+    }
+
+    #[test]
+    fn partial_take_contains_only_dirty_rows() {
+        fn cell_with_symbol(symbol: &str) -> Cell {
+            let mut cell = Cell::default();
+            cell.set_symbol(symbol);
+            cell
+        }
+
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 3)
+            .build();
+
+        let row_a = cell_with_symbol("A");
+        let row_b = cell_with_symbol("B");
+        let row_c = cell_with_symbol("C");
+        RatatuiBackend::draw(
+            &mut backend,
+            [(0u16, 0u16, &row_a), (0u16, 1u16, &row_b), (0u16, 2u16, &row_c)].into_iter(),
+        )
+        .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let _ = backend.take_draw_payload(); // consume the initial full take
+
+        // Change only row 1.
+        let row_b_changed = cell_with_symbol("Z");
+        RatatuiBackend::draw(&mut backend, [(0u16, 1u16, &row_b_changed)].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+
+        assert!(!payload.is_full(), "a single-row change must take a partial payload");
+
+        let cell_height_px = backend.fonts.height_px() as f32;
+        let y0 = 1.0 * cell_height_px;
+        let y1 = 2.0 * cell_height_px;
+        let expected_color = u32::from_be_bytes(backend.initial_fill);
+
+        // The first 4 vertices are the synthesized row-clear quad for row 1
+        // (TL, TR, BL, BR), in the `initial_fill` color.
+        assert_eq!(payload.bg_vertices[0].vertex, [0.0, y0]);
+        assert_eq!(payload.bg_vertices[1].vertex[1], y0);
+        assert_eq!(payload.bg_vertices[2].vertex, [0.0, y1]);
+        assert_eq!(payload.bg_vertices[3].vertex[1], y1);
+        for vertex in &payload.bg_vertices[0..4] {
+            assert_eq!(vertex.bg_color, expected_color);
+        }
+
+        // No vertex (row-clear quad or actual glyph/bg content) may lie
+        // outside row 1's pixel span - partial redraw must not touch rows
+        // it didn't mark dirty.
+        for vertex in &payload.bg_vertices {
+            assert!(vertex.vertex[1] >= y0 && vertex.vertex[1] <= y1);
+        }
+        for vertex in &payload.text_vertices {
+            assert!(vertex.vertex[1] >= y0 && vertex.vertex[1] <= y1);
+        }
+    }
+
+    #[test]
+    fn partial_take_scissors_to_the_dirty_row_band() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(2, 4)
+            .build();
+
+        let mut cell = Cell::default();
+        cell.set_symbol("A");
+        let cells: Vec<_> = (0..4u16).map(|y| (0u16, y, &cell)).collect();
+        RatatuiBackend::draw(&mut backend, cells.into_iter()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let full = backend.take_draw_payload();
+        assert_eq!(full.scissor, None, "a LoadOp::Clear payload is never scissored");
+
+        // Rows 1 and 2 change - the band spans both, full row width.
+        let mut changed = Cell::default();
+        changed.set_symbol("Z");
+        RatatuiBackend::draw(
+            &mut backend,
+            [(1u16, 1u16, &changed), (1u16, 2u16, &changed)].into_iter(),
+        )
+        .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+
+        let row_height = backend.fonts.height_px();
+        let row_width = 2 * backend.fonts.min_width_px();
+        assert!(!payload.is_full());
+        assert_eq!(payload.scissor, Some([0, row_height, row_width, 2 * row_height]));
+    }
+
+    #[test]
+    fn partial_take_row_clear_quad_respects_alpha_zero_initial_fill() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 2)
+            .with_initial_fill([0, 0, 0, 0]) // transparent_reset_bg-style fill
+            .build();
+
+        let mut cell_top = Cell::default();
+        cell_top.set_symbol("A");
+        let mut cell_bottom = Cell::default();
+        cell_bottom.set_symbol("B");
+
+        RatatuiBackend::draw(
+            &mut backend,
+            [(0u16, 0u16, &cell_top), (0u16, 1u16, &cell_bottom)].into_iter(),
+        )
+        .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let _ = backend.take_draw_payload();
+
+        let mut cell_bottom_changed = Cell::default();
+        cell_bottom_changed.set_symbol("Z");
+        RatatuiBackend::draw(&mut backend, [(0u16, 1u16, &cell_bottom_changed)].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+
+        assert!(!payload.is_full());
+        assert_eq!(
+            payload.bg_vertices[0].bg_color & 0xFF,
+            0,
+            "the synthesized row-clear quad must carry initial_fill's alpha (0), \
+             matching what a LoadOp::Clear(initial_fill) would have produced"
+        );
+    }
+
+    #[test]
+    fn take_after_resize_is_full() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 1)
+            .build();
+
+        let mut cell = Cell::default();
+        cell.set_symbol("a");
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let _ = backend.take_draw_payload();
+
+        backend.resize(2, 2);
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+
+        assert!(payload.is_full(), "the first take after a resize must be full");
+    }
+
+    #[test]
+    fn take_after_clear_is_full() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 1)
+            .build();
+
+        let mut cell = Cell::default();
+        cell.set_symbol("a");
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let _ = backend.take_draw_payload();
+
+        RatatuiBackend::clear(&mut backend).expect("clear failed");
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+
+        assert!(payload.is_full(), "the first take after a clear must be full");
+    }
+
+    #[test]
+    fn all_rows_dirty_takes_full_path() {
+        fn cell_with_symbol(symbol: &str) -> Cell {
+            let mut cell = Cell::default();
+            cell.set_symbol(symbol);
+            cell
+        }
+
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 2)
+            .build();
 
-        // `pending_cache_updates` must NOT be cleared here: its entries are
-        // one-shot atlas uploads for slots the `Atlas` LRU already
-        // considers cached, queued by a *previous* flush (or by
-        // `populate_programmatic_glyphs` at creation) and not yet drained
-        // by `take_draw_payload`. Clearing them here destroys the only
-        // copy of those glyphs' pixels - the glyph is never re-rasterized
-        // (the LRU says it's cached) and its slot stays garbage forever,
-        // i.e. permanently invisible characters. This is exactly what
-        // happens when `Terminal::draw` runs more than once between
-        // payload-takes: creation-time populate + a setup-time initial
-        // draw + the first per-frame draw are three flushes before the
-        // first `gpu_flush_system` ever runs.
+        let row_a = cell_with_symbol("A");
+        let row_b = cell_with_symbol("B");
+        RatatuiBackend::draw(
+            &mut backend,
+            [(0u16, 0u16, &row_a), (0u16, 1u16, &row_b)].into_iter(),
+        )
+        .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let _ = backend.take_draw_payload(); // consume the initial full take
 
-        // Track which rows actually changed content this flush,
-        // accumulated across flushes since the last `take_draw_payload` -
-        // `take` unions this into the row-clear set for the next partial
-        // payload. See the field doc on `rows_dirty_since_take` for why
-        // pass 2's eviction-driven reshapes below do NOT feed into this.
-        self.rows_dirty_since_take.resize(height, false);
+        // Change every row - `all_dirty` should route this back through the
+        // full path (cheaper than synthesizing a clear quad per row), even
+        // though `full_redraw_needed` is false at this point.
+        let row_a2 = cell_with_symbol("C");
+        let row_b2 = cell_with_symbol("D");
+        RatatuiBackend::draw(
+            &mut backend,
+            [(0u16, 0u16, &row_a2), (0u16, 1u16, &row_b2)].into_iter(),
+        )
+        .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
 
-        // Snapshot which rows ratatui's diff actually marked dirty coming
-        // INTO this flush - `dirty_rows[y]` is cleared as each such row is
-        // processed below, so this stable copy is what tells pass 2 which
-        // rows still need looking at (a row just cleared by pass 1 must
-        // not be reconsidered by pass 2 as "was already clean").
-        let was_dirty = self.dirty_rows[..height].to_vec();
-        for (y, &dirty) in was_dirty.iter().enumerate() {
-            if dirty {
-                self.rows_dirty_since_take[y] = true;
-            }
+        assert!(payload.is_full(), "every row dirty must take the full path");
+    }
+
+    // Cursor clamping on an unsized grid: a freshly built backend has never
+    // seen a `draw()`, and with a zero-sized grid the old `bounds.width - 1`
+    // clamp underflowed (a debug-build panic).
+    #[test]
+    fn set_cursor_position_on_zero_sized_grid_does_not_underflow() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(0, 0)
+            .build();
+
+        RatatuiBackend::set_cursor_position(&mut backend, (5u16, 7u16))
+            .expect("set_cursor_position failed");
+
+        let pos = RatatuiBackend::get_cursor_position(&mut backend).unwrap();
+        assert_eq!((pos.x, pos.y), (0, 0));
+    }
+
+    #[test]
+    fn set_cursor_position_before_any_draw_clamps_to_grid() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(4, 3)
+            .build();
+
+        RatatuiBackend::set_cursor_position(&mut backend, (100u16, 100u16))
+            .expect("set_cursor_position failed");
+
+        let pos = RatatuiBackend::get_cursor_position(&mut backend).unwrap();
+        assert_eq!((pos.x, pos.y), (3, 2));
+    }
+
+    // Backgrounds for glyph-less cells: an all-empty-symbol row used to
+    // early-return from `shape_row` before emitting any bg quad, so
+    // clearing a colored row left it showing `initial_fill` instead of
+    // `reset_bg`.
+    #[test]
+    fn clearing_a_colored_row_repaints_the_default_background() {
+        let reset_bg = [10, 20, 30];
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(2, 1)
+            // Distinct from the default `initial_fill` (opaque black), so
+            // the reset background is never skipped as redundant.
+            .with_reset_bg(reset_bg)
+            .build();
+
+        let mut colored = Cell::default();
+        colored.set_symbol("x");
+        colored.bg = Color::Rgb(200, 0, 0);
+        RatatuiBackend::draw(
+            &mut backend,
+            [(0u16, 0u16, &colored), (1u16, 0u16, &colored)].into_iter(),
+        )
+        .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let _ = backend.take_draw_payload();
+
+        // "Clear" both cells to glyph-less cells with the default style.
+        RatatuiBackend::draw(
+            &mut backend,
+            [(0u16, 0u16, &NULL_CELL), (1u16, 0u16, &NULL_CELL)].into_iter(),
+        )
+        .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+
+        let [r, g, b] = reset_bg;
+        let expected = u32::from_be_bytes([r, g, b, 255]);
+        assert!(payload.text_vertices.is_empty(), "no glyphs to draw");
+        assert_eq!(
+            payload.bg_vertices.len(),
+            8,
+            "one bg quad per cell even though neither cell has a glyph"
+        );
+        for vertex in &payload.bg_vertices {
+            assert_eq!(vertex.bg_color, expected);
         }
+    }
 
-        // Cloned (not borrowed) so the closure below can hold it alongside
-        // an independent `&mut self` for `self.shape_row(...)` - `self.fonts`
-        // itself can't be borrowed for the `with_shared_cpu_state` receiver
-        // while a closure captures all of `self` mutably for the `shape_row`
-        // calls (IMPROVEMENT.md C3: the atlas/plan cache live in `Fonts`,
-        // shared by every terminal using the same `Arc<Fonts>`).
-        let fonts = Arc::clone(&self.fonts);
-        fonts.with_shared_cpu_state(|shared| {
-            let generation_before_flush = shared.cached.generation();
+    #[test]
+    fn wide_glyph_background_spans_its_continuation_cell() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(2, 1)
+            .build();
 
-            // Pass 1: regenerate every row that actually changed.
-            for (y, &dirty) in was_dirty.iter().enumerate() {
-                if !dirty {
-                    continue;
-                }
-                let (bg_vertices, text_vertices) = self.shape_row(y, width, shared);
-                self.row_geometry[y] = RowGeometry {
-                    bg_vertices,
-                    text_vertices,
-                    atlas_generation: shared.cached.generation(),
-                };
-                self.dirty_rows[y] = false;
-            }
+        let mut wide = Cell::default();
+        wide.set_symbol("あ");
+        wide.bg = Color::Rgb(0, 200, 0);
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &wide)].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
 
-            // If shaping this frame's dirty rows evicted anything from the
-            // atlas, no previously-cached row is safe to reuse blindly this
-            // frame (see the doc comment above) - fall back to reshaping
-            // every remaining row too, just this once.
-            let atlas_evicted_this_flush = shared.cached.generation() != generation_before_flush;
+        // One quad covering both cells - the continuation `NULL_CELL`
+        // (reset style) must not paint over the wide glyph's background.
+        let row_width_px = 2.0 * backend.fonts.min_width_px() as f32;
+        assert_eq!(payload.bg_vertices.len(), 4);
+        assert_eq!(payload.bg_vertices[1].vertex[0], row_width_px);
+    }
 
-            // Pass 2: rows that were clean coming into this flush.
-            for (y, &dirty) in was_dirty.iter().enumerate() {
-                if dirty {
-                    continue; // already handled in pass 1
-                }
+    #[test]
+    fn invalidate_all_forces_re_rasterization_on_an_unchanged_frame() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 1)
+            .build();
 
-                let cache_valid = !atlas_evicted_this_flush
-                    && self.row_geometry[y].atlas_generation == generation_before_flush;
+        let mut cell = Cell::default();
+        cell.set_symbol("a");
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let _ = backend.take_draw_payload();
+        assert!(!backend.take_shared_glyph_uploads().is_empty());
 
-                if cache_valid {
-                    continue;
-                }
+        // An empty diff (what ratatui sends for an unchanged frame) does
+        // nothing on its own.
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        assert!(!backend.cells_changed_last_draw());
+        assert!(backend.take_shared_glyph_uploads().is_empty());
 
-                let (bg_vertices, text_vertices) = self.shape_row(y, width, shared);
-                self.row_geometry[y] = RowGeometry {
-                    bg_vertices,
-                    text_vertices,
-                    atlas_generation: shared.cached.generation(),
-                };
-            }
-        });
+        backend.invalidate_all();
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
 
-        Ok(())
+        assert!(
+            backend.cells_changed_last_draw(),
+            "an invalidated backend must report the next draw as changed"
+        );
+        assert!(
+            !backend.take_shared_glyph_uploads().is_empty(),
+            "the glyph must be re-rasterized after invalidate_all"
+        );
+        let payload = backend.take_draw_payload();
+        assert!(payload.is_full());
+        assert!(!payload.text_vertices.is_empty(), "the cell must be redrawn");
     }
 
-    fn clear_region(
-        &mut self,
-        _clear_type: ratatui::backend::ClearType,
-    ) -> Result<(), Self::Error> {
-        // For now, just delegate to clear() for all clear types
-        self.clear()
+    #[test]
+    fn invalidate_all_keeps_other_terminals_queued_uploads() {
+        let fonts = test_fonts();
+        let mut other = TerminalBuilder::new(Arc::clone(&fonts))
+            .with_dimensions(1, 1)
+            .build();
+        let mut backend = TerminalBuilder::new(fonts).with_dimensions(1, 1).build();
+
+        let draw = |backend: &mut BevyTerminalBackend, symbol: &str| {
+            let mut cell = Cell::default();
+            cell.set_symbol(symbol);
+            RatatuiBackend::draw(backend, [(0u16, 0u16, &cell)].into_iter())
+                .expect("draw failed");
+            RatatuiBackend::flush(backend).expect("flush failed");
+        };
+        draw(&mut backend, "a");
+        let _ = backend.take_shared_glyph_uploads();
+
+        // `other` queues a glyph that hasn't reached the GPU yet when
+        // `backend` invalidates everything.
+        draw(&mut other, "b");
+        backend.invalidate_all();
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+
+        let uploads = backend.take_shared_glyph_uploads();
+        assert_eq!(uploads.len(), 2, "b's upload survives and a is re-rasterized");
+        assert_ne!(uploads[0].0, uploads[1].0, "the glyphs keep their own slots");
     }
-}
 
-// ============================================================================
-// Test: cell-level transparency (P2-2). Pure CPU - drives ratatui's
-// `Backend::draw()` directly and inspects the packed vertex color, no
-// GPU/App needed. `bg_color`'s alpha byte is what `composite_bg.wgsl`
-// outputs unmodified (`BlendState::REPLACE`), so this is the whole story:
-// no shader/pipeline test is needed on top of this.
-// ============================================================================
+    #[test]
+    fn cell_grid_toggles_separator_quads() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(3, 2)
+            .with_cell_grid(Some([80, 80, 80]))
+            .build();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::fonts::{Font, Fonts};
-    use ratatui::backend::Backend as RatatuiBackend;
-    use ratatui::buffer::Cell;
-    use ratatui::style::Color;
+        let mut cell = Cell::default();
+        cell.set_symbol("a");
+        let content: Vec<(u16, u16, &Cell)> = (0..2u16)
+            .flat_map(|y| (0..3u16).map(move |x| (x, y)))
+            .map(|(x, y)| (x, y, &cell))
+            .collect();
+        RatatuiBackend::draw(&mut backend, content.into_iter()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
 
-    fn test_fonts() -> Arc<Fonts> {
-        let font_data = include_bytes!("../../examples/assets/fonts/Mplus1Code-Regular.ttf");
-        let font = Font::new(font_data).expect("failed to load test font");
-        Arc::new(Fonts::new(font, 16))
+        // Default reset_bg == initial_fill, so the only bg quads are the
+        // separators: 2 vertical per row, plus 1 horizontal for row 1.
+        assert_eq!(payload.bg_vertices.len(), (2 + 2 + 1) * 4);
+        let grid = u32::from_be_bytes([80, 80, 80, 255]);
+        assert!(payload.bg_vertices.iter().all(|v| v.bg_color == grid));
+
+        backend.set_cell_grid(None);
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+
+        assert!(payload.is_full());
+        assert!(
+            payload.bg_vertices.is_empty(),
+            "turning the grid off must drop every separator quad"
+        );
+        assert!(!payload.text_vertices.is_empty(), "content must still be redrawn");
     }
 
     #[test]
-    fn transparent_reset_bg_zeroes_alpha_only_for_reset_backgrounds() {
+    fn background_gradient_steps_reset_cells_by_row() {
         let mut backend = TerminalBuilder::new(test_fonts())
-            .with_dimensions(2, 1)
-            .with_transparent_reset_bg(true)
+            .with_dimensions(2, 4)
+            .with_background_gradient([0, 0, 0], [200, 100, 0])
             .build();
 
-        let mut reset_cell = Cell::default();
-        reset_cell.set_symbol("a");
-        reset_cell.bg = Color::Reset;
+        // Every other cell keeps its default `Color::Reset` background.
+        let mut red = Cell::default();
+        red.set_bg(ratatui::style::Color::Rgb(255, 0, 0));
+        RatatuiBackend::draw(&mut backend, std::iter::once((1, 0, &red))).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
 
-        let mut colored_cell = Cell::default();
-        colored_cell.set_symbol("b");
-        colored_cell.bg = Color::Rgb(10, 20, 30);
+        let colors: Vec<u32> =
+            payload.bg_vertices.iter().step_by(4).map(|v| v.bg_color).collect();
+        let row = |r: u8, g: u8| u32::from_be_bytes([r, g, 0, 255]);
+        assert_eq!(
+            colors,
+            vec![
+                row(25, 13),
+                u32::from_be_bytes([255, 0, 0, 255]),
+                row(75, 38),
+                row(75, 38),
+                row(125, 63),
+                row(125, 63),
+                row(175, 88),
+                row(175, 88),
+            ],
+            "reset cells take their row's gradient color; explicit colors win"
+        );
 
-        RatatuiBackend::draw(
-            &mut backend,
-            [(0u16, 0u16, &reset_cell), (1u16, 0u16, &colored_cell)].into_iter(),
-        )
-        .expect("draw failed");
+        backend.set_background_gradient(None);
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
         RatatuiBackend::flush(&mut backend).expect("flush failed");
         let payload = backend.take_draw_payload();
-
-        // First-ever take on this backend is always full (no synthesized
-        // row-clear quad), so this is exactly one quad per cell.
-        assert!(payload.is_full(), "the first take on a fresh backend must be full");
+        assert!(payload.is_full());
         assert_eq!(
             payload.bg_vertices.len(),
-            8,
-            "one quad (4 vertices) per cell, 2 cells"
+            4,
+            "without the gradient only the red cell differs from initial_fill"
         );
-        for vertex in &payload.bg_vertices[0..4] {
-            assert_eq!(
-                vertex.bg_color & 0xFF,
-                0,
-                "a Color::Reset background must pack alpha 0 when \
-                 transparent_reset_bg is enabled"
-            );
-        }
-        for vertex in &payload.bg_vertices[4..8] {
-            assert_eq!(
-                vertex.bg_color & 0xFF,
-                255,
-                "an explicit background color must stay fully opaque"
-            );
-        }
     }
 
+    // With `system_fonts` an installed OS font might cover the test char.
+    #[cfg(not(feature = "system_fonts"))]
     #[test]
-    fn transparent_reset_bg_disabled_keeps_reset_cells_opaque() {
+    fn missing_glyph_tracking_records_uncovered_chars() {
+        // U+13000 EGYPTIAN HIEROGLYPH A001 - not in M+ 1Code.
         let mut backend = TerminalBuilder::new(test_fonts())
-            .with_dimensions(1, 1)
-            .with_transparent_reset_bg(false) // the default
-            // A `reset_bg` distinct from the default `initial_fill` (opaque
-            // black) - otherwise this cell's resolved background exactly
-            // matches the render pass's own clear color and B3's redundant-
-            // quad skip (IMPROVEMENT.md B3) correctly omits it entirely,
-            // which would starve this test of the vertices it inspects.
-            .with_reset_bg([10, 20, 30])
+            .with_dimensions(3, 1)
+            .with_missing_glyph_tracking(true)
             .build();
 
-        let mut reset_cell = Cell::default();
-        reset_cell.set_symbol("a");
-        reset_cell.bg = Color::Reset;
-
-        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &reset_cell)].into_iter())
-            .expect("draw failed");
+        let mut present = Cell::default();
+        present.set_symbol("a");
+        let mut missing = Cell::default();
+        missing.set_symbol("\u{13000}");
+        RatatuiBackend::draw(
+            &mut backend,
+            [(0, 0, &present), (1, 0, &missing)].into_iter(),
+        )
+        .expect("draw failed");
         RatatuiBackend::flush(&mut backend).expect("flush failed");
-        let payload = backend.take_draw_payload();
 
-        assert!(!payload.bg_vertices.is_empty(), "flush must generate vertices");
-        for vertex in &payload.bg_vertices {
-            assert_eq!(
-                vertex.bg_color & 0xFF,
-                255,
-                "without transparent_reset_bg, Reset backgrounds render \
-                 opaque (using reset_bg), matching pre-P2-2 behavior"
-            );
-        }
+        assert_eq!(
+            backend.missing_glyphs().iter().copied().collect::<Vec<_>>(),
+            vec!['\u{13000}'],
+            "only the uncovered char is reported"
+        );
+
+        backend.clear_missing_glyphs();
+        assert!(backend.missing_glyphs().is_empty());
+
+        backend.set_missing_glyph_tracking(false);
+        backend.invalidate_all();
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        assert!(
+            backend.missing_glyphs().is_empty(),
+            "nothing is recorded while tracking is off"
+        );
     }
 
     #[test]
-    fn reversed_modifier_checks_fg_for_reset_transparency() {
-        // With Modifier::REVERSED, cell.fg becomes the effective background
-        // (see the color-swap in flush()) - transparency must follow that
-        // swap, not check cell.bg unconditionally.
+    fn msaa_sample_count_rides_on_the_payload_and_forces_a_full_redraw() {
         let mut backend = TerminalBuilder::new(test_fonts())
-            .with_dimensions(1, 1)
-            .with_transparent_reset_bg(true)
+            .with_dimensions(2, 1)
+            .with_msaa(4)
             .build();
 
         let mut cell = Cell::default();
         cell.set_symbol("a");
-        cell.modifier.insert(ratatui::style::Modifier::REVERSED);
-        cell.fg = Color::Reset; // effective background under REVERSED
-        cell.bg = Color::Rgb(1, 2, 3); // effective foreground under REVERSED - must not matter
+        RatatuiBackend::draw(&mut backend, std::iter::once((0, 0, &cell))).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        assert_eq!(backend.take_draw_payload().sample_count, 4);
 
-        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter())
-            .expect("draw failed");
+        // Unchanged content, new sample count: still a full payload.
+        backend.set_msaa(0);
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
+        assert!(backend.cells_changed_last_draw());
         RatatuiBackend::flush(&mut backend).expect("flush failed");
         let payload = backend.take_draw_payload();
-
-        assert!(!payload.bg_vertices.is_empty(), "flush must generate vertices");
-        for vertex in &payload.bg_vertices {
-            assert_eq!(
-                vertex.bg_color & 0xFF,
-                0,
-                "reversed cell.fg == Reset must still zero the background alpha"
-            );
-        }
+        assert_eq!(payload.sample_count, 1);
+        assert!(payload.is_full());
     }
 
-    // ========================================================================
-    // Test: A1 - flush() early-out on an unchanged frame (IMPROVEMENT.md A1).
-    // ========================================================================
-
     #[test]
-    fn flush_skips_vertex_regeneration_on_byte_identical_redraw() {
+    fn cell_fade_starts_transparent_and_keeps_rows_dirty_until_done() {
         let mut backend = TerminalBuilder::new(test_fonts())
             .with_dimensions(2, 1)
+            .with_cell_fade(Duration::from_secs(3600))
             .build();
 
         let mut cell = Cell::default();
         cell.set_symbol("a");
-        cell.bg = Color::Rgb(10, 20, 30);
-
-        // First draw: content changes (empty -> "a"), so this flush must do
-        // real work and produce vertices.
-        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter())
-            .expect("draw failed");
+        RatatuiBackend::draw(&mut backend, std::iter::once((0, 0, &cell))).expect("draw failed");
         RatatuiBackend::flush(&mut backend).expect("flush failed");
         let payload = backend.take_draw_payload();
+        assert!(!payload.text_vertices.is_empty());
         assert!(
-            !payload.bg_vertices.is_empty(),
-            "first draw onto an empty buffer must generate vertices"
-        );
-
-        // Second draw: byte-identical content. Note ratatui's own
-        // `Terminal::flush` (ratatui-core `terminal/buffers.rs`) is what
-        // diffs the previous/current buffers and passes only the CHANGED
-        // cells to `Backend::draw` - an identical redraw means that diff is
-        // empty, so `Backend::draw` is called with an empty iterator (not
-        // the same cell tuple again; feeding the same tuple twice would
-        // incorrectly mark it changed both times, since this trait method
-        // itself performs no diffing - it trusts the caller already diffed).
-        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
-        assert!(
-            !backend.cells_changed_last_draw(),
-            "an identical redraw (empty diff) must not report a cell change"
+            payload.text_vertices.iter().all(|v| v.fg_color & 0xFF < 8),
+            "a glyph that just appeared must start (nearly) transparent"
         );
-        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        assert!(backend.is_fading());
 
+        // Ticks on app time rebuild the fading row, whatever ratatui draws.
+        let start = Duration::from_secs(1);
+        assert!(backend.tick_fades(start), "the first tick starts the fade");
+        assert!(backend.reshape_marked_rows());
         let payload = backend.take_draw_payload();
-        assert!(
-            payload.bg_vertices.is_empty(),
-            "flush must skip regenerating bg vertices for an unchanged frame"
-        );
-        assert!(
-            payload.text_vertices.is_empty(),
-            "flush must skip regenerating text vertices for an unchanged frame"
-        );
-    }
-
-    // ========================================================================
-    // Test: A2 - row-incremental flush (IMPROVEMENT.md A2). Vertex order
-    // is not part of the contract (two-pass processing visits rows in a
-    // different order than a straight 0..height scan - see the doc
-    // comment on `flush`), so vertices are compared as an order-independent
-    // multiset via their raw bytes (`TextBgVertexMember`/`TextVertexMember`
-    // are `bytemuck::Pod`, so this is a byte-exact comparison, not a
-    // fuzzy one).
-    // ========================================================================
+        assert!(payload.text_vertices.iter().all(|v| v.fg_color & 0xFF < 8));
+        assert!(backend.tick_fades(start + Duration::from_secs(1800)));
+        assert!(backend.reshape_marked_rows());
+        let payload = backend.take_draw_payload();
+        assert!(payload.text_vertices.iter().all(|v| (120..=135).contains(&(v.fg_color & 0xFF))));
+        assert!(backend.tick_fades(start + Duration::from_secs(3600)), "a finished fade repaints once more");
+        assert!(backend.reshape_marked_rows());
+        let payload = backend.take_draw_payload();
+        assert!(payload.text_vertices.iter().all(|v| v.fg_color & 0xFF == 255));
+        assert!(!backend.is_fading());
+        assert!(!backend.tick_fades(start + Duration::from_secs(3601)));
 
-    fn sorted_bytes<T: bytemuck::Pod>(items: &[T]) -> Vec<Vec<u8>> {
-        let mut bytes: Vec<Vec<u8>> = items.iter().map(|i| bytemuck::bytes_of(i).to_vec()).collect();
-        bytes.sort();
-        bytes
+        // Without a fade, the same glyph is opaque immediately.
+        let mut plain = TerminalBuilder::new(test_fonts())
+            .with_dimensions(2, 1)
+            .with_cell_fade(Duration::ZERO)
+            .build();
+        RatatuiBackend::draw(&mut plain, std::iter::once((0, 0, &cell))).expect("draw failed");
+        RatatuiBackend::flush(&mut plain).expect("flush failed");
+        let payload = plain.take_draw_payload();
+        assert!(payload.text_vertices.iter().all(|v| v.fg_color & 0xFF == 255));
+        assert!(!plain.is_fading());
     }
 
     #[test]
-    fn row_incremental_flush_matches_a_from_scratch_flush_of_the_same_content() {
-        // Deliberately a SINGLE backend (one `Atlas`) throughout - a glyph's
-        // UV rect depends on its insertion order into the atlas, not just
-        // its identity, so two independently-built backends would assign
-        // "A"/"B"/"C"/"D"/"Z" to different slots and legitimately produce
-        // different (but each internally-correct) UV bytes. Comparing across
-        // two atlases would be testing atlas insertion order, not row-cache
-        // correctness. Instead: incrementally flush on this backend, save
-        // the result, then force the SAME backend (same atlas, same
-        // established slot assignments - no new glyphs are introduced by
-        // this step, so no new allocation) through a full reshape of every
-        // row and compare against the saved result.
-        fn cell_with_symbol(symbol: &str) -> Cell {
-            let mut cell = Cell::default();
-            cell.set_symbol(symbol);
-            cell
-        }
-
+    fn turning_cell_fade_off_finishes_running_fades() {
         let mut backend = TerminalBuilder::new(test_fonts())
-            .with_dimensions(1, 4)
+            .with_dimensions(2, 1)
             .build();
+        backend.set_cell_fade(Duration::from_secs(3600));
 
-        let row_a = cell_with_symbol("A");
-        let row_b = cell_with_symbol("B");
-        let row_c = cell_with_symbol("C");
-        let row_d = cell_with_symbol("D");
-        RatatuiBackend::draw(
-            &mut backend,
-            [
-                (0u16, 0u16, &row_a),
-                (0u16, 1u16, &row_b),
-                (0u16, 2u16, &row_c),
-                (0u16, 3u16, &row_d),
-            ]
-            .into_iter(),
-        )
-        .expect("draw failed");
+        let mut cell = Cell::default();
+        cell.set_symbol("a");
+        RatatuiBackend::draw(&mut backend, std::iter::once((0, 0, &cell))).expect("draw failed");
         RatatuiBackend::flush(&mut backend).expect("flush failed");
         let _ = backend.take_draw_payload();
+        assert!(backend.is_fading());
 
-        // Change ONLY row 2's cell and flush again - the row cache should
-        // reuse rows 0, 1, 3 from `row_geometry` and reshape only row 2
-        // (allocating "Z" a fresh atlas slot).
-        let row_c_changed = cell_with_symbol("Z");
-        RatatuiBackend::draw(&mut backend, [(0u16, 2u16, &row_c_changed)].into_iter())
-            .expect("draw failed");
+        backend.set_cell_fade(Duration::ZERO);
+        assert!(!backend.is_fading());
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
         RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+        assert!(!payload.text_vertices.is_empty(), "the faded row must be repainted");
+        assert!(payload.text_vertices.iter().all(|v| v.fg_color & 0xFF == 255));
+    }
 
-        // Snapshot the row cache produced incrementally. `row_geometry` -
-        // what IMPROVEMENT.md A2 caches - is what this test verifies;
-        // `take_draw_payload`'s full/partial concatenation and row-clear-quad
-        // synthesis (Phase 2) are orthogonal to row-cache correctness, so
-        // comparing `row_geometry` directly (rather than a concatenated
-        // payload) keeps this test decoupled from that later concern.
-        let incremental_rows: Vec<RowGeometry> = backend.row_geometry.clone();
-        let _ = backend.take_draw_payload();
+    #[test]
+    fn blinking_text_drops_its_glyph_but_keeps_its_background_while_off() {
+        use ratatui::style::Modifier;
 
-        // Force every row to be reshaped from scratch, on this same
-        // backend. "A", "B", "Z", "D" are all already in the atlas from
-        // the two flushes above, so this introduces no new glyphs and
-        // therefore no new allocations - `Atlas::get` hits the existing
-        // LRU entries and returns the SAME UV rects it already handed out,
-        // making this a true apples-to-apples comparison against the
-        // incremental flush above.
-        backend.dirty_rows.iter_mut().for_each(|dirty| *dirty = true);
-        backend.cells_changed_last_draw = true;
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(2, 2)
+            .with_blink_rates(Duration::from_millis(250), Duration::MAX)
+            .build();
+        let height = backend.fonts.height_px() as f32;
+
+        let mut blinking = Cell::default();
+        blinking.set_symbol("a").set_bg(Color::Rgb(200, 0, 0));
+        blinking.modifier = Modifier::RAPID_BLINK;
+        let mut slow = Cell::default();
+        slow.set_symbol("s");
+        slow.modifier = Modifier::SLOW_BLINK;
+        let mut steady = Cell::default();
+        steady.set_symbol("b");
+        let cells = [(0u16, 0u16, &blinking), (0, 1, &steady), (1, 1, &slow)];
+        RatatuiBackend::draw(&mut backend, cells.into_iter()).expect("draw failed");
         RatatuiBackend::flush(&mut backend).expect("flush failed");
+        backend.take_draw_payload();
+
+        let glyphs_in_row = |payload: &crate::backend::TerminalDrawPayload, row: f32| {
+            payload
+                .text_vertices
+                .iter()
+                .filter(|v| v.vertex[1] >= row * height && v.vertex[1] < (row + 1.0) * height)
+                .count()
+        };
+        let start = Duration::from_secs(1);
+        assert!(!backend.tick_blink(start), "the first tick only starts the clocks");
+        assert!(!backend.tick_blink(start + Duration::from_millis(100)));
+
+        // Off phase: row 0 loses its glyph, not its background.
+        assert!(backend.tick_blink(start + Duration::from_millis(250)));
+        assert!(backend.reshape_marked_rows());
+        backend.force_full_redraw();
+        let payload = backend.take_draw_payload();
+        assert_eq!(glyphs_in_row(&payload, 0.0), 0);
+        assert!(payload.bg_vertices.iter().any(|v| v.bg_color == 0xC800_00FF));
+        // The steady cell and the slow one (`Duration::MAX`: never blinks)
+        // still show.
+        assert_eq!(glyphs_in_row(&payload, 1.0), 8);
+
+        // On again after another phase.
+        assert!(backend.tick_blink(start + Duration::from_millis(500)));
+        assert!(backend.reshape_marked_rows());
+        let payload = backend.take_draw_payload();
+        assert_eq!(glyphs_in_row(&payload, 0.0), 4);
 
-        for (y, (incremental, reshaped)) in incremental_rows
-            .iter()
-            .zip(backend.row_geometry.iter())
-            .enumerate()
-        {
-            assert_eq!(
-                sorted_bytes(&incremental.bg_vertices),
-                sorted_bytes(&reshaped.bg_vertices),
-                "row {y} bg vertices from an incremental (row-cached) flush must \
-                 match a full reshape of the same content on the same atlas state"
-            );
-            assert_eq!(
-                sorted_bytes(&incremental.text_vertices),
-                sorted_bytes(&reshaped.text_vertices),
-                "row {y} text vertices from an incremental (row-cached) flush must \
-                 match a full reshape of the same content on the same atlas state"
-            );
-        }
+        // Nothing blinks: a flip marks no rows.
+        let mut plain = TerminalBuilder::new(test_fonts())
+            .with_dimensions(2, 1)
+            .with_blink_rates(Duration::from_millis(1), Duration::from_millis(1))
+            .build();
+        RatatuiBackend::draw(&mut plain, std::iter::once((0, 0, &steady))).expect("draw failed");
+        plain.tick_blink(start);
+        assert!(!plain.tick_blink(start + Duration::from_millis(5)));
     }
 
-    // ========================================================================
-    // Phase 2 tests: partial redraw (full vs. dirty-rows-only payloads).
-    // ========================================================================
+    #[test]
+    fn set_blink_rates_shows_blinking_text_again() {
+        use ratatui::style::Modifier;
 
-    #[test]
-    fn first_take_is_full() {
         let mut backend = TerminalBuilder::new(test_fonts())
             .with_dimensions(2, 1)
             .build();
-
-        let mut cell = Cell::default();
-        cell.set_symbol("a");
-        cell.bg = Color::Rgb(10, 20, 30);
-
-        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter())
+        let mut blinking = Cell::default();
+        blinking.set_symbol("a");
+        blinking.modifier = Modifier::SLOW_BLINK;
+        RatatuiBackend::draw(&mut backend, std::iter::once((0, 0, &blinking)))
             .expect("draw failed");
         RatatuiBackend::flush(&mut backend).expect("flush failed");
-        let payload = backend.take_draw_payload();
-
-        assert!(payload.is_full(), "the very first take must be a full payload");
+        backend.take_draw_payload();
+
+        let start = Duration::from_secs(1);
+        backend.tick_blink(start);
+        assert!(backend.tick_blink(start + Duration::from_millis(500)));
+        assert!(backend.reshape_marked_rows());
+        assert!(backend.take_draw_payload().text_vertices.is_empty());
+
+        // Disabling blinking mid-phase must not leave the text hidden.
+        backend.set_blink_rates(Duration::MAX, Duration::MAX);
+        assert!(backend.reshape_marked_rows());
+        assert_eq!(backend.take_draw_payload().text_vertices.len(), 4);
+        assert!(!backend.tick_blink(start + Duration::from_secs(3600)));
     }
 
     #[test]
-    fn partial_take_contains_only_dirty_rows() {
-        fn cell_with_symbol(symbol: &str) -> Cell {
-            let mut cell = Cell::default();
-            cell.set_symbol(symbol);
-            cell
-        }
-
+    fn set_reset_colors_and_palette_rebuild_with_new_colors() {
         let mut backend = TerminalBuilder::new(test_fonts())
-            .with_dimensions(1, 3)
+            .with_dimensions(2, 1)
             .build();
 
-        let row_a = cell_with_symbol("A");
-        let row_b = cell_with_symbol("B");
-        let row_c = cell_with_symbol("C");
+        let reset = Cell::default(); // bg: Color::Reset
+        let mut red = Cell::default();
+        red.bg = Color::Red;
         RatatuiBackend::draw(
             &mut backend,
-            [(0u16, 0u16, &row_a), (0u16, 1u16, &row_b), (0u16, 2u16, &row_c)].into_iter(),
+            [(0u16, 0u16, &reset), (1u16, 0u16, &red)].into_iter(),
         )
         .expect("draw failed");
         RatatuiBackend::flush(&mut backend).expect("flush failed");
-        let _ = backend.take_draw_payload(); // consume the initial full take
+        let _ = backend.take_draw_payload();
 
-        // Change only row 1.
-        let row_b_changed = cell_with_symbol("Z");
-        RatatuiBackend::draw(&mut backend, [(0u16, 1u16, &row_b_changed)].into_iter())
-            .expect("draw failed");
+        let mut palette = [[0, 0, 0]; 16];
+        palette[1] = [1, 2, 3]; // Color::Red's slot
+        backend.set_reset_colors([255, 255, 255], [40, 40, 40]);
+        backend.set_palette(Some(palette));
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
         RatatuiBackend::flush(&mut backend).expect("flush failed");
         let payload = backend.take_draw_payload();
 
-        assert!(!payload.is_full(), "a single-row change must take a partial payload");
+        assert!(payload.is_full());
+        assert_eq!(payload.bg_vertices.len(), 8);
+        assert_eq!(
+            payload.bg_vertices[0].bg_color,
+            u32::from_be_bytes([40, 40, 40, 255]),
+            "Color::Reset must follow the new reset background"
+        );
+        assert_eq!(
+            payload.bg_vertices[4].bg_color,
+            u32::from_be_bytes([1, 2, 3, 255]),
+            "a named color must follow the palette override"
+        );
+    }
 
-        let cell_height_px = backend.fonts.height_px() as f32;
-        let y0 = 1.0 * cell_height_px;
-        let y1 = 2.0 * cell_height_px;
-        let expected_color = u32::from_be_bytes(backend.initial_fill);
+    #[test]
+    fn color_quantize_snaps_every_resolved_color() {
+        let palette = Palette::green_phosphor();
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(2, 1)
+            .with_color_quantize(palette.clone())
+            .build();
 
-        // The first 4 vertices are the synthesized row-clear quad for row 1
-        // (TL, TR, BL, BR), in the `initial_fill` color.
-        assert_eq!(payload.bg_vertices[0].vertex, [0.0, y0]);
-        assert_eq!(payload.bg_vertices[1].vertex[1], y0);
-        assert_eq!(payload.bg_vertices[2].vertex, [0.0, y1]);
-        assert_eq!(payload.bg_vertices[3].vertex[1], y1);
-        for vertex in &payload.bg_vertices[0..4] {
-            assert_eq!(vertex.bg_color, expected_color);
-        }
+        let mut red = Cell::default();
+        red.set_char('a').set_bg(Color::Red);
+        RatatuiBackend::draw(&mut backend, [(1u16, 0u16, &red)].into_iter()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
 
-        // No vertex (row-clear quad or actual glyph/bg content) may lie
-        // outside row 1's pixel span - partial redraw must not touch rows
-        // it didn't mark dirty.
-        for vertex in &payload.bg_vertices {
-            assert!(vertex.vertex[1] >= y0 && vertex.vertex[1] <= y1);
-        }
-        for vertex in &payload.text_vertices {
-            assert!(vertex.vertex[1] >= y0 && vertex.vertex[1] <= y1);
+        let pack = |[r, g, b]: Rgb| u32::from_be_bytes([r, g, b, 255]);
+        let dark = palette.quantize([0, 0, 0]);
+        assert_eq!(payload.clear_color, [dark[0], dark[1], dark[2], 255]);
+        // The reset cell matches the (quantized) fill: only the red cell's quad.
+        assert_eq!(payload.bg_vertices.len(), 4);
+        assert_eq!(payload.bg_vertices[0].bg_color, pack(palette.quantize(crate::colors::named::RED)));
+        assert!(!payload.text_vertices.is_empty());
+        assert!(payload
+            .text_vertices
+            .iter()
+            .all(|v| v.fg_color == pack(palette.quantize([255, 255, 255]))));
+
+        backend.set_color_quantize(None);
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+        assert!(payload.is_full(), "clearing the palette rebuilds every row");
+        assert_eq!(payload.bg_vertices[0].bg_color, pack(crate::colors::named::RED));
+    }
+
+    #[test]
+    fn glyph_budget_defers_rasterization_to_later_flushes() {
+        fn row(text: &str) -> Vec<Cell> {
+            text.chars()
+                .map(|c| {
+                    let mut cell = Cell::default();
+                    cell.set_char(c);
+                    cell
+                })
+                .collect()
         }
+        let glyph_quads = |backend: &mut BevyTerminalBackend| {
+            let payload = backend.take_draw_payload();
+            payload.text_vertices.len() / 4
+        };
+
+        let fonts = test_fonts();
+        let mut backend = TerminalBuilder::new(fonts.clone())
+            .with_dimensions(10, 1)
+            .with_glyph_budget(4)
+            .build();
+        backend.set_grid(&row("abcdefghij"), 10, 1).expect("set_grid failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        assert_eq!(glyph_quads(&mut backend), 4, "only the budget is rasterized");
+        assert!(backend.has_deferred_glyphs());
+
+        assert!(backend.rasterize_deferred_glyphs());
+        assert_eq!(glyph_quads(&mut backend), 8, "the row is reshaped with the next batch");
+        assert!(backend.rasterize_deferred_glyphs());
+        assert_eq!(glyph_quads(&mut backend), 10);
+        assert!(!backend.has_deferred_glyphs());
+        assert!(!backend.rasterize_deferred_glyphs(), "nothing left to do");
+
+        // Glyphs already in the (shared) atlas don't count against a budget.
+        let mut other = TerminalBuilder::new(fonts)
+            .with_dimensions(10, 1)
+            .with_glyph_budget(1)
+            .build();
+        other.set_grid(&row("jihgfedcba"), 10, 1).expect("set_grid failed");
+        RatatuiBackend::flush(&mut other).expect("flush failed");
+        assert_eq!(glyph_quads(&mut other), 10);
+        assert!(!other.has_deferred_glyphs());
     }
 
     #[test]
-    fn partial_take_row_clear_quad_respects_alpha_zero_initial_fill() {
+    fn empty_cell_draws_its_glyph_in_blank_cells_only() {
+        let dot: Rgb = [60, 60, 70];
+        let pack = |[r, g, b]: Rgb| u32::from_be_bytes([r, g, b, 255]);
         let mut backend = TerminalBuilder::new(test_fonts())
-            .with_dimensions(1, 2)
-            .with_initial_fill([0, 0, 0, 0]) // transparent_reset_bg-style fill
+            .with_dimensions(4, 1)
+            .with_empty_cell(Some(('·', dot)))
             .build();
 
-        let mut cell_top = Cell::default();
-        cell_top.set_symbol("A");
-        let mut cell_bottom = Cell::default();
-        cell_bottom.set_symbol("B");
+        let mut letter = Cell::default();
+        letter.set_char('a');
+        let mut reversed = Cell::default();
+        reversed.set_char(' ').modifier = ratatui::style::Modifier::REVERSED;
+        RatatuiBackend::draw(&mut backend, [(1u16, 0u16, &letter), (3, 0, &reversed)].into_iter())
+            .expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+        let quads: Vec<u32> = payload.text_vertices.chunks(4).map(|q| q[0].fg_color).collect();
+        // Cells 0 and 2 get a dot; the letter and the reversed blank are untouched.
+        assert_eq!(quads.len(), 3);
+        assert_eq!(quads.iter().filter(|&&fg| fg == pack(dot)).count(), 2);
+        // The reversed blank isn't trimmed; the dots never reach the text.
+        assert_eq!(backend.get_text()[0].to_string(), " a  ");
+
+        backend.set_empty_cell(None);
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        assert_eq!(backend.take_draw_payload().text_vertices.len(), 4, "only the letter");
+    }
 
-        RatatuiBackend::draw(
-            &mut backend,
-            [(0u16, 0u16, &cell_top), (0u16, 1u16, &cell_bottom)].into_iter(),
-        )
-        .expect("draw failed");
+    #[test]
+    fn corner_radius_paints_the_fill_as_a_quad_and_clears_transparent() {
+        let fill = [10, 20, 30, 255];
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(3, 2)
+            .with_initial_fill(fill)
+            .with_reset_bg([10, 20, 30])
+            .with_corner_radius(6)
+            .build();
+        let mut cell = Cell::default();
+        cell.set_char('a');
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter()).expect("draw failed");
         RatatuiBackend::flush(&mut backend).expect("flush failed");
-        let _ = backend.take_draw_payload();
+        let payload = backend.take_draw_payload();
+        assert!(payload.is_full());
+        assert_eq!(payload.corner_radius, 6.0);
+        // The clear can't be rounded: it's transparent, and a fill quad
+        // covering the whole texture stands in for it.
+        assert_eq!(payload.clear_color, [0, 0, 0, 0]);
+        let width = 3.0 * backend.fonts.min_width_px() as f32;
+        let height = 2.0 * backend.fonts.height_px() as f32;
+        assert_eq!(payload.bg_vertices[0].vertex, [0.0, 0.0]);
+        assert_eq!(payload.bg_vertices[3].vertex, [width, height]);
+        assert_eq!(payload.bg_vertices[0].bg_color, u32::from_be_bytes(fill));
+
+        backend.set_corner_radius(0);
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+        assert_eq!(payload.clear_color, fill);
+        assert!(payload.bg_vertices.is_empty(), "square corners clear to the fill");
+    }
 
-        let mut cell_bottom_changed = Cell::default();
-        cell_bottom_changed.set_symbol("Z");
-        RatatuiBackend::draw(&mut backend, [(0u16, 1u16, &cell_bottom_changed)].into_iter())
-            .expect("draw failed");
+    #[test]
+    fn text_brightness_rides_the_payload_and_repaints_when_changed() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(3, 2)
+            .with_text_brightness(4.0)
+            .build();
+        let mut cell = Cell::default();
+        cell.set_char('a');
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter()).expect("draw failed");
         RatatuiBackend::flush(&mut backend).expect("flush failed");
         let payload = backend.take_draw_payload();
+        assert_eq!(payload.text_brightness, 4.0);
+        // The colors themselves stay 8-bit; only the compositor scales them.
+        let white = u32::from_be_bytes([255, 255, 255, 255]);
+        assert!(payload.text_vertices.iter().all(|v| v.fg_color == white));
 
-        assert!(!payload.is_full());
-        assert_eq!(
-            payload.bg_vertices[0].bg_color & 0xFF,
-            0,
-            "the synthesized row-clear quad must carry initial_fill's alpha (0), \
-             matching what a LoadOp::Clear(initial_fill) would have produced"
-        );
+        backend.set_text_brightness(-1.0);
+        assert_eq!(backend.text_brightness(), 0.0, "negative factors clamp to zero");
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+        assert!(payload.is_full(), "a new brightness repaints every row");
+        assert_eq!(payload.text_brightness, 0.0);
     }
 
     #[test]
-    fn take_after_resize_is_full() {
+    fn render_stats_count_the_last_payload() {
+        let mut backend = TerminalBuilder::new(test_fonts()).with_dimensions(4, 3).build();
+        assert_eq!(backend.render_stats(), RenderStats::default(), "nothing rendered yet");
+
+        let mut cell = Cell::default();
+        cell.set_char('a').set_bg(Color::Blue);
+        let cells = [(0u16, 0u16, &cell), (1, 0, &cell), (0, 2, &cell)];
+        RatatuiBackend::draw(&mut backend, cells.into_iter()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+        let stats = backend.render_stats();
+        assert_eq!(stats.bg_quads, payload.bg_vertices.len() / 4);
+        assert_eq!(stats.fg_quads, payload.text_vertices.len() / 4);
+        assert_eq!(stats.fg_quads, 3, "one glyph quad per 'a'");
+        assert_eq!(stats.draw_calls, 2, "one background and one foreground draw");
+        assert!(stats.full);
+
+        // Only row 0 changes: a partial payload with just that row, plus
+        // its synthesized clear quad.
+        cell.set_char('b');
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+        let partial = backend.render_stats();
+        assert!(!partial.full);
+        assert_eq!(partial.fg_quads, 2);
+        assert_eq!(partial.bg_quads, payload.bg_vertices.len() / 4);
+        assert_eq!(partial.draw_calls, 2);
+    }
+
+    #[test]
+    fn alpha_mask_output_draws_only_white_coverage() {
         let mut backend = TerminalBuilder::new(test_fonts())
-            .with_dimensions(1, 1)
+            .with_dimensions(3, 1)
+            .with_padding(2, 2, 2, 2)
+            .with_output_mode(OutputMode::AlphaMask)
             .build();
 
-        let mut cell = Cell::default();
-        cell.set_symbol("a");
-        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter())
-            .expect("draw failed");
+        let mut red_on_blue = Cell::default();
+        red_on_blue.set_char('a').set_fg(Color::Red).set_bg(Color::Blue);
+        let mut reversed = Cell::default();
+        reversed.set_char('b').modifier = ratatui::style::Modifier::REVERSED;
+        let cells = [(0u16, 0u16, &red_on_blue), (1, 0, &reversed)];
+        RatatuiBackend::draw(&mut backend, cells.into_iter()).expect("draw failed");
         RatatuiBackend::flush(&mut backend).expect("flush failed");
-        let _ = backend.take_draw_payload();
+        let payload = backend.take_draw_payload();
+        assert_eq!(payload.clear_color, [0, 0, 0, 0]);
+        // Colored and reversed backgrounds alike are transparent black,
+        // letterbox included.
+        assert!(payload.bg_vertices.iter().all(|v| v.bg_color == 0));
+        let text: Vec<u32> = payload.text_vertices.chunks(4).map(|q| q[0].fg_color).collect();
+        assert_eq!(text, [MASK_COVERAGE, MASK_COVERAGE]);
+
+        backend.set_output_mode(OutputMode::Color);
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).expect("draw failed");
+        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        let payload = backend.take_draw_payload();
+        assert_eq!(payload.clear_color, [0, 0, 0, 255]);
+        assert!(payload.bg_vertices.iter().any(|v| v.bg_color != 0));
+    }
 
-        backend.resize(2, 2);
-        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter())
+    #[test]
+    fn densely_filled_huge_grid_splits_into_bounded_draws() {
+        use crate::backend::{quad_draw_ranges, MAX_QUADS_PER_DRAW};
+
+        // 80k cells: more bg quads than one draw call takes.
+        let (cols, rows) = (400u16, 200u16);
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(cols, rows)
+            .build();
+        let mut cell = Cell::default();
+        cell.set_symbol("W");
+        cell.bg = Color::Rgb(10, 20, 30);
+        let cells: Vec<_> = (0..rows)
+            .flat_map(|y| (0..cols).map(move |x| (x, y)))
+            .collect();
+        RatatuiBackend::draw(&mut backend, cells.iter().map(|&(x, y)| (x, y, &cell)))
             .expect("draw failed");
         RatatuiBackend::flush(&mut backend).expect("flush failed");
         let payload = backend.take_draw_payload();
 
-        assert!(payload.is_full(), "the first take after a resize must be full");
+        let cell_count = cols as usize * rows as usize;
+        assert_eq!(payload.bg_vertices.len(), cell_count * 4, "one bg quad per cell");
+        assert_eq!(payload.text_vertices.len(), cell_count * 4, "one glyph quad per cell");
+
+        let quads = cell_count as u32;
+        let ranges: Vec<_> = quad_draw_ranges(quads).collect();
+        assert!(ranges.len() > 1, "must not fit a single draw call");
+        assert_eq!(ranges.iter().map(|r| r.len()).sum::<usize>(), cell_count);
+        assert!(
+            ranges.iter().all(|r| r.len() as u32 <= MAX_QUADS_PER_DRAW),
+            "every draw indexes at most MAX_QUADS_PER_DRAW quads from vertex 0"
+        );
     }
 
-    #[test]
-    fn take_after_clear_is_full() {
+    /// Draws two identical red-on-blue "a" cells, shows the cursor on the
+    /// second one, and returns that frame's payload.
+    fn payload_with_cursor_on_second_cell(style: CursorStyle) -> crate::backend::TerminalDrawPayload {
         let mut backend = TerminalBuilder::new(test_fonts())
-            .with_dimensions(1, 1)
+            .with_dimensions(2, 1)
+            .with_cursor_style(style)
             .build();
-
         let mut cell = Cell::default();
         cell.set_symbol("a");
-        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter())
+        cell.fg = Color::Rgb(200, 0, 0);
+        cell.bg = Color::Rgb(0, 0, 200);
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell), (1u16, 0u16, &cell)].into_iter())
             .expect("draw failed");
+        // Same order as `Terminal::draw`: cursor calls between draw and flush.
+        RatatuiBackend::show_cursor(&mut backend).unwrap();
+        RatatuiBackend::set_cursor_position(&mut backend, (1u16, 0u16)).unwrap();
         RatatuiBackend::flush(&mut backend).expect("flush failed");
+        backend.take_draw_payload()
+    }
+
+    #[test]
+    fn visible_cursor_inverts_its_cell_by_default() {
+        let payload = payload_with_cursor_on_second_cell(CursorStyle::default());
+        let red = u32::from_be_bytes([200, 0, 0, 255]);
+        let blue = u32::from_be_bytes([0, 0, 200, 255]);
+        assert_eq!(payload.bg_vertices.len(), 8);
+        assert_eq!(payload.bg_vertices[0].bg_color, blue, "non-cursor cell unchanged");
+        assert_eq!(payload.bg_vertices[4].bg_color, red, "cursor block takes the fg");
+        assert_eq!(payload.text_vertices.len(), 8);
+        assert_eq!(payload.text_vertices[0].fg_color, red);
+        assert_eq!(payload.text_vertices[4].fg_color, blue, "text takes the bg");
+    }
+
+    #[test]
+    fn cursor_style_colors_override_inversion() {
+        let payload = payload_with_cursor_on_second_cell(CursorStyle {
+            color: Some([0, 255, 0]),
+            text_color: Some([1, 2, 3]),
+        });
+        assert_eq!(payload.bg_vertices[4].bg_color, u32::from_be_bytes([0, 255, 0, 255]));
+        assert_eq!(payload.text_vertices[4].fg_color, u32::from_be_bytes([1, 2, 3, 255]));
+    }
+
+    #[test]
+    fn hiding_the_cursor_redraws_its_row_without_it() {
+        let mut backend = TerminalBuilder::new(test_fonts()).with_dimensions(2, 2).build();
+        let mut cell = Cell::default();
+        cell.set_symbol("a");
+        cell.bg = Color::Rgb(0, 0, 200);
+        RatatuiBackend::draw(&mut backend, [(0u16, 1u16, &cell)].into_iter()).unwrap();
+        RatatuiBackend::show_cursor(&mut backend).unwrap();
+        RatatuiBackend::set_cursor_position(&mut backend, (0u16, 1u16)).unwrap();
+        RatatuiBackend::flush(&mut backend).unwrap();
         let _ = backend.take_draw_payload();
 
-        RatatuiBackend::clear(&mut backend).expect("clear failed");
-        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &cell)].into_iter())
-            .expect("draw failed");
-        RatatuiBackend::flush(&mut backend).expect("flush failed");
+        // An empty diff: only the cursor changes.
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).unwrap();
+        RatatuiBackend::hide_cursor(&mut backend).unwrap();
+        assert!(backend.cells_changed_last_draw(), "hiding the cursor is a visible change");
+        RatatuiBackend::flush(&mut backend).unwrap();
         let payload = backend.take_draw_payload();
-
-        assert!(payload.is_full(), "the first take after a clear must be full");
+        assert!(!payload.is_full());
+        // Row-clear quad, then the cell's own (un-inverted) background.
+        assert_eq!(payload.bg_vertices.len(), 8);
+        assert_eq!(payload.bg_vertices[4].bg_color, u32::from_be_bytes([0, 0, 200, 255]));
     }
 
     #[test]
-    fn all_rows_dirty_takes_full_path() {
-        fn cell_with_symbol(symbol: &str) -> Cell {
-            let mut cell = Cell::default();
-            cell.set_symbol(symbol);
-            cell
-        }
+    fn tab_expanded_columns_align_to_tab_stops() {
+        let row: Vec<Cell> = ["a", "\t", "b", "c", "\t", "d"]
+            .into_iter()
+            .map(Cell::new)
+            .collect();
+        assert_eq!(tab_expanded_columns(&row, 4), Some(vec![0, 1, 4, 5, 6, 8, 9]));
+        assert_eq!(tab_expanded_columns(&row, 8), Some(vec![0, 1, 8, 9, 10, 16, 17]));
+        // A tab already on a stop still advances to the next one.
+        let row = vec![Cell::new("\t"), Cell::new("x")];
+        assert_eq!(tab_expanded_columns(&row, 0), Some(vec![0, 1, 2]), "0 acts as 1");
+        assert_eq!(tab_expanded_columns(&[Cell::new("x")], 8), None);
+    }
 
+    #[test]
+    fn tab_cells_render_blank_up_to_the_next_tab_stop() {
         let mut backend = TerminalBuilder::new(test_fonts())
-            .with_dimensions(1, 2)
+            .with_dimensions(10, 1)
+            .with_tab_width(4)
             .build();
-
-        let row_a = cell_with_symbol("A");
-        let row_b = cell_with_symbol("B");
+        let cw = backend.fonts.min_width_px() as f32;
+        let cells: Vec<Cell> = ["a", "\t", "b", "\t", "c", "d", "e", "f", "g", "h"]
+            .into_iter()
+            .map(|symbol| {
+                let mut cell = Cell::new(symbol);
+                cell.bg = Color::Rgb(0, 0, 200);
+                cell
+            })
+            .collect();
         RatatuiBackend::draw(
             &mut backend,
-            [(0u16, 0u16, &row_a), (0u16, 1u16, &row_b)].into_iter(),
+            cells.iter().enumerate().map(|(x, cell)| (x as u16, 0u16, cell)),
         )
         .expect("draw failed");
         RatatuiBackend::flush(&mut backend).expect("flush failed");
-        let _ = backend.take_draw_payload(); // consume the initial full take
+        let payload = backend.take_draw_payload();
 
-        // Change every row - `all_dirty` should route this back through the
-        // full path (cheaper than synthesizing a clear quad per row), even
-        // though `full_redraw_needed` is false at this point.
-        let row_a2 = cell_with_symbol("C");
-        let row_b2 = cell_with_symbol("D");
+        // Screen columns: a=0, tab=1..4, b=4, tab=5..8, c=8, d=9; e..h are
+        // pushed past the 10-column row and dropped. The tabs are blank
+        // cells in the grid itself, so the text reads the same.
+        assert_eq!(backend.get_text()[0].to_string(), "a   b   cd");
+        let glyph_x: Vec<f32> = payload
+            .text_vertices
+            .iter()
+            .step_by(4)
+            .map(|v| v.vertex[0])
+            .collect();
+        assert_eq!(glyph_x, vec![0.0, 4.0 * cw, 8.0 * cw, 9.0 * cw], "no glyph for the tabs");
+        let bg_spans: Vec<(f32, f32)> = payload
+            .bg_vertices
+            .chunks(4)
+            .map(|quad| (quad[0].vertex[0], quad[3].vertex[0]))
+            .collect();
+        let cell_spans: Vec<(f32, f32)> = (0..10)
+            .map(|x| (x as f32 * cw, (x + 1) as f32 * cw))
+            .collect();
+        assert_eq!(bg_spans, cell_spans, "one background per cell, tab blanks included");
+
+        // Changing the tab width reshapes the row even with an empty diff.
+        backend.set_tab_width(2);
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).unwrap();
+        RatatuiBackend::flush(&mut backend).unwrap();
+        let payload = backend.take_draw_payload();
+        let glyph_x: Vec<f32> = payload
+            .text_vertices
+            .iter()
+            .step_by(4)
+            .map(|v| v.vertex[0])
+            .collect();
+        assert_eq!(glyph_x[..3], [0.0, 2.0 * cw, 4.0 * cw]);
+    }
+
+    #[test]
+    fn replacing_a_tab_lays_its_row_out_again() {
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(6, 1)
+            .with_tab_width(4)
+            .build();
+        let cells: Vec<Cell> = ["a", "\t", "b", "c", "d", "e"]
+            .into_iter()
+            .map(Cell::new)
+            .collect();
         RatatuiBackend::draw(
             &mut backend,
-            [(0u16, 0u16, &row_a2), (0u16, 1u16, &row_b2)].into_iter(),
+            cells.iter().enumerate().map(|(x, cell)| (x as u16, 0u16, cell)),
         )
         .expect("draw failed");
+        assert_eq!(backend.get_text()[0].to_string(), "a   bc");
+
+        // Ratatui's diff only resends the tab's own cell; the cells it had
+        // shifted (or pushed off the row) move back.
+        let x = Cell::new("x");
+        RatatuiBackend::draw(&mut backend, [(1u16, 0u16, &x)].into_iter()).expect("draw failed");
+        assert_eq!(backend.get_text()[0].to_string(), "axbcde");
+        assert!(backend.tab_rows.is_empty());
+    }
+
+    #[test]
+    fn default_modifier_is_ored_into_every_cell() {
+        use ratatui::style::Modifier;
+
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(2, 1)
+            .with_default_modifier(Modifier::REVERSED)
+            .build();
+        let mut plain = Cell::new("a");
+        plain.fg = Color::Rgb(200, 0, 0);
+        plain.bg = Color::Rgb(0, 0, 200);
+        let mut bold = plain.clone();
+        bold.modifier = Modifier::BOLD;
+        RatatuiBackend::draw(&mut backend, [(0u16, 0u16, &plain), (1u16, 0u16, &bold)].into_iter())
+            .expect("draw failed");
         RatatuiBackend::flush(&mut backend).expect("flush failed");
         let payload = backend.take_draw_payload();
+        let red = u32::from_be_bytes([200, 0, 0, 255]);
+        let blue = u32::from_be_bytes([0, 0, 200, 255]);
+        assert_eq!(payload.bg_vertices[0].bg_color, red, "default REVERSED applies");
+        assert_eq!(payload.bg_vertices[4].bg_color, red, "cell's own BOLD keeps it too");
+        assert_eq!(backend.cells[1].modifier, Modifier::BOLD, "stored cells are untouched");
+
+        // Clearing the default restyles the already-drawn row.
+        backend.set_default_modifier(Modifier::empty());
+        RatatuiBackend::draw(&mut backend, std::iter::empty()).unwrap();
+        RatatuiBackend::flush(&mut backend).unwrap();
+        let payload = backend.take_draw_payload();
+        assert!(payload.bg_vertices.iter().all(|v| v.bg_color != red));
+        assert!(payload.bg_vertices.iter().any(|v| v.bg_color == blue));
+    }
 
-        assert!(payload.is_full(), "every row dirty must take the full path");
+    /// One "a" cell drawn with `modifier` and a white foreground, and the
+    /// resulting frame's glyph vertices.
+    fn decorated_glyph(backend: &mut BevyTerminalBackend, cell: &Cell) -> Vec<TextVertexMember> {
+        RatatuiBackend::draw(backend, [(0u16, 0u16, cell)].into_iter()).expect("draw failed");
+        RatatuiBackend::flush(backend).expect("flush failed");
+        backend.take_draw_payload().text_vertices
+    }
+
+    #[test]
+    fn underline_uses_the_configured_color_not_the_text_color() {
+        use ratatui::style::Modifier;
+
+        let blue = [0, 0, 255];
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 1)
+            .with_underline_color(Some(blue))
+            .build();
+        let mut cell = Cell::new("a");
+        cell.fg = Color::Rgb(255, 255, 255);
+        cell.modifier = Modifier::UNDERLINED;
+        let vertices = decorated_glyph(&mut backend, &cell);
+
+        assert_eq!(vertices.len(), 4, "an underline needs no extra quad");
+        let white = u32::from_be_bytes([255, 255, 255, 255]);
+        assert!(vertices.iter().all(|v| v.fg_color == white), "glyph stays white");
+        assert!(vertices.iter().all(|v| v.underline_color == u32::from_be_bytes([0, 0, 255, 255])));
+
+        // The band lies inside the glyph's atlas rows, in its lower half.
+        let (y_min, y_max) = (vertices[0].underline_pos >> 16, vertices[0].underline_pos & 0xFFFF);
+        let (uv_top, uv_bottom) = (vertices[0].uv[1], vertices[3].uv[1]);
+        assert!(y_min < y_max, "non-empty band");
+        assert!(y_min as f32 >= (uv_top + uv_bottom) / 2.0 && y_max as f32 <= uv_bottom);
+
+        // Without UNDERLINED there is no band.
+        cell.modifier = Modifier::empty();
+        let vertices = decorated_glyph(&mut backend, &cell);
+        assert!(vertices.iter().all(|v| v.underline_pos == 0));
+    }
+
+    #[test]
+    fn wide_glyph_underline_spans_both_columns_in_one_band() {
+        use ratatui::style::Modifier;
+
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(3, 1)
+            .build();
+        let mut wide = Cell::new("あ");
+        wide.modifier = Modifier::UNDERLINED;
+        let vertices = decorated_glyph(&mut backend, &wide);
+
+        // The band rides on the glyph quad, which covers the continuation
+        // column too - one unbroken underline, no gap between the halves.
+        assert_eq!(vertices.len(), 4, "one quad for the whole wide glyph");
+        assert_ne!(vertices[0].underline_pos, 0);
+        let cell_width = backend.fonts.min_width_px() as f32;
+        assert_eq!(vertices[0].vertex[0], 0.0);
+        assert_eq!(vertices[3].vertex[0], 2.0 * cell_width);
+        assert_eq!(vertices[3].uv[0] - vertices[0].uv[0], 2.0 * cell_width);
+    }
+
+    #[test]
+    fn strikethrough_is_a_second_transparent_quad_with_its_own_color() {
+        use ratatui::style::Modifier;
+
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 1)
+            .with_strikethrough_color(Some([255, 0, 0]))
+            .build();
+        let mut cell = Cell::new("a");
+        cell.fg = Color::Rgb(255, 255, 255);
+        cell.modifier = Modifier::UNDERLINED | Modifier::CROSSED_OUT;
+        let vertices = decorated_glyph(&mut backend, &cell);
+
+        assert_eq!(vertices.len(), 8);
+        let (glyph, strike) = vertices.split_at(4);
+        assert_eq!(glyph[0].underline_color, u32::from_be_bytes([255, 255, 255, 255]), "text color");
+        assert_eq!(strike[0].fg_color & 0xFF, 0, "strike quad draws no glyph");
+        assert_eq!(strike[0].underline_color, u32::from_be_bytes([255, 0, 0, 255]));
+        assert_eq!(strike[0].uv, glyph[0].uv);
+        assert!(
+            strike[0].underline_pos >> 16 < glyph[0].underline_pos >> 16,
+            "strikethrough sits above the underline"
+        );
+    }
+
+    #[cfg(feature = "underline_color")]
+    #[test]
+    fn cell_underline_color_wins_over_the_terminal_default() {
+        use ratatui::style::Modifier;
+
+        let mut backend = TerminalBuilder::new(test_fonts())
+            .with_dimensions(1, 1)
+            .with_underline_color(Some([0, 0, 255]))
+            .build();
+        let mut cell = Cell::new("a");
+        cell.modifier = Modifier::UNDERLINED;
+        cell.underline_color = Color::Rgb(0, 200, 0);
+        let vertices = decorated_glyph(&mut backend, &cell);
+        assert_eq!(vertices[0].underline_color, u32::from_be_bytes([0, 200, 0, 255]));
     }
 }
@@ -40,10 +40,13 @@
 //! - **Smart Cache Updates** - Defer GPU uploads until render time
 //! - **Unicode Shaping** - Full Unicode support with complex text layout
 
+pub mod atlas_snapshot;
 pub mod bevy_backend;
 pub(crate) mod programmatic_glyphs;
 pub(crate) mod rasterize;
 
+pub use programmatic_glyphs::{PowerlineShape, PowerlineStyle};
+
 /// Width of the glyph cache texture in pixels.
 ///
 /// Square, and pinned to exactly `wgpu::Limits::downlevel_webgl2_defaults()`'s
@@ -68,10 +71,35 @@ pub(crate) const CACHE_HEIGHT: u32 = 2048;
 // Compositor builders
 use wgpu::*;
 
-pub(crate) fn build_text_bg_compositor(device: &Device, format: TextureFormat) -> TextCacheBgPipeline {
+/// WGSL source of the two compositor pipelines. `Default` is the copy
+/// embedded at compile time; with the `shader_hot_reload` feature the
+/// render world swaps in files loaded at runtime instead (see
+/// `crate::shader_reload`). Shared, so the copies held per font and the
+/// one extracted to the render world cost a refcount, not the source.
+#[derive(Clone, Debug)]
+pub(crate) struct CompositorShaders {
+    pub(crate) bg: std::sync::Arc<str>,
+    pub(crate) fg: std::sync::Arc<str>,
+}
+
+impl Default for CompositorShaders {
+    fn default() -> Self {
+        Self {
+            bg: include_str!("shaders/composite_bg.wgsl").into(),
+            fg: include_str!("shaders/composite_fg.wgsl").into(),
+        }
+    }
+}
+
+pub(crate) fn build_text_bg_compositor(
+    device: &Device,
+    format: TextureFormat,
+    source: &str,
+    sample_count: u32,
+) -> TextCacheBgPipeline {
     let bg_shader = device.create_shader_module(ShaderModuleDescriptor {
         label: Some("BG Compositor Shader"),
-        source: ShaderSource::Wgsl(include_str!("shaders/composite_bg.wgsl").into()),
+        source: ShaderSource::Wgsl(source.into()),
     });
 
     // Bind group 0 (the screen-size uniform) is intentionally per-terminal,
@@ -84,7 +112,8 @@ pub(crate) fn build_text_bg_compositor(device: &Device, format: TextureFormat) -
         label: Some("BG Bind Group Layout"),
         entries: &[BindGroupLayoutEntry {
             binding: 0,
-            visibility: ShaderStages::VERTEX,
+            // The fragment stage reads the corner radius (`ScreenSize.z`).
+            visibility: ShaderStages::VERTEX | ShaderStages::FRAGMENT,
             ty: BindingType::Buffer {
                 ty: BufferBindingType::Uniform,
                 has_dynamic_offset: false,
@@ -128,7 +157,10 @@ pub(crate) fn build_text_bg_compositor(device: &Device, format: TextureFormat) -
             ..Default::default()
         },
         depth_stencil: None,
-        multisample: MultisampleState::default(),
+        multisample: MultisampleState {
+            count: sample_count,
+            ..Default::default()
+        },
         multiview_mask: None,
         cache: None,
     });
@@ -142,10 +174,12 @@ pub(crate) fn build_text_fg_compositor(
     cache: &TextureView,
     sampler: &Sampler,
     format: TextureFormat,
+    source: &str,
+    sample_count: u32,
 ) -> TextCacheFgPipeline {
     let fg_shader = device.create_shader_module(ShaderModuleDescriptor {
         label: Some("FG Compositor Shader"),
-        source: ShaderSource::Wgsl(include_str!("shaders/composite_fg.wgsl").into()),
+        source: ShaderSource::Wgsl(source.into()),
     });
 
     // Bind group 0 (the screen-size uniform) is per-terminal - see the
@@ -155,7 +189,8 @@ pub(crate) fn build_text_fg_compositor(
         label: Some("FG Bind Group Layout 0"),
         entries: &[BindGroupLayoutEntry {
             binding: 0,
-            visibility: ShaderStages::VERTEX,
+            // The fragment stage reads the corner radius (`ScreenSize.z`).
+            visibility: ShaderStages::VERTEX | ShaderStages::FRAGMENT,
             ty: BindingType::Buffer {
                 ty: BufferBindingType::Uniform,
                 has_dynamic_offset: false,
@@ -260,7 +295,10 @@ pub(crate) fn build_text_fg_compositor(
             ..Default::default()
         },
         depth_stencil: None,
-        multisample: MultisampleState::default(),
+        multisample: MultisampleState {
+            count: sample_count,
+            ..Default::default()
+        },
         multiview_mask: None,
         cache: None,
     });
@@ -303,17 +341,179 @@ impl From<(NonZeroU32, NonZeroU32)> for Dimensions {
 
 /// Controls the area the text is rendered to relative to the presentation
 /// surface.
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum Viewport {
     /// Render to the entire surface.
     #[default]
     Full,
-    /// Render to a reduced area starting at the top right and rendering up to
-    /// the bottom left - (width, height).
+    /// Render to a reduced area: `width`/`height` pixels are taken off the
+    /// right and bottom edges, and ratatui gets only the whole cells that
+    /// still fit. The uncovered margin shows the terminal's initial fill
+    /// color. Insets larger than the texture leave an empty (0x0) area.
     Shrink { width: u32, height: u32 },
 }
 
+/// Pixel margin between the cell grid and the texture's edges - see
+/// [`TerminalBuilder::with_padding`](bevy_backend::TerminalBuilder::with_padding).
+/// Positive sides grow the texture and letterbox the grid with the reset
+/// background color; negative sides shrink it and move the grid past that
+/// edge, so e.g. a box-drawing border's outer half bleeds off the texture.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Padding {
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+    pub left: i32,
+}
+
+impl Padding {
+    /// Padding in CSS order: `top`, `right`, `bottom`, `left`.
+    pub const fn new(top: i32, right: i32, bottom: i32, left: i32) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// The same padding on all four sides.
+    pub const fn uniform(px: i32) -> Self {
+        Self::new(px, px, px, px)
+    }
+
+    /// Texture size for a `grid_width` x `grid_height` pixel grid with
+    /// this padding around it, never below 1x1.
+    pub fn texture_size(&self, grid_width: u32, grid_height: u32) -> (u32, u32) {
+        let width = grid_width as i64 + self.left as i64 + self.right as i64;
+        let height = grid_height as i64 + self.top as i64 + self.bottom as i64;
+        (
+            width.clamp(1, u32::MAX as i64) as u32,
+            height.clamp(1, u32::MAX as i64) as u32,
+        )
+    }
+}
+
+/// A border drawn around the terminal's content by the crate itself - see
+/// [`TerminalBuilder::with_chrome`](bevy_backend::TerminalBuilder::with_chrome).
+/// It takes the outermost ring of cells, drawn with the same box-drawing
+/// glyphs a `Block::bordered()` would get, and ratatui's `frame.area()`
+/// shrinks to the cells inside it.
+///
+/// The grid keeps its coordinates: `frame.area()` starts at `(1, 1)`, and
+/// mouse events still report texture-grid cells, so a widget's `Rect` can
+/// be tested against an event's `column`/`row` as usual. A click on the
+/// border reports a border cell, outside `frame.area()`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ChromeConfig {
+    /// Line style of the border. Default `BorderType::Plain`.
+    pub border_type: ratatui::widgets::BorderType,
+    /// Colors and modifiers of the border and its title.
+    pub style: ratatui::style::Style,
+    /// Text drawn into the top border, left-aligned. Default `None`.
+    pub title: Option<String>,
+}
+
+impl ChromeConfig {
+    /// The border as a ratatui `Block`.
+    fn block(&self) -> ratatui::widgets::Block<'_> {
+        let block = ratatui::widgets::Block::bordered()
+            .border_type(self.border_type)
+            .border_style(self.style);
+        match &self.title {
+            Some(title) => block.title(title.as_str()).title_style(self.style),
+            None => block,
+        }
+    }
+
+    /// The border's cells on a `width` x `height` grid, `(x, y, cell)` in
+    /// row-major order - just the outer ring, empty below 2x2.
+    pub(crate) fn ring(&self, width: u16, height: u16) -> Vec<(u16, u16, ratatui::buffer::Cell)> {
+        use ratatui::widgets::Widget;
+
+        if width < 2 || height < 2 {
+            return vec![];
+        }
+        let area = ratatui::layout::Rect::new(0, 0, width, height);
+        let mut buffer = ratatui::buffer::Buffer::empty(area);
+        self.block().render(area, &mut buffer);
+        area.positions()
+            .filter(|position| {
+                let edge_x = position.x == 0 || position.x == width - 1;
+                edge_x || position.y == 0 || position.y == height - 1
+            })
+            .map(|position| (position.x, position.y, buffer[position].clone()))
+            .collect()
+    }
+}
+
+/// Colors of the block cursor shown while ratatui's cursor is visible
+/// (`Frame::set_cursor_position`). Each unset color falls back to
+/// inverting the cell under the cursor: the block takes the cell's
+/// foreground, the text its background. Set them when inversion is hard
+/// to see against a theme - e.g. a fixed bright block on a terminal with
+/// many similarly colored cells.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CursorStyle {
+    /// Block color, or `None` for the cell's foreground.
+    pub color: Option<[u8; 3]>,
+    /// Color of the glyph under the cursor, or `None` for the cell's
+    /// background.
+    pub text_color: Option<[u8; 3]>,
+}
+
+/// How [`BevyTerminalBackend::get_text_with`](bevy_backend::BevyTerminalBackend::get_text_with)
+/// and [`BevyTerminalBackend::text_in_range`](bevy_backend::BevyTerminalBackend::text_in_range)
+/// turn cells into text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextExtractOptions {
+    /// Drop the blank cells (a space on the reset background) at the end
+    /// of each line - what a copy of the text usually wants. Off keeps
+    /// every line as wide as the grid, for copying it as a fixed grid.
+    /// On by default.
+    pub trim_trailing: bool,
+}
+
+impl Default for TextExtractOptions {
+    fn default() -> Self {
+        Self {
+            trim_trailing: true,
+        }
+    }
+}
+
+/// What a terminal writes into its texture - see
+/// [`TerminalBuilder::with_output_mode`](bevy_backend::TerminalBuilder::with_output_mode).
+///
+/// The texture format is the same `Rgba8Unorm` (sRGB-tagged on the Bevy
+/// side) in both modes; only what the pixels hold changes:
+///
+/// | | `Color` | `AlphaMask` |
+/// |---|---|---|
+/// | rgb | the cell colors | the coverage, repeated in r, g and b |
+/// | alpha | 255, or 0 for `transparent_reset_bg` cells | the coverage |
+///
+/// *Coverage* is how much of the pixel the terminal's drawn shapes cover:
+/// 255 inside a glyph, a cursor block or an underline, 0 in the background,
+/// the antialiased ramp on glyph edges. Sample any one channel of a mask
+/// texture - `.a` is the natural choice - and don't rely on the rgb being
+/// linear: the sRGB view of the texture decodes them as colors.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutputMode {
+    /// The terminal as it looks: backgrounds, text and decorations in
+    /// their resolved colors.
+    #[default]
+    Color,
+    /// Text coverage only: every background is transparent black, and
+    /// everything drawn over it is white at the drawing's alpha. Meant as
+    /// a mask for your own compositing - e.g. cutting text out of a video
+    /// or a material in a custom shader. All colors, including
+    /// `REVERSED`, the palette and the themes, are ignored; a block cursor
+    /// is a solid block, hiding the glyph under it.
+    AlphaMask,
+}
+
 #[repr(C)]
 #[derive(bytemuck::Pod, bytemuck::Zeroable, Debug, Clone, Copy)]
 struct TextBgVertexMember {
@@ -342,7 +542,14 @@ pub(crate) struct TextCacheFgPipeline {
 }
 
 
-fn c2c(color: ratatui::style::Color, reset: Rgb) -> Rgb {
+/// Resolve a ratatui color to RGB. `reset` stands in for `Color::Reset`;
+/// `palette`, if set, overrides the 16 ANSI colors (ratatui's named colors
+/// and `Indexed(0..16)` - see [`ansi_slot`]), e.g. from a
+/// [`TerminalTheme`](crate::theme::TerminalTheme).
+pub(crate) fn c2c(color: ratatui::style::Color, reset: Rgb, palette: Option<&[Rgb; 16]>) -> Rgb {
+    if let Some(slot) = palette.and_then(|palette| ansi_slot(color).map(|slot| palette[slot])) {
+        return slot;
+    }
     match color {
         Color::Reset => reset,
         Color::Black => BLACK,
@@ -366,6 +573,54 @@ fn c2c(color: ratatui::style::Color, reset: Rgb) -> Rgb {
     }
 }
 
+/// The ANSI palette slot (0-15) a named ratatui color (or a low
+/// `Indexed` value) occupies, in the standard xterm order.
+fn ansi_slot(color: ratatui::style::Color) -> Option<usize> {
+    Some(match color {
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+        Color::Indexed(idx) if idx < 16 => idx as usize,
+        _ => return None,
+    })
+}
+
+/// Size of the most recent draw payload a terminal produced - see
+/// [`BevyTerminalBackend::render_stats`](bevy_backend::BevyTerminalBackend::render_stats).
+/// Counts what the render world is handed, not what the GPU ended up
+/// rasterizing: a partial payload (only the dirty rows) is small even on a
+/// busy screen, and a frame with nothing dirty renders nothing at all and
+/// leaves these as they were.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RenderStats {
+    /// Background quads: cell backgrounds, rules, box outlines, plus the
+    /// synthesized row clears of a partial payload and the letterbox fill.
+    pub bg_quads: usize,
+    /// Foreground quads: one per glyph (and per underline drawn apart
+    /// from it).
+    pub fg_quads: usize,
+    /// `draw_indexed` calls issued for them - one per non-empty pass,
+    /// more when a pass exceeds the per-draw quad limit; `0` for a
+    /// payload that only clears.
+    pub draw_calls: usize,
+    /// Whether the payload repainted every row (`false`: only the rows
+    /// that changed since the previous one).
+    pub full: bool,
+}
+
 /// CPU-computed draw payload extracted from a dirty `Tui` each frame: the
 /// background/foreground vertex data ratatui's diffed buffer produced this
 /// draw, plus which font's shared atlas/pipelines to render it against.
@@ -385,12 +640,26 @@ fn c2c(color: ratatui::style::Color, reset: Rgb) -> Rgb {
 ///   `[4i, 4i+1, 4i+2, 4i+2, 4i+3, 4i+1]`), so `TerminalGpuState` derives
 ///   them from one static, grow-only index buffer instead of this payload
 ///   shipping a freshly rebuilt one every frame.
+///
+/// `Clone` so one payload can be rendered into several destinations - see
+/// [`TerminalTexture::add_mirror`](crate::setup::TerminalTexture::add_mirror).
+#[derive(Clone)]
 pub(crate) struct TerminalDrawPayload {
     /// Pixel dimensions used for the screen-size uniform - the *drawable*
     /// area (viewport insets already applied), not necessarily the full
     /// `cols * char_width_px` grid size.
     screen_width_px: f32,
     screen_height_px: f32,
+    /// Radius of the rounded corners both compositors mask the screen
+    /// rect with, in the same pixels (`0.0`: square) - see
+    /// [`bevy_backend::TerminalBuilder::with_corner_radius`]. Carried in
+    /// the screen-size uniform's third component.
+    corner_radius: f32,
+    /// Factor the foreground compositor scales text colors by (`1.0`:
+    /// as given) - see
+    /// [`bevy_backend::TerminalBuilder::with_text_brightness`]. Carried in
+    /// the screen-size uniform's fourth component.
+    text_brightness: f32,
     /// Color to clear to when both vertex `Vec`s are empty (nothing drawn
     /// yet, or mid-resize) - see `BevyTerminalBackend::initial_fill`.
     clear_color: [u8; 4],
@@ -408,6 +677,21 @@ pub(crate) struct TerminalDrawPayload {
     /// current content can't be trusted (see
     /// `BevyTerminalBackend::full_redraw_needed`).
     load_previous: bool,
+    /// MSAA sample count the backend asked for (`1` = off) - see
+    /// [`bevy_backend::TerminalBuilder::with_msaa`]. Validated against the
+    /// adapter in the render world, not here.
+    sample_count: u32,
+    /// Partial present: pixel rect `[x, y, width, height]` the render pass
+    /// is scissored to - the bounding box of this payload's dirty rows,
+    /// full row width. Only ever `Some` alongside `load_previous`: a
+    /// `LoadOp::Clear` clears the whole attachment regardless of any
+    /// scissor, so a full payload has nothing to gain from one. Inside the
+    /// rect, the synthesized row-clear quads stand in for the clear color;
+    /// outside it, no pixel is written at all - not even by a glyph whose
+    /// outline overhangs its row (tall emoji, oversized fallback glyphs),
+    /// which under `LoadOp::Load` would otherwise smear into rows this
+    /// payload never cleared.
+    scissor: Option<[u32; 4]>,
     bg_vertices: Vec<TextBgVertexMember>,
     text_vertices: Vec<TextVertexMember>,
 }
@@ -424,6 +708,21 @@ impl TerminalDrawPayload {
         self.bg_vertices.clear();
         self.text_vertices.clear();
         self.load_previous = false;
+        self.scissor = None;
+    }
+
+    /// Quad and draw-call counts `TerminalGpuState::render` will issue for
+    /// this payload (ignoring the device buffer limit it clamps to).
+    pub(crate) fn render_stats(&self) -> RenderStats {
+        let bg_quads = self.bg_vertices.len() / 4;
+        let fg_quads = self.text_vertices.len() / 4;
+        let draws = |quads: usize| quad_draw_ranges(quads.min(u32::MAX as usize) as u32).count();
+        RenderStats {
+            bg_quads,
+            fg_quads,
+            draw_calls: draws(bg_quads) + draws(fg_quads),
+            full: !self.load_previous,
+        }
     }
 
     /// Identity of the `Fonts` this terminal renders with - used by
@@ -433,14 +732,175 @@ impl TerminalDrawPayload {
         self.font_key
     }
 
+    /// Requested MSAA sample count - see the field doc.
+    pub(crate) fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
     /// `true` iff this payload is a full redraw (`load_previous == false`).
-    /// A `pub(crate)` accessor for tests outside the `backend` module -
-    /// `load_previous` itself stays private since callers outside this
-    /// module otherwise never need to inspect payload internals.
-    #[cfg(test)]
+    /// Used by `render_tui_textures`' lost-texture check (a queued full
+    /// payload already repaints everything) and by tests outside the
+    /// `backend` module - `load_previous` itself stays private.
     pub(crate) fn is_full(&self) -> bool {
         !self.load_previous
     }
+
+    /// One packed color per background quad, in emission order. Test-only
+    /// accessor for callers outside the `backend` module, same reasoning
+    /// as `is_full`.
+    #[cfg(test)]
+    pub(crate) fn bg_quad_colors(&self) -> Vec<u32> {
+        self.bg_vertices.chunks_exact(4).map(|quad| quad[0].bg_color).collect()
+    }
+
+    /// Number of glyph quads. Test-only, see `bg_quad_colors`.
+    #[cfg(test)]
+    pub(crate) fn text_quad_count(&self) -> usize {
+        self.text_vertices.len() / 4
+    }
+}
+
+/// Intersects a `[x, y, width, height]` scissor rect with a
+/// `target_width x target_height` attachment. `None` when nothing is left,
+/// in which case the pass runs unscissored - the payload's geometry is
+/// already limited to its dirty rows, so that only gives up the clipping.
+fn clamp_scissor(rect: [u32; 4], target_width: u32, target_height: u32) -> Option<[u32; 4]> {
+    let [x, y, width, height] = rect;
+    let x1 = x.saturating_add(width).min(target_width);
+    let y1 = y.saturating_add(height).min(target_height);
+    (x < x1 && y < y1).then(|| [x, y, x1 - x, y1 - y])
+}
+
+/// Raster content copied straight into a terminal's destination texture
+/// after its text pass - see
+/// [`TerminalTexture::overlay_pixmap`](crate::setup::TerminalTexture::overlay_pixmap).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PixmapOverlay {
+    /// Top-left corner, in texture pixels.
+    pub(crate) origin: [u32; 2],
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    /// Straight-alpha RGBA8, rows tightly packed (`width * 4` bytes each).
+    pub(crate) rgba: Vec<u8>,
+}
+
+impl PixmapOverlay {
+    /// The top-left `max_width` x `max_height` pixels of `pixmap` (all of
+    /// it if smaller), demultiplied to the straight alpha the destination
+    /// texture holds. `None` if nothing is left to copy.
+    pub(crate) fn from_pixmap(
+        pixmap: &tiny_skia::Pixmap,
+        origin: [u32; 2],
+        max_width: u32,
+        max_height: u32,
+    ) -> Option<Self> {
+        let width = pixmap.width().min(max_width);
+        let height = pixmap.height().min(max_height);
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let mut rgba = Vec::with_capacity((width * height * 4) as usize);
+        for row in pixmap.pixels().chunks(pixmap.width() as usize).take(height as usize) {
+            for pixel in &row[..width as usize] {
+                let color = pixel.demultiply();
+                rgba.extend_from_slice(&[color.red(), color.green(), color.blue(), color.alpha()]);
+            }
+        }
+        Some(Self {
+            origin,
+            width,
+            height,
+            rgba,
+        })
+    }
+
+    /// The part of this overlay inside a `target_width` x `target_height`
+    /// texture, as `(width, height, bytes)` with each row padded to
+    /// `COPY_BYTES_PER_ROW_ALIGNMENT` for a buffer-to-texture copy. `None`
+    /// if it lies entirely outside - the terminal may have been resized
+    /// since the overlay was queued.
+    fn padded_rows(&self, target_width: u32, target_height: u32) -> Option<(u32, u32, Vec<u8>)> {
+        let [x, y] = self.origin;
+        let width = self.width.min(target_width.saturating_sub(x));
+        let height = self.height.min(target_height.saturating_sub(y));
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let row_bytes = (width * 4) as usize;
+        let padded = row_bytes.next_multiple_of(COPY_BYTES_PER_ROW_ALIGNMENT as usize);
+        let mut bytes = vec![0u8; padded * height as usize];
+        for (dst, src) in bytes
+            .chunks_exact_mut(padded)
+            .zip(self.rgba.chunks_exact((self.width * 4) as usize))
+        {
+            dst[..row_bytes].copy_from_slice(&src[..row_bytes]);
+        }
+        Some((width, height, bytes))
+    }
+
+    /// Records the copy into `texture` on `encoder`, so it lands after any
+    /// render pass already recorded there - the same frame's text pass
+    /// can't paint over it. An HDR destination gets each channel widened
+    /// to a half float; the padded rows just double, staying aligned.
+    pub(crate) fn record(&self, device: &Device, encoder: &mut CommandEncoder, texture: &Texture) {
+        use wgpu::util::{BufferInitDescriptor, DeviceExt};
+
+        let size = texture.size();
+        let Some((width, height, mut bytes)) = self.padded_rows(size.width, size.height) else {
+            return;
+        };
+        if texture.format() == HDR_TEXTURE_FORMAT {
+            bytes = bytes
+                .iter()
+                .flat_map(|&channel| unorm8_to_f16_bits(channel).to_le_bytes())
+                .collect();
+        }
+        let buffer = device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("Terminal Pixmap Overlay"),
+            contents: &bytes,
+            usage: BufferUsages::COPY_SRC,
+        });
+        encoder.copy_buffer_to_texture(
+            wgpu::TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(bytes.len() as u32 / height),
+                    rows_per_image: Some(height),
+                },
+            },
+            wgpu::TexelCopyTextureInfo {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: self.origin[0],
+                    y: self.origin[1],
+                    z: 0,
+                },
+                aspect: wgpu::TextureAspect::All,
+            },
+            Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+    }
+}
+
+/// The IEEE half float nearest `value / 255`, as stored in an
+/// [`HDR_TEXTURE_FORMAT`] texel. Every non-zero input is a normal half
+/// (the smallest, 1/255, is about 2^-8), so no subnormal, overflow or sign
+/// handling is needed; a rounding carry out of the mantissa correctly
+/// bumps the exponent.
+fn unorm8_to_f16_bits(value: u8) -> u16 {
+    if value == 0 {
+        return 0;
+    }
+    let bits = (value as f32 / 255.0).to_bits();
+    let exponent = ((bits >> 23) & 0xFF) - 127 + 15;
+    let mantissa = bits & 0x7F_FFFF;
+    (((exponent << 10) | (mantissa >> 13)) + ((mantissa >> 12) & 1)) as u16
 }
 
 /// Zeroes an entire freshly created 2D texture via one full-extent
@@ -479,6 +939,32 @@ fn zero_init_texture(queue: &Queue, texture: &Texture, width: u32, height: u32,
 /// frame ever drawn).
 const INITIAL_INDEX_QUAD_CAPACITY: u32 = 64;
 
+/// Most quads a single `draw_indexed` call covers. Bigger frames are split
+/// into several draws, each re-binding the vertex buffer at its first quad
+/// (`set_vertex_buffer` with an offset slice - not `base_vertex`, which
+/// WebGL2 lacks), so every draw indexes from vertex 0 again. Keeps the
+/// largest index value at `4 * MAX_QUADS_PER_DRAW` and the index buffer at
+/// a fixed ceiling, however large the terminal gets.
+const MAX_QUADS_PER_DRAW: u32 = 1 << 16;
+
+/// Splits `quad_count` quads into the `start..end` quad ranges drawn by
+/// one `draw_indexed` call each - see [`MAX_QUADS_PER_DRAW`].
+fn quad_draw_ranges(quad_count: u32) -> impl Iterator<Item = std::ops::Range<u32>> {
+    (0..quad_count)
+        .step_by(MAX_QUADS_PER_DRAW as usize)
+        .map(move |start| start..quad_count.min(start.saturating_add(MAX_QUADS_PER_DRAW)))
+}
+
+/// Quads of `vertex_count` vertices (4 per quad) that fit in one buffer of
+/// at most `max_buffer_bytes`, given `vertex_bytes` per vertex. Anything
+/// beyond is dropped by the caller with a warning instead of failing wgpu
+/// validation - only reachable for grids far past any texture size limit.
+fn drawable_quads(vertex_count: usize, vertex_bytes: usize, max_buffer_bytes: u64) -> u32 {
+    let quads = (vertex_count / 4) as u64;
+    let fit = max_buffer_bytes / (4 * vertex_bytes as u64);
+    quads.min(fit).min(u32::MAX as u64) as u32
+}
+
 /// Builds the index pattern for `quad_count` independent quads: quad `i`
 /// occupies vertices `[4i, 4i+1, 4i+2, 4i+3]` and is drawn as two
 /// triangles `(4i, 4i+1, 4i+2)` + `(4i+2, 4i+3, 4i+1)`. Pure function of
@@ -513,7 +999,12 @@ fn ensure_buffer_capacity(
     if needed_bytes <= *capacity_bytes {
         return;
     }
-    let new_capacity = needed_bytes.next_power_of_two();
+    // Doubling must not step past the device limit on a device whose
+    // `max_buffer_size` isn't a power of two; `needed_bytes` itself is
+    // already within it (see `drawable_quads`).
+    let new_capacity = needed_bytes
+        .next_power_of_two()
+        .min(device.limits().max_buffer_size.max(needed_bytes));
     *buffer = device.create_buffer(&BufferDescriptor {
         label: Some(label),
         size: new_capacity,
@@ -523,6 +1014,35 @@ fn ensure_buffer_capacity(
     *capacity_bytes = new_capacity;
 }
 
+/// The largest MSAA sample count `<= requested` that `adapter` can render
+/// to `format` with and resolve from. `1` (no MSAA) is always supported,
+/// so this never fails - an unsupported request just degrades.
+pub(crate) fn supported_sample_count(adapter: &Adapter, format: TextureFormat, requested: u32) -> u32 {
+    let flags = adapter.get_texture_format_features(format).flags;
+    if !flags.contains(TextureFormatFeatureFlags::MULTISAMPLE_RESOLVE) {
+        return 1;
+    }
+    [16, 8, 4, 2]
+        .into_iter()
+        .find(|&count| count <= requested && flags.sample_count_supported(count))
+        .unwrap_or(1)
+}
+
+/// Destination format of an HDR terminal - see
+/// [`TerminalTexture::with_hdr`](crate::setup::TerminalTexture::with_hdr).
+pub(crate) const HDR_TEXTURE_FORMAT: TextureFormat = TextureFormat::Rgba16Float;
+
+/// Whether `adapter` can render terminals into [`HDR_TEXTURE_FORMAT`]:
+/// the compositors need it as a render attachment and alpha-blend the
+/// text onto it. Core `Rgba16Float` support covers both on every native
+/// backend, but WebGL2 only renders to float textures with the
+/// `EXT_color_buffer_float` extension, so this is checked, not assumed.
+pub(crate) fn hdr_format_supported(adapter: &Adapter) -> bool {
+    let features = adapter.get_texture_format_features(HDR_TEXTURE_FORMAT);
+    features.allowed_usages.contains(TextureUsages::RENDER_ATTACHMENT)
+        && features.flags.contains(TextureFormatFeatureFlags::BLENDABLE)
+}
+
 /// Render-world GPU resources shared by every terminal using the same
 /// `Fonts` (IMPROVEMENT.md C3): the glyph atlas texture and the
 /// background/foreground compositor pipelines (which bind to that same
@@ -530,18 +1050,38 @@ fn ensure_buffer_capacity(
 /// identity ([`crate::fonts::Fonts::identity`]) in the render-world store
 /// (`bevy_plugin.rs`), not by destination image - two terminals sharing a
 /// font share one 2048x2048 atlas and one pipeline pair instead of one
-/// each. `target_format` mirrors whatever pixel format terminal
-/// destination `GpuImage`s are actually created with (always
-/// `Rgba8Unorm` today - see `TerminalTexture::create` - so in practice
-/// every terminal's format matches regardless of font).
+/// each. Pipelines are built per destination format: `Rgba8Unorm`, or
+/// `Rgba16Float` for an HDR terminal (`TerminalTexture::with_hdr`) - two
+/// terminals sharing a font can differ there, and still share the atlas.
 pub(crate) struct SharedFontGpuState {
     text_cache: Texture,
-    text_bg_compositor: TextCacheBgPipeline,
-    text_fg_compositor: TextCacheFgPipeline,
+    /// Compositor pipeline pairs keyed by destination format and MSAA
+    /// sample count. The single-sample pair for the format `new` was given
+    /// is built up front; others only once a terminal using this font asks
+    /// for them (see [`Self::ensure_compositors`]).
+    compositors: std::collections::HashMap<
+        (TextureFormat, u32),
+        (TextCacheBgPipeline, TextCacheFgPipeline),
+    >,
+    /// Everything needed to build another compositor pair against this
+    /// same atlas: the atlas-size uniform, atlas view, sampler and WGSL
+    /// source.
+    compositor_inputs: (Buffer, TextureView, Sampler, CompositorShaders),
 }
 
 impl SharedFontGpuState {
-    pub(crate) fn new(device: &Device, queue: &Queue, target_format: TextureFormat) -> Self {
+    /// The glyph atlas texture, for `Tui::read_back_atlas_blocking`.
+    #[cfg(feature = "atlas_dump")]
+    pub(crate) fn atlas_texture(&self) -> &Texture {
+        &self.text_cache
+    }
+
+    pub(crate) fn new(
+        device: &Device,
+        queue: &Queue,
+        target_format: TextureFormat,
+        shaders: &CompositorShaders,
+    ) -> Self {
         use wgpu::util::{BufferInitDescriptor, DeviceExt};
         use wgpu::{AddressMode, FilterMode, SamplerDescriptor};
 
@@ -600,19 +1140,63 @@ impl SharedFontGpuState {
             usage: wgpu::BufferUsages::UNIFORM,
         });
 
-        let text_bg_compositor = build_text_bg_compositor(device, target_format);
-        let text_fg_compositor = build_text_fg_compositor(
-            device,
-            &atlas_size_buffer,
-            &text_cache_view,
-            &sampler,
-            target_format,
-        );
-
-        Self {
+        let mut shared = Self {
             text_cache,
-            text_bg_compositor,
-            text_fg_compositor,
+            compositors: std::collections::HashMap::new(),
+            compositor_inputs: (atlas_size_buffer, text_cache_view, sampler, shaders.clone()),
+        };
+        shared.ensure_compositors(device, target_format, 1);
+        shared
+    }
+
+    /// Build the compositor pair rendering into `format` with
+    /// `sample_count` if this font doesn't have one yet. Both must already
+    /// be validated against the adapter (see [`supported_sample_count`]
+    /// and [`hdr_format_supported`]).
+    pub(crate) fn ensure_compositors(
+        &mut self,
+        device: &Device,
+        format: TextureFormat,
+        sample_count: u32,
+    ) {
+        let (atlas_size_buffer, text_cache_view, sampler, shaders) = &self.compositor_inputs;
+        self.compositors.entry((format, sample_count)).or_insert_with(|| {
+            (
+                build_text_bg_compositor(device, format, &shaders.bg, sample_count),
+                build_text_fg_compositor(
+                    device,
+                    atlas_size_buffer,
+                    text_cache_view,
+                    sampler,
+                    format,
+                    &shaders.fg,
+                    sample_count,
+                ),
+            )
+        });
+    }
+
+    /// The compositor pair for `format` and `sample_count` - built by a
+    /// prior [`Self::ensure_compositors`] call.
+    fn compositors(
+        &self,
+        format: TextureFormat,
+        sample_count: u32,
+    ) -> &(TextCacheBgPipeline, TextCacheFgPipeline) {
+        &self.compositors[&(format, sample_count)]
+    }
+
+    /// Recreate the compositor pipelines from new WGSL, keeping the atlas
+    /// texture (and every glyph already uploaded to it). Terminals'
+    /// `TerminalGpuState`s hold bind groups built against the old pipeline
+    /// layout and must be recreated too - the caller drops them.
+    #[cfg(feature = "shader_hot_reload")]
+    pub(crate) fn rebuild_compositors(&mut self, device: &Device, shaders: &CompositorShaders) {
+        self.compositor_inputs.3 = shaders.clone();
+        let keys: Vec<(TextureFormat, u32)> = self.compositors.keys().copied().collect();
+        self.compositors.clear();
+        for (format, sample_count) in keys {
+            self.ensure_compositors(device, format, sample_count);
         }
     }
 
@@ -678,6 +1262,19 @@ pub(crate) struct TerminalGpuState {
     bg_vertex_buffer_capacity_bytes: u64,
     fg_vertex_buffer: Buffer,
     fg_vertex_buffer_capacity_bytes: u64,
+    /// Format of the destination texture - selects the compositor pair
+    /// (see [`SharedFontGpuState::ensure_compositors`]).
+    format: TextureFormat,
+    /// MSAA sample count this terminal renders with, already validated
+    /// against the adapter (`1` = no MSAA), and the count the backend
+    /// asked for - see `render_tui_textures` in `bevy_plugin.rs`.
+    sample_count: u32,
+    requested_sample_count: u32,
+    /// Multisampled color target the passes render into when
+    /// `sample_count > 1`, resolved into the destination texture at the
+    /// end of every pass. Persistent (not transient) because a partial
+    /// payload's `LoadOp::Load` needs last frame's samples.
+    msaa_target: Option<(Texture, TextureView)>,
 }
 
 impl TerminalGpuState {
@@ -687,8 +1284,15 @@ impl TerminalGpuState {
     /// this terminal's OWN screen-size buffer needs a bind group built
     /// against that layout, distinct from `shared`'s own (placeholder)
     /// one. Terminals sharing `shared` each get their own such bind group,
-    /// pointing at their own buffer.
-    pub(crate) fn new(device: &Device, shared: &SharedFontGpuState) -> Self {
+    /// pointing at their own buffer. `shared` must already hold the
+    /// compositor pair for `format` and `sample_count`.
+    pub(crate) fn new(
+        device: &Device,
+        shared: &SharedFontGpuState,
+        format: TextureFormat,
+        requested_sample_count: u32,
+        sample_count: u32,
+    ) -> Self {
         use wgpu::util::{BufferInitDescriptor, DeviceExt};
         use wgpu::{BufferDescriptor, BufferUsages};
 
@@ -701,7 +1305,11 @@ impl TerminalGpuState {
 
         let text_screen_size_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Terminal Screen Size Bind Group"),
-            layout: &shared.text_bg_compositor.pipeline.get_bind_group_layout(0),
+            layout: &shared
+                .compositors(format, sample_count)
+                .0
+                .pipeline
+                .get_bind_group_layout(0),
             entries: &[wgpu::BindGroupEntry {
                 binding: 0,
                 resource: text_screen_size_buffer.as_entire_binding(),
@@ -741,9 +1349,62 @@ impl TerminalGpuState {
             bg_vertex_buffer_capacity_bytes,
             fg_vertex_buffer,
             fg_vertex_buffer_capacity_bytes,
+            format,
+            sample_count,
+            requested_sample_count,
+            msaa_target: None,
         }
     }
 
+    /// The destination format this state renders into -
+    /// `render_tui_textures` rebuilds the state once the destination image
+    /// changes format.
+    pub(crate) fn format(&self) -> TextureFormat {
+        self.format
+    }
+
+    /// The sample count the backend asked for when this state was built -
+    /// `render_tui_textures` rebuilds the state once a payload asks for a
+    /// different one.
+    pub(crate) fn requested_sample_count(&self) -> u32 {
+        self.requested_sample_count
+    }
+
+    /// The (validated) sample count this terminal renders with.
+    pub(crate) fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
+    /// (Re)creates the multisampled color target if MSAA is on and the
+    /// current one is missing or sized for a different destination.
+    /// Returns `true` if a new target was created - its samples start out
+    /// undefined.
+    fn ensure_msaa_target(&mut self, device: &Device, destination: &Texture) -> bool {
+        if self.sample_count <= 1 {
+            return false;
+        }
+        if self
+            .msaa_target
+            .as_ref()
+            .is_some_and(|(texture, _)| texture.size() == destination.size())
+        {
+            return false;
+        }
+        let texture = device.create_texture(&TextureDescriptor {
+            label: Some("Terminal MSAA Target"),
+            size: destination.size(),
+            mip_level_count: 1,
+            sample_count: self.sample_count,
+            dimension: TextureDimension::D2,
+            format: destination.format(),
+            usage: TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&TextureViewDescriptor::default());
+        self.msaa_target = Some((texture, view));
+        true
+    }
+
     /// Grows `index_buffer` (doubling) if it doesn't already cover
     /// `needed_quads`. The index pattern is a pure function of quad count
     /// (see `build_quad_indices`), so regenerating it at a larger size is
@@ -776,15 +1437,23 @@ impl TerminalGpuState {
     /// partial payload, whose dirty rows already carry a synthesized
     /// full-row clear quad from `take_draw_payload`) or `LoadOp::Clear`
     /// (wipe the whole texture first - a full payload).
+    ///
+    /// With MSAA both passes render into this terminal's multisampled
+    /// target and resolve into `target`. Returns `true` when that target
+    /// was just (re)created under a partial payload - the rows the payload
+    /// doesn't cover are then undefined, and the caller must queue a full
+    /// redraw.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn render(
         &mut self,
         device: &Device,
         queue: &Queue,
         shared: &SharedFontGpuState,
         encoder: &mut wgpu::CommandEncoder,
+        destination: &Texture,
         target: &TextureView,
         draw: &TerminalDrawPayload,
-    ) {
+    ) -> bool {
         use wgpu::{
             BufferUsages, IndexFormat, LoadOp, Operations, RenderPassColorAttachment,
             RenderPassDescriptor, StoreOp,
@@ -811,6 +1480,16 @@ impl TerminalGpuState {
             LoadOp::Clear(clear_color)
         };
 
+        let msaa_target_recreated = self.ensure_msaa_target(device, destination);
+        // A cloned handle, so `self` stays free for the mutable buffer
+        // growth below while the pass still points at the MSAA view.
+        let msaa_view = self.msaa_target.as_ref().map(|(_, view)| view.clone());
+        let (view, resolve_target) = match &msaa_view {
+            Some(msaa_view) => (msaa_view, Some(target)),
+            None => (target, None),
+        };
+        let (bg_compositor, fg_compositor) = shared.compositors(self.format, self.sample_count);
+
         // Branch on EITHER vertex `Vec`, not `text_vertices` alone: a
         // partial payload can legitimately carry bg-only content (a row's
         // text was deleted, leaving just its synthesized clear quad) -
@@ -826,12 +1505,35 @@ impl TerminalGpuState {
             queue.write_buffer(
                 &self.text_screen_size_buffer,
                 0,
-                bytemuck::cast_slice(&[draw.screen_width_px, draw.screen_height_px, 0.0, 0.0]),
+                bytemuck::cast_slice(&[
+                    draw.screen_width_px,
+                    draw.screen_height_px,
+                    draw.corner_radius,
+                    draw.text_brightness,
+                ]),
             );
 
-            let bg_quads = draw.bg_vertices.len() as u32 / 4;
-            let fg_quads = draw.text_vertices.len() as u32 / 4;
-            self.ensure_index_capacity(device, bg_quads.max(fg_quads));
+            let max_buffer_bytes = device.limits().max_buffer_size;
+            let bg_quads = drawable_quads(
+                draw.bg_vertices.len(),
+                std::mem::size_of::<TextBgVertexMember>(),
+                max_buffer_bytes,
+            );
+            let fg_quads = drawable_quads(
+                draw.text_vertices.len(),
+                std::mem::size_of::<TextVertexMember>(),
+                max_buffer_bytes,
+            );
+            if bg_quads as usize * 4 < draw.bg_vertices.len()
+                || fg_quads as usize * 4 < draw.text_vertices.len()
+            {
+                tracing::warn!(
+                    "terminal frame exceeds the device's max buffer size; dropping the quads past it"
+                );
+            }
+            let bg_vertices = &draw.bg_vertices[..bg_quads as usize * 4];
+            let text_vertices = &draw.text_vertices[..fg_quads as usize * 4];
+            self.ensure_index_capacity(device, bg_quads.max(fg_quads).min(MAX_QUADS_PER_DRAW));
 
             // Persistent, grow-only vertex buffers (IMPROVEMENT.md B1):
             // `write_buffer` this frame's bytes at offset 0 instead of
@@ -841,7 +1543,7 @@ impl TerminalGpuState {
             // `0..quads*4` (via the index buffer), so stale bytes past the
             // current frame's data in an oversized buffer are never
             // sampled.
-            let bg_bytes = bytemuck::cast_slice::<_, u8>(&draw.bg_vertices).len() as u64;
+            let bg_bytes = bytemuck::cast_slice::<_, u8>(bg_vertices).len() as u64;
             ensure_buffer_capacity(
                 device,
                 &mut self.bg_vertex_buffer,
@@ -851,10 +1553,10 @@ impl TerminalGpuState {
                 "Text Bg Vertices",
             );
             if bg_bytes > 0 {
-                queue.write_buffer(&self.bg_vertex_buffer, 0, bytemuck::cast_slice(&draw.bg_vertices));
+                queue.write_buffer(&self.bg_vertex_buffer, 0, bytemuck::cast_slice(bg_vertices));
             }
 
-            let fg_bytes = bytemuck::cast_slice::<_, u8>(&draw.text_vertices).len() as u64;
+            let fg_bytes = bytemuck::cast_slice::<_, u8>(text_vertices).len() as u64;
             ensure_buffer_capacity(
                 device,
                 &mut self.fg_vertex_buffer,
@@ -864,14 +1566,14 @@ impl TerminalGpuState {
                 "Text Vertices",
             );
             if fg_bytes > 0 {
-                queue.write_buffer(&self.fg_vertex_buffer, 0, bytemuck::cast_slice(&draw.text_vertices));
+                queue.write_buffer(&self.fg_vertex_buffer, 0, bytemuck::cast_slice(text_vertices));
             }
 
             let mut text_render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
                 label: Some("Terminal Text Render Pass"),
                 color_attachments: &[Some(RenderPassColorAttachment {
-                    view: target,
-                    resolve_target: None,
+                    view,
+                    resolve_target,
                     ops: Operations {
                         load,
                         store: StoreOp::Store,
@@ -882,28 +1584,51 @@ impl TerminalGpuState {
             });
 
             // Both passes draw out of the same static index buffer - it's
-            // large enough for the larger of the two quad counts (just
-            // ensured above), and each pass only ever draws its own
+            // large enough for the larger of the two per-draw quad counts
+            // (just ensured above), and each draw only ever uses its own
             // `0..quads*6` range, so the shared buffer never mixes bg and
             // fg indices.
             text_render_pass.set_index_buffer(self.index_buffer.slice(..), IndexFormat::Uint32);
 
-            text_render_pass.set_pipeline(&shared.text_bg_compositor.pipeline);
+            // Partial present: confine this pass to the dirty-row band.
+            // Clamped to the attachment, which wgpu requires - the grid can
+            // briefly be larger than the texture mid-resize.
+            if let Some(rect) = draw.load_previous.then_some(draw.scissor).flatten() {
+                if let Some([x, y, width, height]) =
+                    clamp_scissor(rect, destination.width(), destination.height())
+                {
+                    text_render_pass.set_scissor_rect(x, y, width, height);
+                }
+            }
+
+            text_render_pass.set_pipeline(&bg_compositor.pipeline);
             text_render_pass.set_bind_group(0, &self.text_screen_size_bind_group, &[]);
-            text_render_pass.set_vertex_buffer(0, self.bg_vertex_buffer.slice(..));
-            text_render_pass.draw_indexed(0..bg_quads * 6, 0, 0..1);
+            let bg_quad_bytes = 4 * std::mem::size_of::<TextBgVertexMember>() as u64;
+            for quads in quad_draw_ranges(bg_quads) {
+                text_render_pass.set_vertex_buffer(
+                    0,
+                    self.bg_vertex_buffer.slice(quads.start as u64 * bg_quad_bytes..),
+                );
+                text_render_pass.draw_indexed(0..quads.len() as u32 * 6, 0, 0..1);
+            }
 
-            text_render_pass.set_pipeline(&shared.text_fg_compositor.pipeline);
+            text_render_pass.set_pipeline(&fg_compositor.pipeline);
             text_render_pass.set_bind_group(0, &self.text_screen_size_bind_group, &[]);
-            text_render_pass.set_bind_group(1, &shared.text_fg_compositor.atlas_bindings, &[]);
-            text_render_pass.set_vertex_buffer(0, self.fg_vertex_buffer.slice(..));
-            text_render_pass.draw_indexed(0..fg_quads * 6, 0, 0..1);
+            text_render_pass.set_bind_group(1, &fg_compositor.atlas_bindings, &[]);
+            let fg_quad_bytes = 4 * std::mem::size_of::<TextVertexMember>() as u64;
+            for quads in quad_draw_ranges(fg_quads) {
+                text_render_pass.set_vertex_buffer(
+                    0,
+                    self.fg_vertex_buffer.slice(quads.start as u64 * fg_quad_bytes..),
+                );
+                text_render_pass.draw_indexed(0..quads.len() as u32 * 6, 0, 0..1);
+            }
         } else {
             let _clear_pass = encoder.begin_render_pass(&RenderPassDescriptor {
                 label: Some("Terminal Clear Pass"),
                 color_attachments: &[Some(RenderPassColorAttachment {
-                    view: target,
-                    resolve_target: None,
+                    view,
+                    resolve_target,
                     ops: Operations {
                         load,
                         store: StoreOp::Store,
@@ -913,5 +1638,201 @@ impl TerminalGpuState {
                 ..Default::default()
             });
         }
+
+        msaa_target_recreated && draw.load_previous
+    }
+}
+
+/// Everything [`TerminalTexture::record_render`](crate::setup::TerminalTexture::record_render)
+/// needs to render one terminal on a caller's own `Device`, outside the
+/// bevy render world: a private atlas and pipeline pair (there is no
+/// `SharedFontGpuStore` to share them through), the per-terminal buffers,
+/// and the destination texture itself. Created on the first call.
+pub(crate) struct StandaloneGpuState {
+    shared: SharedFontGpuState,
+    terminal: TerminalGpuState,
+    texture: Texture,
+    view: TextureView,
+}
+
+impl StandaloneGpuState {
+    /// `format` is the destination texture's - the same as a bevy-side
+    /// destination image's (`TerminalTexture::format`). It and
+    /// `sample_count` are used as-is: without an `Adapter` there is nothing
+    /// to validate them against (see [`supported_sample_count`]).
+    pub(crate) fn new(
+        device: &Device,
+        queue: &Queue,
+        format: TextureFormat,
+        width: u32,
+        height: u32,
+        sample_count: u32,
+    ) -> Self {
+        let mut shared =
+            SharedFontGpuState::new(device, queue, format, &CompositorShaders::default());
+        shared.ensure_compositors(device, format, sample_count);
+        let terminal = TerminalGpuState::new(device, &shared, format, sample_count, sample_count);
+        let texture = device.create_texture(&TextureDescriptor {
+            label: Some("Terminal Standalone Target"),
+            size: Extent3d {
+                width: width.max(1),
+                height: height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format,
+            usage: TextureUsages::RENDER_ATTACHMENT
+                | TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_SRC
+                | TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&TextureViewDescriptor::default());
+        Self {
+            shared,
+            terminal,
+            texture,
+            view,
+        }
+    }
+
+    /// `true` if this state can keep rendering a `width` x `height`
+    /// terminal into `format` at `sample_count` - otherwise the caller
+    /// builds a new one (and, since the new texture starts blank, a full
+    /// payload for it).
+    pub(crate) fn matches(
+        &self,
+        format: TextureFormat,
+        width: u32,
+        height: u32,
+        sample_count: u32,
+    ) -> bool {
+        self.texture.format() == format
+            && self.texture.width() == width.max(1)
+            && self.texture.height() == height.max(1)
+            && self.terminal.requested_sample_count() == sample_count
+    }
+
+    pub(crate) fn texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    /// Upload `uploads` to the atlas, then record `draw` and `overlays`
+    /// into `encoder` - the same sequence `render_tui_textures` runs for
+    /// one terminal. Returns `true` if the caller must queue a full redraw
+    /// (see [`TerminalGpuState::render`]).
+    pub(crate) fn record(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        encoder: &mut CommandEncoder,
+        uploads: &[(crate::utils::text_atlas::CacheRect, Vec<u32>)],
+        draw: &TerminalDrawPayload,
+        overlays: &[PixmapOverlay],
+    ) -> bool {
+        self.shared.upload_glyphs(queue, uploads);
+        let needs_full_redraw =
+            self.terminal
+                .render(device, queue, &self.shared, encoder, &self.texture, &self.view, draw);
+        for overlay in overlays {
+            overlay.record(device, encoder, &self.texture);
+        }
+        needs_full_redraw
+    }
+}
+
+// ============================================================================
+// Test: splitting a frame's quads into bounded draw calls. Pure CPU - the
+// ranges and limits are computed before any wgpu call.
+// ============================================================================
+
+#[cfg(test)]
+mod draw_split_tests {
+    use super::*;
+
+    #[test]
+    fn quad_draw_ranges_cover_every_quad_in_bounded_chunks() {
+        assert_eq!(quad_draw_ranges(0).count(), 0);
+        assert_eq!(quad_draw_ranges(5).collect::<Vec<_>>(), vec![0..5]);
+
+        let total = 2 * MAX_QUADS_PER_DRAW + 7;
+        let ranges: Vec<_> = quad_draw_ranges(total).collect();
+        assert_eq!(ranges.len(), 3);
+        let mut next = 0;
+        for range in &ranges {
+            assert_eq!(range.start, next, "ranges are contiguous");
+            assert!(range.len() as u32 <= MAX_QUADS_PER_DRAW);
+            next = range.end;
+        }
+        assert_eq!(next, total);
+
+        // No overflow at the very top of the u32 range either.
+        assert_eq!(quad_draw_ranges(u32::MAX).last().unwrap().end, u32::MAX);
+    }
+
+    #[test]
+    fn drawable_quads_clamps_to_the_buffer_limit() {
+        assert_eq!(drawable_quads(40, 16, u64::MAX), 10);
+        // 3 quads of 64 bytes fit in 200 bytes.
+        assert_eq!(drawable_quads(40, 16, 200), 3);
+        assert_eq!(drawable_quads(0, 16, 200), 0);
+    }
+}
+
+// ============================================================================
+// Test: pixmap overlay cropping, demultiplying and row padding. Pure CPU -
+// `record`'s wgpu copy itself needs a device and isn't exercised here.
+// ============================================================================
+
+#[cfg(test)]
+mod overlay_tests {
+    use super::*;
+
+    fn pixmap(width: u32, height: u32, color: tiny_skia::Color) -> tiny_skia::Pixmap {
+        let mut pixmap = tiny_skia::Pixmap::new(width, height).unwrap();
+        pixmap.fill(color);
+        pixmap
+    }
+
+    #[test]
+    fn from_pixmap_crops_to_the_region_and_demultiplies() {
+        let half_red = tiny_skia::Color::from_rgba8(255, 0, 0, 128);
+        let overlay = PixmapOverlay::from_pixmap(&pixmap(10, 6, half_red), [8, 16], 4, 8).unwrap();
+        assert_eq!((overlay.origin, overlay.width, overlay.height), ([8, 16], 4, 6));
+        assert_eq!(overlay.rgba.len(), 4 * 6 * 4);
+        // Straight alpha: full red at half coverage, not premultiplied 128.
+        assert_eq!(&overlay.rgba[..4], &[255, 0, 0, 128]);
+
+        assert!(PixmapOverlay::from_pixmap(&pixmap(10, 6, half_red), [0, 0], 0, 8).is_none());
+    }
+
+    #[test]
+    fn hdr_overlay_channels_widen_to_half_floats() {
+        assert_eq!(unorm8_to_f16_bits(0), 0);
+        assert_eq!(unorm8_to_f16_bits(255), 0x3C00, "1.0");
+        assert_eq!(unorm8_to_f16_bits(128), 0x3804, "the half nearest 128/255");
+        assert_eq!(unorm8_to_f16_bits(1), 0x1C04, "the half nearest 1/255");
+        for value in 1..=255u8 {
+            assert!(unorm8_to_f16_bits(value) > unorm8_to_f16_bits(value - 1));
+        }
+    }
+
+    #[test]
+    fn padded_rows_align_and_clip_to_the_target() {
+        let white = tiny_skia::Color::WHITE;
+        let overlay = PixmapOverlay::from_pixmap(&pixmap(3, 2, white), [10, 4], 3, 2).unwrap();
+
+        let (width, height, bytes) = overlay.padded_rows(64, 64).unwrap();
+        assert_eq!((width, height), (3, 2));
+        assert_eq!(bytes.len(), 2 * COPY_BYTES_PER_ROW_ALIGNMENT as usize);
+        assert!(bytes[..12].iter().all(|&b| b == 255));
+        assert!(bytes[12..COPY_BYTES_PER_ROW_ALIGNMENT as usize].iter().all(|&b| b == 0));
+
+        // Target shrank under the overlay: only the part still inside.
+        let (width, height, _) = overlay.padded_rows(11, 5).unwrap();
+        assert_eq!((width, height), (1, 1));
+        assert!(overlay.padded_rows(10, 64).is_none());
     }
 }
@@ -14,6 +14,8 @@ mod braille;
 mod powerline;
 mod primitives;
 
+pub use powerline::{PowerlineShape, PowerlineStyle};
+
 use tiny_skia::Pixmap;
 
 /// Check if a character should be rendered programmatically
@@ -26,6 +28,12 @@ pub fn is_programmatic_glyph(c: char) -> bool {
     )
 }
 
+/// Check if a character is one of the powerline separators, whose
+/// geometry follows the terminal's [`PowerlineStyle`]
+pub fn is_powerline_glyph(c: char) -> bool {
+    matches!(c, '\u{E0B0}'..='\u{E0BF}')
+}
+
 /// Render a programmatic glyph to a bitmap
 ///
 /// Returns a tiny-skia Pixmap containing the rendered glyph, or None if the
@@ -35,16 +43,61 @@ pub fn is_programmatic_glyph(c: char) -> bool {
 /// * `c` - The Unicode character to render
 /// * `width` - Width of the glyph cell in pixels
 /// * `height` - Height of the glyph cell in pixels
-pub fn render_programmatic_glyph(c: char, width: u32, height: u32) -> Option<Pixmap> {
-    match c {
-        '\u{2500}'..='\u{257F}' => box_drawing::render(c, width, height),
-        '\u{2580}'..='\u{259F}' => block_elements::render(c, width, height),
-        '\u{2800}'..='\u{28FF}' => braille::render(c, width, height),
-        '\u{E0B0}'..='\u{E0BF}' => powerline::render(c, width, height),
+/// * `powerline` - Separator geometry, used for U+E0B0–U+E0BF only
+/// * `supersample` - Render at this multiple of the cell size and
+///   box-filter back down, for smoother arcs and diagonals; `1` (or `0`)
+///   renders at cell size directly
+pub fn render_programmatic_glyph(
+    c: char,
+    width: u32,
+    height: u32,
+    powerline: &PowerlineStyle,
+    supersample: u32,
+) -> Option<Pixmap> {
+    let factor = supersample.max(1);
+    let (render_width, render_height) = (width * factor, height * factor);
+    let pixmap = match c {
+        '\u{2500}'..='\u{257F}' => box_drawing::render(c, render_width, render_height),
+        '\u{2580}'..='\u{259F}' => block_elements::render(c, render_width, render_height),
+        '\u{2800}'..='\u{28FF}' => braille::render(c, render_width, render_height),
+        '\u{E0B0}'..='\u{E0BF}' => powerline::render(c, render_width, render_height, powerline),
         _ => None,
+    }?;
+    if factor == 1 {
+        Some(pixmap)
+    } else {
+        downsample(&pixmap, factor)
     }
 }
 
+/// Box-filter `pixmap` down by `factor` on each axis: every output pixel
+/// is the average of a `factor` x `factor` block. Averaging tiny-skia's
+/// premultiplied values keeps partially covered edges correctly weighted.
+fn downsample(pixmap: &Pixmap, factor: u32) -> Option<Pixmap> {
+    let (width, height) = (pixmap.width() / factor, pixmap.height() / factor);
+    let mut out = Pixmap::new(width, height)?;
+    let src = pixmap.data();
+    let stride = pixmap.width() as usize * 4;
+    let samples = factor * factor;
+    for (i, pixel) in out.data_mut().chunks_exact_mut(4).enumerate() {
+        let (x, y) = (i % width as usize, i / width as usize);
+        let mut sum = [0u32; 4];
+        for sy in 0..factor as usize {
+            let row = (y * factor as usize + sy) * stride;
+            for sx in 0..factor as usize {
+                let at = row + (x * factor as usize + sx) * 4;
+                for (total, &channel) in sum.iter_mut().zip(&src[at..at + 4]) {
+                    *total += channel as u32;
+                }
+            }
+        }
+        for (channel, total) in pixel.iter_mut().zip(sum) {
+            *channel = ((total + samples / 2) / samples) as u8;
+        }
+    }
+    Some(out)
+}
+
 /// Get an iterator over all programmatic glyphs for eager pre-population
 ///
 /// This returns all 440 glyphs that should be pre-rendered into the atlas:
@@ -89,6 +142,48 @@ mod tests {
         assert!(!is_programmatic_glyph('あ'));
     }
 
+    #[test]
+    fn downsample_averages_each_block() {
+        let mut pixmap = Pixmap::new(4, 2).unwrap();
+        // Left block fully opaque white, right block one opaque pixel of four.
+        for (i, pixel) in pixmap.data_mut().chunks_exact_mut(4).enumerate() {
+            let (x, y) = (i % 4, i / 4);
+            if x < 2 || (x == 2 && y == 0) {
+                pixel.copy_from_slice(&[255, 255, 255, 255]);
+            }
+        }
+        let small = downsample(&pixmap, 2).unwrap();
+        assert_eq!((small.width(), small.height()), (2, 1));
+        assert_eq!(small.data(), &[255, 255, 255, 255, 64, 64, 64, 64]);
+    }
+
+    #[test]
+    fn supersampling_keeps_solid_fills_and_smooths_arcs() {
+        let style = PowerlineStyle::default();
+        // Stroke widths are rounded at the rendered size; a 20px-tall cell
+        // gets the same 2px stroke at 1x and 4x.
+        let (width, height) = (10, 20);
+
+        // A full block covers every sample, so it downsamples to itself.
+        let block = render_programmatic_glyph('█', width, height, &style, 1).unwrap();
+        let block_4x = render_programmatic_glyph('█', width, height, &style, 4).unwrap();
+        assert_eq!(block.data(), block_4x.data());
+
+        // A rounded corner: same cell size and about the same ink, but
+        // with more partial-coverage pixels along the curve.
+        let arc = render_programmatic_glyph('╭', width, height, &style, 1).unwrap();
+        let arc_4x = render_programmatic_glyph('╭', width, height, &style, 4).unwrap();
+        assert_eq!((arc_4x.width(), arc_4x.height()), (width, height));
+        let alphas = |p: &Pixmap| p.data().chunks_exact(4).map(|px| px[3] as u32).collect::<Vec<_>>();
+        let (ink, ink_4x): (u32, u32) = (alphas(&arc).iter().sum(), alphas(&arc_4x).iter().sum());
+        assert!(ink.abs_diff(ink_4x) * 4 < ink, "coverage changed too much: {ink} vs {ink_4x}");
+        let partial = |a: &[u32]| a.iter().filter(|&&a| a > 0 && a < 255).count();
+        assert!(
+            partial(&alphas(&arc_4x)) > partial(&alphas(&arc)),
+            "supersampling should add anti-aliased edge pixels"
+        );
+    }
+
     #[test]
     fn test_glyph_count() {
         let count = all_programmatic_glyphs().count();
@@ -4,34 +4,166 @@
 // These are commonly used in shell prompts and status bars.
 
 use super::primitives::*;
-use tiny_skia::Pixmap;
+use tiny_skia::{Color, Pixmap};
 
-pub fn render(c: char, width: u32, height: u32) -> Option<Pixmap> {
+/// Outline of the arrow separators (U+E0B0–U+E0B3).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PowerlineShape {
+    /// Straight edges meeting in a point - the classic powerline arrow.
+    #[default]
+    Sharp,
+    /// A half-oval bulging towards the tip, for fonts whose separators
+    /// are drawn rounded.
+    Rounded,
+}
+
+/// Geometry of the programmatically drawn powerline separators - set per
+/// terminal with [`TerminalBuilder::with_powerline_style`](crate::TerminalBuilder::with_powerline_style)
+/// or [`TerminalConfig::powerline_style`](crate::TerminalConfig::powerline_style).
+///
+/// The default reproduces the built-in rendering exactly. Glyphs drawn in
+/// a non-default style are cached under their own atlas keys, so
+/// terminals sharing one `Fonts` can use different styles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PowerlineStyle {
+    /// Outline of the arrow separators. Default [`PowerlineShape::Sharp`].
+    pub shape: PowerlineShape,
+    /// How far the arrows and curves (U+E0B0–U+E0B7) reach across the
+    /// cell, as a fraction of its width - lower values give a steeper,
+    /// narrower separator. Clamped to `0.1..=1.0`; default `1.0`.
+    pub reach: f32,
+    /// Multiplier on the stroke width of the hollow and slash variants, to
+    /// match lighter or heavier fonts. Clamped to `0.25..=4.0`; default
+    /// `1.0`.
+    pub weight: f32,
+}
+
+impl Default for PowerlineStyle {
+    fn default() -> Self {
+        Self {
+            shape: PowerlineShape::Sharp,
+            reach: 1.0,
+            weight: 1.0,
+        }
+    }
+}
+
+impl PowerlineStyle {
+    /// `reach`, clamped to the range drawing uses.
+    fn clamped_reach(&self) -> f32 {
+        self.reach.clamp(0.1, 1.0)
+    }
+
+    /// `weight`, clamped to the range drawing uses.
+    fn clamped_weight(&self) -> f32 {
+        self.weight.clamp(0.25, 4.0)
+    }
+
+    /// Value mixed into the atlas key's font id for powerline glyphs, so
+    /// each style gets its own cache entries. `0` for the default style,
+    /// keeping its keys identical to the ones `populate_programmatic_glyphs`
+    /// has always produced. Built from the clamped fields, so styles that
+    /// draw identically share entries.
+    pub(crate) fn cache_salt(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let default = Self::default();
+        let key = |style: &Self| {
+            (style.shape, style.clamped_reach().to_bits(), style.clamped_weight().to_bits())
+        };
+        let (shape, reach, weight) = key(self);
+        if (shape, reach, weight) == key(&default) {
+            return 0;
+        }
+        let mut hasher = std::hash::DefaultHasher::new();
+        (shape == PowerlineShape::Rounded).hash(&mut hasher);
+        reach.hash(&mut hasher);
+        weight.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Points along a half-oval spanning the full cell height whose widest
+/// point sits `depth` pixels from `base_x`, in top-to-bottom order.
+/// `direction` is `1.0` to bulge right, `-1.0` to bulge left.
+fn half_oval(base_x: f32, depth: f32, direction: f32, h: f32, segments: usize) -> Vec<(f32, f32)> {
+    (0..=segments)
+        .map(|i| {
+            let t = i as f32 / segments as f32;
+            let normalized_y = 2.0 * t - 1.0;
+            let x_pos = base_x + direction * depth * (1.0 - normalized_y * normalized_y).sqrt();
+            (x_pos, t * h)
+        })
+        .collect()
+}
+
+/// Draw the arrow separators (U+E0B0–U+E0B3): `base_x` is the flat edge,
+/// `tip_x` the point (or the widest point of the rounded shape).
+#[allow(clippy::too_many_arguments)]
+fn draw_arrow(
+    pixmap: &mut Pixmap,
+    style: &PowerlineStyle,
+    base_x: f32,
+    tip_x: f32,
+    h: f32,
+    solid: bool,
+    stroke: f32,
+    color: Color,
+) {
+    match style.shape {
+        PowerlineShape::Sharp => {
+            if solid {
+                draw_triangle(pixmap, base_x, 0.0, base_x, h, tip_x, h / 2.0, color);
+            } else {
+                draw_line(pixmap, base_x, 0.0, tip_x, h / 2.0, stroke, color);
+                draw_line(pixmap, base_x, h, tip_x, h / 2.0, stroke, color);
+            }
+        }
+        PowerlineShape::Rounded => {
+            let direction = (tip_x - base_x).signum();
+            let depth = (tip_x - base_x).abs();
+            if solid {
+                let mut points = vec![(base_x, h), (base_x, 0.0)];
+                points.extend(half_oval(base_x, depth, direction, h, 60));
+                draw_polygon(pixmap, &points, color);
+            } else {
+                let points = half_oval(base_x, depth, direction, h, 30);
+                for pair in points.windows(2) {
+                    draw_line(
+                        pixmap, pair[0].0, pair[0].1, pair[1].0, pair[1].1, stroke, color,
+                    );
+                }
+            }
+        }
+    }
+}
+
+pub fn render(c: char, width: u32, height: u32, style: &PowerlineStyle) -> Option<Pixmap> {
     let mut pixmap = Pixmap::new(width, height)?;
     let w = width as f32;
     let h = height as f32;
     let color = default_color();
-    let stroke = stroke_width(height) * 0.5;
+    let stroke = stroke_width(height) * 0.5 * style.clamped_weight();
+    // Horizontal extent of the arrows and curves.
+    let d = w * style.clamped_reach();
 
     match c {
         // ═══ Solid Triangles ═══
         '\u{E0B0}' => {
             // Right-pointing solid triangle
-            draw_triangle(&mut pixmap, 0.0, 0.0, 0.0, h, w, h / 2.0, color);
+            draw_arrow(&mut pixmap, style, 0.0, d, h, true, stroke, color);
         }
         '\u{E0B1}' => {
             // Right-pointing hollow triangle (line)
-            draw_line(&mut pixmap, 0.0, 0.0, w, h / 2.0, stroke, color);
-            draw_line(&mut pixmap, 0.0, h, w, h / 2.0, stroke, color);
+            draw_arrow(&mut pixmap, style, 0.0, d, h, false, stroke, color);
         }
         '\u{E0B2}' => {
             // Left-pointing solid triangle
-            draw_triangle(&mut pixmap, w, 0.0, w, h, 0.0, h / 2.0, color);
+            draw_arrow(&mut pixmap, style, w, w - d, h, true, stroke, color);
         }
         '\u{E0B3}' => {
             // Left-pointing hollow triangle (line)
-            draw_line(&mut pixmap, w, 0.0, 0.0, h / 2.0, stroke, color);
-            draw_line(&mut pixmap, w, h, 0.0, h / 2.0, stroke, color);
+            draw_arrow(&mut pixmap, style, w, w - d, h, false, stroke, color);
         }
 
         // ═══ Curved Variants ═══
@@ -49,7 +181,7 @@ pub fn render(c: char, width: u32, height: u32) -> Option<Pixmap> {
             for i in (0..=segments).rev() {
                 let t = i as f32 / segments as f32;
                 let normalized_y = 2.0 * t - 1.0;
-                let x_pos = w - (w * (1.0 - normalized_y * normalized_y).sqrt());
+                let x_pos = w - (d * (1.0 - normalized_y * normalized_y).sqrt());
                 let y_pos = t * h;
                 points.push((x_pos, y_pos));
             }
@@ -67,9 +199,9 @@ pub fn render(c: char, width: u32, height: u32) -> Option<Pixmap> {
                 let norm_y1 = 2.0 * t1 - 1.0;
                 let norm_y2 = 2.0 * t2 - 1.0;
 
-                let x1 = w - (w * (1.0 - norm_y1 * norm_y1).sqrt());
+                let x1 = w - (d * (1.0 - norm_y1 * norm_y1).sqrt());
                 let y1 = t1 * h;
-                let x2 = w - (w * (1.0 - norm_y2 * norm_y2).sqrt());
+                let x2 = w - (d * (1.0 - norm_y2 * norm_y2).sqrt());
                 let y2 = t2 * h;
 
                 draw_line(&mut pixmap, x1, y1, x2, y2, stroke, color);
@@ -92,7 +224,7 @@ pub fn render(c: char, width: u32, height: u32) -> Option<Pixmap> {
             for i in 0..=segments {
                 let t = i as f32 / segments as f32;
                 let normalized_y = 2.0 * t - 1.0;
-                let x_pos = w * (1.0 - normalized_y * normalized_y).sqrt();
+                let x_pos = d * (1.0 - normalized_y * normalized_y).sqrt();
                 let y_pos = t * h;
                 points.push((x_pos, y_pos));
             }
@@ -109,9 +241,9 @@ pub fn render(c: char, width: u32, height: u32) -> Option<Pixmap> {
                 let norm_y1 = 2.0 * t1 - 1.0;
                 let norm_y2 = 2.0 * t2 - 1.0;
 
-                let x1 = w * (1.0 - norm_y1 * norm_y1).sqrt();
+                let x1 = d * (1.0 - norm_y1 * norm_y1).sqrt();
                 let y1 = t1 * h;
-                let x2 = w * (1.0 - norm_y2 * norm_y2).sqrt();
+                let x2 = d * (1.0 - norm_y2 * norm_y2).sqrt();
                 let y2 = t2 * h;
 
                 draw_line(&mut pixmap, x1, y1, x2, y2, stroke, color);
@@ -168,13 +300,113 @@ mod tests {
 
     #[test]
     fn test_powerline_right_triangle() {
-        let pixmap = render('\u{E0B0}', 32, 32).unwrap();
+        let pixmap = render('\u{E0B0}', 32, 32, &PowerlineStyle::default()).unwrap();
         assert!(pixmap.pixels().iter().any(|p| p.alpha() > 0));
     }
 
     #[test]
     fn test_powerline_left_triangle() {
-        let pixmap = render('\u{E0B2}', 32, 32).unwrap();
+        let pixmap = render('\u{E0B2}', 32, 32, &PowerlineStyle::default()).unwrap();
         assert!(pixmap.pixels().iter().any(|p| p.alpha() > 0));
     }
+
+    fn coverage(pixmap: &Pixmap) -> u32 {
+        pixmap.pixels().iter().map(|p| u32::from(p.alpha())).sum()
+    }
+
+    #[test]
+    fn test_powerline_rounded_shape_differs_from_sharp() {
+        let rounded = PowerlineStyle {
+            shape: PowerlineShape::Rounded,
+            ..PowerlineStyle::default()
+        };
+        for c in ['\u{E0B0}', '\u{E0B1}', '\u{E0B2}', '\u{E0B3}'] {
+            let sharp = render(c, 16, 32, &PowerlineStyle::default()).unwrap();
+            let round = render(c, 16, 32, &rounded).unwrap();
+            assert_ne!(sharp.data(), round.data(), "U+{:04X}", c as u32);
+        }
+        // A half-oval with the same tip covers more of the cell than a
+        // triangle.
+        let sharp = render('\u{E0B0}', 16, 32, &PowerlineStyle::default()).unwrap();
+        let round = render('\u{E0B0}', 16, 32, &rounded).unwrap();
+        assert!(coverage(&round) > coverage(&sharp));
+    }
+
+    #[test]
+    fn test_powerline_reach_narrows_the_separator() {
+        let narrow = PowerlineStyle {
+            reach: 0.5,
+            ..PowerlineStyle::default()
+        };
+        let full = render('\u{E0B0}', 16, 32, &PowerlineStyle::default()).unwrap();
+        let half = render('\u{E0B0}', 16, 32, &narrow).unwrap();
+        assert!(coverage(&half) < coverage(&full));
+        // Nothing past the tip: the right half of every row stays empty.
+        for y in 0..32 {
+            for x in 9..16 {
+                assert_eq!(half.pixel(x, y).unwrap().alpha(), 0, "({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn test_powerline_weight_thickens_strokes() {
+        let heavy = PowerlineStyle {
+            weight: 2.0,
+            ..PowerlineStyle::default()
+        };
+        for c in ['\u{E0B1}', '\u{E0B9}'] {
+            let normal = render(c, 16, 32, &PowerlineStyle::default()).unwrap();
+            let thick = render(c, 16, 32, &heavy).unwrap();
+            assert!(coverage(&thick) > coverage(&normal), "U+{:04X}", c as u32);
+        }
+        // Solid shapes have no stroke to thicken.
+        let normal = render('\u{E0B0}', 16, 32, &PowerlineStyle::default()).unwrap();
+        let thick = render('\u{E0B0}', 16, 32, &heavy).unwrap();
+        assert_eq!(normal.data(), thick.data());
+    }
+
+    #[test]
+    fn test_powerline_cache_salt_is_zero_only_for_default() {
+        assert_eq!(PowerlineStyle::default().cache_salt(), 0);
+        let rounded = PowerlineStyle {
+            shape: PowerlineShape::Rounded,
+            ..PowerlineStyle::default()
+        };
+        let narrow = PowerlineStyle {
+            reach: 0.75,
+            ..PowerlineStyle::default()
+        };
+        assert_ne!(rounded.cache_salt(), 0);
+        assert_ne!(narrow.cache_salt(), 0);
+        assert_ne!(rounded.cache_salt(), narrow.cache_salt());
+    }
+
+    #[test]
+    fn test_powerline_cache_salt_keys_on_clamped_fields() {
+        // Past the clamp, a style draws like the default - and caches
+        // like it.
+        let far = PowerlineStyle {
+            reach: 3.0,
+            weight: 0.0,
+            ..PowerlineStyle::default()
+        };
+        let clamped = PowerlineStyle {
+            reach: 1.0,
+            weight: 0.25,
+            ..PowerlineStyle::default()
+        };
+        assert_eq!(far.cache_salt(), clamped.cache_salt());
+
+        // Fields that used to overlap bit ranges under XOR stay apart.
+        let mut salts = std::collections::HashSet::new();
+        for shape in [PowerlineShape::Sharp, PowerlineShape::Rounded] {
+            for reach in [0.1, 0.5, 0.75, 1.0] {
+                for weight in [0.25, 0.5, 1.0, 2.0, 4.0] {
+                    salts.insert(PowerlineStyle { shape, reach, weight }.cache_salt());
+                }
+            }
+        }
+        assert_eq!(salts.len(), 2 * 4 * 5);
+    }
 }
@@ -1,8 +1,10 @@
 struct VertexOutput {
     @location(0) @interpolate(flat) BgColor: u32,
+    @location(1) Pixel: vec2<f32>,
     @builtin(position) gl_Position: vec4<f32>,
 }
 
+// xy: screen size in pixels, z: corner radius in pixels.
 @group(0) @binding(0)
 var<uniform> ScreenSize: vec4<f32>;
 
@@ -12,7 +14,7 @@ fn vs_main(
     @location(1) BgColor: u32,
 ) -> VertexOutput {
     let gl_Position = vec4<f32>((2.0 * VertexCoord / ScreenSize.xy - 1.0) * vec2(1.0, -1.0), 0.0, 1.0);
-    return VertexOutput(BgColor, gl_Position);
+    return VertexOutput(BgColor, VertexCoord, gl_Position);
 }
 
 struct FragmentOutput {
@@ -28,9 +30,27 @@ fn unpack_color(color: u32) -> vec4<f32> {
     );
 }
 
+// Coverage of the pixel centered at `pixel` by the screen rect with its
+// corners rounded to `ScreenSize.z` pixels (capped at half the shorter
+// side) - 1.0 everywhere when the radius is 0. A signed distance to the
+// rounded rect, antialiased over one pixel.
+fn corner_coverage(pixel: vec2<f32>) -> f32 {
+    let half_size = ScreenSize.xy * 0.5;
+    let radius = min(ScreenSize.z, min(half_size.x, half_size.y));
+    if radius <= 0.0 {
+        return 1.0;
+    }
+    let q = abs(pixel - half_size) - (half_size - vec2<f32>(radius));
+    let distance = length(max(q, vec2<f32>(0.0))) + min(max(q.x, q.y), 0.0) - radius;
+    return clamp(0.5 - distance, 0.0, 1.0);
+}
 
 @fragment
-fn fs_main(@location(0) @interpolate(flat) BgColor: u32) -> FragmentOutput {
-    let bgColorUnpacked = unpack_color(BgColor);
+fn fs_main(
+    @location(0) @interpolate(flat) BgColor: u32,
+    @location(1) Pixel: vec2<f32>,
+) -> FragmentOutput {
+    var bgColorUnpacked = unpack_color(BgColor);
+    bgColorUnpacked.a *= corner_coverage(Pixel);
     return FragmentOutput(bgColorUnpacked);
 }
\ No newline at end of file
@@ -3,9 +3,12 @@ struct VertexOutput {
     @location(1) @interpolate(flat) FgColor: u32,
     @location(2) @interpolate(flat) UnderlinePos: u32,
     @location(3) @interpolate(flat) UnderlineColor: u32,
+    @location(4) Pixel: vec2<f32>,
     @builtin(position) gl_Position: vec4<f32>,
 }
 
+// xy: screen size in pixels, z: corner radius in pixels, w: text
+// brightness factor.
 @group(0) @binding(0)
 var<uniform> ScreenSize: vec4<f32>;
 
@@ -18,7 +21,7 @@ fn vs_main(
     @location(4) UnderlineColor: u32,
 ) -> VertexOutput {
     let gl_Position = vec4<f32>((2.0 * VertexCoord / ScreenSize.xy - 1.0) * vec2(1.0, -1.0), 0.0, 1.0);
-    return VertexOutput(UV, FgColor, UnderlinePos, UnderlineColor, gl_Position);
+    return VertexOutput(UV, FgColor, UnderlinePos, UnderlineColor, VertexCoord, gl_Position);
 }
 
 struct FragmentOutput {
@@ -42,6 +45,20 @@ fn unpack_color(color: u32) -> vec4<f32> {
     );
 }
 
+// Coverage of the pixel centered at `pixel` by the screen rect with its
+// corners rounded to `ScreenSize.z` pixels (capped at half the shorter
+// side) - 1.0 everywhere when the radius is 0. A signed distance to the
+// rounded rect, antialiased over one pixel.
+fn corner_coverage(pixel: vec2<f32>) -> f32 {
+    let half_size = ScreenSize.xy * 0.5;
+    let radius = min(ScreenSize.z, min(half_size.x, half_size.y));
+    if radius <= 0.0 {
+        return 1.0;
+    }
+    let q = abs(pixel - half_size) - (half_size - vec2<f32>(radius));
+    let distance = length(max(q, vec2<f32>(0.0))) + min(max(q.x, q.y), 0.0) - radius;
+    return clamp(0.5 - distance, 0.0, 1.0);
+}
 
 @fragment
 fn fs_main(
@@ -49,6 +66,7 @@ fn fs_main(
     @location(1) @interpolate(flat) FgColor: u32,
     @location(2) @interpolate(flat) UnderlinePos: u32,
     @location(3) @interpolate(flat) UnderlineColor: u32,
+    @location(4) Pixel: vec2<f32>,
 ) -> FragmentOutput {
     let underLineColorUnpacked = unpack_color(UnderlineColor);
 
@@ -64,6 +82,9 @@ fn fs_main(
     let yMax = UnderlinePos & 0xFFFFu;
     let yMin = UnderlinePos >> 16u;
     fgColor = select(fgColor, underLineColorUnpacked, u32(UV.y) >= yMin && u32(UV.y) < yMax);
+    // Past 1.0 only survives in a float (HDR) destination; an 8-bit one
+    // clamps it back.
+    fgColor = vec4<f32>(fgColor.rgb * ScreenSize.w, fgColor.a * corner_coverage(Pixel));
 
     return FragmentOutput(fgColor);
 }
\ No newline at end of file
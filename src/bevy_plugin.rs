@@ -4,7 +4,8 @@
 use bevy::prelude::*;
 use bevy::render::render_asset::RenderAssets;
 use bevy::render::renderer::{
-    FlushCommands, RenderContext, RenderDevice, RenderGraph, RenderGraphSystems, RenderQueue,
+    FlushCommands, RenderAdapter, RenderContext, RenderDevice, RenderGraph, RenderGraphSystems,
+    RenderQueue,
 };
 use bevy::render::texture::GpuImage;
 use bevy::render::{ExtractSchedule, MainWorld, RenderApp};
@@ -12,7 +13,12 @@ use std::collections::HashMap;
 use tracing::debug;
 use wgpu;
 
+use crate::backend::CompositorShaders;
+use crate::backend::PixmapOverlay;
 use crate::backend::SharedFontGpuState;
+use crate::backend::hdr_format_supported;
+use crate::backend::supported_sample_count;
+use crate::backend::HDR_TEXTURE_FORMAT;
 use crate::backend::TerminalDrawPayload;
 use crate::backend::TerminalGpuState;
 use crate::input::*;
@@ -55,6 +61,8 @@ impl TerminalPlugin {
     ///     mouse_enabled: true,
     ///     auto_focus: true,
     ///     focus_button: MouseButton::Left,
+    ///     snap_wide_glyphs: false,
+    ///     edit_actions: false,
     /// });
     /// ```
     pub fn new(config: TerminalInputConfig) -> Self {
@@ -111,10 +119,13 @@ impl Plugin for TerminalPlugin {
 
         // Register messages (events)
         app.add_message::<TerminalEvent>();
+        app.add_message::<TerminalTextureLost>();
 
         // Insert resources
         app.insert_resource(self.input_config.clone());
         app.insert_resource(TerminalFocus::default());
+        app.insert_resource(HoveredTerminal::default());
+        app.insert_resource(HeldKeys::default());
         app.insert_resource(CursorPosition::default());
 
         // Configure system sets with execution order
@@ -164,6 +175,24 @@ impl Plugin for TerminalPlugin {
             debug!("Auto-focus (Tab cycling) enabled");
         }
 
+        // Window focus loss cleanup (always enabled), after every system
+        // that can press a key or button or move focus this frame.
+        {
+            let system = window_focus_system.after(terminal_focus_system);
+            #[cfg(feature = "keyboard_input")]
+            let system = system.after(keyboard_input_system);
+            #[cfg(feature = "mouse_input")]
+            let system = system.after(mouse_input_system);
+            app.add_systems(Update, system.in_set(TerminalSystemSet::Input));
+        }
+
+        if self.input_config.move_on_focus {
+            let system = focus_hover_system.after(terminal_focus_system);
+            #[cfg(feature = "mouse_input")]
+            let system = system.after(mouse_input_system);
+            app.add_systems(Update, system.in_set(TerminalSystemSet::Input));
+        }
+
         // Declarative spawning: turn `TuiRequest` components into live
         // terminals. Scheduled before the Input set so a terminal
         // materialized this frame is already visible to the same frame's
@@ -173,12 +202,59 @@ impl Plugin for TerminalPlugin {
             crate::setup::materialize_tui_requests.before(TerminalSystemSet::Input),
         );
 
+        // Optional plugin-wide default colors - a no-op unless the app
+        // inserts a `TerminalTheme` resource. Right after materialization
+        // so a terminal spawned this frame is themed before its first
+        // user draw.
+        app.add_systems(
+            Update,
+            crate::theme::apply_terminal_theme
+                .after(crate::setup::materialize_tui_requests)
+                .before(TerminalSystemSet::Input),
+        );
+        // Same for the optional `TerminalDebug` switches.
+        app.add_systems(
+            Update,
+            crate::debug::apply_terminal_debug
+                .after(crate::setup::materialize_tui_requests)
+                .before(TerminalSystemSet::Input),
+        );
+
+        // Stats overlay sampling - a no-op unless the app inserts
+        // `TerminalDebugStats(true)`. After input, before the user's draw
+        // systems, so this frame's draw paints the refreshed text.
+        #[cfg(feature = "debug_stats")]
+        app.add_systems(
+            Update,
+            crate::debug_stats::update_debug_stats
+                .after(TerminalSystemSet::Input)
+                .before(TerminalSystemSet::UserUpdate),
+        );
+
         // Plugin-owned CPU-side plumbing for the `Tui` component: extracts a
         // draw payload from dirty terminals, so user drawing systems can
         // take zero render-resource parameters. The actual GPU render
         // happens in the render world - see `extract_tui_draws` /
         // `render_tui_textures` below.
         app.add_systems(Update, gpu_flush_system.in_set(TerminalSystemSet::Render));
+
+        // `TypewriterTerminal` reveals - drawn before this frame's flush so
+        // each newly revealed character renders the same frame.
+        app.add_message::<crate::typewriter::TypewriterFinished>()
+            .add_systems(
+                Update,
+                crate::typewriter::advance_typewriters
+                    .before(gpu_flush_system)
+                    .in_set(TerminalSystemSet::Render),
+            );
+        // `TerminalContent` renderers - likewise drawn right before the
+        // flush, which then renders exactly what they drew.
+        app.add_systems(
+            Update,
+            crate::content::draw_terminal_content
+                .before(gpu_flush_system)
+                .in_set(TerminalSystemSet::Render),
+        );
         #[cfg(feature = "3d")]
         app.add_systems(
             Update,
@@ -186,6 +262,18 @@ impl Plugin for TerminalPlugin {
                 .after(gpu_flush_system)
                 .in_set(TerminalSystemSet::Render),
         );
+        #[cfg(feature = "3d")]
+        app.add_systems(
+            Update,
+            apply_terminal_glow.in_set(TerminalSystemSet::Render),
+        );
+        #[cfg(feature = "3d")]
+        app.add_systems(
+            Update,
+            sync_terminal_materials
+                .after(gpu_flush_system)
+                .in_set(TerminalSystemSet::Render),
+        );
 
         // Attaching a Tui to an existing mesh. Runs early so the same-frame
         // Render pass sees the swapped material.
@@ -195,6 +283,22 @@ impl Plugin for TerminalPlugin {
             crate::setup::attach_terminal_system.in_set(TerminalSystemSet::Input),
         );
 
+        // Runtime-loaded compositor shaders, rebuilt whenever their files
+        // change on disk. Main-world half loads/watches the files; the
+        // render-world half (registered below) rebuilds the pipelines.
+        #[cfg(feature = "shader_hot_reload")]
+        {
+            app.init_resource::<crate::shader_reload::TerminalShaderFiles>()
+                .init_resource::<crate::shader_reload::ReloadedCompositorShaders>()
+                .add_plugins(bevy::render::extract_resource::ExtractResourcePlugin::<
+                    crate::shader_reload::ReloadedCompositorShaders,
+                >::default())
+                .add_systems(
+                    Update,
+                    crate::shader_reload::watch_compositor_shaders.in_set(TerminalSystemSet::Render),
+                );
+        }
+
         // Main-world half of the blocking-readback channel (see
         // `Tui::read_back_blocking`). Always inserted, even without a
         // render sub-app - `TuiReadbackChannel::request_blocking` degrades
@@ -224,10 +328,15 @@ impl Plugin for TerminalPlugin {
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app
                 .init_resource::<PendingTuiDraws>()
+                .init_resource::<PendingTuiOverlays>()
                 .init_resource::<TerminalGpuStore>()
                 .init_resource::<SharedFontGpuStore>()
+                .init_resource::<ActiveCompositorShaders>()
                 .init_resource::<PendingFontUploads>()
                 .init_resource::<LiveFontKeys>()
+                .init_resource::<RenderedTuiTargets>()
+                .init_resource::<LostTuiTextures>()
+                .init_resource::<VanishedTuiTargets>()
                 .init_resource::<PendingTuiReadbacks>()
                 .insert_resource(TuiReadbackReceiver(std::sync::Mutex::new(readback_rx)))
                 .add_systems(ExtractSchedule, extract_tui_draws)
@@ -255,6 +364,13 @@ impl Plugin for TerminalPlugin {
                         process_tui_readbacks.in_set(RenderGraphSystems::Finish),
                     ),
                 );
+            #[cfg(feature = "shader_hot_reload")]
+            render_app.add_systems(
+                RenderGraph,
+                crate::shader_reload::apply_compositor_reload
+                    .in_set(RenderGraphSystems::Begin)
+                    .before(render_tui_textures),
+            );
         }
 
         debug!("TerminalPlugin initialized with input handling");
@@ -264,13 +380,132 @@ impl Plugin for TerminalPlugin {
 /// Terminal dimensions component.
 ///
 /// Stores the actual terminal grid dimensions (columns and rows) and font metrics
-/// for accurate mouse coordinate conversion.
+/// for accurate mouse coordinate conversion. Non-exhaustive - build one
+/// with [`TerminalDimensions::new`].
 #[derive(Component, Debug, Clone, Copy)]
+#[non_exhaustive]
 pub struct TerminalDimensions {
     pub cols: u16,
     pub rows: u16,
     pub char_width_px: u32,
     pub char_height_px: u32,
+    /// Margin between the grid and the texture's edges - see
+    /// [`TerminalTexture::with_padding`](crate::setup::TerminalTexture::with_padding).
+    pub padding: crate::backend::Padding,
+}
+
+impl TerminalDimensions {
+    /// Dimensions of an unpadded `cols` x `rows` grid of
+    /// `char_width_px` x `char_height_px` cells; set `padding` afterwards
+    /// for a padded texture.
+    pub fn new(cols: u16, rows: u16, char_width_px: u32, char_height_px: u32) -> Self {
+        Self {
+            cols,
+            rows,
+            char_width_px,
+            char_height_px,
+            padding: crate::backend::Padding::default(),
+        }
+    }
+
+    /// The destination texture's pixel size: the grid plus its padding.
+    pub fn texture_size(&self) -> Vec2 {
+        let (width, height) = self.padding.texture_size(
+            self.cols as u32 * self.char_width_px,
+            self.rows as u32 * self.char_height_px,
+        );
+        Vec2::new(width as f32, height as f32)
+    }
+
+    /// Map a texture UV (`0..=1` across the whole, padded texture) to the
+    /// same point as a fraction of the cell grid - outside `0..=1` over
+    /// the padding.
+    pub fn texture_uv_to_grid_uv(&self, uv: Vec2) -> Vec2 {
+        let grid = Vec2::new(
+            (self.cols as u32 * self.char_width_px).max(1) as f32,
+            (self.rows as u32 * self.char_height_px).max(1) as f32,
+        );
+        let origin = Vec2::new(self.padding.left as f32, self.padding.top as f32);
+        (uv * self.texture_size() - origin) / grid
+    }
+
+    /// Texture-space pixel rect covered by a cell-space ratatui `Rect` -
+    /// for placing bevy-native overlays (borders, glows, sprites) exactly
+    /// over a widget's area. `min` is the top-left corner of the first
+    /// cell, `max` the bottom-right corner of the last; y grows downward,
+    /// as in the texture, and any [`padding`](Self::padding) is included.
+    /// The rect is clipped to the grid first, so an area running past the
+    /// right or bottom edge stops at the grid's edge, and one entirely
+    /// outside it comes back empty (zero size).
+    pub fn rect_to_pixels(&self, area: ratatui::layout::Rect) -> Rect {
+        let area = area.intersection(ratatui::layout::Rect::new(0, 0, self.cols, self.rows));
+        let (cw, ch) = (self.char_width_px as f32, self.char_height_px as f32);
+        let (left, top) = (self.padding.left as f32, self.padding.top as f32);
+        Rect::new(
+            left + area.x as f32 * cw,
+            top + area.y as f32 * ch,
+            left + area.right() as f32 * cw,
+            top + area.bottom() as f32 * ch,
+        )
+    }
+}
+
+/// Caps how often a terminal's content is re-rendered, in frames per
+/// second - independent of the app's own frame rate. Add it next to a
+/// [`Tui`] (on the same entity): a slowly-changing status panel in a 144Hz
+/// game can be capped at, say, `TerminalRenderRate(10.0)` and stop costing
+/// a GPU render every frame. `0.0` (or less) means uncapped, the same as
+/// leaving the component off.
+///
+/// Only the GPU side is throttled. `Tui::draw` still runs whenever your
+/// systems call it (and is cheap when nothing changed); between renders the
+/// texture keeps showing the last rendered content, and whatever was drawn
+/// in the meantime is rendered together at the next due frame. That means
+/// animations that update every frame - including
+/// [`TerminalBuilder::with_cell_fade`](crate::TerminalBuilder::with_cell_fade)
+/// fades - advance in steps of `1 / fps` seconds: intermediate frames are
+/// dropped, not delayed. A resize or lost-texture recovery is never held
+/// back.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct TerminalRenderRate(pub f32);
+
+/// Opt-in: only render this terminal while bevy considers it visible. Add
+/// it next to a [`Tui`] (on the same entity); without it terminals render
+/// whenever they change, visible or not.
+///
+/// A terminal counts as hidden when
+/// - its `InheritedVisibility` is hidden (`Visibility::Hidden` on it or an
+///   ancestor),
+/// - it is a rendered entity (a mesh or sprite - anything with a
+///   `VisibilityClass`) that no camera saw last frame, per its
+///   `ViewVisibility` - behind the camera, frustum-culled, off-screen,
+/// - it is a UI node laid out at zero size (feature `2d`), e.g.
+///   `Display::None` on it or an ancestor.
+///
+/// Like [`TerminalRenderRate`], only the GPU side is skipped: `Tui::draw`
+/// keeps working, and the terminal stays dirty, so whatever was drawn while
+/// hidden is rendered in one go as soon as it is visible again. Because
+/// `ViewVisibility` is computed after this plugin's systems, a terminal
+/// coming into view shows its old content for one frame first. Lost-texture
+/// recovery still goes ahead while hidden. Terminals that only appear in
+/// places bevy's visibility doesn't know about (a texture sampled by a
+/// custom shader, a `TuiKind::Headless` image) must not use this.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TerminalRenderWhenVisible;
+
+/// Whether a [`TerminalRenderWhenVisible`] terminal is hidden - see there.
+/// `class`: the entity has a `VisibilityClass`, so bevy's visibility
+/// checks keep its `ViewVisibility` up to date. `node_size`: its UI
+/// layout size, if it is a UI node.
+pub(crate) fn terminal_hidden(
+    inherited: Option<&InheritedVisibility>,
+    view: Option<&ViewVisibility>,
+    class: bool,
+    node_size: Option<Vec2>,
+) -> bool {
+    inherited.is_some_and(|inherited| !inherited.get())
+        || (class && view.is_some_and(|view| !view.get()))
+        || node_size.is_some_and(|size| size.cmple(Vec2::ZERO).any())
 }
 
 // ============================================================================
@@ -285,22 +520,44 @@ pub struct TerminalDimensions {
 /// the actual GPU render happens in the render world (`render_tui_textures`,
 /// below), which writes directly into the exact texture the destination
 /// material's bind group already references - no material touching
-/// anywhere.
+/// anywhere. Terminals with a [`TerminalRenderRate`] only flush when due
+/// (by virtual `Time`; uncapped in an app without one), and terminals with
+/// [`TerminalRenderWhenVisible`] only while visible. Blinking text and cell
+/// fade-ins advance by the same virtual `Time`.
 pub fn gpu_flush_system(
-    mut terminals: Query<(&mut Tui, Option<&mut TerminalDimensions>)>,
+    mut terminals: Query<(Entity, &mut Tui, Option<&mut TerminalDimensions>, Option<&TerminalRenderRate>)>,
+    visibility: Query<
+        (
+            Option<&InheritedVisibility>,
+            Option<&ViewVisibility>,
+            Has<bevy::camera::visibility::VisibilityClass>,
+        ),
+        With<TerminalRenderWhenVisible>,
+    >,
+    #[cfg(feature = "2d")] nodes: Query<&bevy::ui::ComputedNode>,
     mut images: ResMut<Assets<Image>>,
+    time: Option<Res<Time>>,
 ) {
-    for (mut tui, dimensions) in &mut terminals {
-        if let (Some((cols, rows)), Some(mut dimensions)) =
+    for (entity, mut tui, dimensions, rate) in &mut terminals {
+        if let (Some(_), Some(mut dimensions)) =
             (tui.apply_pending_resize(&mut images), dimensions)
         {
-            let size = tui.size_px();
-            dimensions.cols = cols;
-            dimensions.rows = rows;
-            dimensions.char_width_px = size.x / (cols.max(1) as u32);
-            dimensions.char_height_px = size.y / (rows.max(1) as u32);
+            *dimensions = tui.dimensions();
+        }
+        if let Ok((inherited, view, class)) = visibility.get(entity) {
+            #[cfg(feature = "2d")]
+            let node_size = nodes.get(entity).ok().map(bevy::ui::ComputedNode::size);
+            #[cfg(not(feature = "2d"))]
+            let node_size = None;
+            if terminal_hidden(inherited, view, class, node_size) {
+                continue;
+            }
+        }
+        // No rate: flush every frame (`fps` 0), on app time for blinking and fades.
+        match &time {
+            Some(time) => tui.flush_at_most(rate.map_or(0.0, |rate| rate.0), time.elapsed()),
+            None => tui.flush(),
         }
-        tui.flush();
     }
 }
 
@@ -326,8 +583,8 @@ fn resize_world_quad_meshes(
         return;
     };
     for (dimensions, height, mut mesh3d) in &mut terminals {
-        let aspect = dimensions.cols as f32 * dimensions.char_width_px as f32
-            / (dimensions.rows as f32 * dimensions.char_height_px as f32);
+        let size = dimensions.texture_size();
+        let aspect = size.x / size.y;
         let half_height = height.0 / 2.0;
         mesh3d.0 = meshes.add(Plane3d::new(
             Vec3::Z,
@@ -336,6 +593,88 @@ fn resize_world_quad_meshes(
     }
 }
 
+/// Pushes [`TerminalGlow`](crate::setup::TerminalGlow) changes into the
+/// entity's `StandardMaterial`. Registered automatically by
+/// `TerminalPlugin` (feature `3d`) in `TerminalSystemSet::Render`; keyed on
+/// `Changed` so an unchanging glow costs nothing per frame.
+#[cfg(feature = "3d")]
+fn apply_terminal_glow(
+    terminals: Query<
+        (&crate::setup::TerminalGlow, &MeshMaterial3d<bevy::pbr::StandardMaterial>),
+        Changed<crate::setup::TerminalGlow>,
+    >,
+    // `Option` for the same reason as in `resize_world_quad_meshes`.
+    materials: Option<ResMut<Assets<bevy::pbr::StandardMaterial>>>,
+) {
+    let Some(mut materials) = materials else {
+        return;
+    };
+    for (glow, material) in &terminals {
+        if let Some(mut material) = materials.get_mut(&material.0) {
+            glow.apply(&mut material);
+        }
+    }
+}
+
+/// Keeps every `StandardMaterial` showing a terminal in sync with it, so
+/// no app system has to. Rendering itself needs nothing: the render world
+/// draws into the very texture the material's bind group references, so
+/// a material is left alone frame to frame. Only when a terminal's images
+/// are recreated - a resize (applied by `gpu_flush_system`, which this
+/// runs after), [`Tui::set_sampler`](crate::setup::Tui::set_sampler),
+/// [`Tui::set_hdr`](crate::setup::Tui::set_hdr), a padding change - does
+/// each `StandardMaterial` whose base color or emissive texture is one of
+/// them, mirrors included, get marked modified, so bevy re-prepares its
+/// bind group against the new texture that same frame instead of holding
+/// on to the old one. Registered automatically by `TerminalPlugin`
+/// (feature `3d`) in `TerminalSystemSet::Render`. Custom material types
+/// (an `ExtendedMaterial`, `AttachMaterial::custom`) aren't known here;
+/// apps with those re-mark them the same way when they resize.
+#[cfg(feature = "3d")]
+fn sync_terminal_materials(
+    mut terminals: Query<&mut Tui>,
+    // `Option` for the same reason as in `resize_world_quad_meshes`.
+    materials: Option<ResMut<Assets<bevy::pbr::StandardMaterial>>>,
+) {
+    let mut recreated = std::collections::HashSet::new();
+    for mut tui in &mut terminals {
+        // Not a change to the `Tui` anyone filtering on `Changed` cares about.
+        let tui = tui.bypass_change_detection();
+        if tui.take_images_recreated() {
+            recreated.extend(tui.destinations());
+        }
+    }
+    let Some(mut materials) = materials else {
+        return;
+    };
+    if recreated.is_empty() {
+        return;
+    }
+    for id in materials_sampling(&materials, &recreated) {
+        // Taking it mutably is what marks it modified.
+        let _ = materials.get_mut(id);
+    }
+}
+
+/// The `StandardMaterial`s whose base color or emissive texture is one of
+/// `images` - see [`sync_terminal_materials`].
+#[cfg(feature = "3d")]
+fn materials_sampling(
+    materials: &Assets<bevy::pbr::StandardMaterial>,
+    images: &std::collections::HashSet<AssetId<Image>>,
+) -> Vec<AssetId<bevy::pbr::StandardMaterial>> {
+    materials
+        .iter()
+        .filter(|(_, material)| {
+            [&material.base_color_texture, &material.emissive_texture]
+                .into_iter()
+                .flatten()
+                .any(|texture| images.contains(&texture.id()))
+        })
+        .map(|(id, _)| id)
+        .collect()
+}
+
 // ============================================================================
 // Render-world GPU render (replaces the old GPU->GPU copy entirely)
 // ============================================================================
@@ -346,12 +685,20 @@ fn resize_world_quad_meshes(
 #[derive(Resource, Default)]
 struct PendingTuiDraws(HashMap<AssetId<Image>, TerminalDrawPayload>);
 
+/// Render-world resource: pixmap overlays extracted from each `Tui` (see
+/// [`TerminalTexture::overlay_pixmap`](crate::setup::TerminalTexture::overlay_pixmap)),
+/// keyed by destination `Image` asset id, in the order they were queued.
+/// Copied in by `render_tui_textures` after that destination's draw, and
+/// held back with it while the draw is still waiting on its `GpuImage`.
+#[derive(Resource, Default)]
+struct PendingTuiOverlays(HashMap<AssetId<Image>, Vec<PixmapOverlay>>);
+
 /// Render-world resource: the GPU pipelines/atlas texture for each terminal,
 /// keyed by destination `Image` asset id. Created lazily on a terminal's
 /// first render (`render_tui_textures`) and evicted automatically once the
 /// destination image itself is gone (see that function's doc comment).
 #[derive(Resource, Default)]
-struct TerminalGpuStore(HashMap<AssetId<Image>, TerminalGpuState>);
+pub(crate) struct TerminalGpuStore(pub(crate) HashMap<AssetId<Image>, TerminalGpuState>);
 
 /// Render-world resource: per-font glyph-atlas texture + compositor
 /// pipelines (IMPROVEMENT.md C3), keyed by [`crate::fonts::Fonts::identity`]
@@ -359,7 +706,14 @@ struct TerminalGpuStore(HashMap<AssetId<Image>, TerminalGpuState>);
 /// one entry here. Created lazily in `render_tui_textures`, evicted once no
 /// live `Tui` reports that font key anymore (see [`LiveFontKeys`]).
 #[derive(Resource, Default)]
-struct SharedFontGpuStore(HashMap<usize, SharedFontGpuState>);
+pub(crate) struct SharedFontGpuStore(pub(crate) HashMap<usize, SharedFontGpuState>);
+
+/// Render-world resource: the WGSL every new [`SharedFontGpuState`] builds
+/// its compositor pipelines from - the embedded shaders, unless the
+/// `shader_hot_reload` feature has swapped in runtime-loaded ones (see
+/// `crate::shader_reload`).
+#[derive(Resource, Default)]
+pub(crate) struct ActiveCompositorShaders(pub(crate) CompositorShaders);
 
 /// Render-world resource: glyph rasterizations queued by [`extract_tui_draws`]
 /// for each font, still waiting to be uploaded to that font's shared atlas.
@@ -371,6 +725,41 @@ struct SharedFontGpuStore(HashMap<usize, SharedFontGpuState>);
 #[derive(Resource, Default)]
 struct PendingFontUploads(HashMap<usize, Vec<(crate::utils::text_atlas::CacheRect, Vec<u32>)>>);
 
+/// Render-world resource: the wgpu texture each destination was last
+/// rendered into, keyed by destination `Image` asset id. A `GpuImage` whose
+/// texture id no longer matches was re-prepared underneath the terminal
+/// (device loss/reset, suspend/resume, an `Assets<Image>::get_mut` on the
+/// terminal's image, a resize) - its content is undefined, and a static
+/// terminal would otherwise never redraw it. See [`LostTuiTextures`].
+#[derive(Resource, Default)]
+struct RenderedTuiTargets(HashMap<AssetId<Image>, bevy::render::render_resource::TextureId>);
+
+/// Render-world resource: destinations `render_tui_textures` found recreated
+/// (see [`RenderedTuiTargets`]), consumed by the next [`extract_tui_draws`],
+/// which makes each affected `Tui` re-send its whole content
+/// ([`Tui::recover_lost_texture`](crate::setup::Tui::recover_lost_texture)).
+#[derive(Resource, Default)]
+struct LostTuiTextures(std::collections::HashSet<AssetId<Image>>);
+
+/// Render-world resource: destinations `render_tui_textures` rendered
+/// into before whose `GpuImage` is gone without a replacement. The next
+/// [`extract_tui_draws`] reports each one a live `Tui` still draws to as
+/// [`TerminalTextureLost`] (the rest belonged to despawned terminals) and
+/// forgets them all.
+#[derive(Resource, Default)]
+struct VanishedTuiTargets(std::collections::HashSet<AssetId<Image>>);
+
+/// Sent when a terminal's destination texture is gone from the render
+/// world without being recreated: its image asset was removed, or the GPU
+/// couldn't prepare it again (e.g. after a device reset). Names the `Tui`
+/// entity. The terminal has nothing to render into until the image comes
+/// back - it is then redrawn in full - so respawn the terminal or
+/// recreate its image if it doesn't. Textures recreated underneath a
+/// terminal (resize, suspend/resume, a re-prepared `GpuImage`) are
+/// recovered automatically and send nothing.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalTextureLost(pub Entity);
+
 /// Render-world resource: every font key currently reported by a live `Tui`
 /// entity, recomputed from scratch each extract. Used by `render_tui_textures`
 /// to evict [`SharedFontGpuStore`] entries for fonts no longer in use by any
@@ -389,12 +778,16 @@ struct LiveFontKeys(std::collections::HashSet<usize>);
 /// mutates the main world through [`MainWorld`] (rather than the read-only
 /// `Extract<Query>>`) because draining the payload - so a static terminal's
 /// next frame doesn't re-push the same draw - requires `&mut Tui`.
+#[allow(clippy::too_many_arguments)]
 fn extract_tui_draws(
     mut main_world: ResMut<MainWorld>,
     mut pending: ResMut<PendingTuiDraws>,
+    mut overlays: ResMut<PendingTuiOverlays>,
     mut font_uploads: ResMut<PendingFontUploads>,
     mut live_fonts: ResMut<LiveFontKeys>,
-    mut query_state: Local<Option<QueryState<&'static mut Tui>>>,
+    mut lost: ResMut<LostTuiTextures>,
+    mut vanished: ResMut<VanishedTuiTargets>,
+    mut query_state: Local<Option<QueryState<(Entity, &'static mut Tui)>>>,
 ) {
     // Cache the `QueryState` across frames (IMPROVEMENT.md D2) instead of
     // constructing a fresh one (archetype matching from scratch) every
@@ -403,7 +796,8 @@ fn extract_tui_draws(
     // needed here.
     let query = query_state.get_or_insert_with(|| main_world.query());
     live_fonts.0.clear();
-    for mut tui in query.iter_mut(&mut main_world) {
+    let mut lost_terminals = Vec::new();
+    for (entity, mut tui) in query.iter_mut(&mut main_world) {
         let (font_key, uploads) = tui.take_shared_font_uploads();
         live_fonts.0.insert(font_key);
         if !uploads.is_empty() {
@@ -424,14 +818,58 @@ fn extract_tui_draws(
         // frame's `Tui::flush` sees `pending_draw.is_some()` and upgrades
         // that next payload to a full one, which safely supersedes
         // whatever this one would have covered - nothing is lost.
-        if pending.0.contains_key(&tui.image_handle().id()) {
+        //
+        // Mirrors (`TerminalTexture::add_mirror`) get a clone of every
+        // payload, and are gated together with the primary destination:
+        // all of them must have rendered the previous payload before any
+        // takes the next, or a partial payload would skip rows on one.
+        let dests = tui.destinations();
+        if dests.iter().any(|dest| vanished.0.contains(dest)) {
+            tracing::warn!("terminal {entity:?} lost its destination texture");
+            lost_terminals.push(entity);
+            // Redrawn in full should the image come back.
+            lost.0.extend(dests.iter().copied());
+        }
+        let mut recovered = false;
+        for dest in &dests {
+            if lost.0.remove(dest) {
+                // Destination texture recreated (see `RenderedTuiTargets`):
+                // a full payload supersedes anything still queued for it.
+                debug!("terminal texture {dest:?} was recreated - redrawing in full");
+                recovered = true;
+            }
+        }
+        if recovered {
+            for dest in &dests {
+                pending.0.remove(dest);
+            }
+            tui.recover_lost_texture();
+        }
+
+        // Overlays never wait on `pending`: they are copied after whatever
+        // draw ends up rendered for this destination, in queue order.
+        let new_overlays = tui.take_overlays();
+        if !new_overlays.is_empty() {
+            for dest in &dests {
+                overlays.0.entry(*dest).or_default().extend(new_overlays.iter().cloned());
+            }
+        }
+
+        if dests.iter().any(|dest| pending.0.contains_key(dest)) {
             continue;
         }
 
         if let Some((dest, draw)) = tui.take_pending_draw() {
+            for mirror in &dests[1..] {
+                pending.0.insert(*mirror, draw.clone());
+            }
             pending.0.insert(dest, draw);
         }
     }
+    vanished.0.clear();
+    for entity in lost_terminals {
+        main_world.write_message(TerminalTextureLost(entity));
+    }
 }
 
 /// Render-world system: renders every pending draw payload queued by
@@ -460,21 +898,50 @@ fn extract_tui_draws(
 #[allow(clippy::too_many_arguments)]
 fn render_tui_textures(
     mut pending: ResMut<PendingTuiDraws>,
+    mut overlays: ResMut<PendingTuiOverlays>,
     mut store: ResMut<TerminalGpuStore>,
     mut font_store: ResMut<SharedFontGpuStore>,
     mut font_uploads: ResMut<PendingFontUploads>,
     live_fonts: Res<LiveFontKeys>,
+    shaders: Res<ActiveCompositorShaders>,
+    mut rendered_targets: ResMut<RenderedTuiTargets>,
+    mut lost: ResMut<LostTuiTextures>,
+    mut vanished: ResMut<VanishedTuiTargets>,
     gpu_images: Res<RenderAssets<GpuImage>>,
     render_device: Res<RenderDevice>,
+    render_adapter: Res<RenderAdapter>,
     render_queue: Res<RenderQueue>,
     mut render_context: RenderContext,
+    mut warned_hdr_unsupported: Local<bool>,
 ) {
     // A font with no live `Tui` left using it (its last terminal despawned)
     // has no destination image to key eviction off of the way
     // `TerminalGpuStore` does below, hence the separate liveness set.
     font_store.0.retain(|key, _| live_fonts.0.contains(key));
 
-    if pending.0.is_empty() {
+    // Lost-texture detection: a destination whose `GpuImage` now wraps a
+    // different texture than the one last rendered into holds undefined
+    // content. Flag it for the next extract (which re-sends a full
+    // payload) unless a full payload is already queued for it anyway. A
+    // destination that is gone entirely is forgotten - same eviction rule
+    // as `TerminalGpuStore` - and handed to the next extract, which tells
+    // a terminal still drawing to it (see `TerminalTextureLost`).
+    rendered_targets.0.retain(|dest, texture_id| {
+        let Some(gpu_image) = gpu_images.get(*dest) else {
+            vanished.0.insert(*dest);
+            return false;
+        };
+        let current = gpu_image.texture.id();
+        if *texture_id != current {
+            *texture_id = current;
+            if pending.0.get(dest).is_none_or(|draw| !draw.is_full()) {
+                lost.0.insert(*dest);
+            }
+        }
+        true
+    });
+
+    if pending.0.is_empty() && overlays.0.is_empty() {
         store.0.retain(|dest, _| gpu_images.get(*dest).is_some());
         font_uploads.0.clear();
         return;
@@ -486,33 +953,88 @@ fn render_tui_textures(
         let Some(gpu_image) = gpu_images.get(*dest) else {
             return true; // destination GpuImage not prepared yet - retry next frame
         };
+        let format = gpu_image.texture_descriptor.format;
+        // `materialize_tui_requests` already falls back to `Rgba8Unorm`
+        // for a `TerminalConfig::hdr` request the adapter can't render;
+        // this catches a `TerminalTexture::with_hdr` built by hand.
+        if format == HDR_TEXTURE_FORMAT && !hdr_format_supported(&render_adapter) {
+            if !*warned_hdr_unsupported {
+                *warned_hdr_unsupported = true;
+                tracing::warn!(
+                    "{HDR_TEXTURE_FORMAT:?} terminal textures are unsupported on this adapter; \
+                     HDR terminals are not rendered"
+                );
+            }
+            return false;
+        }
 
         let shared = font_store.0.entry(draw.font_key()).or_insert_with(|| {
             SharedFontGpuState::new(
                 render_device.wgpu_device(),
                 render_queue.0.as_ref(),
-                gpu_image.texture_descriptor.format,
+                format,
+                &shaders.0,
             )
         });
         if let Some(uploads) = font_uploads.0.remove(&draw.font_key()) {
             shared.upload_glyphs(render_queue.0.as_ref(), &uploads);
         }
 
-        let gpu_state = store
-            .0
-            .entry(*dest)
-            .or_insert_with(|| TerminalGpuState::new(render_device.wgpu_device(), shared));
-        gpu_state.render(
+        // MSAA: validate the requested sample count against the adapter
+        // once per terminal (and again only if the request changes), so an
+        // unsupported count degrades instead of failing pipeline creation.
+        // The same goes for a destination recreated in another format
+        // (`TerminalTexture::set_hdr`).
+        if store.0.get(dest).is_some_and(|state| {
+            state.requested_sample_count() != draw.sample_count() || state.format() != format
+        }) {
+            store.0.remove(dest);
+        }
+        let gpu_state = store.0.entry(*dest).or_insert_with(|| {
+            let requested = draw.sample_count();
+            let sample_count = supported_sample_count(&render_adapter, format, requested);
+            if sample_count != requested {
+                tracing::warn!("{requested}x MSAA unsupported for terminal {dest:?}, using {sample_count}x");
+            }
+            let device = render_device.wgpu_device();
+            shared.ensure_compositors(device, format, sample_count);
+            TerminalGpuState::new(device, shared, format, requested, sample_count)
+        });
+        shared.ensure_compositors(render_device.wgpu_device(), format, gpu_state.sample_count());
+        let needs_full_redraw = gpu_state.render(
             render_device.wgpu_device(),
             render_queue.0.as_ref(),
             shared,
             encoder,
+            &gpu_image.texture,
             &gpu_image.texture_view,
             draw,
         );
+        if needs_full_redraw {
+            // Fresh multisampled target under a partial payload: every row
+            // the payload didn't cover resolved from undefined samples.
+            lost.0.insert(*dest);
+        }
+        rendered_targets.0.insert(*dest, gpu_image.texture.id());
         false // rendered - drop from the pending map
     });
 
+    // After the draws above, so this frame's text pass can't cover them.
+    // A destination whose draw is still pending keeps its overlays too;
+    // one that no longer exists drops them.
+    overlays.0.retain(|dest, queued| {
+        if pending.0.contains_key(dest) {
+            return true;
+        }
+        let Some(gpu_image) = gpu_images.get(*dest) else {
+            return false;
+        };
+        for overlay in queued.iter() {
+            overlay.record(render_device.wgpu_device(), encoder, &gpu_image.texture);
+        }
+        false
+    });
+
     store.0.retain(|dest, _| gpu_images.get(*dest).is_some());
     font_uploads.0.clear();
 }
@@ -535,11 +1057,54 @@ fn flush_tui_commands(mut flush: FlushCommands) {
 // Blocking CPU readback (goes through the render world via a channel)
 // ============================================================================
 
-/// One outstanding readback request: which destination image to read, and
-/// where to send the resulting tightly-packed RGBA8 bytes.
+/// What a [`TuiReadbackRequest`] reads.
+#[derive(Clone, Copy)]
+pub(crate) enum ReadbackSource {
+    /// A terminal's destination image (or a mirror).
+    Image(AssetId<Image>),
+    /// The shared glyph atlas of the font set with this
+    /// [`Fonts::identity`](crate::fonts::Fonts::identity), all
+    /// `CACHE_WIDTH x CACHE_HEIGHT` texels of it.
+    #[cfg(feature = "atlas_dump")]
+    Atlas(usize),
+}
+
+/// One outstanding readback request: which texture to read, and where to
+/// send the resulting tightly-packed RGBA8 bytes.
 struct TuiReadbackRequest {
-    image_id: AssetId<Image>,
+    source: ReadbackSource,
+    /// Texel rectangle to read, or `None` for the whole texture. Clamped
+    /// to the texture once its size is known.
+    region: Option<URect>,
     response: std::sync::mpsc::Sender<Vec<u8>>,
+    /// Frames this request has been retried because its `GpuImage` wasn't
+    /// prepared - see [`TuiReadbackRequest::keep_waiting`].
+    frames_waited: u32,
+}
+
+/// Frames a readback request waits for its destination `GpuImage` before
+/// being abandoned. A freshly spawned terminal's image is prepared within a
+/// frame or two; one that never shows up belongs to a terminal despawned
+/// (image dropped) before the request was serviced.
+const READBACK_MAX_WAIT_FRAMES: u32 = 60;
+
+impl TuiReadbackRequest {
+    /// Called each frame the destination image isn't prepared: `true` to
+    /// retry next frame, `false` once [`READBACK_MAX_WAIT_FRAMES`] have
+    /// passed. Dropping the abandoned request drops its response sender,
+    /// which unblocks the caller with an empty `Vec` instead of leaving it
+    /// waiting forever on a despawned terminal.
+    fn keep_waiting(&mut self) -> bool {
+        self.frames_waited += 1;
+        if self.frames_waited <= READBACK_MAX_WAIT_FRAMES {
+            return true;
+        }
+        tracing::warn!(
+            "Tui::read_back_blocking: destination image never became ready \
+             (terminal despawned?), returning no pixels"
+        );
+        false
+    }
 }
 
 /// Main-world resource: the sending half of the blocking-readback channel.
@@ -559,12 +1124,40 @@ impl TuiReadbackChannel {
     /// rendering happens on its own thread) - calling it from the same
     /// thread that must also advance the render world deadlocks.
     pub(crate) fn request_blocking(&self, image_id: AssetId<Image>) -> Vec<u8> {
+        self.request_source_blocking(ReadbackSource::Image(image_id))
+    }
+
+    /// [`Self::request_blocking`] for only the texels in `region` - a
+    /// tightly-packed `region.width() x region.height()` RGBA8 block, or
+    /// less where `region` runs off the texture.
+    pub(crate) fn request_region_blocking(
+        &self,
+        image_id: AssetId<Image>,
+        region: URect,
+    ) -> Vec<u8> {
+        self.request_blocking_with(ReadbackSource::Image(image_id), Some(region))
+    }
+
+    /// [`Self::request_blocking`] for any [`ReadbackSource`].
+    pub(crate) fn request_source_blocking(&self, source: ReadbackSource) -> Vec<u8> {
+        self.request_blocking_with(source, None)
+    }
+
+    fn request_blocking_with(&self, source: ReadbackSource, region: Option<URect>) -> Vec<u8> {
+        if cfg!(target_arch = "wasm32") {
+            // No blocking in a browser - see `Tui::read_back_blocking`. The
+            // destination image isn't even created with `COPY_SRC` there.
+            tracing::warn!("Tui::read_back_blocking is unavailable on wasm32");
+            return Vec::new();
+        }
         let (tx, rx) = std::sync::mpsc::channel();
         if self
             .0
             .send(TuiReadbackRequest {
-                image_id,
+                source,
+                region,
                 response: tx,
+                frames_waited: 0,
             })
             .is_err()
         {
@@ -597,26 +1190,49 @@ fn process_tui_readbacks(
     gpu_images: Res<RenderAssets<GpuImage>>,
     render_device: Res<RenderDevice>,
     render_queue: Res<RenderQueue>,
+    #[cfg(feature = "atlas_dump")] font_store: Res<SharedFontGpuStore>,
 ) {
     pending.0.extend(receiver.0.lock().unwrap().try_iter());
     if pending.0.is_empty() {
         return;
     }
 
-    pending.0.retain(|request| {
-        let Some(gpu_image) = gpu_images.get(request.image_id) else {
-            return true; // destination GpuImage not prepared yet - retry next frame
+    pending.0.retain_mut(|request| {
+        // The texture to read and its size. A font's atlas only exists
+        // once one of its terminals has rendered, so it waits like an
+        // unprepared image does.
+        let (texture, width, height): (&wgpu::Texture, u32, u32) = match request.source {
+            ReadbackSource::Image(image_id) => {
+                let Some(gpu_image) = gpu_images.get(image_id) else {
+                    // Not prepared yet - retry next frame, up to a limit.
+                    return request.keep_waiting();
+                };
+                (
+                    &gpu_image.texture,
+                    gpu_image.texture_descriptor.size.width,
+                    gpu_image.texture_descriptor.size.height,
+                )
+            }
+            #[cfg(feature = "atlas_dump")]
+            ReadbackSource::Atlas(font_key) => {
+                let Some(atlas) = font_store.0.get(&font_key) else {
+                    return request.keep_waiting();
+                };
+                (
+                    atlas.atlas_texture(),
+                    crate::backend::CACHE_WIDTH,
+                    crate::backend::CACHE_HEIGHT,
+                )
+            }
         };
 
-        let width = gpu_image.texture_descriptor.size.width;
-        let height = gpu_image.texture_descriptor.size.height;
-        let pixels = read_back_gpu_image_blocking(
-            &gpu_image.texture,
-            width,
-            height,
-            &render_device,
-            &render_queue,
-        );
+        let full = URect::new(0, 0, width, height);
+        let region = request.region.map_or(full, |region| region.intersect(full));
+        let pixels = if region.is_empty() {
+            Vec::new()
+        } else {
+            read_back_gpu_image_blocking(texture, region, &render_device, &render_queue)
+        };
         request.response.send(pixels).ok();
         false // handled - drop from the pending list
     });
@@ -659,16 +1275,19 @@ fn strip_row_padding(
     out
 }
 
-/// Blocking GPU->CPU copy of a whole texture's RGBA8 pixels, with wgpu row
-/// padding already stripped. Shared by [`process_tui_readbacks`].
+/// Blocking GPU->CPU copy of the pixels in `region` of a texture (which
+/// must lie within it), with wgpu row padding already stripped: RGBA8, or
+/// 8 bytes of little-endian RGBA half floats per pixel for an HDR
+/// terminal. Shared by [`process_tui_readbacks`].
 fn read_back_gpu_image_blocking(
     texture: &wgpu::Texture,
-    width: u32,
-    height: u32,
+    region: URect,
     render_device: &RenderDevice,
     render_queue: &RenderQueue,
 ) -> Vec<u8> {
-    let unpadded_bytes_per_row = width * 4;
+    let (width, height) = (region.width(), region.height());
+    let bytes_per_pixel = texture.format().block_copy_size(None).unwrap_or(4);
+    let unpadded_bytes_per_row = width * bytes_per_pixel;
     let bytes_per_row = padded_bytes_per_row(unpadded_bytes_per_row);
     let buffer_size = (bytes_per_row * height) as wgpu::BufferAddress;
 
@@ -687,7 +1306,16 @@ fn read_back_gpu_image_blocking(
             label: Some("Terminal Readback Encoder"),
         });
     encoder.copy_texture_to_buffer(
-        texture.as_image_copy(),
+        wgpu::TexelCopyTextureInfo {
+            texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d {
+                x: region.min.x,
+                y: region.min.y,
+                z: 0,
+            },
+            aspect: wgpu::TextureAspect::All,
+        },
         wgpu::TexelCopyBufferInfo {
             buffer: &staging_buffer,
             layout: wgpu::TexelCopyBufferLayout {
@@ -728,6 +1356,170 @@ fn read_back_gpu_image_blocking(
     out
 }
 
+// ============================================================================
+// Test: TerminalRenderWhenVisible. Pure `World` + `RunSystemOnce` - the
+// flush is CPU work, nothing here needs a render world.
+// ============================================================================
+
+#[cfg(test)]
+mod render_when_visible_tests {
+    use super::*;
+    use crate::fonts::{Font, Fonts};
+    use crate::setup::TerminalTexture;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::sync::Arc;
+
+    fn spawn_drawn_terminal(world: &mut World, extra: impl Bundle) -> Entity {
+        let font_data = include_bytes!("../examples/assets/fonts/Mplus1Code-Regular.ttf");
+        let font = Font::new(font_data).expect("failed to load test font");
+        let fonts = Arc::new(Fonts::new(font, 16));
+        let texture = {
+            let mut images = world.resource_mut::<Assets<Image>>();
+            TerminalTexture::create(4, 2, fonts, false, false, [0, 0, 0, 255], &mut images)
+                .expect("failed to create terminal texture")
+        };
+        let mut tui = Tui::from_texture_state(texture);
+        tui.draw(|frame| {
+            frame.buffer_mut()[(0, 0)].set_symbol("a");
+        });
+        world.spawn((tui, extra)).id()
+    }
+
+    fn flushed(world: &mut World, entity: Entity) -> bool {
+        world.get_mut::<Tui>(entity).unwrap().take_pending_draw().is_some()
+    }
+
+    #[test]
+    fn hidden_terminal_renders_once_visible_again() {
+        let mut world = World::new();
+        world.init_resource::<Assets<Image>>();
+        let opted_in =
+            spawn_drawn_terminal(&mut world, (TerminalRenderWhenVisible, InheritedVisibility::HIDDEN));
+        let always = spawn_drawn_terminal(&mut world, InheritedVisibility::HIDDEN);
+
+        world.run_system_once(gpu_flush_system).unwrap();
+        assert!(!flushed(&mut world, opted_in), "hidden terminal must not render");
+        assert!(flushed(&mut world, always), "without the component hidden terminals still render");
+
+        *world.get_mut::<InheritedVisibility>(opted_in).unwrap() = InheritedVisibility::VISIBLE;
+        world.run_system_once(gpu_flush_system).unwrap();
+        assert!(flushed(&mut world, opted_in), "content drawn while hidden renders once visible");
+    }
+
+    #[cfg(feature = "3d")]
+    #[test]
+    fn recreated_images_mark_only_the_materials_sampling_them() {
+        use bevy::pbr::StandardMaterial;
+
+        let mut world = World::new();
+        world.init_resource::<Assets<Image>>();
+        let entity = spawn_drawn_terminal(&mut world, ());
+        let recreated =
+            |world: &mut World| world.get_mut::<Tui>(entity).unwrap().take_images_recreated();
+        let (image, mirror) = world.resource_scope(|world, mut images: Mut<Assets<Image>>| {
+            let mut tui = world.get_mut::<Tui>(entity).unwrap();
+            let mirror = tui.add_mirror(&mut images);
+            (tui.image_handle().clone(), mirror)
+        });
+        assert!(!recreated(&mut world), "creating images isn't recreating them");
+        world.resource_scope(|world, mut images: Mut<Assets<Image>>| {
+            let mut tui = world.get_mut::<Tui>(entity).unwrap();
+            tui.set_sampler(bevy::image::ImageSampler::nearest(), &mut images);
+        });
+        assert!(recreated(&mut world));
+        assert!(!recreated(&mut world), "reported once");
+
+        let mut materials = Assets::<StandardMaterial>::default();
+        let showing = materials.add(StandardMaterial {
+            base_color_texture: Some(image.clone()),
+            ..default()
+        });
+        let glowing_mirror = materials.add(StandardMaterial {
+            emissive_texture: Some(mirror.clone()),
+            ..default()
+        });
+        let unrelated = materials.add(StandardMaterial::default());
+
+        let tui = world.get::<Tui>(entity).unwrap();
+        let destinations = tui.destinations().into_iter().collect();
+        let mut stale = materials_sampling(&materials, &destinations);
+        stale.sort_by_key(|id| *id == showing.id());
+        assert_eq!(stale, [glowing_mirror.id(), showing.id()]);
+        assert!(!stale.contains(&unrelated.id()));
+    }
+
+    #[test]
+    fn culled_meshes_and_collapsed_nodes_count_as_hidden() {
+        let visible = Some(&InheritedVisibility::VISIBLE);
+        let unseen = ViewVisibility::HIDDEN;
+        assert!(!terminal_hidden(visible, None, false, None));
+        assert!(terminal_hidden(visible, Some(&unseen), true, None), "culled by every camera");
+        assert!(
+            !terminal_hidden(visible, Some(&unseen), false, None),
+            "ViewVisibility is only trusted where bevy's visibility checks update it"
+        );
+        assert!(terminal_hidden(visible, None, false, Some(Vec2::ZERO)), "Display::None");
+        assert!(!terminal_hidden(visible, None, false, Some(Vec2::new(320.0, 200.0))));
+    }
+}
+
+// ============================================================================
+// Test: cell-space to texture-space rect conversion. Pure math.
+// ============================================================================
+
+#[cfg(test)]
+mod rect_to_pixels_tests {
+    use super::*;
+
+    fn dims() -> TerminalDimensions {
+        TerminalDimensions {
+            cols: 10,
+            rows: 4,
+            char_width_px: 8,
+            char_height_px: 16,
+            padding: crate::backend::Padding::default(),
+        }
+    }
+
+    #[test]
+    fn whole_grid_maps_to_the_whole_texture() {
+        let rect = dims().rect_to_pixels(ratatui::layout::Rect::new(0, 0, 10, 4));
+        assert_eq!(rect, Rect::new(0.0, 0.0, 80.0, 64.0));
+    }
+
+    #[test]
+    fn rect_in_the_last_cell_ends_at_the_texture_edge() {
+        let rect = dims().rect_to_pixels(ratatui::layout::Rect::new(9, 3, 1, 1));
+        assert_eq!(rect, Rect::new(72.0, 48.0, 80.0, 64.0));
+    }
+
+    #[test]
+    fn rect_past_the_edges_is_clipped_to_the_grid() {
+        let rect = dims().rect_to_pixels(ratatui::layout::Rect::new(8, 2, 5, 5));
+        assert_eq!(rect, Rect::new(64.0, 32.0, 80.0, 64.0));
+
+        let outside = dims().rect_to_pixels(ratatui::layout::Rect::new(12, 6, 2, 2));
+        assert!(outside.is_empty(), "an off-grid rect has no pixels");
+    }
+
+    #[test]
+    fn padding_offsets_rects_and_maps_texture_uvs_back_to_the_grid() {
+        let padded = TerminalDimensions {
+            padding: crate::backend::Padding::new(8, 0, 8, 20),
+            ..dims()
+        };
+        assert_eq!(padded.texture_size(), Vec2::new(100.0, 80.0));
+        let rect = padded.rect_to_pixels(ratatui::layout::Rect::new(0, 0, 1, 1));
+        assert_eq!(rect, Rect::new(20.0, 8.0, 28.0, 24.0));
+
+        // The grid's top-left and bottom-right corners inside the texture.
+        assert_eq!(padded.texture_uv_to_grid_uv(Vec2::new(0.2, 0.1)), Vec2::ZERO);
+        assert_eq!(padded.texture_uv_to_grid_uv(Vec2::new(1.0, 0.9)), Vec2::ONE);
+        // Left letterbox: before the grid.
+        assert!(padded.texture_uv_to_grid_uv(Vec2::new(0.1, 0.5)).x < 0.0);
+    }
+}
+
 // ============================================================================
 // Test: staging-row padding math (P2-4). Pure CPU - no wgpu device needed
 // (the readback opt-in still uses this math, kept even after Phase A/B).
@@ -789,3 +1581,33 @@ mod row_padding_tests {
     }
 }
 
+// ============================================================================
+// Test: a readback whose terminal is despawned before it is serviced must
+// not leave the caller blocked. Pure CPU - the render world is stood in for
+// by draining the channel by hand.
+// ============================================================================
+
+#[cfg(test)]
+mod readback_request_tests {
+    use super::*;
+
+    #[test]
+    fn a_request_for_a_despawned_terminal_is_abandoned_and_unblocks_the_caller() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let channel = TuiReadbackChannel(tx);
+        // Never prepared - the image of a terminal despawned mid-request.
+        let caller = std::thread::spawn(move || channel.request_blocking(AssetId::default()));
+
+        let mut request = rx.recv().expect("the request must reach the render side");
+        for _ in 0..READBACK_MAX_WAIT_FRAMES {
+            assert!(request.keep_waiting(), "a slow image must get time to be prepared");
+        }
+        assert!(!request.keep_waiting(), "the request must be abandoned eventually");
+        drop(request);
+
+        assert!(
+            caller.join().expect("caller panicked").is_empty(),
+            "an abandoned request returns no pixels instead of blocking"
+        );
+    }
+}
@@ -1,4 +1,127 @@
-pub(crate) type Rgb = [u8; 3];
+//! The RGB type the backend's color options take, and the mapping from
+//! ratatui colors to it that rendering itself uses.
+
+/// An opaque `[r, g, b]` color - what [`TerminalBuilder::with_reset_fg`]
+/// and the other color options of the builder take.
+///
+/// [`TerminalBuilder::with_reset_fg`]: crate::TerminalBuilder::with_reset_fg
+pub type Rgb = [u8; 3];
+
+/// Resolve a ratatui color to the RGB the terminal renders it as, with
+/// `reset` standing in for `Color::Reset`: named colors use the SVG color
+/// keywords, `Indexed` the xterm 256-color table. No
+/// [`TerminalTheme`](crate::theme::TerminalTheme) palette is applied - a
+/// themed terminal draws the 16 ANSI colors from the theme instead.
+///
+/// ```
+/// use bevy_tui_texture::color_to_rgb;
+/// use ratatui::style::Color;
+///
+/// assert_eq!(color_to_rgb(Color::Rgb(1, 2, 3), [0, 0, 0]), [1, 2, 3]);
+/// assert_eq!(color_to_rgb(Color::Reset, [9, 9, 9]), [9, 9, 9]);
+/// ```
+pub fn color_to_rgb(color: ratatui::style::Color, reset: Rgb) -> Rgb {
+    crate::backend::c2c(color, reset, None)
+}
+
+/// A reduced set of colors every color the terminal renders is snapped
+/// to, for retro looks - see
+/// [`TerminalBuilder::with_color_quantize`](crate::TerminalBuilder::with_color_quantize).
+/// Applied after everything else has resolved a color (theme palette,
+/// reset colors, `REVERSED`, the background gradient, the cursor,
+/// decoration and grid colors), so any ratatui content maps onto it.
+///
+/// ```
+/// use bevy_tui_texture::Palette;
+///
+/// let cga = Palette::cga();
+/// assert_eq!(cga.quantize([240, 90, 90]), [0xFF, 0x55, 0x55]);
+/// let green = Palette::green_phosphor();
+/// assert_eq!(green.quantize([0, 0, 0]), [0x00, 0x14, 0x00]);
+/// assert_eq!(green.quantize([255, 255, 255]), [0x33, 0xFF, 0x66]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Palette {
+    /// Snap to the nearest (by RGB distance) of these colors. An empty
+    /// list leaves colors unchanged.
+    Colors(Vec<Rgb>),
+    /// One-hue monochrome: a color's luminance picks one of `levels`
+    /// evenly spaced steps from `dark` (black) to `bright` (white), the
+    /// way a single-phosphor screen shows everything. `levels` below 2 is
+    /// treated as 2.
+    Monochrome { dark: Rgb, bright: Rgb, levels: u8 },
+}
+
+impl Palette {
+    /// The 16 colors of the IBM CGA.
+    pub fn cga() -> Self {
+        Self::Colors(vec![
+            [0x00, 0x00, 0x00],
+            [0x00, 0x00, 0xAA],
+            [0x00, 0xAA, 0x00],
+            [0x00, 0xAA, 0xAA],
+            [0xAA, 0x00, 0x00],
+            [0xAA, 0x00, 0xAA],
+            [0xAA, 0x55, 0x00],
+            [0xAA, 0xAA, 0xAA],
+            [0x55, 0x55, 0x55],
+            [0x55, 0x55, 0xFF],
+            [0x55, 0xFF, 0x55],
+            [0x55, 0xFF, 0xFF],
+            [0xFF, 0x55, 0x55],
+            [0xFF, 0x55, 0xFF],
+            [0xFF, 0xFF, 0x55],
+            [0xFF, 0xFF, 0xFF],
+        ])
+    }
+
+    /// Green-phosphor monochrome (P1), four intensities.
+    pub fn green_phosphor() -> Self {
+        Self::Monochrome {
+            dark: [0x00, 0x14, 0x00],
+            bright: [0x33, 0xFF, 0x66],
+            levels: 4,
+        }
+    }
+
+    /// Amber-phosphor monochrome (P3), four intensities.
+    pub fn amber_phosphor() -> Self {
+        Self::Monochrome {
+            dark: [0x14, 0x0A, 0x00],
+            bright: [0xFF, 0xB0, 0x00],
+            levels: 4,
+        }
+    }
+
+    /// The palette color `color` is drawn as.
+    pub fn quantize(&self, color: Rgb) -> Rgb {
+        match self {
+            Palette::Colors(colors) => {
+                let distance = |candidate: &Rgb| -> u32 {
+                    (0..3)
+                        .map(|i| (candidate[i] as i32 - color[i] as i32).pow(2) as u32)
+                        .sum()
+                };
+                colors.iter().copied().min_by_key(distance).unwrap_or(color)
+            }
+            Palette::Monochrome {
+                dark,
+                bright,
+                levels,
+            } => {
+                let steps = (*levels).max(2) as f32 - 1.0;
+                // Rec. 709 luma.
+                let [r, g, b] = color.map(|c| c as f32 / 255.0);
+                let luma = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+                let t = (luma * steps).round() / steps;
+                std::array::from_fn(|i| {
+                    let (a, b) = (dark[i] as f32, bright[i] as f32);
+                    (a + (b - a) * t).round() as u8
+                })
+            }
+        }
+    }
+}
 
 /// <https://www.w3.org/TR/SVG11/types.html#ColorKeywords>
 pub(crate) mod named {
@@ -283,3 +406,42 @@ pub(crate) const ANSI_TO_RGB: [Rgb; 256] = [
     [0xe4, 0xe4, 0xe4],
     [0xee, 0xee, 0xee],
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+
+    #[test]
+    fn color_to_rgb_matches_the_named_and_indexed_tables() {
+        assert_eq!(color_to_rgb(Color::Reset, [1, 2, 3]), [1, 2, 3]);
+        assert_eq!(color_to_rgb(Color::Blue, [0, 0, 0]), named::BLUE);
+        assert_eq!(color_to_rgb(Color::Indexed(196), [0, 0, 0]), ANSI_TO_RGB[196]);
+        assert_eq!(color_to_rgb(Color::Rgb(10, 20, 30), [0, 0, 0]), [10, 20, 30]);
+    }
+
+    #[test]
+    fn palettes_snap_to_their_colors() {
+        let two = Palette::Colors(vec![[0, 0, 0], [255, 255, 255]]);
+        assert_eq!(two.quantize([100, 100, 100]), [0, 0, 0]);
+        assert_eq!(two.quantize([200, 90, 200]), [255, 255, 255]);
+        assert_eq!(Palette::Colors(vec![]).quantize([1, 2, 3]), [1, 2, 3]);
+
+        let mono = Palette::Monochrome {
+            dark: [0, 0, 0],
+            bright: [0, 255, 0],
+            levels: 2,
+        };
+        assert_eq!(mono.quantize(named::BLUE), [0, 0, 0], "dim blue falls to dark");
+        assert_eq!(mono.quantize(named::YELLOW), [0, 255, 0]);
+        // Every monochrome output lies on the ramp.
+        let green = Palette::green_phosphor();
+        let Palette::Monochrome { dark, bright, .. } = green else {
+            unreachable!()
+        };
+        for color in ANSI_TO_RGB {
+            let out = green.quantize(color);
+            assert!((0..3).all(|i| out[i] >= dark[i].min(bright[i]) && out[i] <= dark[i].max(bright[i])));
+        }
+    }
+}
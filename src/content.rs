@@ -0,0 +1,201 @@
+//! Component-driven drawing: describe a terminal's content instead of
+//! writing a render system for it.
+//!
+//! The usual flow is a system in `TerminalSystemSet::Render` (or
+//! `UserUpdate`) that queries `&mut Tui` and calls [`Tui::draw`]. With a
+//! [`TerminalContent`] on the same entity the plugin makes that call
+//! itself, once per frame, with the renderer the component holds:
+//!
+//! ```ignore
+//! commands.spawn((
+//!     TuiRequest::ui(40, 10, fonts),
+//!     Node::default(),
+//!     TerminalContent::new(|frame: &mut Frame| {
+//!         frame.render_widget(Paragraph::new("Hello!"), frame.area());
+//!     }),
+//! ));
+//! ```
+//!
+//! The renderer is `'static`, so it can't borrow from the ECS. It either
+//! owns its state (a closure's captures, or the fields of a type
+//! implementing [`TerminalRenderer`]), or gets new content from your own
+//! systems through [`TerminalContent::set`] - typically a closure that
+//! captures a snapshot of the resources it shows, set whenever they
+//! change. Shared state behind an `Arc<Mutex<_>>` works too.
+//!
+//! The render policy is respected: a terminal whose
+//! [`TerminalRenderRate`] says the next render isn't due, or a hidden
+//! [`TerminalRenderWhenVisible`] terminal, isn't drawn at all - the
+//! renderer only runs when its output can reach the screen. Like a
+//! [`TypewriterTerminal`](crate::TypewriterTerminal), the component owns
+//! the terminal's content while present; don't also draw into the same
+//! `Tui` from your own systems.
+
+use bevy::prelude::*;
+use ratatui::Frame;
+
+use crate::bevy_plugin::{terminal_hidden, TerminalRenderRate, TerminalRenderWhenVisible};
+use crate::setup::Tui;
+
+/// Something that paints a whole terminal frame - what a
+/// [`TerminalContent`] runs. Implemented for every
+/// `FnMut(&mut Frame) + Send + Sync + 'static` closure; implement it on
+/// your own type for a renderer with named state.
+pub trait TerminalRenderer: Send + Sync + 'static {
+    /// Draw the frame, exactly like the closure passed to [`Tui::draw`].
+    fn render(&mut self, frame: &mut Frame);
+}
+
+impl<F> TerminalRenderer for F
+where
+    F: FnMut(&mut Frame) + Send + Sync + 'static,
+{
+    fn render(&mut self, frame: &mut Frame) {
+        self(frame)
+    }
+}
+
+/// Draws this entity's [`Tui`] every frame with the renderer it holds -
+/// see the [module docs](crate::content).
+#[derive(Component)]
+pub struct TerminalContent {
+    renderer: Box<dyn TerminalRenderer>,
+}
+
+impl TerminalContent {
+    /// Content painted by `renderer` - a closure or a [`TerminalRenderer`].
+    pub fn new(renderer: impl TerminalRenderer) -> Self {
+        Self {
+            renderer: Box::new(renderer),
+        }
+    }
+
+    /// Replace the renderer; the next frame draws with it. This is the way
+    /// to push new app state into the terminal from an ordinary system.
+    pub fn set(&mut self, renderer: impl TerminalRenderer) {
+        self.renderer = Box::new(renderer);
+    }
+}
+
+/// Runs every [`TerminalContent`] renderer whose terminal would render
+/// this frame. Registered by [`TerminalPlugin`](crate::TerminalPlugin) in
+/// `TerminalSystemSet::Render`, ahead of the flush, so the frame drawn
+/// here is the one rendered.
+pub(crate) fn draw_terminal_content(
+    mut terminals: Query<(Entity, &mut TerminalContent, &mut Tui, Option<&TerminalRenderRate>)>,
+    visibility: Query<
+        (
+            Option<&InheritedVisibility>,
+            Option<&ViewVisibility>,
+            Has<bevy::camera::visibility::VisibilityClass>,
+        ),
+        With<TerminalRenderWhenVisible>,
+    >,
+    #[cfg(feature = "2d")] nodes: Query<&bevy::ui::ComputedNode>,
+    time: Option<Res<Time>>,
+) {
+    for (entity, mut content, mut tui, rate) in &mut terminals {
+        if let Ok((inherited, view, class)) = visibility.get(entity) {
+            #[cfg(feature = "2d")]
+            let node_size = nodes.get(entity).ok().map(bevy::ui::ComputedNode::size);
+            #[cfg(not(feature = "2d"))]
+            let node_size = None;
+            if terminal_hidden(inherited, view, class, node_size) {
+                continue;
+            }
+        }
+        if time.as_ref().is_some_and(|time| !tui.render_due(rate, time.elapsed())) {
+            continue;
+        }
+        // Running the renderer isn't a change to the component: leave
+        // `Changed<TerminalContent>` to mean "a new renderer was set".
+        let content = content.bypass_change_detection();
+        tui.draw(|frame| content.renderer.render(frame));
+    }
+}
+
+// ============================================================================
+// Test: the plugin-side draw and the visibility policy. Pure CPU - a bare
+// `App` running only `draw_terminal_content`.
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::{Font, Fonts};
+    use crate::setup::TerminalTexture;
+    use ratatui::widgets::Paragraph;
+    use std::sync::Arc;
+
+    fn test_fonts() -> Arc<Fonts> {
+        let font_data = include_bytes!("../examples/assets/fonts/Mplus1Code-Regular.ttf");
+        let font = Font::new(font_data).expect("failed to load test font");
+        Arc::new(Fonts::new(font, 16))
+    }
+
+    fn app_with_content(content: TerminalContent, extra: impl Bundle) -> (App, Entity) {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_systems(Update, draw_terminal_content);
+        let mut images = Assets::<Image>::default();
+        let texture = TerminalTexture::create(8, 1, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture");
+        let entity = app
+            .world_mut()
+            .spawn((Tui::from_texture_state(texture), content, extra))
+            .id();
+        (app, entity)
+    }
+
+    fn row(app: &App, entity: Entity) -> String {
+        let tui = app.world().get::<Tui>(entity).unwrap();
+        (0..8).map(|x| tui.cell_symbol(x, 0)).collect()
+    }
+
+    /// A renderer with its own state, counting the frames it drew.
+    struct Counter(u32);
+
+    impl TerminalRenderer for Counter {
+        fn render(&mut self, frame: &mut Frame) {
+            self.0 += 1;
+            frame.render_widget(Paragraph::new(format!("n={}", self.0)), frame.area());
+        }
+    }
+
+    #[test]
+    fn draws_every_frame_and_picks_up_a_new_renderer() {
+        let (mut app, entity) = app_with_content(TerminalContent::new(Counter(0)), ());
+
+        app.update();
+        assert_eq!(row(&app, entity), "n=1     ");
+        app.update();
+        assert_eq!(row(&app, entity), "n=2     ", "the renderer keeps its state");
+
+        let greeting = String::from("hi");
+        app.world_mut()
+            .get_mut::<TerminalContent>(entity)
+            .unwrap()
+            .set(move |frame: &mut Frame| {
+                frame.render_widget(Paragraph::new(greeting.as_str()), frame.area());
+            });
+        app.update();
+        assert_eq!(row(&app, entity), "hi      ");
+    }
+
+    #[test]
+    fn hidden_render_when_visible_terminals_are_not_drawn() {
+        let (mut app, entity) = app_with_content(
+            TerminalContent::new(Counter(0)),
+            (TerminalRenderWhenVisible, InheritedVisibility::HIDDEN),
+        );
+
+        app.update();
+        assert_eq!(row(&app, entity), "        ");
+
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(InheritedVisibility::VISIBLE);
+        app.update();
+        assert_eq!(row(&app, entity), "n=1     ", "the renderer never ran while hidden");
+    }
+}
@@ -0,0 +1,45 @@
+//! App-wide debug rendering switches.
+//!
+//! A [`TerminalDebug`] resource turns visual debugging aids on for every
+//! [`Tui`] at once - currently the transparency checkerboard, which paints
+//! cells whose background isn't fully opaque (the `transparent_reset_bg`
+//! cells) with a gray checkerboard so they can be told apart from black.
+//! Like [`TerminalTheme`](crate::theme::TerminalTheme), editing the resource
+//! re-applies it to every terminal and later terminals pick it up as they
+//! spawn; without the resource nothing is applied, and every switch costs
+//! nothing while off.
+//!
+//! ```ignore
+//! app.insert_resource(TerminalDebug {
+//!     transparency_checkerboard: true,
+//! });
+//! ```
+
+use bevy::prelude::*;
+
+use crate::setup::Tui;
+
+/// Debug rendering switches for every terminal - see the
+/// [module docs](self). Not inserted by the plugin; absent means all off.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalDebug {
+    /// Checkerboard behind transparent cells - see
+    /// [`TerminalBuilder::with_transparency_checkerboard`](crate::TerminalBuilder::with_transparency_checkerboard).
+    pub transparency_checkerboard: bool,
+}
+
+/// Applies [`TerminalDebug`] to every [`Tui`] when the resource is inserted
+/// or changed, and to each newly spawned `Tui` otherwise. Registered by
+/// [`TerminalPlugin`](crate::TerminalPlugin) next to `apply_terminal_theme`;
+/// the repaint happens on each terminal's next [`Tui::draw`].
+pub(crate) fn apply_terminal_debug(debug: Option<Res<TerminalDebug>>, mut tuis: Query<&mut Tui>) {
+    let Some(debug) = debug else {
+        return;
+    };
+    let debug_changed = debug.is_changed();
+    for mut tui in &mut tuis {
+        if debug_changed || tui.is_added() {
+            tui.set_transparency_checkerboard(debug.transparency_checkerboard);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! Developer-facing render statistics overlay (feature `debug_stats`).
+//!
+//! Insert [`TerminalDebugStats`]`(true)` and every [`Tui`] gets a one-line
+//! overlay in its top-right corner, refreshed twice a second:
+//!
+//! - `upd/s` - how often this terminal's content actually changed (dirty
+//!   flushes per second - a byte-identical redraw doesn't count).
+//! - `glyphs/f` - glyphs this terminal rasterized per frame, averaged over
+//!   the sample window. Nonzero on a static screen means the atlas is
+//!   thrashing.
+//! - `atlas` - slots in use / capacity of the glyph atlas this terminal's
+//!   font shares with every other terminal using the same `Arc<Fonts>`.
+//!
+//! The overlay is painted into the terminal's own buffer at the end of each
+//! [`Tui::draw`], so it appears (and disappears, after toggling back to
+//! `false`) on the terminal's next draw call. Handy to paste into
+//! performance bug reports. Without the feature none of this - resource,
+//! counters, overlay - is compiled in.
+//!
+//! ```ignore
+//! app.insert_resource(TerminalDebugStats(true));
+//! ```
+
+use bevy::prelude::*;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::Paragraph;
+
+use crate::setup::Tui;
+
+/// Toggles the stats overlay on every terminal - see the
+/// [module docs](self). Not inserted by the plugin; absent means off.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalDebugStats(pub bool);
+
+/// How long counters accumulate before the overlay text is refreshed.
+const SAMPLE_WINDOW_SECS: f32 = 0.5;
+
+/// Per-`Tui` counters and the current overlay text.
+#[derive(Debug, Default)]
+pub(crate) struct DebugStatsState {
+    /// Dirty flushes since the window started.
+    updates: u32,
+    /// Frames since the window started.
+    frames: u32,
+    elapsed_secs: f32,
+    /// Backend rasterization counter at the start of the window.
+    glyphs_at_window_start: u64,
+    /// `None` while the overlay is off.
+    overlay: Option<String>,
+}
+
+impl DebugStatsState {
+    /// Called by `Tui::flush` for every flush that produced a new payload.
+    pub(crate) fn record_update(&mut self) {
+        self.updates += 1;
+    }
+
+    /// Advance the sample window by one frame of `delta_secs`, refreshing
+    /// the overlay text once the window is full. `glyphs_rasterized` is the
+    /// backend's running total; `atlas` is `(used, capacity)`.
+    pub(crate) fn sample(&mut self, delta_secs: f32, glyphs_rasterized: u64, atlas: (u32, u32)) {
+        if self.overlay.is_none() {
+            // Just switched on: start a fresh window, show placeholder
+            // text until the first real sample.
+            *self = Self {
+                glyphs_at_window_start: glyphs_rasterized,
+                overlay: Some(String::from(" stats: sampling... ")),
+                ..Self::default()
+            };
+            return;
+        }
+
+        self.frames += 1;
+        self.elapsed_secs += delta_secs;
+        if self.elapsed_secs < SAMPLE_WINDOW_SECS {
+            return;
+        }
+
+        let glyphs = glyphs_rasterized.saturating_sub(self.glyphs_at_window_start);
+        self.overlay = Some(format!(
+            " {:.1} upd/s | {:.1} glyphs/f | atlas {}/{} ",
+            self.updates as f32 / self.elapsed_secs,
+            glyphs as f32 / self.frames as f32,
+            atlas.0,
+            atlas.1,
+        ));
+        self.updates = 0;
+        self.frames = 0;
+        self.elapsed_secs = 0.0;
+        self.glyphs_at_window_start = glyphs_rasterized;
+    }
+
+    /// Turn the overlay off. Counters restart on the next `sample`.
+    pub(crate) fn clear(&mut self) {
+        self.overlay = None;
+    }
+
+    pub(crate) fn is_active(&self) -> bool {
+        self.overlay.is_some()
+    }
+
+    /// Paint the overlay over whatever the user drew, right-aligned on the
+    /// top row and truncated to the frame width.
+    pub(crate) fn render(&self, frame: &mut ratatui::Frame) {
+        let Some(text) = &self.overlay else {
+            return;
+        };
+        let area = frame.area();
+        let width = (text.chars().count() as u16).min(area.width);
+        if width == 0 || area.height == 0 {
+            return;
+        }
+        let rect = Rect::new(area.x + area.width - width, area.y, width, 1);
+        frame.render_widget(
+            Paragraph::new(text.as_str()).style(Style::default().add_modifier(Modifier::REVERSED)),
+            rect,
+        );
+    }
+}
+
+/// Samples every terminal's counters once per frame while
+/// [`TerminalDebugStats`] is `true`, and turns the overlays off once it
+/// isn't. Registered by [`TerminalPlugin`](crate::TerminalPlugin) before
+/// `TerminalSystemSet::UserUpdate`, so the same frame's user draw already
+/// paints the refreshed text.
+pub(crate) fn update_debug_stats(
+    stats: Option<Res<TerminalDebugStats>>,
+    time: Res<Time>,
+    mut tuis: Query<&mut Tui>,
+) {
+    let enabled = stats.is_some_and(|stats| stats.0);
+    for mut tui in &mut tuis {
+        if enabled {
+            // Counter bookkeeping, not content: sampling every terminal
+            // every frame must not flag them all `Changed<Tui>`.
+            tui.bypass_change_detection().sample_debug_stats(time.delta_secs());
+        } else if tui.debug_stats_active() {
+            tui.clear_debug_stats();
+        }
+    }
+}
+
+// ============================================================================
+// Test: sample window bookkeeping. Pure CPU - drives `DebugStatsState`
+// directly, no App or terminal needed.
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_sample_only_arms_the_overlay() {
+        let mut state = DebugStatsState::default();
+        assert!(!state.is_active());
+        state.sample(0.1, 40, (3, 100));
+        assert!(state.is_active());
+        assert_eq!(state.updates, 0);
+        assert_eq!(state.glyphs_at_window_start, 40);
+    }
+
+    #[test]
+    fn full_window_reports_rates_and_restarts() {
+        let mut state = DebugStatsState::default();
+        state.sample(0.0, 0, (0, 100));
+
+        state.record_update();
+        state.sample(0.25, 4, (5, 100));
+        assert_eq!(state.overlay.as_deref(), Some(" stats: sampling... "));
+
+        // Second quarter-second fills the window: 2 updates / 0.5s, 10
+        // glyphs over 2 frames.
+        state.record_update();
+        state.sample(0.25, 10, (7, 100));
+        assert_eq!(
+            state.overlay.as_deref(),
+            Some(" 4.0 upd/s | 5.0 glyphs/f | atlas 7/100 ")
+        );
+        assert_eq!((state.updates, state.frames), (0, 0));
+        assert_eq!(state.glyphs_at_window_start, 10);
+
+        state.clear();
+        assert!(!state.is_active());
+    }
+}
@@ -77,6 +77,9 @@ pub struct Font {
     font: Face<'static>,
     advance: f32,
     id: u64,
+    /// Hash of the font file's bytes, face index and any variation
+    /// coordinates - see `Font::fingerprint`.
+    fingerprint: u64,
     /// Keeps runtime-loaded font bytes alive for the lifetime of the `Face`
     /// (`None` for `&'static` data). `Clone` clones the `Arc`, so every copy
     /// of the `Face` keeps the backing allocation alive.
@@ -86,7 +89,7 @@ pub struct Font {
 impl Font {
     /// Load a font from a static byte slice (e.g. `include_bytes!`).
     pub fn new(data: &'static [u8]) -> Option<Self> {
-        Self::build(data, None)
+        Self::build(data, 0, None)
     }
 
     /// Load a font from owned bytes (e.g. `std::fs::read` at runtime).
@@ -95,6 +98,11 @@ impl Font {
     /// are kept alive internally for as long as the font (or any clone of it)
     /// exists. No leaking required.
     pub fn from_vec(data: Vec<u8>) -> Option<Self> {
+        Self::from_vec_face(data, 0)
+    }
+
+    /// [`Font::from_vec`] for face `index` of a font collection (`.ttc`).
+    pub(crate) fn from_vec_face(data: Vec<u8>, index: u32) -> Option<Self> {
         let data: std::sync::Arc<[u8]> = data.into();
         // SAFETY: `slice` points into the Arc's heap allocation, which
         // - is never moved (Arc contents are heap-stable),
@@ -105,14 +113,19 @@ impl Font {
         // The `Face` is never handed out with the `'static` lifetime beyond
         // `&self` borrows (`Font::font()` is crate-private).
         let slice: &'static [u8] = unsafe { std::mem::transmute::<&[u8], &'static [u8]>(&data) };
-        Self::build(slice, Some(data))
+        Self::build(slice, index, Some(data))
     }
 
-    fn build(data: &'static [u8], keep_alive: Option<std::sync::Arc<[u8]>>) -> Option<Self> {
+    fn build(
+        data: &'static [u8],
+        index: u32,
+        keep_alive: Option<std::sync::Arc<[u8]>>,
+    ) -> Option<Self> {
         let mut hasher = RandomState::new().build_hasher();
         hasher.write(data);
+        hasher.write_u32(index);
 
-        Face::from_slice(data, 0).map(|font| {
+        Face::from_slice(data, index).map(|font| {
             let advance = font
                 .glyph_hor_advance(font.glyph_index('m').unwrap_or_default())
                 .unwrap_or_default() as f32;
@@ -120,17 +133,89 @@ impl Font {
                 font,
                 advance,
                 id: hasher.finish(),
+                fingerprint: fnv1a(fnv1a(FNV_OFFSET_BASIS, data), &index.to_le_bytes()),
                 _data: keep_alive,
             }
         })
     }
 }
 
+/// Variable-font instancing (feature `variable_fonts`). A variable font
+/// holds a whole design space - weights, widths, slants - in one file; an
+/// instance pinned at some axis coordinates is just another [`Font`], so it
+/// slots into the regular/bold/italic fallback lists like a static file.
+#[cfg(feature = "variable_fonts")]
+impl Font {
+    /// `true` if this font has variation axes (an `fvar` table).
+    pub fn is_variable(&self) -> bool {
+        self.font.is_variable()
+    }
+
+    /// This font's variation axes as `(tag, min, default, max)` - e.g.
+    /// `(*b"wght", 100.0, 400.0, 900.0)`. Empty for a static font.
+    pub fn variation_axes(&self) -> Vec<([u8; 4], f32, f32, f32)> {
+        self.font
+            .variation_axes()
+            .into_iter()
+            .map(|axis| (axis.tag.to_bytes(), axis.min_value, axis.def_value, axis.max_value))
+            .collect()
+    }
+
+    /// A copy of this font instanced at `coords` (axis tag, value) - e.g.
+    /// `[(*b"wght", 700.0)]` for a designed bold. Axes not listed keep
+    /// their current value; values outside an axis' range are clamped,
+    /// and tags the font has no axis for are ignored. `None` for a static
+    /// font. Shaping and rasterizing both use the instance, and it gets
+    /// its own glyph-cache identity, so instances of one font never share
+    /// atlas entries.
+    pub fn with_variations(&self, coords: &[([u8; 4], f32)]) -> Option<Font> {
+        if !self.is_variable() {
+            return None;
+        }
+        let mut instance = self.clone();
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(self.id);
+        for &(tag, value) in coords {
+            let tag = rustybuzz::ttf_parser::Tag::from_bytes(&tag);
+            if instance.font.set_variation(tag, value).is_some() {
+                hasher.write_u32(tag.0);
+                hasher.write_u32(value.to_bits());
+                instance.fingerprint = fnv1a(instance.fingerprint, &tag.0.to_le_bytes());
+                instance.fingerprint = fnv1a(instance.fingerprint, &value.to_bits().to_le_bytes());
+            }
+        }
+        instance.advance = instance
+            .font
+            .glyph_hor_advance(instance.font.glyph_index('m').unwrap_or_default())
+            .unwrap_or_default() as f32;
+        instance.id = hasher.finish();
+        Some(instance)
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+
+/// 64-bit FNV-1a of `bytes`, continuing from `hash`. Fixed by its spec,
+/// unlike std's hashers, so its output can be persisted.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01B3)
+    })
+}
+
 impl Font {
     pub(crate) fn id(&self) -> u64 {
         self.id
     }
 
+    /// Hash of the font file's contents (plus face index and variation
+    /// coordinates): unlike `id`, the same in every process and build, so
+    /// a stored `AtlasSnapshot` can tell whether it was taken with this
+    /// exact font.
+    pub(crate) fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
     pub(crate) fn font(&self) -> &Face<'static> {
         &self.font
     }
@@ -195,6 +280,10 @@ pub(crate) struct SharedFontCpuState {
     pub(crate) cached: crate::utils::text_atlas::Atlas,
     pub(crate) plan_cache: crate::utils::plan_cache::PlanCache,
     pub(crate) pending_cache_updates: Vec<(crate::utils::text_atlas::CacheRect, Vec<u32>)>,
+    /// `(powerline style, supersampling)` combinations the programmatic
+    /// glyphs were already pre-populated with - see
+    /// `BevyTerminalBackend::populate_programmatic_glyphs`.
+    pub(crate) programmatic_populated: Vec<(crate::backend::PowerlineStyle, u32)>,
 }
 
 impl SharedFontCpuState {
@@ -204,11 +293,48 @@ impl SharedFontCpuState {
             cached: crate::utils::text_atlas::Atlas::new(fonts, CACHE_WIDTH, CACHE_HEIGHT),
             plan_cache: crate::utils::plan_cache::PlanCache::new(fonts.count().max(2)),
             pending_cache_updates: Vec::new(),
+            programmatic_populated: Vec::new(),
+        }
+    }
+}
+
+/// A font [`Fonts::fallback_font_for`] found: one of the collection's own,
+/// or - with the `system_fonts` feature - an installed font shared by
+/// every `Fonts` that needed it.
+pub(crate) enum FallbackFont<'a> {
+    Own(&'a Font),
+    #[cfg(all(feature = "system_fonts", not(target_arch = "wasm32")))]
+    System(std::sync::Arc<Font>),
+}
+
+impl std::ops::Deref for FallbackFont<'_> {
+    type Target = Font;
+
+    fn deref(&self) -> &Font {
+        match self {
+            Self::Own(font) => font,
+            #[cfg(all(feature = "system_fonts", not(target_arch = "wasm32")))]
+            Self::System(font) => font,
         }
     }
 }
 
+/// Default [`Fonts::set_max_glyph_px`]: a quarter of the glyph atlas's
+/// side, generous for any sane terminal font size.
+pub const DEFAULT_MAX_GLYPH_PX: u32 = 512;
+
 /// A collection of fonts to use for rendering. Supports font fallback.
+///
+/// Terminals share their glyph atlas (CPU slot allocator and GPU texture)
+/// exactly when they hold the same `Arc<Fonts>` - clone the `Arc` rather
+/// than building a second, identical `Fonts`, which gets an atlas of its
+/// own. `TuiFontSource::Asset` requests for the same asset and size are
+/// given the same `Arc` automatically. The shared state sits behind a
+/// `Mutex`, so terminals on different threads can draw with one `Fonts`
+/// at once; each flush holds the lock for its whole shaping pass. Nothing
+/// is ever invalidated between different `Fonts`: glyph keys include the
+/// font's id, and a `Fonts` can't be changed (every setter takes
+/// `&mut self`) while a terminal holds it.
 pub struct Fonts {
     char_width: u32,
     char_height: u32,
@@ -220,6 +346,18 @@ pub struct Fonts {
     italic: Vec<Font>,
     bold_italic: Vec<Font>,
 
+    /// Cells taken by East Asian Ambiguous-width characters - see
+    /// [`Fonts::set_ambiguous_width`].
+    ambiguous_width: usize,
+    /// Per-character cell widths that win over everything else - see
+    /// [`Fonts::set_width_override`].
+    width_overrides: std::collections::HashMap<char, usize>,
+    /// Fonts pinned to character ranges - see [`Fonts::set_range_font`].
+    range_fonts: Vec<(std::ops::RangeInclusive<char>, Font)>,
+    /// Largest glyph the atlas accepts, per side - see
+    /// [`Fonts::set_max_glyph_px`].
+    max_glyph_px: u32,
+
     /// See [`SharedFontCpuState`]. `None` until first use -
     /// `SharedFontCpuState::new` needs `&Fonts`, which doesn't exist yet
     /// mid-construction in `Fonts::new`.
@@ -243,6 +381,10 @@ impl Fonts {
             bold: vec![],
             italic: vec![],
             bold_italic: vec![],
+            ambiguous_width: 1,
+            width_overrides: std::collections::HashMap::new(),
+            range_fonts: Vec::new(),
+            max_glyph_px: DEFAULT_MAX_GLYPH_PX,
             shared_cpu_state: Mutex::new(None),
         }
     }
@@ -416,17 +558,249 @@ impl Fonts {
     }
 }
 
+#[cfg(feature = "variable_fonts")]
 impl Fonts {
+    /// Fill the bold, italic and bold-italic slots from one variable font
+    /// instead of separate files: `font` instanced at `bold` coordinates
+    /// (e.g. `[(*b"wght", 700.0)]`) is added as a bold font, at `italic`
+    /// (e.g. `[(*b"ital", 1.0)]` or `[(*b"slnt", -10.0)]`) as an italic
+    /// font, and at both as a bold-italic font - so `BOLD`/`ITALIC` cells
+    /// get the designed weight and slant rather than the faked ones. An
+    /// empty `italic` skips the italic and bold-italic slots (italics
+    /// stay faked). Returns `false`, adding nothing, if `font` isn't a
+    /// variable font.
+    ///
+    /// The slots are only consulted with the `bold_italic_fonts` feature,
+    /// which `variable_fonts` enables.
+    pub fn add_variable_styles(
+        &mut self,
+        font: &Font,
+        bold: &[([u8; 4], f32)],
+        italic: &[([u8; 4], f32)],
+    ) -> bool {
+        let Some(bold_font) = font.with_variations(bold) else {
+            return false;
+        };
+        self.add_bold_fonts([bold_font]);
+        if !italic.is_empty() {
+            let both: Vec<_> = bold.iter().chain(italic).copied().collect();
+            self.add_italic_fonts(font.with_variations(italic));
+            self.add_bold_italic_fonts(font.with_variations(&both));
+        }
+        true
+    }
+}
+
+impl Fonts {
+    /// Draw every character in `range` from `font` - e.g.
+    /// `'\u{2600}'..='\u{27BF}'` (Miscellaneous Symbols and Dingbats) from a
+    /// symbol font - even where the primary fonts have a glyph of their
+    /// own. Consulted per glyph before the fallback chain, for every
+    /// style; a character `font` lacks falls back as usual. Later calls
+    /// win where ranges overlap. Programmatic glyphs (box drawing, blocks,
+    /// braille, powerline) are still drawn programmatically. `font` is
+    /// scaled to this collection's cell height but doesn't change the
+    /// cell width. Set it before wrapping the `Fonts` in an `Arc`.
+    pub fn set_range_font(&mut self, range: std::ops::RangeInclusive<char>, font: Font) {
+        self.range_fonts.push((range, font));
+    }
+
+    /// Reject glyphs wider or taller than `px` instead of rasterizing them
+    /// into the atlas. A rejected glyph is drawn as a hollow box in its
+    /// text color, with a warning logged the first time. Default
+    /// [`DEFAULT_MAX_GLYPH_PX`]. Whatever this says, glyphs whose atlas
+    /// slot (two cells wide, one tall) doesn't fit the 2048px atlas are
+    /// always rejected - an extreme font size can never corrupt the
+    /// atlas, it just shows boxes. Set it before wrapping the `Fonts` in
+    /// an `Arc`.
+    pub fn set_max_glyph_px(&mut self, px: u32) {
+        self.max_glyph_px = px;
+    }
+
+    /// The limit set by [`Fonts::set_max_glyph_px`].
+    pub fn max_glyph_px(&self) -> u32 {
+        self.max_glyph_px
+    }
+
+    /// How many cells East Asian Ambiguous-width characters (`‘’`, `…`,
+    /// `°`, Greek/Cyrillic letters, ...) take: `1` (the default, like
+    /// most Western terminals) or `2` (like a CJK-locale terminal). Other
+    /// values are clamped into that range.
+    ///
+    /// Governs where this crate draws glyphs and backgrounds; ratatui
+    /// itself always lays such characters out one cell wide. Apps that
+    /// choose `2` should size and position their text with
+    /// [`Fonts::text_width_cells`] (e.g. pad after each such character) so
+    /// layout and rendering agree. Set it before wrapping the `Fonts` in
+    /// an `Arc`.
+    pub fn set_ambiguous_width(&mut self, width: usize) {
+        self.ambiguous_width = width.clamp(1, 2);
+    }
+
+    /// Force `ch` to take `width` cells (clamped to `1..=2`), regardless of
+    /// its Unicode width or [`Fonts::set_ambiguous_width`] - for matching
+    /// a source terminal's idiosyncratic choices character by character.
+    pub fn set_width_override(&mut self, ch: char, width: usize) {
+        self.width_overrides.insert(ch, width.clamp(1, 2));
+    }
+
+    /// The width `ch` gets from the override table or the ambiguous-width
+    /// setting, or `None` if neither applies and its Unicode width stands.
+    fn custom_width(&self, ch: char) -> Option<usize> {
+        use unicode_width::UnicodeWidthChar;
+
+        if let Some(&width) = self.width_overrides.get(&ch) {
+            return Some(width);
+        }
+        if self.ambiguous_width != 1 && ch.width() != ch.width_cjk() {
+            return ch.width_cjk();
+        }
+        None
+    }
+
+    /// Cells a single character takes when rendered with these fonts:
+    /// its Unicode width, adjusted by [`Fonts::set_ambiguous_width`] and
+    /// [`Fonts::set_width_override`]. Control characters count as 1.
+    pub fn char_width_cells(&self, ch: char) -> usize {
+        use unicode_width::UnicodeWidthChar;
+
+        self.custom_width(ch).unwrap_or_else(|| ch.width().unwrap_or(1))
+    }
+
+    /// Cells `text` takes when rendered with these fonts - the
+    /// `unicode_width` string width with the same adjustments as
+    /// [`Fonts::char_width_cells`]. Use it for layout when ambiguous-width
+    /// or overridden characters are in play.
+    pub fn text_width_cells(&self, text: &str) -> usize {
+        use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+        let base = text.width();
+        if self.ambiguous_width == 1 && self.width_overrides.is_empty() {
+            return base;
+        }
+        text.chars().fold(base, |width, ch| match self.custom_width(ch) {
+            Some(custom) => (width + custom).saturating_sub(ch.width().unwrap_or(0)),
+            None => width,
+        })
+    }
+
     /// The minimum width (in pixels) across all fonts.
     pub fn min_width_px(&self) -> u32 {
         self.char_width
     }
 
+    /// Font units -> pixels for the last-resort font: the same
+    /// `height_px / face height` scale `flush()` lays glyphs out with.
+    fn px_per_unit(&self) -> f32 {
+        self.char_height as f32 / self.last_resort.font().height() as f32
+    }
+
+    /// Distance from the top of a cell to the baseline, in pixels, at this
+    /// collection's size. Like every metric below it comes from the
+    /// last-resort font - the face the cell grid itself is sized from -
+    /// so `ascent_px() + descent_px()` equals [`Self::height_px`] up to
+    /// float rounding.
+    pub fn ascent_px(&self) -> f32 {
+        self.last_resort.font().ascender() as f32 * self.px_per_unit()
+    }
+
+    /// Distance from the baseline down to the bottom of a cell, in pixels
+    /// (positive, unlike the face's raw descender).
+    pub fn descent_px(&self) -> f32 {
+        -(self.last_resort.font().descender() as f32) * self.px_per_unit()
+    }
+
+    /// Underline stroke as `(top, thickness)` in whole pixels from the top
+    /// of a cell, from the last-resort font's underline metrics - or a
+    /// third of the way into the descent when the face has none.
+    pub(crate) fn underline_band_px(&self) -> (u32, u32) {
+        let fallback_top = self.ascent_px() + self.descent_px() / 3.0;
+        self.stroke_band_px(self.last_resort.font().underline_metrics(), fallback_top)
+    }
+
+    /// Strikethrough stroke as `(top, thickness)` in whole pixels from the
+    /// top of a cell, from the last-resort font's strikeout metrics - or
+    /// about half an x-height above the baseline when the face has none.
+    pub(crate) fn strikethrough_band_px(&self) -> (u32, u32) {
+        let fallback_top = self.ascent_px() * 0.7;
+        self.stroke_band_px(self.last_resort.font().strikeout_metrics(), fallback_top)
+    }
+
+    /// Shared by the two above: font-unit `metrics` (the stroke's top edge
+    /// relative to the baseline, positive upwards) converted to pixels and
+    /// clamped so the whole stroke stays inside the cell.
+    fn stroke_band_px(
+        &self,
+        metrics: Option<rustybuzz::ttf_parser::LineMetrics>,
+        fallback_top_px: f32,
+    ) -> (u32, u32) {
+        let thickness = metrics
+            .map(|metrics| metrics.thickness as f32 * self.px_per_unit())
+            .unwrap_or(self.char_height as f32 / 16.0)
+            .round()
+            .clamp(1.0, self.char_height.max(1) as f32) as u32;
+        let top = metrics
+            .map(|metrics| self.ascent_px() - metrics.position as f32 * self.px_per_unit())
+            .unwrap_or(fallback_top_px)
+            .round()
+            .clamp(0.0, self.char_height.saturating_sub(thickness) as f32) as u32;
+        (top, thickness)
+    }
+
+    /// The face's recommended extra spacing between lines, in pixels. Not
+    /// part of the cell height - terminal rows are packed edge to edge.
+    pub fn line_gap_px(&self) -> f32 {
+        self.last_resort.font().line_gap() as f32 * self.px_per_unit()
+    }
+
+    /// Unrounded horizontal advance of the last-resort font's `m`, in
+    /// pixels - what [`Self::min_width_px`] truncates to a whole pixel
+    /// count (before taking the minimum across all fonts).
+    pub fn advance_px(&self) -> f32 {
+        self.last_resort.advance * self.px_per_unit()
+    }
+
     /// Get the last resort font's ID (for programmatic glyph rendering)
     pub(crate) fn last_resort_id(&self) -> u64 {
         self.last_resort.id()
     }
 
+    /// Every font in this collection: the last-resort font, then the
+    /// regular, bold, italic and bold-italic lists, then the range fonts.
+    pub(crate) fn fonts_in_fallback_order(&self) -> impl Iterator<Item = &Font> {
+        std::iter::once(&self.last_resort)
+            .chain(self.regular.iter())
+            .chain(self.bold.iter())
+            .chain(self.italic.iter())
+            .chain(self.bold_italic.iter())
+            .chain(self.range_fonts.iter().map(|(_, font)| font))
+    }
+
+    /// The font [`Fonts::set_range_font`] pinned `ch` to, if any and if
+    /// that font has a glyph for it.
+    pub(crate) fn range_font_for(&self, ch: char) -> Option<&Font> {
+        self.range_fonts
+            .iter()
+            .rev()
+            .find(|(range, font)| range.contains(&ch) && font.font().glyph_index(ch).is_some())
+            .map(|(_, font)| font)
+    }
+
+    /// First font able to render `ch`: the fallback chain in order, then -
+    /// with the `system_fonts` feature - a font installed on the OS (see
+    /// `crate::system_fonts`). `None` if nothing covers it. Used per glyph
+    /// when the font a row was shaped with lacks one of its characters.
+    pub(crate) fn fallback_font_for(&self, ch: char) -> Option<FallbackFont<'_>> {
+        let found = self
+            .fonts_in_fallback_order()
+            .find(|font| font.font().glyph_index(ch).is_some())
+            .map(FallbackFont::Own);
+        #[cfg(all(feature = "system_fonts", not(target_arch = "wasm32")))]
+        let found =
+            found.or_else(|| crate::system_fonts::font_for_char(ch).map(FallbackFont::System));
+        found
+    }
+
     pub(crate) fn count(&self) -> usize {
         1 + self.bold.len() + self.italic.len() + self.bold_italic.len() + self.regular.len()
     }
@@ -530,6 +904,89 @@ impl Fonts {
     }
 }
 
+/// A parsed base font (plus optional fallbacks) that hands out sized
+/// `Arc<Fonts>` on demand - for apps spanning mixed-DPI monitors that keep
+/// one `Fonts` per pixel size and switch terminals between them.
+///
+/// Parsing (`Font::new` / `Font::from_vec`) happens once, up front; every
+/// size produced by [`FontSet::fonts`] clones the already-parsed `Font`s
+/// (cheap - `Face` is a set of table offsets into the shared bytes) and
+/// only re-derives the per-size metrics. Each size is built at most once
+/// and cached, so repeated lookups return the same `Arc` - which matters
+/// beyond allocation cost: terminals share glyph-atlas state exactly when
+/// they hold the same `Arc<Fonts>` (see [`Fonts::identity`] /
+/// IMPROVEMENT.md C3), so two terminals asking for the same size also
+/// share one atlas.
+///
+/// # Example
+///
+/// ```no_run
+/// use bevy_tui_texture::{Font, FontSet};
+///
+/// let font_data = include_bytes!("../examples/assets/fonts/Mplus1Code-Regular.ttf");
+/// let set = FontSet::new(Font::new(font_data).expect("Failed to load font"));
+///
+/// let low_dpi = set.fonts(16);
+/// let high_dpi = set.fonts(32); // no re-parse
+/// ```
+pub struct FontSet {
+    base: Font,
+    fallbacks: Vec<Font>,
+    sized: Mutex<std::collections::HashMap<u32, Arc<Fonts>>>,
+}
+
+impl FontSet {
+    /// Create a set around `font`, used as every produced `Fonts`'
+    /// last-resort font (see [`Fonts::new`]).
+    pub fn new(font: Font) -> Self {
+        Self {
+            base: font,
+            fallbacks: vec![],
+            sized: Mutex::new(std::collections::HashMap::new()),
+        }
+    }
+
+    /// Add fallback fonts, sorted into style slots the same way
+    /// [`Fonts::add_fonts`] does for every size produced afterwards.
+    /// Drops any sizes already cached, since they were built without these
+    /// fallbacks - `Arc`s handed out earlier stay valid but are no longer
+    /// returned by [`FontSet::fonts`].
+    pub fn add_fonts(&mut self, fonts: impl IntoIterator<Item = Font>) {
+        self.fallbacks.extend(fonts);
+        self.sized
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clear();
+    }
+
+    /// The `Fonts` for `size_px`, built on first request and cached after
+    /// that - every call with the same size returns the same `Arc`.
+    pub fn fonts(&self, size_px: u32) -> Arc<Fonts> {
+        let mut sized = self
+            .sized
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        Arc::clone(sized.entry(size_px).or_insert_with(|| {
+            let mut fonts = Fonts::new(self.base.clone(), size_px);
+            if !self.fallbacks.is_empty() {
+                fonts.add_fonts(self.fallbacks.iter().cloned());
+            }
+            Arc::new(fonts)
+        }))
+    }
+
+    /// Pixel sizes currently cached, in ascending order.
+    pub fn cached_sizes(&self) -> Vec<u32> {
+        let sized = self
+            .sized
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut sizes: Vec<u32> = sized.keys().copied().collect();
+        sizes.sort_unstable();
+        sizes
+    }
+}
+
 // ============================================================================
 // Test: font_for_cell's per-style fallback order (P2-4). Pure CPU - loads
 // the shipped M+ font multiple times (each load gets a fresh random `id()`,
@@ -669,4 +1126,235 @@ mod font_for_cell_tests {
             "bold is real (no faking needed) but italic must be faked on top"
         );
     }
+
+    #[test]
+    fn fallback_font_for_takes_the_first_covering_font_in_chain_order() {
+        let last_resort = fresh_font();
+        let last_resort_id = last_resort.id();
+        let mut fonts = Fonts::new(last_resort, 16);
+        fonts.add_regular_fonts([fresh_font()]);
+
+        // Every font here covers 'a' - the last-resort font comes first.
+        let font = fonts.fallback_font_for('a').expect("'a' is covered");
+        assert_eq!(font.id(), last_resort_id);
+        // A noncharacter no font has a glyph for.
+        assert!(fonts.fallback_font_for('\u{FFFF}').is_none());
+    }
+
+    #[test]
+    fn range_fonts_claim_their_characters_and_later_ranges_win() {
+        let mut fonts = Fonts::new(fresh_font(), 16);
+        let symbols = fresh_font();
+        let symbols_id = symbols.id();
+        let letters = fresh_font();
+        let letters_id = letters.id();
+        fonts.set_range_font('a'..='z', symbols);
+        fonts.set_range_font('x'..='z', letters);
+
+        let id_for = |fonts: &Fonts, ch| fonts.range_font_for(ch).map(Font::id);
+        assert_eq!(id_for(&fonts, 'b'), Some(symbols_id));
+        assert_eq!(id_for(&fonts, 'y'), Some(letters_id), "the later, overlapping range wins");
+        assert_eq!(id_for(&fonts, 'A'), None, "characters outside every range aren't pinned");
+
+        // A range covering a character its font lacks leaves it to the
+        // regular fallback walk.
+        fonts.set_range_font('\u{FFF0}'..='\u{FFFF}', fresh_font());
+        assert_eq!(id_for(&fonts, '\u{FFFF}'), None);
+    }
+}
+
+// ============================================================================
+// Test: FontSet sizing (multi-DPI). Pure CPU - every size must come from
+// the one parsed `Font` (same `id()`, which is minted per parse in
+// `Font::build`), and repeated lookups must hit the cache.
+// ============================================================================
+
+#[cfg(all(test, feature = "variable_fonts"))]
+mod variable_font_tests {
+    use super::*;
+
+    const FONT_DATA: &[u8] = include_bytes!("../examples/assets/fonts/Mplus1Code-Regular.ttf");
+
+    #[test]
+    fn static_fonts_are_not_instanced() {
+        let font = Font::new(FONT_DATA).expect("failed to load test font");
+        assert!(!font.is_variable());
+        assert!(font.variation_axes().is_empty());
+        assert!(font.with_variations(&[(*b"wght", 700.0)]).is_none());
+
+        let mut fonts = Fonts::new(font.clone(), 16);
+        let count = fonts.count();
+        assert!(
+            !fonts.add_variable_styles(&font, &[(*b"wght", 700.0)], &[(*b"ital", 1.0)]),
+            "a static font has no styles to instance"
+        );
+        assert_eq!(fonts.count(), count, "nothing may be added for a static font");
+    }
+}
+
+#[cfg(test)]
+mod font_set_tests {
+    use super::*;
+
+    const FONT_DATA: &[u8] = include_bytes!("../examples/assets/fonts/Mplus1Code-Regular.ttf");
+
+    #[test]
+    fn two_sizes_share_the_parsed_face() {
+        let base = Font::from_vec(FONT_DATA.to_vec()).expect("failed to load test font");
+        let base_id = base.id();
+        let set = FontSet::new(base);
+
+        let small = set.fonts(16);
+        let large = set.fonts(32);
+
+        // A re-parse would mint a fresh random id (see `Font::build`).
+        assert_eq!(small.last_resort_id(), base_id);
+        assert_eq!(large.last_resort_id(), base_id);
+        assert_eq!(small.height_px(), 16);
+        assert_eq!(large.height_px(), 32);
+        assert!(large.min_width_px() > small.min_width_px());
+    }
+
+    #[test]
+    fn same_size_returns_the_same_arc() {
+        let set = FontSet::new(Font::new(FONT_DATA).expect("failed to load test font"));
+
+        let a = set.fonts(20);
+        let b = set.fonts(20);
+
+        assert!(
+            Arc::ptr_eq(&a, &b),
+            "same size must reuse the cached Fonts (and with it the shared atlas)"
+        );
+        assert_eq!(set.cached_sizes(), vec![20]);
+    }
+
+    #[test]
+    fn add_fonts_invalidates_cached_sizes() {
+        let mut set = FontSet::new(Font::new(FONT_DATA).expect("failed to load test font"));
+        let before = set.fonts(16);
+
+        set.add_fonts([Font::new(FONT_DATA).expect("failed to load test font")]);
+        let after = set.fonts(16);
+
+        assert!(!Arc::ptr_eq(&before, &after));
+        assert_eq!(after.count(), 2);
+    }
+}
+
+// ============================================================================
+// Test: font metrics. Pure CPU - the bundled M+ font has 1000 units of
+// ascent, 235 of descent, no line gap and a 500-unit `m`, so at a size of
+// 1235px one font unit is exactly one pixel.
+// ============================================================================
+
+#[cfg(test)]
+mod metrics_tests {
+    use super::*;
+
+    const FONT_DATA: &[u8] = include_bytes!("../examples/assets/fonts/Mplus1Code-Regular.ttf");
+
+    fn fonts(size_px: u32) -> Fonts {
+        Fonts::new(Font::new(FONT_DATA).expect("failed to load test font"), size_px)
+    }
+
+    #[test]
+    fn unit_scale_matches_the_font_tables() {
+        let fonts = fonts(1235);
+        assert_eq!(fonts.ascent_px(), 1000.0);
+        assert_eq!(fonts.descent_px(), 235.0);
+        assert_eq!(fonts.line_gap_px(), 0.0);
+        assert_eq!(fonts.advance_px(), 500.0);
+    }
+
+    #[test]
+    fn metrics_scale_with_size_and_fill_the_cell_height() {
+        let fonts = fonts(16);
+        let scale = 16.0 / 1235.0;
+        assert!((fonts.ascent_px() - 1000.0 * scale).abs() < 1e-4);
+        assert!((fonts.ascent_px() + fonts.descent_px() - 16.0).abs() < 1e-4);
+        assert_eq!(fonts.advance_px() as u32, fonts.min_width_px());
+    }
+
+    #[test]
+    fn fingerprint_follows_the_font_file_not_the_process() {
+        let embedded = Font::new(FONT_DATA).expect("failed to load test font");
+        let loaded = Font::from_vec(FONT_DATA.to_vec()).expect("failed to load test font");
+        assert_ne!(embedded.id(), loaded.id());
+        assert_eq!(embedded.fingerprint(), loaded.fingerprint());
+
+        let other = include_bytes!("../examples/assets/fonts/fusion-pixel-10px-monospaced-ja.ttf");
+        let other = Font::new(other).expect("failed to load test font");
+        assert_ne!(other.fingerprint(), embedded.fingerprint());
+    }
+}
+
+// ============================================================================
+// Test: ambiguous-width and per-character width overrides. Pure CPU - only
+// the width tables are consulted, the font is never shaped.
+// ============================================================================
+
+#[cfg(test)]
+mod width_tests {
+    use super::*;
+
+    fn test_fonts() -> Fonts {
+        let font_data = include_bytes!("../examples/assets/fonts/Mplus1Code-Regular.ttf");
+        Fonts::new(Font::new(font_data).expect("failed to load test font"), 16)
+    }
+
+    #[test]
+    fn ambiguous_width_defaults_to_one_cell() {
+        let fonts = test_fonts();
+        assert_eq!(fonts.char_width_cells('\u{2018}'), 1);
+        assert_eq!(fonts.char_width_cells('a'), 1);
+        assert_eq!(fonts.char_width_cells('漢'), 2);
+        assert_eq!(fonts.text_width_cells("\u{2018}a\u{2019}"), 3);
+    }
+
+    #[test]
+    fn ambiguous_width_two_widens_only_ambiguous_characters() {
+        let mut fonts = test_fonts();
+        fonts.set_ambiguous_width(2);
+        assert_eq!(fonts.char_width_cells('\u{2018}'), 2);
+        assert_eq!(fonts.char_width_cells('°'), 2);
+        assert_eq!(fonts.char_width_cells('a'), 1);
+        assert_eq!(fonts.char_width_cells('漢'), 2);
+        assert_eq!(fonts.text_width_cells("\u{2018}a\u{2019}"), 5);
+        assert_eq!(fonts.text_width_cells("abc"), 3);
+
+        fonts.set_ambiguous_width(7);
+        assert_eq!(fonts.char_width_cells('\u{2018}'), 2, "clamped to 2");
+    }
+
+    #[test]
+    fn width_override_wins_over_ambiguous_setting() {
+        let mut fonts = test_fonts();
+        fonts.set_ambiguous_width(2);
+        fonts.set_width_override('\u{2018}', 1);
+        fonts.set_width_override('#', 2);
+        assert_eq!(fonts.char_width_cells('\u{2018}'), 1);
+        assert_eq!(fonts.char_width_cells('\u{2019}'), 2);
+        assert_eq!(fonts.char_width_cells('#'), 2);
+        assert_eq!(fonts.text_width_cells("\u{2018}#\u{2019}"), 6);
+    }
+
+    #[test]
+    fn fullwidth_and_halfwidth_forms_keep_their_widths() {
+        let mut fonts = test_fonts();
+        for ambiguous_width in [1, 2] {
+            // Neither block is East Asian Ambiguous, so the setting must
+            // not move them.
+            fonts.set_ambiguous_width(ambiguous_width);
+            for fullwidth in ['！', 'Ａ', '（', '￥'] {
+                assert_eq!(fonts.char_width_cells(fullwidth), 2, "{fullwidth:?}");
+            }
+            // Halfwidth katakana, including the standalone sound marks
+            // (U+FF9E/U+FF9F are spacing characters, not combining).
+            for halfwidth in ['ｱ', 'ｶ', 'ﾞ', 'ﾟ', '｡', 'ｰ'] {
+                assert_eq!(fonts.char_width_cells(halfwidth), 1, "{halfwidth:?}");
+            }
+            assert_eq!(fonts.text_width_cells("ｶﾞｰ！"), 5);
+        }
+    }
 }
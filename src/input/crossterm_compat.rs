@@ -14,8 +14,8 @@ use bevy::input::mouse::MouseButton;
 use crossterm::event as ct;
 
 impl InputEvent {
-    /// `KeyCode::Unidentified`, non-Left/Right/Middle mouse buttons, and
-    /// `Resize` return `None` - see the module doc comment.
+    /// `KeyCode::Unidentified`, non-Left/Right/Middle mouse buttons,
+    /// `Resize` and `Edit` return `None` - see the module doc comment.
     pub fn to_crossterm(&self) -> Option<ct::Event> {
         Some(match self {
             InputEvent::Key(k) => ct::Event::Key(key_to_crossterm(k)?),
@@ -24,6 +24,8 @@ impl InputEvent {
             InputEvent::FocusGained => ct::Event::FocusGained,
             InputEvent::FocusLost => ct::Event::FocusLost,
             InputEvent::Resize { .. } => return None,
+            // Synthesized from a `Key` that converts on its own.
+            InputEvent::Edit(_) => return None,
         })
     }
 
@@ -28,10 +28,20 @@ use bevy::input::keyboard::KeyCode as BevyKeyCode;
 #[cfg(feature = "mouse_input")]
 use bevy::input::mouse::MouseWheel;
 use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Per-event hit-test tracing (cursor bounds, grid conversion, candidate
+/// sorting). Compiled out entirely unless the `verbose_input_logging`
+/// feature is on, so the formatting never runs on a mouse move in normal
+/// builds - a `tracing` level filter would still evaluate the call site.
+/// Warnings and errors don't go through this.
 #[cfg(feature = "mouse_input")]
-use tracing::debug;
-//use bevy::log::debug;
-//use log::debug;
+macro_rules! hit_test_debug {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "verbose_input_logging")]
+        tracing::debug!($($arg)*);
+    };
+}
 
 // Ray casting for 3D mouse input
 #[cfg(all(feature = "mouse_input", feature = "3d"))]
@@ -56,17 +66,89 @@ pub mod crossterm_compat;
 /// `keyboard_input`/`mouse_input` are both disabled - a caller can write
 /// `TerminalEvent`s from any source (an adapter for another input
 /// backend, a test harness, a network channel) via `MessageWriter`.
+/// Build them with [`TerminalEvent::new`]: the struct is non-exhaustive,
+/// so fields can be added without breaking such writers.
 #[derive(Message, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct TerminalEvent {
     /// The Tui entity that should receive this message.
     pub target: Entity,
     pub input: InputEvent,
+    /// The target's [`TerminalId`], copied in by the input systems when
+    /// the `Tui` entity carries one - a stable key for routing that
+    /// survives respawns, save/load and network replication, unlike
+    /// `target`. `None` for untagged terminals.
+    pub source_tag: Option<u64>,
+}
+
+impl TerminalEvent {
+    /// An untagged event for `target` - for injecting input from outside
+    /// the built-in input systems.
+    pub fn new(target: Entity, input: InputEvent) -> Self {
+        Self {
+            target,
+            input,
+            source_tag: None,
+        }
+    }
+}
+
+/// Per-terminal filtering for [`TerminalEvent`] readers.
+///
+/// Replaces the `events.read().filter(|e| e.target == entity)` pattern every
+/// handler otherwise repeats - and with it the easy mistake of comparing
+/// against the wrong entity or forgetting the filter altogether, which
+/// silently makes one terminal react to another's input. Like `read()`,
+/// events not matching `entity` are still consumed.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_tui_texture::prelude::*;
+///
+/// #[derive(Component)]
+/// struct CatalogTerminal;
+///
+/// fn handle_terminal_events(
+///     mut events: MessageReader<TerminalEvent>,
+///     query: Query<Entity, With<CatalogTerminal>>,
+/// ) {
+///     let Ok(terminal_entity) = query.single() else {
+///         return;
+///     };
+///     for input in events.for_terminal(terminal_entity) {
+///         match input {
+///             InputEvent::Mouse(m) if matches!(m.kind, MouseEventKind::Down(_)) => {
+///                 info!("press at col={}, row={}", m.column, m.row);
+///             }
+///             InputEvent::Key(key) if key.kind == KeyEventKind::Press => {
+///                 info!("key {:?}", key.code);
+///             }
+///             _ => {}
+///         }
+///     }
+/// }
+/// ```
+pub trait TerminalEventReaderExt {
+    /// Reads every unread event, yielding the [`InputEvent`] of those
+    /// targeted at `entity` (a `Tui` entity - see [`TerminalEvent::target`]).
+    fn for_terminal(&mut self, entity: Entity) -> impl Iterator<Item = &InputEvent>;
+}
+
+impl TerminalEventReaderExt for MessageReader<'_, '_, TerminalEvent> {
+    fn for_terminal(&mut self, entity: Entity) -> impl Iterator<Item = &InputEvent> {
+        self.read()
+            .filter(move |event| event.target == entity)
+            .map(|event| &event.input)
+    }
 }
 
 /// Mirror of `crossterm::event::Event`. Self-defined because crossterm does
 /// not build on wasm32-unknown-unknown; see the `crossterm-compat` feature
 /// for lossy conversions to/from the real crossterm type on native.
+/// Non-exhaustive: events crossterm has no counterpart for (like
+/// [`InputEvent::Edit`]) may be added.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum InputEvent {
     Key(KeyEvent),
     Mouse(MouseEvent),
@@ -84,6 +166,60 @@ pub enum InputEvent {
     /// actually need (see `examples/resize.rs`); grid changes are always
     /// caller-initiated, so the caller already knows them.
     Resize { pixels: UVec2 },
+    /// Text-editing intent synthesized from a key press, written right
+    /// after the [`InputEvent::Key`] it came from (which is still
+    /// delivered) - only when [`TerminalInputConfig::edit_actions`] is on.
+    /// Lets a text field match on intents instead of re-implementing the
+    /// key mapping. Not part of the crossterm mirror; converts to `None`
+    /// in `crossterm-compat`.
+    Edit(EditAction),
+}
+
+/// Text-editing intent carried by [`InputEvent::Edit`]. See
+/// [`EditAction::from_key`] for which keys produce which action.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EditAction {
+    /// Delete the character before the caret.
+    Backspace,
+    /// Delete the character after the caret.
+    Delete,
+    /// Insert a line break (Enter).
+    Newline,
+    /// Move the caret one character left.
+    Left,
+    /// Move the caret one character right.
+    Right,
+    /// Move the caret to the start of the line.
+    Home,
+    /// Move the caret to the end of the line.
+    End,
+}
+
+impl EditAction {
+    /// The edit `key` stands for: Backspace, Delete, Enter, Left, Right,
+    /// Home and End on `Press` or `Repeat`. Releases, other keys, and any
+    /// key held with Ctrl/Alt/Meta (a shortcut, not an edit) map to
+    /// `None`; Shift alone is allowed, so Shift+Enter is still a newline.
+    pub fn from_key(key: &KeyEvent) -> Option<Self> {
+        if key.kind == KeyEventKind::Release
+            || key.modifiers.ctrl
+            || key.modifiers.alt
+            || key.modifiers.meta
+        {
+            return None;
+        }
+        Some(match key.code {
+            KeyCode::Backspace => EditAction::Backspace,
+            KeyCode::Delete => EditAction::Delete,
+            KeyCode::Enter => EditAction::Newline,
+            KeyCode::Left => EditAction::Left,
+            KeyCode::Right => EditAction::Right,
+            KeyCode::Home => EditAction::Home,
+            KeyCode::End => EditAction::End,
+            _ => return None,
+        })
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -191,6 +327,129 @@ pub struct TerminalFocus {
     pub focused: Option<Entity>,
 }
 
+/// The terminal under the mouse cursor right now, and the cell it points
+/// at - immediate-mode hover state for logic that would rather read a
+/// resource than follow `MouseMove`/`MouseEnter`/`MouseLeave` events
+/// (e.g. a Bevy-side hover highlight).
+///
+/// Updated by `mouse_input_system` from the same topmost hit it emits
+/// events for, so it agrees with the event stream: `entity` is the hit
+/// surface entity (like [`TerminalFocus::focused`]; map it to its `Tui`
+/// through [`TuiSurface`](crate::setup::TuiSurface) for attached
+/// terminals), and `cell` is `(col, row)` after
+/// [`TerminalInputConfig::snap_wide_glyphs`]. Both are `None` while no
+/// terminal with `TerminalInput::mouse` is under the cursor. Only written
+/// when the hover actually changes, so `Res<HoveredTerminal>::is_changed`
+/// is a cheap "hover moved" check.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HoveredTerminal {
+    /// Surface entity of the hovered terminal.
+    pub entity: Option<Entity>,
+    /// Hovered cell as `(col, row)`.
+    pub cell: Option<(u16, u16)>,
+}
+
+impl HoveredTerminal {
+    /// Built from `mouse_input_system`'s `(entity, col, row)` hover.
+    fn from_hover(hover: Option<(Entity, u16, u16)>) -> Self {
+        Self {
+            entity: hover.map(|(entity, _, _)| entity),
+            cell: hover.map(|(_, col, row)| (col, row)),
+        }
+    }
+
+    /// The inverse of `from_hover` - what `mouse_input_system` compares a
+    /// fresh hit against to dedupe `MouseMove` events.
+    fn hover(&self) -> Option<(Entity, u16, u16)> {
+        let (col, row) = self.cell?;
+        Some((self.entity?, col, row))
+    }
+}
+
+/// Opt-in multi-focus: broadcast keyboard input to several terminals at once.
+///
+/// Not inserted by `TerminalPlugin` - single-focus via [`TerminalFocus`]
+/// stays the default. While this resource exists with at least one member,
+/// `keyboard_input_system` writes every key event once per member (each
+/// still subject to its own `TerminalInput::keyboard`) and ignores
+/// `TerminalFocus::focused`. Remove the resource, or clear `members`, to
+/// fall back to single focus.
+///
+/// Members are surface entities, exactly like `TerminalFocus::focused`;
+/// events are retargeted to each member's `Tui` entity. Mouse routing and
+/// Tab cycling are unaffected and keep operating on `TerminalFocus` - a
+/// chat+input split UI typically keeps both panes in the group and uses
+/// `TerminalFocus` only for which pane shows a cursor.
+#[derive(Resource, Default, Debug, Clone)]
+pub struct TerminalFocusGroup {
+    /// Surface entities receiving keyboard input, in event-write order.
+    /// Duplicates are written once.
+    pub members: Vec<Entity>,
+}
+
+/// One local player (or any other independent input owner) in a
+/// split-screen setup - see [`TerminalSourceFocus`]. The number is the
+/// app's own player id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct InputSource(pub u32);
+
+/// Opt-in per-player focus: several terminals focused at once, each by a
+/// different [`InputSource`].
+///
+/// Not inserted by `TerminalPlugin`. While this resource exists with at
+/// least one entry in `focused`, `keyboard_input_system` routes each key
+/// event to the terminal focused by the event's source - the window's
+/// entry in `window_sources`, else `keyboard` - and ignores
+/// [`TerminalFocusGroup`] and [`TerminalFocus`]. A key event with no
+/// source, or whose source focuses nothing, is dropped.
+///
+/// Bevy doesn't tell keyboards apart, only the windows their events land
+/// in, so a single-window game can route the keyboard to one player at
+/// most; the other players typically drive their terminals with
+/// gamepads. Gamepad input never goes through this crate's systems: a
+/// gamepad system looks up [`TerminalSourceFocus::focused_by`] for its
+/// player and writes `TerminalEvent`s for that entity itself.
+///
+/// Entries are surface entities, like `TerminalFocus::focused`; key events
+/// are retargeted to each surface's `Tui` entity. Click-to-focus and Tab
+/// cycling keep operating on `TerminalFocus` - move a player's focus with
+/// [`TerminalSourceFocus::focus`].
+#[derive(Resource, Default, Debug, Clone)]
+pub struct TerminalSourceFocus {
+    /// Surface entity each source currently drives.
+    pub focused: HashMap<InputSource, Entity>,
+    /// Source owning the keyboard in each window, for multi-window
+    /// split-screen.
+    pub window_sources: HashMap<Entity, InputSource>,
+    /// Source owning the keyboard in every window not listed in
+    /// `window_sources`, or `None` to drop key events from those windows.
+    pub keyboard: Option<InputSource>,
+}
+
+impl TerminalSourceFocus {
+    /// Make `source` drive `surface`. Two sources may focus the same
+    /// terminal.
+    pub fn focus(&mut self, source: InputSource, surface: Entity) {
+        self.focused.insert(source, surface);
+    }
+
+    /// Clear `source`'s focus.
+    pub fn unfocus(&mut self, source: InputSource) {
+        self.focused.remove(&source);
+    }
+
+    /// Surface entity `source` currently drives, if any.
+    pub fn focused_by(&self, source: InputSource) -> Option<Entity> {
+        self.focused.get(&source).copied()
+    }
+
+    /// Surface entity a key event delivered to `window` should go to.
+    fn keyboard_target(&self, window: Entity) -> Option<Entity> {
+        let source = self.window_sources.get(&window).copied().or(self.keyboard)?;
+        self.focused_by(source)
+    }
+}
+
 /// Global config for terminal input. Inserted by `TerminalPlugin`.
 #[derive(Resource, Clone, Debug)]
 pub struct TerminalInputConfig {
@@ -202,6 +461,37 @@ pub struct TerminalInputConfig {
     pub auto_focus: bool,
     /// Mouse button used for focus/selection
     pub focus_button: MouseButton,
+    /// Report mouse events over the right half of a wide (e.g. CJK)
+    /// character at the column the character starts in - where its text
+    /// actually lives in ratatui's buffer - instead of the continuation
+    /// column the cursor is physically over. Off by default: apps mapping
+    /// clicks to raw cell geometry (grids, pixel-art) want every column.
+    pub snap_wide_glyphs: bool,
+    /// Follow each editing key press with an [`InputEvent::Edit`] (see
+    /// [`EditAction::from_key`]). Off by default: shortcut-driven apps
+    /// match on [`InputEvent::Key`] alone and never see the extra events.
+    pub edit_actions: bool,
+    /// When a terminal gains focus (Tab, a click, or setting
+    /// [`TerminalFocus::focused`]) while the cursor is already over it,
+    /// send it a synthetic [`MouseEventKind::Moved`] at the hovered cell,
+    /// so hover-driven widgets update without waiting for the mouse to
+    /// move - see [`focus_hover_system`]. Off by default: apps that treat
+    /// every `Moved` as real pointer motion don't want the extra event.
+    pub move_on_focus: bool,
+    /// When the window loses focus, also take focus away from the focused
+    /// terminal (it gets [`InputEvent::FocusLost`]) and hand it back when
+    /// the window is focused again ([`InputEvent::FocusGained`]) - see
+    /// [`window_focus_system`]. Off by default: terminal focus is app
+    /// state, and most apps want it to survive an Alt+Tab untouched.
+    pub clear_focus_on_window_blur: bool,
+    /// How far, in logical window pixels, the pointer may move between a
+    /// press and its release for the pair to still count as a click: until
+    /// the pointer has moved further than this, no `Drag` is sent and the
+    /// `Up` is reported at the cell (and terminal) of the `Down`, so a
+    /// jittery click never turns into a one-cell drag. Beyond it the press
+    /// is an ordinary drag. `0.0` (the default) turns this off: every move
+    /// with a button held is a drag.
+    pub click_drag_threshold: f32,
 }
 
 impl Default for TerminalInputConfig {
@@ -211,8 +501,62 @@ impl Default for TerminalInputConfig {
             mouse_enabled: true,
             auto_focus: true,
             focus_button: MouseButton::Left,
+            snap_wide_glyphs: false,
+            edit_actions: false,
+            move_on_focus: false,
+            clear_focus_on_window_blur: false,
+            click_drag_threshold: 0.0,
+        }
+    }
+}
+
+/// Keys a terminal has been sent a [`KeyEventKind::Press`] for and no
+/// `Release` yet. Inserted by `TerminalPlugin`; `keyboard_input_system`
+/// records into it, [`window_focus_system`] releases whatever is left when
+/// the window loses focus - the OS doesn't deliver the release of a key
+/// let go while another window has focus.
+///
+/// Keys are matched by physical key, so a release pairs with its press
+/// even when the logical key changed in between (Shift let go first, or
+/// the `BackTab` quirk on [`KeyCode::BackTab`]).
+#[derive(Resource, Default, Debug)]
+pub struct HeldKeys {
+    /// `(surface entity, physical key, the Press)` per key still down.
+    held: Vec<(Entity, BevyKeyCode, KeyEvent)>,
+}
+
+impl HeldKeys {
+    /// Whether no terminal has a key held.
+    pub fn is_empty(&self) -> bool {
+        self.held.is_empty()
+    }
+
+    /// Track `key`, written to `surface` for the physical key `physical`.
+    fn record(&mut self, surface: Entity, physical: BevyKeyCode, key: KeyEvent) {
+        let same = |&(held_surface, held_physical, _): &(Entity, BevyKeyCode, KeyEvent)| {
+            (held_surface, held_physical) == (surface, physical)
+        };
+        match key.kind {
+            KeyEventKind::Press if !self.held.iter().any(same) => {
+                self.held.push((surface, physical, key));
+            }
+            KeyEventKind::Release => self.held.retain(|entry| !same(entry)),
+            _ => {}
         }
     }
+
+    /// A `Release` for every held key, in press order, and forget them.
+    /// Modifiers are empty: they were let go along with everything else.
+    fn release_all(&mut self) -> impl Iterator<Item = (Entity, KeyEvent)> + '_ {
+        self.held.drain(..).map(|(surface, _, press)| {
+            let release = KeyEvent {
+                kind: KeyEventKind::Release,
+                modifiers: KeyModifiers::default(),
+                ..press
+            };
+            (surface, release)
+        })
+    }
 }
 
 /// Cached cursor position in window coordinates.
@@ -232,12 +576,30 @@ pub struct CursorPosition {
 // ============================================================================
 
 /// Enable input routing for a terminal. Without this component, terminals are display-only.
+/// Non-exhaustive - start from [`TerminalInput::default`] or
+/// [`TerminalInput::new`] and set the remaining fields on the value.
 #[derive(Component, Debug, Clone)]
+#[non_exhaustive]
 pub struct TerminalInput {
     /// Whether this terminal can receive keyboard input
     pub keyboard: bool,
     /// Whether this terminal can receive mouse input
     pub mouse: bool,
+    /// Whether Tab focus cycling ([`terminal_focus_system`]) stops at this
+    /// terminal. Independent of [`Self::keyboard`]: a terminal with
+    /// `keyboard: true, focusable: false` still gets key events once it is
+    /// focused by a click or by setting [`TerminalFocus::focused`], it just
+    /// isn't reachable with Tab - useful for status bars, log views and
+    /// other mostly display-only panes. Terminals with `keyboard: false`
+    /// are never cycled to, whatever this says.
+    pub focusable: bool,
+    /// Whether a mesh terminal is hit from behind too, not just on its
+    /// front face - for double-sided panels (e.g. a material with
+    /// `cull_mode: None`) that stay interactive once they rotate past 90°.
+    /// The hit maps to the cell drawn at that point of the mesh, which
+    /// from behind shows mirrored just like the picture does. Ignored by
+    /// UI terminals. Default `false`.
+    pub hit_backfaces: bool,
 }
 
 impl Default for TerminalInput {
@@ -245,10 +607,123 @@ impl Default for TerminalInput {
         Self {
             keyboard: true,
             mouse: true,
+            focusable: true,
+            hit_backfaces: false,
+        }
+    }
+}
+
+impl TerminalInput {
+    /// Keyboard and / or mouse input, everything else as in
+    /// [`TerminalInput::default`].
+    pub fn new(keyboard: bool, mouse: bool) -> Self {
+        Self {
+            keyboard,
+            mouse,
+            ..Self::default()
         }
     }
 }
 
+/// A stable, app-chosen id for a terminal, copied into
+/// [`TerminalEvent::source_tag`] of every event the input systems write
+/// for it. Put it on the `Tui` entity (the event target); entity ids
+/// change across respawns, this doesn't.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TerminalId(pub u64);
+
+/// Replace the built-in cursor→cell mapping of one terminal - the
+/// bounding-box test of a UI node, the ray cast against a mesh - with
+/// your own, e.g. for a curved CRT mesh whose UVs don't map the grid
+/// linearly, or a UI terminal that isn't rectangular.
+/// [`mouse_input_system`] calls it with a [`HitTestInput`] whenever it
+/// recomputes the hover (cursor moved, button/touch transition, wheel,
+/// camera or terminal moved); return the `(col, row)` under the cursor,
+/// or `None` for a miss. Event emission is unchanged: moves, clicks,
+/// drags, scrolls, focus and [`HoveredTerminal`] all follow the returned
+/// cell. Cells outside the grid (per [`TerminalDimensions`]) count as a
+/// miss.
+///
+/// When several terminals are hit, a custom hit on a UI terminal competes
+/// by its bevy_ui stacking order, as usual; on a mesh terminal by the
+/// distance from the frontmost ray's origin to the terminal's translation.
+///
+/// [`TerminalDimensions`]: crate::bevy_plugin::TerminalDimensions
+#[cfg(feature = "mouse_input")]
+#[derive(Component)]
+pub struct CustomHitTest(pub Box<dyn Fn(&HitTestInput) -> Option<(u16, u16)> + Send + Sync>);
+
+#[cfg(feature = "mouse_input")]
+impl CustomHitTest {
+    /// Wrap a hit-test closure.
+    pub fn new(
+        hit_test: impl Fn(&HitTestInput) -> Option<(u16, u16)> + Send + Sync + 'static,
+    ) -> Self {
+        Self(Box::new(hit_test))
+    }
+}
+
+/// What a [`CustomHitTest`] gets to work with. Everything is as the
+/// built-in hit tests see it this frame.
+#[cfg(feature = "mouse_input")]
+pub struct HitTestInput<'a> {
+    /// Cursor position in logical window pixels, top-left origin, +Y down
+    /// (the primary window's, or a touch's - see [`CursorPosition`]).
+    pub cursor: Vec2,
+    /// World-space rays through the cursor, one per active camera whose
+    /// viewport contains it, topmost-rendered camera (highest
+    /// `Camera::order`) first. Empty when no camera sees the cursor.
+    #[cfg(feature = "3d")]
+    pub rays: &'a [bevy::math::Ray3d],
+    /// The terminal's `GlobalTransform` - mesh terminals have one, pure
+    /// UI nodes don't.
+    #[cfg(feature = "3d")]
+    pub transform: Option<&'a GlobalTransform>,
+    /// The UI node's layout transform (its center, in physical pixels).
+    #[cfg(feature = "2d")]
+    pub ui_transform: Option<&'a bevy::ui::UiGlobalTransform>,
+    /// The UI node's computed size (physical pixels) and scale factor.
+    #[cfg(feature = "2d")]
+    pub computed_node: Option<&'a bevy::ui::ComputedNode>,
+    /// Grid size, cell size and padding, once the terminal has been set
+    /// up.
+    pub dimensions: Option<&'a crate::bevy_plugin::TerminalDimensions>,
+}
+
+/// Run `hit_test` and keep its answer if it lies on the grid.
+#[cfg(feature = "mouse_input")]
+fn custom_hit(hit_test: &CustomHitTest, input: &HitTestInput) -> Option<HitTestResult> {
+    let (col, row) = (hit_test.0)(input)?;
+    if input.dimensions.is_some_and(|d| col >= d.cols || row >= d.rows) {
+        return None;
+    }
+    Some(HitTestResult { col, row })
+}
+
+/// Sort key of a hit on a UI terminal - see [`SortKey::UiStack`]. A node
+/// not laid out yet (no `ComputedStackIndex`) sorts at the bottom.
+#[cfg(all(feature = "mouse_input", feature = "2d"))]
+fn ui_stack(stack_index: Option<&bevy::ui::ComputedStackIndex>) -> SortKey {
+    SortKey::UiStack(stack_index.map_or(0, |index| index.0))
+}
+
+/// Sort key of a [`CustomHitTest`] hit on a mesh terminal: the frontmost
+/// camera, at the terminal's distance from that camera's ray origin.
+#[cfg(all(feature = "mouse_input", feature = "3d"))]
+fn custom_hit_distance(
+    rays: &[bevy::math::Ray3d],
+    transform: Option<&GlobalTransform>,
+) -> SortKey {
+    let distance = match (rays.first(), transform) {
+        (Some(ray), Some(transform)) => ray.origin.distance(transform.translation()),
+        _ => f32::INFINITY,
+    };
+    SortKey::Distance {
+        camera_priority: 0,
+        distance,
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -321,8 +796,58 @@ fn keycode_from_logical(key: &Key, shift: bool, physical: BevyKeyCode) -> Option
 /// `AttachTerminal`), where the surface entity (carrying
 /// `TerminalInput`/`TerminalDimensions`, where hit-testing happens) differs
 /// from the `Tui` entity, actually remap.
-fn remap_to_tui(entity: Entity, surfaces: &Query<&crate::setup::TuiSurface>) -> Entity {
-    surfaces.get(entity).map(|s| s.tui).unwrap_or(entity)
+fn remap_to_tui(entity: Entity, surfaces: &TuiSurfaces) -> Entity {
+    surfaces.surfaces.get(entity).map(|s| s.tui).unwrap_or(entity)
+}
+
+/// The entity lookups the event-writing input systems share: surface to
+/// `Tui` remapping (see [`remap_to_tui`]) and the `Tui` itself (wide-glyph
+/// snapping, see `snap_column`). Bundled into one `SystemParam` for the
+/// same reason as `MouseChangeProbes` - the unified `mouse_input_system`
+/// is at bevy's 16-parameter ceiling.
+///
+/// `pub` only because it appears in the public input systems' parameter
+/// lists; bevy injects it.
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct TuiSurfaces<'w, 's> {
+    surfaces: Query<'w, 's, &'static crate::setup::TuiSurface>,
+    #[cfg_attr(not(feature = "mouse_input"), allow(dead_code))]
+    tuis: Query<'w, 's, &'static crate::setup::Tui>,
+    ids: Query<'w, 's, &'static TerminalId>,
+}
+
+impl TuiSurfaces<'_, '_> {
+    /// The event for `input` hit on (or focused at) `surface`: targeted at
+    /// its `Tui` entity and tagged with that entity's [`TerminalId`], if
+    /// any. Every input system writes its events through this.
+    fn event(&self, surface: Entity, input: InputEvent) -> TerminalEvent {
+        let target = remap_to_tui(surface, self);
+        TerminalEvent {
+            target,
+            input,
+            source_tag: self.ids.get(target).ok().map(|id| id.0),
+        }
+    }
+}
+
+/// Applies [`TerminalInputConfig::snap_wide_glyphs`] to a hit on
+/// `surface_entity`: the column of the wide character covering `col`, or
+/// `col` unchanged when snapping is off or the surface has no `Tui`.
+#[cfg(feature = "mouse_input")]
+fn snap_column(
+    config: &TerminalInputConfig,
+    surface_entity: Entity,
+    col: u16,
+    row: u16,
+    surfaces: &TuiSurfaces,
+) -> u16 {
+    if !config.snap_wide_glyphs {
+        return col;
+    }
+    surfaces
+        .tuis
+        .get(remap_to_tui(surface_entity, surfaces))
+        .map_or(col, |tui| tui.glyph_origin_column(col, row))
 }
 
 // ============================================================================
@@ -358,52 +883,112 @@ pub fn update_cursor_position_system(
     }
 }
 
+/// Surface entities a key event should be written for: the
+/// [`TerminalFocusGroup`] members when the group is present and non-empty
+/// (deduplicated, order kept), else the single focused entity.
+fn keyboard_targets(focus: &TerminalFocus, group: Option<&TerminalFocusGroup>) -> Vec<Entity> {
+    match group.filter(|group| !group.members.is_empty()) {
+        Some(group) => {
+            let mut targets = Vec::with_capacity(group.members.len());
+            for &member in &group.members {
+                if !targets.contains(&member) {
+                    targets.push(member);
+                }
+            }
+            targets
+        }
+        None => focus.focused.into_iter().collect(),
+    }
+}
+
+/// Converts one bevy key event into this crate's `KeyEvent`, or `None` for
+/// keys with no terminal equivalent.
+fn key_event_from(key_event: &KeyboardInput, modifiers: KeyModifiers) -> Option<KeyEvent> {
+    let kind = match key_event.state {
+        ButtonState::Released => KeyEventKind::Release,
+        ButtonState::Pressed if key_event.repeat => KeyEventKind::Repeat,
+        ButtonState::Pressed => KeyEventKind::Press,
+    };
+    let code = keycode_from_logical(&key_event.logical_key, modifiers.shift, key_event.key_code)?;
+    Some(KeyEvent { code, modifiers, kind })
+}
+
+/// Events written for one key: the `Key` itself, then its
+/// [`InputEvent::Edit`] when `edit_actions` is on and the key is an edit.
+fn key_inputs(key: KeyEvent, edit_actions: bool) -> impl Iterator<Item = InputEvent> {
+    let edit = edit_actions
+        .then(|| EditAction::from_key(&key))
+        .flatten()
+        .map(InputEvent::Edit);
+    std::iter::once(InputEvent::Key(key)).chain(edit)
+}
+
 /// Keyboard input capture system.
 ///
-/// Captures keyboard input and emits `TerminalEvent`s for the focused terminal.
-/// Only processes input if a terminal has focus and has keyboard input enabled.
+/// Captures keyboard input and emits `TerminalEvent`s for the focused terminal
+/// - or for every member of a [`TerminalFocusGroup`], when one is present,
+/// or for each event's source's terminal under a [`TerminalSourceFocus`].
+/// Only targets that have keyboard input enabled receive events.
+#[allow(clippy::too_many_arguments)]
 pub fn keyboard_input_system(
     mut key_events: MessageReader<KeyboardInput>,
     keyboard: Res<ButtonInput<BevyKeyCode>>,
+    config: Res<TerminalInputConfig>,
     focus: Res<TerminalFocus>,
+    group: Option<Res<TerminalFocusGroup>>,
+    sources: Option<Res<TerminalSourceFocus>>,
     terminals: Query<&TerminalInput>,
-    surfaces: Query<&crate::setup::TuiSurface>,
+    surfaces: TuiSurfaces,
     mut events: MessageWriter<TerminalEvent>,
+    mut held: ResMut<HeldKeys>,
 ) {
-    // Check if any terminal has focus
-    let Some(focused_entity) = focus.focused else {
+    let accepts_keys = |entity: &Entity| terminals.get(*entity).is_ok_and(|input| input.keyboard);
+
+    // Per-source routing: the target depends on each event's window.
+    if let Some(sources) = sources.as_deref().filter(|sources| !sources.focused.is_empty()) {
+        let modifiers = read_modifiers(&keyboard);
+        for key_event in key_events.read() {
+            let Some(target) = sources.keyboard_target(key_event.window).filter(accepts_keys)
+            else {
+                continue;
+            };
+            let Some(key) = key_event_from(key_event, modifiers) else {
+                continue;
+            };
+            held.record(target, key_event.key_code, key);
+            for input in key_inputs(key, config.edit_actions) {
+                events.write(surfaces.event(target, input));
+            }
+        }
         return;
-    };
+    }
 
-    // Check if focused terminal accepts keyboard input
-    let Ok(input) = terminals.get(focused_entity) else {
-        return;
-    };
+    // Focused entities store the surface entity (where TerminalInput lives);
+    // remap to the Tui entity only for the emitted event's target.
+    let targets: Vec<Entity> = keyboard_targets(&focus, group.as_deref())
+        .into_iter()
+        .filter(accepts_keys)
+        .collect();
 
-    if !input.keyboard {
+    if targets.is_empty() {
         return;
     }
 
-    // `focus.focused` stores the surface entity (where TerminalInput lives);
-    // remap to the Tui entity only for the emitted event's target.
-    let target = remap_to_tui(focused_entity, &surfaces);
     let modifiers = read_modifiers(&keyboard);
 
     for key_event in key_events.read() {
-        let kind = match key_event.state {
-            ButtonState::Released => KeyEventKind::Release,
-            ButtonState::Pressed if key_event.repeat => KeyEventKind::Repeat,
-            ButtonState::Pressed => KeyEventKind::Press,
-        };
-        let Some(code) = keycode_from_logical(&key_event.logical_key, modifiers.shift, key_event.key_code)
-        else {
+        let Some(key) = key_event_from(key_event, modifiers) else {
             continue;
         };
+        for &target in &targets {
+            held.record(target, key_event.key_code, key);
+        }
 
-        events.write(TerminalEvent {
-            target,
-            input: InputEvent::Key(KeyEvent { code, modifiers, kind }),
-        });
+        for input in key_inputs(key, config.edit_actions) {
+            for &target in &targets {
+                events.write(surfaces.event(target, input.clone()));
+            }
+        }
     }
 }
 
@@ -439,8 +1024,14 @@ struct HitTestResult {
 #[cfg(feature = "mouse_input")]
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum SortKey {
-    /// Z-index for 2D UI terminals (higher = on top)
-    ZIndex(i32),
+    /// 2D UI terminal hit: bevy_ui's stack index, `ComputedStackIndex`
+    /// (higher = drawn later, on top). Unlike a node's own `ZIndex`, which
+    /// only orders it among its siblings, this is the real paint order
+    /// across the whole UI tree: a child terminal nested in a parent
+    /// terminal's node beats the parent, siblings follow `ZIndex` and then
+    /// their order among the parent's children, and `GlobalZIndex` lifts a
+    /// node out of its hierarchy.
+    UiStack(u32),
     /// 3D mesh terminal hit: camera priority (0 = topmost-rendered camera,
     /// i.e. highest `Camera::order`), then ray distance (lower = closer).
     Distance {
@@ -453,7 +1044,7 @@ enum SortKey {
 impl PartialOrd for SortKey {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         match (self, other) {
-            (SortKey::ZIndex(a), SortKey::ZIndex(b)) => b.partial_cmp(a), // Higher Z on top
+            (SortKey::UiStack(a), SortKey::UiStack(b)) => b.partial_cmp(a), // Higher stack on top
             (
                 SortKey::Distance {
                     camera_priority: ca,
@@ -489,8 +1080,8 @@ impl PartialOrd for SortKey {
             // purely from incidental query ordering. bevy_ui is rendered as
             // a screen-space overlay on top of every 3D camera regardless
             // of `Camera::order`, so a UI hit always wins when both fire.
-            (SortKey::ZIndex(_), SortKey::Distance { .. }) => Some(std::cmp::Ordering::Less),
-            (SortKey::Distance { .. }, SortKey::ZIndex(_)) => Some(std::cmp::Ordering::Greater),
+            (SortKey::UiStack(_), SortKey::Distance { .. }) => Some(std::cmp::Ordering::Less),
+            (SortKey::Distance { .. }, SortKey::UiStack(_)) => Some(std::cmp::Ordering::Greater),
         }
     }
 }
@@ -500,7 +1091,9 @@ impl PartialOrd for SortKey {
 /// `SystemParam` tuple impl tops out at 16 elements; the unified variant's
 /// own parameter list already sits at that ceiling once `keyboard` and
 /// `wheel` are added for scroll/modifier support, so these three
-/// low-frequency queries are folded into one slot instead of three.
+/// low-frequency queries are folded into one slot instead of three. The
+/// click/drag press origin (see [`click_drag_target`]) rides along for the
+/// same reason.
 ///
 /// `pub`, not `pub(crate)`: it appears in `mouse_input_system`'s (public)
 /// parameter list, so it must be at least as visible as that function -
@@ -528,6 +1121,7 @@ pub struct MouseChangeProbes<'w, 's> {
             Or<(Changed<bevy::ui::ComputedNode>, Changed<bevy::ui::UiGlobalTransform>)>,
         ),
     >,
+    press: Local<'s, PressOrigin>,
 }
 
 #[cfg(all(feature = "mouse_input", feature = "2d", feature = "3d"))]
@@ -646,7 +1240,7 @@ fn bounding_box_hit_test(
         // natively and in wasm (wasm on a Retina Mac also reports
         // `devicePixelRatio` 2).
         let center = t.translation * inverse_scale;
-        debug!(
+        hit_test_debug!(
             "UiGlobalTransform center=({:.1}, {:.1}), size=({:.1}x{:.1})",
             center.x, center.y, width_px, height_px
         );
@@ -661,13 +1255,13 @@ fn bounding_box_hit_test(
             bevy::ui::Val::Px(v) => v,
             _ => 0.0,
         };
-        debug!(
+        hit_test_debug!(
             "Hit test using Node.left/top fallback: pos=({:.1}, {:.1}), size=({:.1}x{:.1})",
             left_px, top_px, width_px, height_px
         );
         (left_px, top_px)
     } else {
-        debug!(
+        hit_test_debug!(
             "Hit test using origin fallback: size=({:.1}x{:.1})",
             width_px, height_px
         );
@@ -686,25 +1280,27 @@ fn bounding_box_hit_test(
     }
 
     // Get terminal dimensions and font metrics
-    let (cols, rows, char_width, char_height) = if let Some(dims) = dimensions {
+    let (cols, rows, char_width, char_height, padding) = if let Some(dims) = dimensions {
         (
             dims.cols as f32,
             dims.rows as f32,
             dims.char_width_px as f32,
             dims.char_height_px as f32,
+            dims.padding,
         )
     } else {
         // Fallback to defaults
         let cols = 80.0;
         let rows = 24.0;
-        (cols, rows, width_px / cols, height_px / rows)
+        (cols, rows, width_px / cols, height_px / rows, Default::default())
     };
 
-    // Convert screen coordinates to terminal-local coordinates
-    let local_x = cursor_pos.x - node_min_x;
-    let local_y = cursor_pos.y - node_min_y;
+    // Convert screen coordinates to grid-local coordinates - a click on
+    // the padding clamps to the nearest edge cell.
+    let local_x = cursor_pos.x - node_min_x - padding.left as f32;
+    let local_y = cursor_pos.y - node_min_y - padding.top as f32;
 
-    debug!(
+    hit_test_debug!(
         "Hit test conversion: cursor=({:.1}, {:.1}), bounds=({:.1},{:.1})-({:.1},{:.1}), local=({:.1}, {:.1}), char_size=({:.1}x{:.1})",
         cursor_pos.x,
         cursor_pos.y,
@@ -721,7 +1317,7 @@ fn bounding_box_hit_test(
     // Convert to terminal grid coordinates
     let (col, row) = pixel_to_cell(local_x, local_y, char_width, char_height, cols as u16, rows as u16);
 
-    debug!("Hit test result: col={}, row={}", col, row);
+    hit_test_debug!("Hit test result: col={}, row={}", col, row);
 
     Some(HitTestResult { col, row })
 }
@@ -773,6 +1369,9 @@ fn ray_intersects_aabb(
 ///
 /// Converts cursor position to terminal grid coordinates using ray casting and UV mapping.
 /// Works with both Mesh2d and Mesh3d by accepting the inner Handle<Mesh>.
+/// Triangles facing away from the ray only count with `hit_backfaces`
+/// (see [`TerminalInput::hit_backfaces`]); their interpolated UV needs no
+/// mirroring, it already names the texel drawn at the hit point.
 #[cfg(all(feature = "mouse_input", feature = "3d"))]
 fn ray_cast_hit_test_inner(
     world_ray: &crate::input::ray::Ray,
@@ -780,6 +1379,7 @@ fn ray_cast_hit_test_inner(
     mesh_handle: &bevy::asset::Handle<bevy::mesh::Mesh>,
     meshes: &bevy::asset::Assets<bevy::mesh::Mesh>,
     dimensions: Option<&crate::bevy_plugin::TerminalDimensions>,
+    hit_backfaces: bool,
 ) -> Option<(HitTestResult, f32)> {
     use bevy::math::Ray3d;
     use bevy::mesh::VertexAttributeValues;
@@ -793,6 +1393,11 @@ fn ray_cast_hit_test_inner(
         bevy::math::Dir3::new_unchecked(world_ray.direction),
     );
     let mesh_transform_affine = mesh_transform.affine();
+    let backfaces = if hit_backfaces {
+        Backfaces::Include
+    } else {
+        Backfaces::Cull
+    };
 
     let VertexAttributeValues::Float32x3(positions) = mesh.attribute(Mesh::ATTRIBUTE_POSITION)?
     else {
@@ -818,7 +1423,7 @@ fn ray_cast_hit_test_inner(
             vertex_normals,
             Some(indices.as_slice()),
             uvs,
-            Backfaces::Cull,
+            backfaces,
         )
     } else {
         ray_mesh_intersection(
@@ -828,22 +1433,24 @@ fn ray_cast_hit_test_inner(
             vertex_normals,
             None::<&[u32]>,
             uvs,
-            Backfaces::Cull,
+            backfaces,
         )
     }?;
 
     let uv = hit.uv?;
 
-    let (cols, rows) = if let Some(dims) = dimensions {
-        (dims.cols, dims.rows)
+    // The UV spans the whole texture; padding around the grid is mapped
+    // out before the cell lookup.
+    let (cols, rows, uv) = if let Some(dims) = dimensions {
+        (dims.cols, dims.rows, dims.texture_uv_to_grid_uv(uv))
     } else {
-        (80, 24)
+        (80, 24, uv)
     };
 
     // UV to terminal grid mapping (90° CCW rotated mesh)
     let (col, row) = uv_to_cell(uv.x, uv.y, cols, rows);
 
-    debug!(
+    hit_test_debug!(
         "3D Hit Test: uv=({:.3},{:.3}) distance={:.1} cols={} rows={} -> grid=({},{})",
         uv.x, uv.y, hit.distance, cols, rows, col, row
     );
@@ -867,6 +1474,56 @@ fn move_kind(left: bool, right: bool, middle: bool) -> MouseEventKind {
     }
 }
 
+/// The press [`TerminalInputConfig::click_drag_threshold`] is measured
+/// from: cursor position and hit `(surface, col, row)`.
+#[cfg(feature = "mouse_input")]
+type PressOrigin = Option<(Vec2, (Entity, u16, u16))>;
+
+/// `(just pressed, just released, held)` over all mouse buttons, with a
+/// touch counting as a button - for [`click_drag_target`].
+#[cfg(feature = "mouse_input")]
+fn button_phase(buttons: &ButtonInput<MouseButton>, touches: &Touches) -> (bool, bool, bool) {
+    (
+        buttons.get_just_pressed().len() > 0 || touches.any_just_pressed(),
+        buttons.get_just_released().len() > 0 || touches.any_just_released(),
+        buttons.get_pressed().len() > 0 || touches.first_pressed_position().is_some(),
+    )
+}
+
+/// Where this run's button events go for a pointer at `cursor` over
+/// `hit`, and whether the press still counts as a click rather than a
+/// drag (no `Drag` is sent then): a press that has stayed within
+/// [`TerminalInputConfig::click_drag_threshold`] pixels of where it went
+/// down keeps the `(surface, col, row)` it went down on, so its release
+/// lands there. Moving further makes it an ordinary drag until released.
+#[cfg(feature = "mouse_input")]
+fn click_drag_target(
+    press: &mut PressOrigin,
+    threshold: f32,
+    cursor: Vec2,
+    hit: (Entity, u16, u16),
+    (pressed, released, held): (bool, bool, bool),
+) -> ((Entity, u16, u16), bool) {
+    if !pressed && !released && !held {
+        // Released away from every terminal, where nothing was reported.
+        *press = None;
+    }
+    if pressed && threshold > 0.0 {
+        *press = Some((cursor, hit));
+    }
+    let target = match *press {
+        Some((origin, target)) if cursor.distance(origin) <= threshold => (target, true),
+        _ => {
+            *press = None;
+            (hit, false)
+        }
+    };
+    if !held {
+        *press = None;
+    }
+    target
+}
+
 /// Selects a `MouseEventKind::Scroll*` from a `MouseWheel` message's raw
 /// `(x, y)` delta - sign only, no accumulation. `y` takes priority over
 /// `x` (matches how a plain vertical-wheel mouse reports). `None` when
@@ -897,7 +1554,7 @@ fn emit_mouse_move(
     buttons: &ButtonInput<MouseButton>,
     touches: &Touches,
     modifiers: KeyModifiers,
-    surfaces: &Query<&crate::setup::TuiSurface>,
+    surfaces: &TuiSurfaces,
     events: &mut MessageWriter<TerminalEvent>,
 ) {
     // An active touch counts as a held left button - consistent with the
@@ -911,15 +1568,15 @@ fn emit_mouse_move(
         buttons.pressed(MouseButton::Right),
         buttons.pressed(MouseButton::Middle),
     );
-    events.write(TerminalEvent {
-        target: remap_to_tui(surface_entity, surfaces),
-        input: InputEvent::Mouse(MouseEvent {
+    events.write(surfaces.event(
+        surface_entity,
+        InputEvent::Mouse(MouseEvent {
             kind,
             column: col,
             row,
             modifiers,
         }),
-    });
+    ));
 }
 
 /// `old_focus`/`new_focus` are surface entities (what `TerminalFocus` stores,
@@ -931,23 +1588,17 @@ fn emit_focus_events(
     old_focus: &mut Option<Entity>,
     focus_button: MouseButton,
     button: MouseButton,
-    surfaces: &Query<&crate::setup::TuiSurface>,
+    surfaces: &TuiSurfaces,
     events: &mut MessageWriter<TerminalEvent>,
 ) {
     if button == focus_button && *old_focus != Some(new_focus) {
         if let Some(old_entity) = *old_focus {
-            events.write(TerminalEvent {
-                target: remap_to_tui(old_entity, surfaces),
-                input: InputEvent::FocusLost,
-            });
+            events.write(surfaces.event(old_entity, InputEvent::FocusLost));
         }
 
         *old_focus = Some(new_focus);
 
-        events.write(TerminalEvent {
-            target: remap_to_tui(new_focus, surfaces),
-            input: InputEvent::FocusGained,
-        });
+        events.write(surfaces.event(new_focus, InputEvent::FocusGained));
     }
 }
 
@@ -962,10 +1613,9 @@ fn emit_button_events(
     modifiers: KeyModifiers,
     focus: &mut TerminalFocus,
     config: &TerminalInputConfig,
-    surfaces: &Query<&crate::setup::TuiSurface>,
+    surfaces: &TuiSurfaces,
     events: &mut MessageWriter<TerminalEvent>,
 ) {
-    let target = remap_to_tui(surface_entity, surfaces);
     for button in [MouseButton::Left, MouseButton::Right, MouseButton::Middle] {
         // Touch taps emulate the left mouse button: winit never synthesizes
         // mouse events from touches, so without this a tap hit-tests (via
@@ -981,27 +1631,27 @@ fn emit_button_events(
                 events,
             );
 
-            events.write(TerminalEvent {
-                target,
-                input: InputEvent::Mouse(MouseEvent {
+            events.write(surfaces.event(
+                surface_entity,
+                InputEvent::Mouse(MouseEvent {
                     kind: MouseEventKind::Down(button),
                     column: col,
                     row,
                     modifiers,
                 }),
-            });
+            ));
         }
 
         if buttons.just_released(button) || (touch && touches.any_just_released()) {
-            events.write(TerminalEvent {
-                target,
-                input: InputEvent::Mouse(MouseEvent {
+            events.write(surfaces.event(
+                surface_entity,
+                InputEvent::Mouse(MouseEvent {
                     kind: MouseEventKind::Up(button),
                     column: col,
                     row,
                     modifiers,
                 }),
-            });
+            ));
         }
     }
 }
@@ -1010,26 +1660,27 @@ fn emit_button_events(
 /// cell. Shared by all three `mouse_input_system` variants.
 #[cfg(feature = "mouse_input")]
 fn emit_scroll_events(
-    target: Entity,
+    surface_entity: Entity,
     col: u16,
     row: u16,
     wheel_messages: &[MouseWheel],
     modifiers: KeyModifiers,
+    surfaces: &TuiSurfaces,
     events: &mut MessageWriter<TerminalEvent>,
 ) {
     for wheel in wheel_messages {
         let Some(kind) = scroll_kind(wheel.x, wheel.y) else {
             continue;
         };
-        events.write(TerminalEvent {
-            target,
-            input: InputEvent::Mouse(MouseEvent {
+        events.write(surfaces.event(
+            surface_entity,
+            InputEvent::Mouse(MouseEvent {
                 kind,
                 column: col,
                 row,
                 modifiers,
             }),
-        });
+        ));
     }
 }
 
@@ -1049,7 +1700,7 @@ fn emit_scroll_events(
 /// 1. Iterates all terminals with `TerminalInput`
 /// 2. Auto-detects terminal type from components
 /// 3. Dispatches to appropriate hit-test function
-/// 4. Collects hits with sort keys (Z-index for 2D, distance for 3D)
+/// 4. Collects hits with sort keys (stacking order for 2D, distance for 3D)
 /// 5. Selects the topmost/closest terminal
 /// 6. Emits mouse events and handles focus
 #[cfg(all(feature = "mouse_input", feature = "2d", feature = "3d"))]
@@ -1076,20 +1727,21 @@ pub fn mouse_input_system(
         Option<&Mesh3d>,
         Option<&bevy::ui::Node>,
         Option<&bevy::ui::ComputedNode>,
+        Option<&bevy::ui::ComputedStackIndex>,
         Option<&bevy::ui::UiGlobalTransform>,
         Option<&crate::bevy_plugin::TerminalDimensions>,
-        Option<&bevy::ui::ZIndex>,
         Option<&bevy::camera::visibility::ViewVisibility>,
         Option<&bevy::camera::primitives::Aabb>,
+        Option<&CustomHitTest>,
     )>,
-    surfaces: Query<&crate::setup::TuiSurface>,
+    surfaces: TuiSurfaces,
     mut events: MessageWriter<TerminalEvent>,
     // Change-detection gate (IMPROVEMENT.md D1): anything that can move a
     // cursor→cell mapping without the cursor pixel position itself
     // changing. Bundled into one `SystemParam` - see `MouseChangeProbes`.
-    change_probes: MouseChangeProbes,
+    mut change_probes: MouseChangeProbes,
     mut last_cursor_pos: Local<Option<Vec2>>,
-    mut last_hovered: Local<Option<(Entity, u16, u16)>>,
+    mut hovered_terminal: ResMut<HoveredTerminal>,
 ) {
     let wheel_messages: Vec<MouseWheel> = wheel.read().copied().collect();
 
@@ -1097,7 +1749,7 @@ pub fn mouse_input_system(
         Some(pos) => pos,
         None => {
             *last_cursor_pos = None;
-            *last_hovered = None;
+            hovered_terminal.set_if_neq(HoveredTerminal::default());
             return;
         }
     };
@@ -1128,19 +1780,22 @@ pub fn mouse_input_system(
         .filter(|(camera, _)| camera.is_active)
         .collect();
     cameras.sort_by_key(|(camera, _)| std::cmp::Reverse(camera.order));
-    let world_rays: Vec<crate::input::ray::Ray> = cameras
+    let camera_rays: Vec<bevy::math::Ray3d> = cameras
         .iter()
         .filter_map(|(camera, camera_transform)| {
             let viewport = camera.logical_viewport_rect()?;
             if !viewport.contains(cursor_pos) {
                 return None;
             }
-            let ray3d = camera
+            camera
                 .viewport_to_world(camera_transform, cursor_pos - viewport.min)
-                .ok()?;
-            Some(crate::input::ray::Ray::new(ray3d.origin, *ray3d.direction))
+                .ok()
         })
         .collect();
+    let world_rays: Vec<crate::input::ray::Ray> = camera_rays
+        .iter()
+        .map(|ray3d| crate::input::ray::Ray::new(ray3d.origin, *ray3d.direction))
+        .collect();
 
     let mut hit_candidates: Vec<(Entity, HitTestResult, SortKey)> = Vec::new();
 
@@ -1152,11 +1807,12 @@ pub fn mouse_input_system(
         mesh3d,
         node,
         computed,
+        stack_index,
         ui_transform,
         dimensions,
-        z_index,
         view_visibility,
         aabb,
+        custom_hit_test,
     ) in terminals.iter()
     {
         if !input.mouse {
@@ -1165,6 +1821,29 @@ pub fn mouse_input_system(
 
         let terminal_type = detect_terminal_type(mesh2d, mesh3d, node);
 
+        if let Some(hit_test) = custom_hit_test {
+            // Invisible meshes are skipped as in stage 1 below.
+            if terminal_type == TerminalType::Mesh3D && view_visibility.is_some_and(|v| !v.get()) {
+                continue;
+            }
+            let hit_input = HitTestInput {
+                cursor: cursor_pos,
+                rays: &camera_rays,
+                transform,
+                ui_transform,
+                computed_node: computed,
+                dimensions,
+            };
+            if let Some(hit_result) = custom_hit(hit_test, &hit_input) {
+                let sort_key = match terminal_type {
+                    TerminalType::UI2D => ui_stack(stack_index),
+                    _ => custom_hit_distance(&camera_rays, transform),
+                };
+                hit_candidates.push((entity, hit_result, sort_key));
+            }
+            continue;
+        }
+
         match terminal_type {
             TerminalType::Mesh3D => {
                 // Get the inner Handle<Mesh> from either Mesh3d or Mesh2d
@@ -1193,7 +1872,10 @@ pub fn mouse_input_system(
 
                     // Stage 3: precise triangle-level intersection.
                     if let Some((hit_result, distance)) = mesh_handle.and_then(|handle| {
-                        ray_cast_hit_test_inner(ray, transform, handle, &meshes, dimensions)
+                        let backfaces = input.hit_backfaces;
+                        ray_cast_hit_test_inner(
+                            ray, transform, handle, &meshes, dimensions, backfaces,
+                        )
                     }) {
                         hit_candidates.push((
                             entity,
@@ -1211,8 +1893,7 @@ pub fn mouse_input_system(
                 if let Some(hit_result) =
                     bounding_box_hit_test(cursor_pos, ui_transform, node, computed, dimensions)
                 {
-                    let z = z_index.map(|z| z.0).unwrap_or(0);
-                    hit_candidates.push((entity, hit_result, SortKey::ZIndex(z)));
+                    hit_candidates.push((entity, hit_result, ui_stack(stack_index)));
                 }
             }
             TerminalType::Unknown => {
@@ -1223,18 +1904,19 @@ pub fn mouse_input_system(
     }
 
     if hit_candidates.is_empty() {
-        *last_hovered = None;
+        hovered_terminal.set_if_neq(HoveredTerminal::default());
         return;
     }
 
     // Debug: Log all hits before sorting
+    #[cfg(feature = "verbose_input_logging")]
     if hit_candidates.len() > 1 {
-        debug!(
+        hit_test_debug!(
             "Multiple terminals hit at cursor ({:.1}, {:.1}):",
             cursor_pos.x, cursor_pos.y
         );
         for (entity, result, sort_key) in &hit_candidates {
-            debug!(
+            hit_test_debug!(
                 "  Entity {:?}: col={}, row={}, sort_key={:?}",
                 entity, result.col, result.row, sort_key
             );
@@ -1246,23 +1928,33 @@ pub fn mouse_input_system(
     hit_candidates.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal));
 
     // Debug: Log selection after sorting
+    #[cfg(feature = "verbose_input_logging")]
     if hit_candidates.len() > 1 {
-        debug!(
+        hit_test_debug!(
             "After sorting, selected: Entity {:?} with sort_key={:?}",
             hit_candidates[0].0, hit_candidates[0].2
         );
     }
 
     if let Some((entity, hit_result, _sort_key)) = hit_candidates.first() {
+        let col = snap_column(&config, *entity, hit_result.col, hit_result.row, &surfaces);
         // Dedupe MouseMove (IMPROVEMENT.md D1): only emit when the
         // hovered (entity, col, row) actually changed since the last
         // recompute, so hovering inside one cell stops re-emitting on
         // every gate-triggered recompute.
-        let hovered = (*entity, hit_result.col, hit_result.row);
-        if *last_hovered != Some(hovered) {
+        let hovered = (*entity, col, hit_result.row);
+        let (target, clicking) = click_drag_target(
+            &mut change_probes.press,
+            config.click_drag_threshold,
+            cursor_pos,
+            hovered,
+            button_phase(&buttons, &touches),
+        );
+        // A press still within `click_drag_threshold` sends no `Drag`.
+        if !clicking && hovered_terminal.hover() != Some(hovered) {
             emit_mouse_move(
                 *entity,
-                hit_result.col,
+                col,
                 hit_result.row,
                 &buttons,
                 &touches,
@@ -1270,12 +1962,12 @@ pub fn mouse_input_system(
                 &surfaces,
                 &mut events,
             );
-            *last_hovered = Some(hovered);
+            hovered_terminal.set_if_neq(HoveredTerminal::from_hover(Some(hovered)));
         }
         emit_button_events(
-            *entity,
-            hit_result.col,
-            hit_result.row,
+            target.0,
+            target.1,
+            target.2,
             &buttons,
             &touches,
             modifiers,
@@ -1285,11 +1977,12 @@ pub fn mouse_input_system(
             &mut events,
         );
         emit_scroll_events(
-            remap_to_tui(*entity, &surfaces),
-            hit_result.col,
+            *entity,
+            col,
             hit_result.row,
             &wheel_messages,
             modifiers,
+            &surfaces,
             &mut events,
         );
     }
@@ -1312,11 +2005,12 @@ pub fn mouse_input_system(
         &TerminalInput,
         Option<&bevy::ui::Node>,
         Option<&bevy::ui::ComputedNode>,
+        Option<&bevy::ui::ComputedStackIndex>,
         Option<&bevy::ui::UiGlobalTransform>,
         Option<&crate::bevy_plugin::TerminalDimensions>,
-        Option<&bevy::ui::ZIndex>,
+        Option<&CustomHitTest>,
     )>,
-    surfaces: Query<&crate::setup::TuiSurface>,
+    surfaces: TuiSurfaces,
     mut events: MessageWriter<TerminalEvent>,
     // Change-detection gate (IMPROVEMENT.md D1) - no camera/3D probe
     // needed here: `bounding_box_hit_test` is pure screen-space, it never
@@ -1329,7 +2023,8 @@ pub fn mouse_input_system(
         ),
     >,
     mut last_cursor_pos: Local<Option<Vec2>>,
-    mut last_hovered: Local<Option<(Entity, u16, u16)>>,
+    mut press: Local<PressOrigin>,
+    mut hovered_terminal: ResMut<HoveredTerminal>,
 ) {
     let wheel_messages: Vec<MouseWheel> = wheel.read().copied().collect();
 
@@ -1337,7 +2032,7 @@ pub fn mouse_input_system(
         Some(pos) => pos,
         None => {
             *last_cursor_pos = None;
-            *last_hovered = None;
+            hovered_terminal.set_if_neq(HoveredTerminal::default());
             return;
         }
     };
@@ -1355,32 +2050,51 @@ pub fn mouse_input_system(
 
     let mut hit_candidates: Vec<(Entity, HitTestResult, SortKey)> = Vec::new();
 
-    for (entity, input, node, computed, ui_transform, dimensions, z_index) in terminals.iter() {
+    for (entity, input, node, computed, stack_index, ui_transform, dimensions, custom_hit_test) in
+        terminals.iter()
+    {
         if !input.mouse {
             continue;
         }
 
-        if let Some(hit_result) =
-            bounding_box_hit_test(cursor_pos, ui_transform, node, computed, dimensions)
-        {
-            let z = z_index.map(|z| z.0).unwrap_or(0);
-            hit_candidates.push((entity, hit_result, SortKey::ZIndex(z)));
+        let hit = match custom_hit_test {
+            Some(hit_test) => custom_hit(
+                hit_test,
+                &HitTestInput {
+                    cursor: cursor_pos,
+                    ui_transform,
+                    computed_node: computed,
+                    dimensions,
+                },
+            ),
+            None => bounding_box_hit_test(cursor_pos, ui_transform, node, computed, dimensions),
+        };
+        if let Some(hit_result) = hit {
+            hit_candidates.push((entity, hit_result, ui_stack(stack_index)));
         }
     }
 
     if hit_candidates.is_empty() {
-        *last_hovered = None;
+        hovered_terminal.set_if_neq(HoveredTerminal::default());
         return;
     }
 
     hit_candidates.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal));
 
     if let Some((entity, hit_result, _sort_key)) = hit_candidates.first() {
-        let hovered = (*entity, hit_result.col, hit_result.row);
-        if *last_hovered != Some(hovered) {
+        let col = snap_column(&config, *entity, hit_result.col, hit_result.row, &surfaces);
+        let hovered = (*entity, col, hit_result.row);
+        let (target, clicking) = click_drag_target(
+            &mut press,
+            config.click_drag_threshold,
+            cursor_pos,
+            hovered,
+            button_phase(&buttons, &touches),
+        );
+        if !clicking && hovered_terminal.hover() != Some(hovered) {
             emit_mouse_move(
                 *entity,
-                hit_result.col,
+                col,
                 hit_result.row,
                 &buttons,
                 &touches,
@@ -1388,12 +2102,12 @@ pub fn mouse_input_system(
                 &surfaces,
                 &mut events,
             );
-            *last_hovered = Some(hovered);
+            hovered_terminal.set_if_neq(HoveredTerminal::from_hover(Some(hovered)));
         }
         emit_button_events(
-            *entity,
-            hit_result.col,
-            hit_result.row,
+            target.0,
+            target.1,
+            target.2,
             &buttons,
             &touches,
             modifiers,
@@ -1403,11 +2117,12 @@ pub fn mouse_input_system(
             &mut events,
         );
         emit_scroll_events(
-            remap_to_tui(*entity, &surfaces),
-            hit_result.col,
+            *entity,
+            col,
             hit_result.row,
             &wheel_messages,
             modifiers,
+            &surfaces,
             &mut events,
         );
     }
@@ -1436,8 +2151,9 @@ pub fn mouse_input_system(
         Option<&crate::bevy_plugin::TerminalDimensions>,
         Option<&bevy::camera::visibility::ViewVisibility>,
         Option<&bevy::camera::primitives::Aabb>,
+        Option<&CustomHitTest>,
     )>,
-    surfaces: Query<&crate::setup::TuiSurface>,
+    surfaces: TuiSurfaces,
     mut events: MessageWriter<TerminalEvent>,
     // Change-detection gate (IMPROVEMENT.md D1) - see the unified system's
     // doc comment for the full rationale; no UI-layout probe needed here
@@ -1451,7 +2167,8 @@ pub fn mouse_input_system(
     >,
     terminal_3d_changed: Query<(), (With<TerminalInput>, Changed<GlobalTransform>)>,
     mut last_cursor_pos: Local<Option<Vec2>>,
-    mut last_hovered: Local<Option<(Entity, u16, u16)>>,
+    mut press: Local<PressOrigin>,
+    mut hovered_terminal: ResMut<HoveredTerminal>,
 ) {
     let wheel_messages: Vec<MouseWheel> = wheel.read().copied().collect();
 
@@ -1459,7 +2176,7 @@ pub fn mouse_input_system(
         Some(pos) => pos,
         None => {
             *last_cursor_pos = None;
-            *last_hovered = None;
+            hovered_terminal.set_if_neq(HoveredTerminal::default());
             return;
         }
     };
@@ -1483,39 +2200,65 @@ pub fn mouse_input_system(
         .filter(|(camera, _)| camera.is_active)
         .collect();
     cameras.sort_by_key(|(camera, _)| std::cmp::Reverse(camera.order));
-    let world_rays: Vec<crate::input::ray::Ray> = cameras
+    let camera_rays: Vec<bevy::math::Ray3d> = cameras
         .iter()
         .filter_map(|(camera, camera_transform)| {
             let viewport = camera.logical_viewport_rect()?;
             if !viewport.contains(cursor_pos) {
                 return None;
             }
-            let ray3d = camera
+            camera
                 .viewport_to_world(camera_transform, cursor_pos - viewport.min)
-                .ok()?;
-            Some(crate::input::ray::Ray::new(ray3d.origin, *ray3d.direction))
+                .ok()
         })
         .collect();
+    let world_rays: Vec<crate::input::ray::Ray> = camera_rays
+        .iter()
+        .map(|ray3d| crate::input::ray::Ray::new(ray3d.origin, *ray3d.direction))
+        .collect();
 
     let mut hit_candidates: Vec<(Entity, HitTestResult, SortKey)> = Vec::new();
 
-    for (entity, input, transform, mesh2d, mesh3d, dimensions, view_visibility, aabb) in
-        terminals.iter()
+    for (
+        entity,
+        input,
+        transform,
+        mesh2d,
+        mesh3d,
+        dimensions,
+        view_visibility,
+        aabb,
+        custom_hit_test,
+    ) in terminals.iter()
     {
         if !input.mouse {
             continue;
         }
-        let mesh_handle = mesh3d.map(|m| &m.0).or_else(|| mesh2d.map(|m| &m.0));
-        let Some(transform) = transform else {
-            continue; // no GlobalTransform - can't ray-cast
-        };
-
         // Stage 1 (coarse->fine, IMPROVEMENT.md D1): visibility. Fail
         // open (no component = not pruned).
         if view_visibility.is_some_and(|v| !v.get()) {
             continue;
         }
 
+        if let Some(hit_test) = custom_hit_test {
+            let hit_input = HitTestInput {
+                cursor: cursor_pos,
+                rays: &camera_rays,
+                transform,
+                dimensions,
+            };
+            if let Some(hit_result) = custom_hit(hit_test, &hit_input) {
+                let sort_key = custom_hit_distance(&camera_rays, transform);
+                hit_candidates.push((entity, hit_result, sort_key));
+            }
+            continue;
+        }
+
+        let mesh_handle = mesh3d.map(|m| &m.0).or_else(|| mesh2d.map(|m| &m.0));
+        let Some(transform) = transform else {
+            continue; // no GlobalTransform - can't ray-cast
+        };
+
         for (camera_priority, ray) in world_rays.iter().enumerate() {
             // Stage 2: AABB bounding check.
             if !ray_intersects_aabb(ray, transform, aabb) {
@@ -1524,7 +2267,8 @@ pub fn mouse_input_system(
 
             // Stage 3: precise triangle-level intersection.
             if let Some((hit_result, distance)) = mesh_handle.and_then(|handle| {
-                ray_cast_hit_test_inner(ray, transform, handle, &meshes, dimensions)
+                let backfaces = input.hit_backfaces;
+                ray_cast_hit_test_inner(ray, transform, handle, &meshes, dimensions, backfaces)
             }) {
                 hit_candidates.push((
                     entity,
@@ -1540,18 +2284,26 @@ pub fn mouse_input_system(
     }
 
     if hit_candidates.is_empty() {
-        *last_hovered = None;
+        hovered_terminal.set_if_neq(HoveredTerminal::default());
         return;
     }
 
     hit_candidates.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal));
 
     if let Some((entity, hit_result, _sort_key)) = hit_candidates.first() {
-        let hovered = (*entity, hit_result.col, hit_result.row);
-        if *last_hovered != Some(hovered) {
+        let col = snap_column(&config, *entity, hit_result.col, hit_result.row, &surfaces);
+        let hovered = (*entity, col, hit_result.row);
+        let (target, clicking) = click_drag_target(
+            &mut press,
+            config.click_drag_threshold,
+            cursor_pos,
+            hovered,
+            button_phase(&buttons, &touches),
+        );
+        if !clicking && hovered_terminal.hover() != Some(hovered) {
             emit_mouse_move(
                 *entity,
-                hit_result.col,
+                col,
                 hit_result.row,
                 &buttons,
                 &touches,
@@ -1559,12 +2311,12 @@ pub fn mouse_input_system(
                 &surfaces,
                 &mut events,
             );
-            *last_hovered = Some(hovered);
+            hovered_terminal.set_if_neq(HoveredTerminal::from_hover(Some(hovered)));
         }
         emit_button_events(
-            *entity,
-            hit_result.col,
-            hit_result.row,
+            target.0,
+            target.1,
+            target.2,
             &buttons,
             &touches,
             modifiers,
@@ -1574,11 +2326,12 @@ pub fn mouse_input_system(
             &mut events,
         );
         emit_scroll_events(
-            remap_to_tui(*entity, &surfaces),
-            hit_result.col,
+            *entity,
+            col,
             hit_result.row,
             &wheel_messages,
             modifiers,
+            &surfaces,
             &mut events,
         );
     }
@@ -1590,7 +2343,7 @@ pub fn mouse_input_system(
 pub fn window_resize_system(
     mut resize_events: MessageReader<bevy::window::WindowResized>,
     terminals: Query<Entity, With<crate::setup::Tui>>,
-    surfaces: Query<&crate::setup::TuiSurface>,
+    surfaces: TuiSurfaces,
     mut events: MessageWriter<TerminalEvent>,
 ) {
     for resize_event in resize_events.read() {
@@ -1598,10 +2351,108 @@ pub fn window_resize_system(
 
         // Emit resize event for all terminals
         for entity in terminals.iter() {
-            events.write(TerminalEvent {
-                target: remap_to_tui(entity, &surfaces),
-                input: InputEvent::Resize { pixels },
-            });
+            events.write(surfaces.event(entity, InputEvent::Resize { pixels }));
+        }
+    }
+}
+
+/// Window focus loss cleanup.
+///
+/// The OS sends no key or button releases to a window without focus, so
+/// whatever is held when the window loses it (Alt+Tab, a click into
+/// another app) would otherwise stay down as far as the terminals know: a
+/// key whose `Release` never comes, a drag that never ends. On every
+/// `WindowFocused { focused: false }` this system:
+///
+/// - writes a [`KeyEventKind::Release`] for every key in [`HeldKeys`], to
+///   the terminal that got its `Press`;
+/// - writes a [`MouseEventKind::Up`] for every button a terminal got a
+///   `Down` for and no `Up`, at the last cell it was told about, and
+///   resets Bevy's `ButtonInput<MouseButton>` so the next cursor move is a
+///   `Moved` instead of a continued `Drag`;
+/// - with [`TerminalInputConfig::clear_focus_on_window_blur`], clears
+///   [`TerminalFocus::focused`] and writes [`InputEvent::FocusLost`].
+///
+/// On `WindowFocused { focused: true }` a focus cleared that way is given
+/// back, with [`InputEvent::FocusGained`] - unless something else was
+/// focused meanwhile or the terminal lost its `TerminalInput`. Nothing is
+/// replayed: a key still physically down on return shows up with its
+/// next event (usually a `Release` without a `Press`, which handlers
+/// ignore). The synthetic events carry no modifiers.
+///
+/// Mouse buttons are followed through the [`TerminalEvent`] stream
+/// itself, so `Down`s written by your own input systems are released too.
+/// Keys need their physical key code and are only tracked for
+/// `keyboard_input_system`.
+#[allow(clippy::too_many_arguments)]
+pub fn window_focus_system(
+    mut window_focus: MessageReader<bevy::window::WindowFocused>,
+    mut events: ResMut<bevy::ecs::message::Messages<TerminalEvent>>,
+    mut seen: Local<bevy::ecs::message::MessageCursor<TerminalEvent>>,
+    mut pressed: Local<Vec<(MouseButton, TerminalEvent)>>,
+    mut blurred_focus: Local<Option<Entity>>,
+    mut held: ResMut<HeldKeys>,
+    mut mouse_buttons: Option<ResMut<ButtonInput<MouseButton>>>,
+    mut focus: ResMut<TerminalFocus>,
+    config: Res<TerminalInputConfig>,
+    terminals: Query<(), With<TerminalInput>>,
+    surfaces: TuiSurfaces,
+) {
+    // Every button a terminal currently believes is down, with the last
+    // event that said so.
+    for event in seen.read(&events) {
+        let InputEvent::Mouse(mouse) = &event.input else {
+            continue;
+        };
+        match mouse.kind {
+            MouseEventKind::Down(button) => {
+                pressed.retain(|(down, _)| *down != button);
+                pressed.push((button, event.clone()));
+            }
+            MouseEventKind::Drag(button) => {
+                if let Some(entry) = pressed.iter_mut().find(|(down, _)| *down == button) {
+                    entry.1 = event.clone();
+                }
+            }
+            MouseEventKind::Up(button) => pressed.retain(|(down, _)| *down != button),
+            _ => {}
+        }
+    }
+
+    for window_event in window_focus.read() {
+        if window_event.focused {
+            let restore = blurred_focus
+                .take()
+                .filter(|&surface| focus.focused.is_none() && terminals.contains(surface));
+            if let Some(surface) = restore {
+                focus.focused = Some(surface);
+                events.write(surfaces.event(surface, InputEvent::FocusGained));
+            }
+            continue;
+        }
+
+        for (surface, release) in held.release_all() {
+            events.write(surfaces.event(surface, InputEvent::Key(release)));
+        }
+        for (button, mut event) in pressed.drain(..) {
+            if let InputEvent::Mouse(mouse) = &mut event.input {
+                mouse.kind = MouseEventKind::Up(button);
+                mouse.modifiers = KeyModifiers::default();
+            }
+            events.write(event);
+        }
+        if let Some(buttons) = mouse_buttons.as_deref_mut() {
+            buttons.reset_all();
+        }
+
+        let cleared = if config.clear_focus_on_window_blur {
+            focus.focused.take()
+        } else {
+            None
+        };
+        if let Some(surface) = cleared {
+            events.write(surfaces.event(surface, InputEvent::FocusLost));
+            *blurred_focus = Some(surface);
         }
     }
 }
@@ -1609,12 +2460,15 @@ pub fn window_resize_system(
 /// Terminal focus cycling system.
 ///
 /// Handles Tab key to cycle focus between terminals with `TerminalInput` component.
+/// Only terminals with both [`TerminalInput::keyboard`] and
+/// [`TerminalInput::focusable`] set take part in the cycle; if the focused
+/// terminal isn't one of them, Tab moves to the first one that is.
 /// Emits FocusGained/FocusLost events when focus changes.
 pub fn terminal_focus_system(
     keyboard: Res<ButtonInput<BevyKeyCode>>,
     mut focus: ResMut<TerminalFocus>,
     terminals: Query<(Entity, &TerminalInput)>,
-    surfaces: Query<&crate::setup::TuiSurface>,
+    surfaces: TuiSurfaces,
     mut events: MessageWriter<TerminalEvent>,
 ) {
     // Check if Tab was just pressed
@@ -1622,10 +2476,10 @@ pub fn terminal_focus_system(
         return;
     }
 
-    // Collect terminals with keyboard input enabled
+    // Collect terminals that take part in the Tab cycle
     let mut terminal_entities: Vec<Entity> = terminals
         .iter()
-        .filter(|(_, input)| input.keyboard)
+        .filter(|(_, input)| input.keyboard && input.focusable)
         .map(|(entity, _)| entity)
         .collect();
 
@@ -1655,21 +2509,53 @@ pub fn terminal_focus_system(
     if focus.focused != Some(next_entity) {
         // Emit FocusLost for old focus
         if let Some(old_focus) = focus.focused {
-            events.write(TerminalEvent {
-                target: remap_to_tui(old_focus, &surfaces),
-                input: InputEvent::FocusLost,
-            });
+            events.write(surfaces.event(old_focus, InputEvent::FocusLost));
         }
 
         // Update focus
         focus.focused = Some(next_entity);
 
         // Emit FocusGained
-        events.write(TerminalEvent {
-            target: remap_to_tui(next_entity, &surfaces),
-            input: InputEvent::FocusGained,
-        });
+        events.write(surfaces.event(next_entity, InputEvent::FocusGained));
+    }
+}
+
+/// Synthetic hover on focus gain ([`TerminalInputConfig::move_on_focus`]).
+///
+/// Watches [`TerminalFocus`] and, whenever it moves to a terminal that
+/// [`HoveredTerminal`] says is under the cursor, writes one
+/// [`MouseEventKind::Moved`] for the hovered cell - the same cell, snapping
+/// and target the real hit-test produced. Nothing is sent when the cursor
+/// is elsewhere or outside the window. Runs after the focus and mouse
+/// systems, so a focus change and the hover it relies on come from the
+/// same frame.
+pub fn focus_hover_system(
+    focus: Res<TerminalFocus>,
+    hovered: Res<HoveredTerminal>,
+    keyboard: Res<ButtonInput<BevyKeyCode>>,
+    mut last_focused: Local<Option<Entity>>,
+    surfaces: TuiSurfaces,
+    mut events: MessageWriter<TerminalEvent>,
+) {
+    if *last_focused == focus.focused {
+        return;
+    }
+    *last_focused = focus.focused;
+    let Some((entity, column, row)) = hovered.hover() else {
+        return;
+    };
+    if focus.focused != Some(entity) {
+        return;
     }
+    events.write(surfaces.event(
+        entity,
+        InputEvent::Mouse(MouseEvent {
+            kind: MouseEventKind::Moved,
+            column,
+            row,
+            modifiers: read_modifiers(&keyboard),
+        }),
+    ));
 }
 
 // ============================================================================
@@ -1680,6 +2566,377 @@ pub fn terminal_focus_system(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_for_terminal_yields_only_that_terminals_inputs() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.init_resource::<bevy::ecs::message::Messages<TerminalEvent>>();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        world.write_message(TerminalEvent::new(a, InputEvent::FocusGained));
+        world.write_message(TerminalEvent::new(b, InputEvent::FocusLost));
+        world.write_message(TerminalEvent::new(a, InputEvent::Paste("x".into())));
+
+        let seen = world
+            .run_system_once(move |mut events: MessageReader<TerminalEvent>| {
+                events.for_terminal(a).cloned().collect::<Vec<_>>()
+            })
+            .expect("system failed");
+        assert_eq!(seen, vec![InputEvent::FocusGained, InputEvent::Paste("x".into())]);
+    }
+
+    #[test]
+    fn test_input_systems_copy_terminal_id_into_events() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.init_resource::<bevy::ecs::message::Messages<TerminalEvent>>();
+        world.insert_resource(TerminalFocus::default());
+        let mut keys = ButtonInput::<BevyKeyCode>::default();
+        keys.press(BevyKeyCode::Tab);
+        world.insert_resource(keys);
+        let tagged = world.spawn((TerminalInput::default(), TerminalId(42))).id();
+        let untagged = world.spawn(TerminalInput::default()).id();
+
+        // Tab focuses the lower entity first, then the next one.
+        world.run_system_once(terminal_focus_system).expect("system failed");
+        world.run_system_once(terminal_focus_system).expect("system failed");
+
+        let events: Vec<TerminalEvent> = world
+            .resource_mut::<bevy::ecs::message::Messages<TerminalEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                TerminalEvent {
+                    target: tagged,
+                    input: InputEvent::FocusGained,
+                    source_tag: Some(42),
+                },
+                TerminalEvent {
+                    target: tagged,
+                    input: InputEvent::FocusLost,
+                    source_tag: Some(42),
+                },
+                TerminalEvent::new(untagged, InputEvent::FocusGained),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tab_cycle_skips_non_focusable_terminals() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.init_resource::<bevy::ecs::message::Messages<TerminalEvent>>();
+        world.insert_resource(TerminalFocus::default());
+        let mut keys = ButtonInput::<BevyKeyCode>::default();
+        keys.press(BevyKeyCode::Tab);
+        world.insert_resource(keys);
+        let first = world.spawn(TerminalInput::default()).id();
+        let status_bar = world
+            .spawn(TerminalInput {
+                focusable: false,
+                ..default()
+            })
+            .id();
+        let display_only = world
+            .spawn(TerminalInput {
+                keyboard: false,
+                ..default()
+            })
+            .id();
+        let second = world.spawn(TerminalInput::default()).id();
+
+        let mut order = Vec::new();
+        for _ in 0..3 {
+            world.run_system_once(terminal_focus_system).expect("system failed");
+            order.push(world.resource::<TerminalFocus>().focused);
+        }
+        assert_eq!(order, vec![Some(first), Some(second), Some(first)]);
+
+        // Focused some other way (a click), the non-focusable terminal
+        // hands focus on to the cycle instead of trapping it.
+        world.resource_mut::<TerminalFocus>().focused = Some(status_bar);
+        world.run_system_once(terminal_focus_system).expect("system failed");
+        assert_eq!(world.resource::<TerminalFocus>().focused, Some(first));
+        assert_ne!(world.resource::<TerminalFocus>().focused, Some(display_only));
+    }
+
+    #[test]
+    fn test_window_blur_releases_held_input_and_restores_focus() {
+        use bevy::window::WindowFocused;
+
+        let mut world = World::new();
+        world.init_resource::<bevy::ecs::message::Messages<TerminalEvent>>();
+        world.init_resource::<bevy::ecs::message::Messages<WindowFocused>>();
+        world.init_resource::<HeldKeys>();
+        world.insert_resource(TerminalInputConfig {
+            clear_focus_on_window_blur: true,
+            ..default()
+        });
+        let terminal = world.spawn(TerminalInput::default()).id();
+        let window = world.spawn_empty().id();
+        world.insert_resource(TerminalFocus {
+            focused: Some(terminal),
+        });
+        let mut buttons = ButtonInput::<MouseButton>::default();
+        buttons.press(MouseButton::Left);
+        world.insert_resource(buttons);
+
+        let key = |kind| KeyEvent {
+            code: KeyCode::Char('x'),
+            modifiers: KeyModifiers::default(),
+            kind,
+        };
+        let mouse = |kind, column| {
+            TerminalEvent::new(
+                terminal,
+                InputEvent::Mouse(MouseEvent {
+                    kind,
+                    column,
+                    row: 1,
+                    modifiers: KeyModifiers::default(),
+                }),
+            )
+        };
+        world
+            .resource_mut::<HeldKeys>()
+            .record(terminal, BevyKeyCode::KeyX, key(KeyEventKind::Press));
+        world.write_message(mouse(MouseEventKind::Down(MouseButton::Left), 2));
+        world.write_message(mouse(MouseEventKind::Drag(MouseButton::Left), 4));
+
+        let mut system = IntoSystem::into_system(window_focus_system);
+        system.initialize(&mut world);
+        let mut run = |world: &mut World, focused: Option<bool>| {
+            if let Some(focused) = focused {
+                world.write_message(WindowFocused { window, focused });
+            }
+            system.run((), world).expect("system failed");
+            world
+                .resource_mut::<bevy::ecs::message::Messages<TerminalEvent>>()
+                .drain()
+                .collect::<Vec<_>>()
+        };
+
+        // Still focused: the drag is only followed.
+        assert_eq!(run(&mut world, None).len(), 2);
+        assert_eq!(
+            run(&mut world, Some(false)),
+            vec![
+                TerminalEvent::new(terminal, InputEvent::Key(key(KeyEventKind::Release))),
+                mouse(MouseEventKind::Up(MouseButton::Left), 4),
+                TerminalEvent::new(terminal, InputEvent::FocusLost),
+            ]
+        );
+        assert!(world.resource::<HeldKeys>().is_empty());
+        assert!(!world.resource::<ButtonInput<MouseButton>>().pressed(MouseButton::Left));
+        assert_eq!(world.resource::<TerminalFocus>().focused, None);
+
+        assert_eq!(
+            run(&mut world, Some(true)),
+            vec![TerminalEvent::new(terminal, InputEvent::FocusGained)]
+        );
+        assert_eq!(world.resource::<TerminalFocus>().focused, Some(terminal));
+        assert_eq!(run(&mut world, Some(false)).len(), 1, "nothing held the second time");
+    }
+
+    #[test]
+    fn test_focus_gain_under_the_cursor_sends_one_synthetic_move() {
+        let mut world = World::new();
+        world.init_resource::<bevy::ecs::message::Messages<TerminalEvent>>();
+        world.init_resource::<ButtonInput<BevyKeyCode>>();
+        world.insert_resource(TerminalFocus::default());
+        let hovered = world.spawn(TerminalInput::default()).id();
+        let elsewhere = world.spawn(TerminalInput::default()).id();
+        world.insert_resource(HoveredTerminal {
+            entity: Some(hovered),
+            cell: Some((3, 1)),
+        });
+        let mut system = IntoSystem::into_system(focus_hover_system);
+        system.initialize(&mut world);
+        let mut run = |world: &mut World, focused| {
+            world.resource_mut::<TerminalFocus>().focused = focused;
+            system.run((), world).expect("system failed");
+            world
+                .resource_mut::<bevy::ecs::message::Messages<TerminalEvent>>()
+                .drain()
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            run(&mut world, Some(hovered)),
+            vec![TerminalEvent::new(
+                hovered,
+                InputEvent::Mouse(MouseEvent {
+                    kind: MouseEventKind::Moved,
+                    column: 3,
+                    row: 1,
+                    modifiers: KeyModifiers::default(),
+                }),
+            )]
+        );
+        assert_eq!(run(&mut world, Some(hovered)), vec![], "only on a focus change");
+        assert_eq!(run(&mut world, Some(elsewhere)), vec![], "the cursor isn't over it");
+        assert_eq!(run(&mut world, None), vec![]);
+    }
+
+    #[cfg(all(feature = "mouse_input", feature = "2d"))]
+    #[test]
+    fn test_custom_hit_test_replaces_the_built_in_mapping() {
+        let mut world = World::new();
+        world.init_resource::<bevy::ecs::message::Messages<TerminalEvent>>();
+        world.init_resource::<bevy::ecs::message::Messages<MouseWheel>>();
+        world.init_resource::<ButtonInput<MouseButton>>();
+        world.init_resource::<ButtonInput<BevyKeyCode>>();
+        world.init_resource::<Touches>();
+        world.init_resource::<CursorPosition>();
+        world.init_resource::<TerminalInputConfig>();
+        world.init_resource::<TerminalFocus>();
+        world.init_resource::<HoveredTerminal>();
+        #[cfg(feature = "3d")]
+        world.init_resource::<Assets<bevy::mesh::Mesh>>();
+        // A 10x4 grid whose cells are 10px squares from the window's
+        // origin, wherever the node itself is laid out.
+        let terminal = world
+            .spawn((
+                TerminalInput::default(),
+                bevy::ui::Node::default(),
+                crate::bevy_plugin::TerminalDimensions {
+                    cols: 10,
+                    rows: 4,
+                    char_width_px: 8,
+                    char_height_px: 16,
+                    padding: Default::default(),
+                },
+                CustomHitTest::new(|input| {
+                    Some(((input.cursor.x / 10.0) as u16, (input.cursor.y / 10.0) as u16))
+                }),
+            ))
+            .id();
+
+        let mut system = IntoSystem::into_system(mouse_input_system);
+        system.initialize(&mut world);
+        let mut move_to = |world: &mut World, cursor: Vec2| {
+            world.resource_mut::<CursorPosition>().position = Some(cursor);
+            system.run((), world).expect("system failed");
+            world
+                .resource_mut::<bevy::ecs::message::Messages<TerminalEvent>>()
+                .drain()
+                .collect::<Vec<_>>()
+        };
+
+        let moved = |column, row| {
+            TerminalEvent::new(
+                terminal,
+                InputEvent::Mouse(MouseEvent {
+                    kind: MouseEventKind::Moved,
+                    column,
+                    row,
+                    modifiers: KeyModifiers::default(),
+                }),
+            )
+        };
+        assert_eq!(move_to(&mut world, Vec2::new(35.0, 25.0)), vec![moved(3, 2)]);
+        assert_eq!(
+            *world.resource::<HoveredTerminal>(),
+            HoveredTerminal {
+                entity: Some(terminal),
+                cell: Some((3, 2)),
+            }
+        );
+        // Past the grid's 10 columns: a miss.
+        assert_eq!(move_to(&mut world, Vec2::new(200.0, 5.0)), vec![]);
+        assert_eq!(*world.resource::<HoveredTerminal>(), HoveredTerminal::default());
+    }
+
+    #[cfg(all(feature = "mouse_input", feature = "2d"))]
+    #[test]
+    fn test_nested_ui_terminal_wins_over_its_parent() {
+        let mut world = World::new();
+        world.init_resource::<bevy::ecs::message::Messages<TerminalEvent>>();
+        world.init_resource::<bevy::ecs::message::Messages<MouseWheel>>();
+        world.init_resource::<ButtonInput<MouseButton>>();
+        world.init_resource::<ButtonInput<BevyKeyCode>>();
+        world.init_resource::<Touches>();
+        world.init_resource::<CursorPosition>();
+        world.init_resource::<TerminalInputConfig>();
+        world.init_resource::<TerminalFocus>();
+        world.init_resource::<HoveredTerminal>();
+        #[cfg(feature = "3d")]
+        world.init_resource::<Assets<bevy::mesh::Mesh>>();
+        // 10px square cells. bevy_ui paints a child after its parent, so
+        // it gets the higher stack index - the `ZIndex` of both is the
+        // default 0, which used to leave the winner to query order.
+        let terminal = |left: f32, top: f32, cols: u16, rows: u16, stack_index: u32| {
+            let size = Vec2::new(cols as f32 * 10.0, rows as f32 * 10.0);
+            (
+                TerminalInput::default(),
+                bevy::ui::Node {
+                    left: bevy::ui::Val::Px(left),
+                    top: bevy::ui::Val::Px(top),
+                    ..default()
+                },
+                bevy::ui::ComputedNode {
+                    size,
+                    unrounded_size: size,
+                    ..bevy::ui::ComputedNode::DEFAULT
+                },
+                bevy::ui::ComputedStackIndex(stack_index),
+                crate::bevy_plugin::TerminalDimensions {
+                    cols,
+                    rows,
+                    char_width_px: 10,
+                    char_height_px: 10,
+                    padding: Default::default(),
+                },
+            )
+        };
+        let parent = world.spawn_empty().id();
+        let child = world.spawn((terminal(20.0, 20.0, 4, 2, 1), ChildOf(parent))).id();
+        world.entity_mut(parent).insert(terminal(0.0, 0.0, 10, 6, 0));
+
+        let mut system = IntoSystem::into_system(mouse_input_system);
+        system.initialize(&mut world);
+        let mut hover_at = |world: &mut World, cursor: Vec2| {
+            world.resource_mut::<CursorPosition>().position = Some(cursor);
+            system.run((), world).expect("system failed");
+            let hovered = *world.resource::<HoveredTerminal>();
+            (hovered.entity, hovered.cell)
+        };
+
+        assert_eq!(hover_at(&mut world, Vec2::new(35.0, 25.0)), (Some(child), Some((1, 0))));
+        assert_eq!(hover_at(&mut world, Vec2::new(75.0, 25.0)), (Some(parent), Some((7, 2))));
+    }
+
+    #[test]
+    fn test_tab_does_nothing_without_focusable_terminals() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        world.init_resource::<bevy::ecs::message::Messages<TerminalEvent>>();
+        world.insert_resource(TerminalFocus::default());
+        let mut keys = ButtonInput::<BevyKeyCode>::default();
+        keys.press(BevyKeyCode::Tab);
+        world.insert_resource(keys);
+        world.spawn(TerminalInput {
+            focusable: false,
+            ..default()
+        });
+
+        world.run_system_once(terminal_focus_system).expect("system failed");
+        assert_eq!(world.resource::<TerminalFocus>().focused, None);
+        assert_eq!(
+            world
+                .resource_mut::<bevy::ecs::message::Messages<TerminalEvent>>()
+                .drain()
+                .count(),
+            0
+        );
+    }
+
     #[test]
     fn test_keycode_from_logical_character() {
         assert_eq!(
@@ -1751,6 +3008,104 @@ mod tests {
         assert!(config.mouse_enabled);
         assert!(config.auto_focus);
         assert_eq!(config.focus_button, MouseButton::Left);
+        assert!(!config.edit_actions);
+    }
+
+    #[test]
+    fn test_edit_action_from_key() {
+        let key = |code, kind, modifiers| KeyEvent { code, modifiers, kind };
+        let plain = KeyModifiers::default();
+        let shift = KeyModifiers { shift: true, ..default() };
+        let ctrl = KeyModifiers { ctrl: true, ..default() };
+
+        assert_eq!(
+            EditAction::from_key(&key(KeyCode::Backspace, KeyEventKind::Press, plain)),
+            Some(EditAction::Backspace)
+        );
+        assert_eq!(
+            EditAction::from_key(&key(KeyCode::Enter, KeyEventKind::Press, shift)),
+            Some(EditAction::Newline)
+        );
+        assert_eq!(
+            EditAction::from_key(&key(KeyCode::Left, KeyEventKind::Repeat, plain)),
+            Some(EditAction::Left)
+        );
+        assert_eq!(EditAction::from_key(&key(KeyCode::Delete, KeyEventKind::Release, plain)), None);
+        assert_eq!(EditAction::from_key(&key(KeyCode::End, KeyEventKind::Press, ctrl)), None);
+        assert_eq!(EditAction::from_key(&key(KeyCode::Char('a'), KeyEventKind::Press, plain)), None);
+    }
+
+    #[test]
+    fn test_key_inputs_adds_edit_only_when_enabled() {
+        let key = KeyEvent {
+            code: KeyCode::Home,
+            modifiers: KeyModifiers::default(),
+            kind: KeyEventKind::Press,
+        };
+        assert_eq!(key_inputs(key, false).collect::<Vec<_>>(), vec![InputEvent::Key(key)]);
+        assert_eq!(
+            key_inputs(key, true).collect::<Vec<_>>(),
+            vec![InputEvent::Key(key), InputEvent::Edit(EditAction::Home)]
+        );
+    }
+
+    #[test]
+    fn test_hovered_terminal_from_hover() {
+        assert_eq!(HoveredTerminal::from_hover(None), HoveredTerminal::default());
+        let entity = World::new().spawn_empty().id();
+        assert_eq!(
+            HoveredTerminal::from_hover(Some((entity, 3, 4))),
+            HoveredTerminal {
+                entity: Some(entity),
+                cell: Some((3, 4)),
+            }
+        );
+        assert_eq!(
+            HoveredTerminal::from_hover(Some((entity, 3, 4))).hover(),
+            Some((entity, 3, 4))
+        );
+        assert_eq!(HoveredTerminal::default().hover(), None);
+    }
+
+    #[test]
+    fn test_keyboard_targets_single_focus_by_default() {
+        let focused = World::new().spawn_empty().id();
+        let focus = TerminalFocus { focused: Some(focused) };
+        assert_eq!(keyboard_targets(&focus, None), vec![focused]);
+        // An empty group falls back to single focus.
+        let empty = TerminalFocusGroup::default();
+        assert_eq!(keyboard_targets(&focus, Some(&empty)), vec![focused]);
+        assert!(keyboard_targets(&TerminalFocus::default(), None).is_empty());
+    }
+
+    #[test]
+    fn test_keyboard_targets_group_overrides_focus_and_dedups() {
+        let mut world = World::new();
+        let [a, b, c] = [(); 3].map(|_| world.spawn_empty().id());
+        let focus = TerminalFocus { focused: Some(c) };
+        let group = TerminalFocusGroup { members: vec![b, a, b] };
+        assert_eq!(keyboard_targets(&focus, Some(&group)), vec![b, a]);
+    }
+
+    #[test]
+    fn test_source_focus_routes_by_window_then_keyboard_owner() {
+        let mut world = World::new();
+        let [window_a, window_b, term_1, term_2] = [(); 4].map(|_| world.spawn_empty().id());
+        let (p1, p2) = (InputSource(1), InputSource(2));
+
+        let mut sources = TerminalSourceFocus::default();
+        sources.focus(p1, term_1);
+        sources.focus(p2, term_2);
+        assert_eq!(sources.keyboard_target(window_a), None, "no keyboard owner yet");
+
+        sources.keyboard = Some(p1);
+        sources.window_sources.insert(window_b, p2);
+        assert_eq!(sources.keyboard_target(window_a), Some(term_1));
+        assert_eq!(sources.keyboard_target(window_b), Some(term_2));
+
+        sources.unfocus(p2);
+        assert_eq!(sources.keyboard_target(window_b), None);
+        assert_eq!(sources.focused_by(p1), Some(term_1));
     }
 
     #[test]
@@ -1799,6 +3154,65 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "mouse_input")]
+    mod click_drag_target_tests {
+        use super::super::{PressOrigin, click_drag_target};
+        use bevy::prelude::*;
+
+        const PRESSED: (bool, bool, bool) = (true, false, true);
+        const HELD: (bool, bool, bool) = (false, false, true);
+        const RELEASED: (bool, bool, bool) = (false, true, false);
+
+        #[test]
+        fn release_within_the_threshold_is_a_click_on_the_pressed_cell() {
+            let terminal = Entity::PLACEHOLDER;
+            let mut press: PressOrigin = None;
+            let down = (terminal, 3, 4);
+            let next_cell = (terminal, 4, 4);
+            let at = |x: f32| Vec2::new(x, 10.0);
+
+            assert_eq!(click_drag_target(&mut press, 4.0, at(10.0), down, PRESSED), (down, true));
+            // Exactly at the threshold, over the next cell: still a click.
+            assert_eq!(click_drag_target(&mut press, 4.0, at(14.0), next_cell, HELD), (down, true));
+            assert_eq!(
+                click_drag_target(&mut press, 4.0, at(14.0), next_cell, RELEASED),
+                (down, true)
+            );
+            assert_eq!(press, None);
+        }
+
+        #[test]
+        fn moving_beyond_the_threshold_drags_until_released() {
+            let terminal = Entity::PLACEHOLDER;
+            let mut press: PressOrigin = None;
+            let down = (terminal, 3, 4);
+            let next_cell = (terminal, 4, 4);
+            let at = |x: f32| Vec2::new(x, 10.0);
+
+            click_drag_target(&mut press, 4.0, at(10.0), down, PRESSED);
+            assert_eq!(
+                click_drag_target(&mut press, 4.0, at(14.5), next_cell, HELD),
+                (next_cell, false)
+            );
+            // Back near the press: a drag stays a drag.
+            assert_eq!(click_drag_target(&mut press, 4.0, at(10.0), down, HELD), (down, false));
+            assert_eq!(
+                click_drag_target(&mut press, 4.0, at(14.5), next_cell, RELEASED),
+                (next_cell, false)
+            );
+        }
+
+        #[test]
+        fn zero_threshold_never_holds_a_press() {
+            let terminal = Entity::PLACEHOLDER;
+            let mut press: PressOrigin = None;
+            let down = (terminal, 3, 4);
+            let at = Vec2::new(10.0, 10.0);
+            assert_eq!(click_drag_target(&mut press, 0.0, at, down, PRESSED), (down, false));
+            assert_eq!(press, None);
+        }
+    }
+
     #[cfg(feature = "mouse_input")]
     mod scroll_kind_tests {
         use super::super::scroll_kind;
@@ -1918,4 +3332,52 @@ mod tests {
             assert_eq!(uv_to_cell(0.5, 0.5, 80, 24), (40, 12));
         }
     }
+
+    #[cfg(all(feature = "mouse_input", feature = "3d"))]
+    mod ray_cast_hit_test_tests {
+        use super::super::ray_cast_hit_test_inner;
+        use crate::input::ray::Ray;
+        use bevy::prelude::*;
+
+        /// Where a ray straight down -Z at `(x, y)` hits a 2x1 quad at the
+        /// origin (UV (0, 0) top-left, (1, 1) bottom-right), on the default
+        /// 80x24 grid.
+        fn hit_at(x: f32, y: f32) -> Option<(u16, u16)> {
+            cast(Ray::new(Vec3::new(x, y, 5.0), Vec3::NEG_Z), false)
+        }
+
+        fn cast(ray: Ray, hit_backfaces: bool) -> Option<(u16, u16)> {
+            let mut meshes = Assets::<Mesh>::default();
+            let quad = meshes.add(Rectangle::new(2.0, 1.0));
+            let transform = GlobalTransform::IDENTITY;
+            ray_cast_hit_test_inner(&ray, &transform, &quad, &meshes, None, hit_backfaces)
+                .map(|(hit, _)| (hit.col, hit.row))
+        }
+
+        #[test]
+        fn back_face_hits_only_when_enabled_and_map_to_the_cell_drawn_there() {
+            // Up +Z from behind the quad: only its back face is in the way.
+            let from_behind = |x: f32, y: f32| Ray::new(Vec3::new(x, y, -5.0), Vec3::Z);
+            assert_eq!(cast(from_behind(-0.9999, 0.4999), false), None);
+            // Same point, same cell as from the front - seen from behind,
+            // the grid (like the texture) is simply mirrored.
+            assert_eq!(cast(from_behind(-0.9999, 0.4999), true), Some((0, 0)));
+            assert_eq!(cast(from_behind(0.9999, -0.4999), true), Some((79, 23)));
+            // The front face is hit either way.
+            let from_front = Ray::new(Vec3::new(-0.9999, 0.4999, 5.0), Vec3::NEG_Z);
+            assert_eq!(cast(from_front, true), Some((0, 0)));
+        }
+
+        #[test]
+        fn corners_of_a_quad_map_to_the_first_and_last_cells() {
+            assert_eq!(hit_at(-0.9999, 0.4999), Some((0, 0)));
+            assert_eq!(hit_at(0.9999, -0.4999), Some((79, 23)));
+            // Exactly on the corner (UV (1, 1)), if the intersection
+            // counts it as a hit at all, is still the last cell.
+            if let Some(cell) = hit_at(1.0, -0.5) {
+                assert_eq!(cell, (79, 23));
+            }
+            assert_eq!(hit_at(0.0, 0.0), Some((40, 12)));
+        }
+    }
 }
@@ -190,40 +190,74 @@
 // Public modules
 pub mod backend;
 pub mod bevy_plugin;
-pub(crate) mod colors;
+pub mod colors;
+pub mod content;
+pub mod debug;
+#[cfg(feature = "debug_stats")]
+pub mod debug_stats;
 pub mod fonts;
 pub mod input;
+#[cfg(feature = "bundled_font")]
+pub mod quick;
 pub mod setup;
+#[cfg(all(feature = "system_fonts", not(target_arch = "wasm32")))]
+pub(crate) mod system_fonts;
+#[cfg(feature = "shader_hot_reload")]
+pub mod shader_reload;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+pub mod theme;
+pub mod typewriter;
 pub(crate) mod utils;
 
 // Re-export external crates
 pub use ratatui;
 pub use wgpu;
+pub use tiny_skia;
 
 // Re-export commonly used types from backend
-pub use backend::bevy_backend::{BevyTerminalBackend, TerminalBuilder};
-pub use backend::{Dimensions, Viewport};
+pub use backend::bevy_backend::{BevyTerminalBackend, CellFilter, TerminalBuilder};
+pub use backend::atlas_snapshot::AtlasSnapshot;
+pub use backend::{
+    ChromeConfig, CursorStyle, Dimensions, OutputMode, Padding, PowerlineShape, PowerlineStyle,
+    RenderStats, TextExtractOptions, Viewport,
+};
+
+// Re-export the color conversion
+pub use colors::{color_to_rgb, Palette, Rgb};
 
 // Re-export font types
-pub use fonts::{Font, Fonts, TerminalFontAsset};
+pub use fonts::{Font, FontSet, Fonts, TerminalFontAsset};
 
 // Re-export bevy plugin types
-pub use bevy_plugin::{TerminalDimensions, TerminalPlugin};
+pub use bevy_plugin::{
+    TerminalDimensions, TerminalPlugin, TerminalRenderRate, TerminalRenderWhenVisible, TerminalTextureLost,
+};
+pub use theme::TerminalTheme;
+pub use debug::TerminalDebug;
+pub use typewriter::{TypewriterFinished, TypewriterTerminal};
+pub use content::{TerminalContent, TerminalRenderer};
+#[cfg(feature = "debug_stats")]
+pub use debug_stats::TerminalDebugStats;
+#[cfg(feature = "shader_hot_reload")]
+pub use shader_reload::TerminalShaderFiles;
 
 // Re-export the ECS-native terminal API
 pub use setup::{TuiFontSource, HitRegions, TerminalConfig, Tui, TuiKind, TuiRequest, TuiSurface};
 #[cfg(feature = "2d")]
 pub use setup::TuiUi;
 #[cfg(feature = "3d")]
-pub use setup::{AttachMaterial, AttachTerminal};
+pub use setup::{AttachMaterial, AttachTerminal, RoomFace, TerminalGlow, CHILD_QUAD_LIFT};
 
 // Error types
 
 /// Errors that can occur creating or operating a terminal. Replaces the
 /// previous `Result<_, String>` used throughout `setup`/`backend` - callers
 /// can now match on a specific variant or chain `source()` instead of
-/// parsing a message string.
+/// parsing a message string. Non-exhaustive: matches need a wildcard arm,
+/// so new failure modes can be added without breaking them.
 #[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
 pub enum TerminalError {
     /// ratatui's own `Terminal::new()` failed.
     #[error("ratatui terminal initialization failed: {0}")]
@@ -232,6 +266,28 @@ pub enum TerminalError {
     /// Font data failed to parse (see [`fonts::Fonts::from_asset`]).
     #[error("invalid font data: {0}")]
     Font(String),
+
+    /// The terminal's texture would exceed WebGL2's size limit. Only
+    /// returned on wasm32 - see [`setup::TerminalTexture::create`].
+    #[error("terminal texture {width}x{height}px exceeds the WebGL2 limit of {max}px per side")]
+    TextureTooLarge { width: u32, height: u32, max: u32 },
+
+    /// [`BevyTerminalBackend::set_grid`] was given a grid that isn't the
+    /// terminal's drawable size, or whose cell count isn't `cols * rows`.
+    #[error("grid of {cols}x{rows} ({cells} cells) doesn't match the terminal's {expected_cols}x{expected_rows} drawable area")]
+    GridSize {
+        cols: u16,
+        rows: u16,
+        cells: usize,
+        expected_cols: u16,
+        expected_rows: u16,
+    },
+
+    /// [`setup::Tui::dump_atlas_png`] read back no pixels or couldn't write
+    /// the PNG.
+    #[cfg(feature = "atlas_dump")]
+    #[error("glyph atlas dump failed: {0}")]
+    AtlasDump(String),
 }
 
 pub type Result<T> = ::std::result::Result<T, TerminalError>;
@@ -239,9 +295,22 @@ pub type Result<T> = ::std::result::Result<T, TerminalError>;
 // Convenience prelude for common imports
 pub mod prelude {
     // Plugin and components
-    pub use crate::bevy_plugin::{TerminalDimensions, TerminalPlugin, TerminalSystemSet};
+    pub use crate::bevy_plugin::{
+        TerminalDimensions, TerminalPlugin, TerminalRenderRate, TerminalRenderWhenVisible, TerminalSystemSet,
+        TerminalTextureLost,
+    };
+    pub use crate::theme::TerminalTheme;
+    pub use crate::debug::TerminalDebug;
+    pub use crate::typewriter::{TypewriterFinished, TypewriterTerminal};
+    pub use crate::content::{TerminalContent, TerminalRenderer};
+    #[cfg(feature = "debug_stats")]
+    pub use crate::debug_stats::TerminalDebugStats;
+    #[cfg(feature = "bundled_font")]
+    pub use crate::quick::bundled_fonts;
+    #[cfg(all(feature = "bundled_font", feature = "2d"))]
+    pub use crate::quick::{QuickTerminal, QuickTerminalPlugin};
 
-    pub use crate::setup::TerminalTexture;
+    pub use crate::setup::{TerminalMetrics, TerminalTexture};
 
     // ECS-native terminal API
     pub use crate::setup::{
@@ -250,10 +319,11 @@ pub mod prelude {
     #[cfg(feature = "2d")]
     pub use crate::setup::TuiUi;
     #[cfg(feature = "3d")]
-    pub use crate::setup::{AttachMaterial, AttachTerminal};
+    pub use crate::setup::{AttachMaterial, AttachTerminal, RoomFace, TerminalGlow};
 
     // Backend and builders
-    pub use crate::{BevyTerminalBackend, Font, Fonts, TerminalBuilder, TerminalFontAsset};
+    pub use crate::{BevyTerminalBackend, Font, FontSet, Fonts, TerminalBuilder, TerminalFontAsset};
+    pub use crate::{color_to_rgb, Palette, Rgb};
 
     // Input handling. `KeyCode` is deliberately NOT re-exported here:
     // `bevy::prelude::*` (glob-imported by every example alongside this
@@ -263,9 +333,13 @@ pub mod prelude {
     // `use bevy_tui_texture::input::KeyCode;` - an explicit `use` always
     // wins over a glob, so it cleanly shadows bevy's.
     pub use crate::input::{
-        CursorPosition, InputEvent, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent,
-        MouseEventKind, TerminalEvent, TerminalFocus, TerminalInput, TerminalInputConfig,
+        CursorPosition, EditAction, HeldKeys, HoveredTerminal, InputEvent, InputSource, KeyEvent,
+        KeyEventKind, KeyModifiers, MouseEvent, MouseEventKind, TerminalEvent, TerminalEventReaderExt,
+        TerminalFocus, TerminalFocusGroup, TerminalId, TerminalInput, TerminalInputConfig,
+        TerminalSourceFocus,
     };
+    #[cfg(feature = "mouse_input")]
+    pub use crate::input::{CustomHitTest, HitTestInput};
 
     // Re-export ratatui for convenience
     pub use ratatui;
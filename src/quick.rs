@@ -0,0 +1,150 @@
+//! Zero-setup terminal for demos and prototypes (feature `bundled_font`).
+//!
+//! [`bundled_fonts`] returns a ready [`Fonts`] built from a monospace font
+//! compiled into the crate, so no asset loading is needed. With the `2d`
+//! feature, [`QuickTerminalPlugin`] goes further: it adds
+//! [`TerminalPlugin`] (unless added before it), spawns a `Camera2d` and one
+//! terminal filling the primary window, keeps its grid matched to the
+//! window size, and publishes its entity as the [`QuickTerminal`]
+//! resource:
+//!
+//! ```ignore
+//! App::new()
+//!     .add_plugins((DefaultPlugins, QuickTerminalPlugin::default()))
+//!     .add_systems(Update, |quick: Res<QuickTerminal>, mut tuis: Query<&mut Tui>| {
+//!         let Ok(mut tui) = tuis.get_mut(quick.0) else { return };
+//!         tui.draw(|frame| frame.render_widget("Hello, World!", frame.area()));
+//!     })
+//!     .run();
+//! ```
+//!
+//! The bundled font is M+ 1Code Regular, Copyright 2021 The M+ FONTS
+//! Project Authors, licensed under the SIL Open Font License 1.1
+//! (`examples/assets/fonts/LICENSE/mplus1code.txt`). Apps shipping it
+//! must carry that license along. It adds about 1.7MB to the binary, which
+//! is why this is opt-in and separate from the lean [`TerminalPlugin`].
+
+use std::sync::Arc;
+
+use crate::fonts::{Font, Fonts};
+#[cfg(feature = "2d")]
+use bevy::prelude::*;
+#[cfg(feature = "2d")]
+use crate::bevy_plugin::{TerminalPlugin, TerminalSystemSet};
+
+/// The raw bytes of the bundled font (M+ 1Code Regular, SIL OFL 1.1).
+pub const BUNDLED_FONT: &[u8] = include_bytes!("../examples/assets/fonts/Mplus1Code-Regular.ttf");
+
+/// A [`Fonts`] built from [`BUNDLED_FONT`] at `size_px`.
+pub fn bundled_fonts(size_px: u32) -> Arc<Fonts> {
+    let font = Font::new(BUNDLED_FONT).expect("the bundled font always parses");
+    Arc::new(Fonts::new(font, size_px))
+}
+
+/// Entity of the terminal spawned by [`QuickTerminalPlugin`] - look its
+/// [`Tui`](crate::setup::Tui) up to draw.
+#[cfg(feature = "2d")]
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuickTerminal(pub Entity);
+
+/// Camera, full-window terminal and [`TerminalPlugin`] in one plugin - see
+/// the [module docs](self). Keyboard and mouse input are on, as with
+/// `TerminalPlugin::default()`.
+///
+/// To configure `TerminalPlugin` yourself, add it *before* this plugin:
+/// `build` adds `TerminalPlugin::default()` only when none is added yet,
+/// and adding a second `TerminalPlugin` afterwards panics as a duplicate
+/// plugin.
+///
+/// ```ignore
+/// app.add_plugins((TerminalPlugin::display_only(), QuickTerminalPlugin::default()));
+/// ```
+#[cfg(feature = "2d")]
+#[derive(Debug, Clone, Copy)]
+pub struct QuickTerminalPlugin {
+    /// Font size of the terminal, in pixels. Default `16`.
+    pub font_size_px: u32,
+}
+
+#[cfg(feature = "2d")]
+impl Default for QuickTerminalPlugin {
+    fn default() -> Self {
+        Self { font_size_px: 16 }
+    }
+}
+
+#[cfg(feature = "2d")]
+impl Plugin for QuickTerminalPlugin {
+    fn build(&self, app: &mut App) {
+        // Only sees plugins added earlier - see the ordering note above.
+        if !app.is_plugin_added::<TerminalPlugin>() {
+            app.add_plugins(TerminalPlugin::default());
+        }
+        let fonts = bundled_fonts(self.font_size_px);
+        app.add_systems(Startup, move |mut commands: Commands| {
+            spawn_quick_terminal(&mut commands, fonts.clone());
+        })
+        .add_systems(
+            Update,
+            fit_quick_terminal_to_window.in_set(TerminalSystemSet::UserUpdate),
+        );
+    }
+}
+
+/// Spawn the camera and an 80x25 terminal stretched over the whole window;
+/// the first window-resize event (bevy sends one on startup) corrects the
+/// grid to the window's actual size.
+#[cfg(feature = "2d")]
+fn spawn_quick_terminal(commands: &mut Commands, fonts: Arc<Fonts>) {
+    commands.spawn(Camera2d);
+    let entity = commands
+        .spawn((
+            crate::setup::TuiRequest::ui(80, 25, fonts),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+        ))
+        .id();
+    commands.insert_resource(QuickTerminal(entity));
+}
+
+/// The `examples/resize.rs` recipe, for the quick terminal only: window
+/// pixels -> cols/rows -> `Tui::request_resize`.
+#[cfg(feature = "2d")]
+fn fit_quick_terminal_to_window(
+    mut events: MessageReader<crate::input::TerminalEvent>,
+    quick: Option<Res<QuickTerminal>>,
+    mut terminals: Query<&mut crate::setup::Tui>,
+) {
+    let Some(quick) = quick else {
+        return;
+    };
+    let Ok(mut tui) = terminals.get_mut(quick.0) else {
+        return;
+    };
+    for event in events.read() {
+        if event.target != quick.0 {
+            continue;
+        }
+        if let crate::input::InputEvent::Resize { pixels } = &event.input {
+            let dims = tui.dimensions();
+            let cols = (pixels.x / dims.char_width_px.max(1)).max(1) as u16;
+            let rows = (pixels.y / dims.char_height_px.max(1)).max(1) as u16;
+            tui.request_resize(cols, rows);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_fonts_have_cell_metrics() {
+        let fonts = bundled_fonts(16);
+        assert_eq!(fonts.height_px(), 16);
+        assert!(fonts.min_width_px() > 0);
+    }
+}
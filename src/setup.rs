@@ -67,11 +67,14 @@ use std::sync::Arc;
 #[cfg(feature = "3d")]
 use std::sync::Mutex;
 
+use bevy::image::ImageSampler;
+use bevy::render::render_resource::TextureFormat;
 #[cfg(feature = "3d")]
 use bevy::pbr::{Material, StandardMaterial};
 use bevy::prelude::*;
 
 use crate::backend::bevy_backend::{BevyTerminalBackend, TerminalBuilder};
+use crate::backend::{ChromeConfig, CursorStyle, OutputMode, Padding, PowerlineStyle, Viewport};
 use crate::bevy_plugin::TerminalDimensions;
 use crate::fonts::Fonts;
 #[cfg(any(feature = "2d", feature = "3d"))]
@@ -86,6 +89,15 @@ use crate::input::TerminalInput;
 ///
 /// This provides maximum flexibility for users who want full control over
 /// entity composition and component setup.
+///
+/// There is no asynchronous copy mode to opt out of: the render world
+/// draws straight into this texture's `Image` in the frame its draw is
+/// flushed, with no staging buffer kept mapped in between (only the
+/// on-demand [`Tui::read_back_blocking`] uses one, for the duration of that
+/// call). A rarely updated terminal - a settings screen - costs nothing
+/// between draws; its texture simply keeps the last rendered content. To
+/// also cap how often a frequently redrawn terminal renders, see
+/// [`TerminalRenderRate`](crate::bevy_plugin::TerminalRenderRate).
 pub struct TerminalTexture {
     pub terminal: ratatui::Terminal<BevyTerminalBackend>,
     pub image_handle: Handle<Image>,
@@ -95,6 +107,25 @@ pub struct TerminalTexture {
     rows: u16,
     char_width_px: u32,
     char_height_px: u32,
+    /// Queued by [`Self::overlay_pixmap`], drained by the render-world
+    /// extract along with the next draw payload.
+    overlays: Vec<crate::backend::PixmapOverlay>,
+    /// Extra destination images rendered from the same payloads - see
+    /// [`Self::add_mirror`].
+    mirrors: Vec<Handle<Image>>,
+    /// Sampler of the destination and mirror images - see
+    /// [`Self::set_sampler`]. Kept here so recreated images keep it.
+    sampler: ImageSampler,
+    /// Pixel format of the destination and mirror images - see
+    /// [`Self::set_hdr`].
+    format: TextureFormat,
+    /// Set whenever the images are recreated (resize, padding, sampler,
+    /// format), cleared by the plugin once it has re-marked the
+    /// `StandardMaterial`s sampling them - see `sync_terminal_materials`
+    /// in `bevy_plugin.rs`.
+    images_recreated: bool,
+    /// GPU resources for [`Self::record_render`], built on its first call.
+    standalone_gpu: Option<crate::backend::StandaloneGpuState>,
 }
 
 impl TerminalTexture {
@@ -116,6 +147,8 @@ impl TerminalTexture {
     /// # Returns
     ///
     /// Returns `Ok(TerminalTexture)` on success, or an error message on failure.
+    /// On wasm32 that includes [`TerminalError::TextureTooLarge`](crate::TerminalError::TextureTooLarge)
+    /// for a grid whose texture would exceed WebGL2's 2048px-per-side limit.
     ///
     /// # Example
     ///
@@ -143,27 +176,23 @@ impl TerminalTexture {
         let width = cols as u32 * char_width_px;
         let height = rows as u32 * char_height_px;
 
-        // Render-world-only image: no CPU-side pixel data ever exists for
-        // it, and no main-world wgpu texture exists either - the render
-        // world's `TerminalGpuState::render` (`backend/mod.rs`) renders
-        // directly into this asset's own `GpuImage::texture_view` every
-        // dirty frame (see `render_tui_textures` in `bevy_plugin.rs`).
-        // `RENDER_ATTACHMENT` makes that possible; `TEXTURE_BINDING` lets
-        // materials sample it; `COPY_SRC` backs `Tui::read_back_blocking`.
-        let mut image = Image::new_uninit(
-            bevy::render::render_resource::Extent3d {
+        // Fail here, with a real error, rather than as a wgpu validation
+        // panic in the render world once the image is first prepared.
+        #[cfg(target_arch = "wasm32")]
+        if width > WEBGL2_MAX_TEXTURE_DIMENSION || height > WEBGL2_MAX_TEXTURE_DIMENSION {
+            return Err(crate::TerminalError::TextureTooLarge {
                 width,
                 height,
-                depth_or_array_layers: 1,
-            },
-            bevy::render::render_resource::TextureDimension::D2,
-            bevy::render::render_resource::TextureFormat::Rgba8Unorm,
-            bevy::asset::RenderAssetUsages::RENDER_WORLD,
-        );
-        image.texture_descriptor.usage = bevy::render::render_resource::TextureUsages::RENDER_ATTACHMENT
-            | bevy::render::render_resource::TextureUsages::TEXTURE_BINDING
-            | bevy::render::render_resource::TextureUsages::COPY_SRC;
-        let image_handle = images.add(image);
+                max: WEBGL2_MAX_TEXTURE_DIMENSION,
+            });
+        }
+
+        let image_handle = images.add(destination_image(
+            width,
+            height,
+            &ImageSampler::Default,
+            TextureFormat::Rgba8Unorm,
+        ));
 
         // Create backend - pure CPU construction, no Device/Queue needed.
         let mut backend = TerminalBuilder::new(fonts)
@@ -188,19 +217,332 @@ impl TerminalTexture {
             rows,
             char_width_px,
             char_height_px,
+            overlays: Vec::new(),
+            mirrors: Vec::new(),
+            sampler: ImageSampler::Default,
+            format: TextureFormat::Rgba8Unorm,
+            images_recreated: false,
+            standalone_gpu: None,
         })
     }
 
+    /// Draw initial content right after [`Self::create`], so the first
+    /// frame the terminal presents already shows it - no blank frame in
+    /// the `initial_fill` color, no separate draw call to schedule. The
+    /// content rides on the first flush of the [`Tui`] wrapping this
+    /// texture, which renders it on the GPU the same frame it is
+    /// extracted; nothing is read back. Optional - without it the terminal
+    /// starts as a cleared grid. The declarative equivalent is
+    /// [`TerminalConfig::initial_draw`].
+    ///
+    /// ```ignore
+    /// let texture = TerminalTexture::create(80, 25, fonts, true, false, [0, 0, 0, 255], &mut images)?
+    ///     .with_initial_draw(|frame| {
+    ///         frame.render_widget(Paragraph::new("Loading..."), frame.area());
+    ///     })?;
+    /// ```
+    pub fn with_initial_draw(
+        mut self,
+        draw_fn: impl FnOnce(&mut ratatui::Frame),
+    ) -> Result<Self, crate::TerminalError> {
+        self.terminal.draw(draw_fn)?;
+        Ok(self)
+    }
+
+    /// Shrink the area ratatui draws into - see [`Viewport`]. With
+    /// `Viewport::Shrink`, the texture keeps its full `cols x rows` size
+    /// but ratatui's `frame.area()` loses the inset from its right and
+    /// bottom edges, leaving an undrawn margin in the `initial_fill` color.
+    /// Chain it before [`Self::with_initial_draw`] so the initial content
+    /// is laid out for the reduced area. The declarative equivalent is
+    /// [`TerminalConfig::viewport`]; [`Tui::set_viewport`] changes it at
+    /// runtime.
+    ///
+    /// ```ignore
+    /// let texture = TerminalTexture::create(80, 25, fonts, true, false, [0, 0, 0, 255], &mut images)?
+    ///     .with_viewport(Viewport::Shrink { width: 16, height: 32 })?;
+    /// ```
+    pub fn with_viewport(mut self, viewport: Viewport) -> Result<Self, crate::TerminalError> {
+        self.apply_viewport(viewport)?;
+        Ok(self)
+    }
+
+    /// Draw a border (with an optional title) around the content - see
+    /// [`ChromeConfig`]. The texture keeps its size; `frame.area()` becomes
+    /// the area inside the border, starting at `(1, 1)`. Chain it before
+    /// [`Self::with_initial_draw`], like [`Self::with_viewport`]. The
+    /// declarative equivalent is [`TerminalConfig::chrome`];
+    /// [`Tui::set_chrome`] changes it at runtime.
+    pub fn with_chrome(mut self, chrome: ChromeConfig) -> Result<Self, crate::TerminalError> {
+        self.apply_chrome(Some(chrome))?;
+        Ok(self)
+    }
+
+    /// Pad the grid inside the texture - see
+    /// [`TerminalBuilder::with_padding`]. Recreates the destination image
+    /// (same handle) at the padded size; the cell grid and ratatui's area
+    /// are unchanged. The declarative equivalent is
+    /// [`TerminalConfig::padding`].
+    ///
+    /// ```ignore
+    /// // A 4px letterbox, except on the left where the border bleeds 1px.
+    /// let texture = TerminalTexture::create(80, 25, fonts, true, false, [0, 0, 0, 255], &mut images)?
+    ///     .with_padding(Padding::new(4, 4, 4, -1), &mut images);
+    /// ```
+    pub fn with_padding(mut self, padding: Padding, images: &mut Assets<Image>) -> Self {
+        self.terminal.backend_mut().set_padding(padding);
+        self.recreate_images(images);
+        self
+    }
+
+    /// Sample the terminal's images with `sampler` - see
+    /// [`Self::set_sampler`]. The declarative equivalent is
+    /// [`TerminalConfig::texture_sampler`].
+    pub fn with_sampler(mut self, sampler: ImageSampler, images: &mut Assets<Image>) -> Self {
+        self.set_sampler(sampler, images);
+        self
+    }
+
+    /// How materials sample this terminal's destination and mirror images.
+    /// Default [`ImageSampler::Default`] (the `ImagePlugin`'s default
+    /// sampler - linear, in a stock app).
+    ///
+    /// Cell backgrounds are already snapped to whole texels, so the texture
+    /// itself has no seams between cells. What shimmers on a scaled or
+    /// rotating 3D terminal (e.g. `examples/widget_catalog_3d.rs`) is the
+    /// texture being minified: these images have no mipmaps, so at a steep
+    /// angle neighbouring screen pixels sample texels far apart, and the
+    /// hard edges between background colors crawl as the quad moves. An
+    /// anisotropic sampler (`anisotropy_clamp` > 1, which wgpu only allows
+    /// with every filter `Linear`) takes several taps along the stretched
+    /// axis and calms this noticeably; `ImageSampler::nearest()` goes the
+    /// other way, keeping every edge hard for a pixel-art look at the cost
+    /// of more crawl.
+    ///
+    /// Recreates the images (same handles), so the content is lost until
+    /// the next render - [`Tui::set_sampler`] re-sends it.
+    pub fn set_sampler(&mut self, sampler: ImageSampler, images: &mut Assets<Image>) {
+        self.sampler = sampler;
+        self.recreate_images(images);
+    }
+
+    /// The sampler set by [`Self::set_sampler`].
+    pub fn sampler(&self) -> &ImageSampler {
+        &self.sampler
+    }
+
+    /// Render into an HDR texture - see [`Self::set_hdr`]. The declarative
+    /// equivalent is [`TerminalConfig::hdr`].
+    pub fn with_hdr(mut self, hdr: bool, images: &mut Assets<Image>) -> Self {
+        self.set_hdr(hdr, images);
+        self
+    }
+
+    /// Whether the destination and mirror images are `Rgba16Float` (HDR)
+    /// instead of the default `Rgba8Unorm`. A float texture keeps colors
+    /// past `1.0` - text brightened with
+    /// [`TerminalBuilder::with_text_brightness`] - so a bloom pass can pick
+    /// them out, where an 8-bit one clamps them and only a 3D material's
+    /// own emissive multiplier (`TerminalGlow`) can push past white. Twice
+    /// the memory per pixel; the glyph atlas stays 8-bit either way.
+    ///
+    /// Showing the difference takes an HDR camera: bevy's `Hdr` component
+    /// on the camera that displays the terminal (`Bloom` requires it, so
+    /// adding `Bloom` is enough) - a non-HDR camera renders into an 8-bit
+    /// target and clamps it all again. Bloom only spreads what is drawn in
+    /// the camera's main pass: a 3D material or a 2D `Sprite` glows, a UI
+    /// `ImageNode` (drawn after post-processing) doesn't. Nothing about
+    /// the materials changes: both formats sample as linear floats (see
+    /// `examples/hdr_bloom.rs`).
+    ///
+    /// Not every adapter can render to a float texture (WebGL2 needs the
+    /// `EXT_color_buffer_float` extension). [`TerminalConfig::hdr`] checks
+    /// and falls back to `Rgba8Unorm` with a warning; an unsupported
+    /// texture set up through this method is left unrendered, with a
+    /// warning, instead of failing wgpu validation. Readbacks of an HDR
+    /// terminal return 8 bytes per pixel (little-endian RGBA half floats),
+    /// and [`Self::record_render`] renders into a texture of the same
+    /// format.
+    ///
+    /// Recreates the images (same handles), so the content is lost until
+    /// the next render - [`Tui::set_hdr`] re-sends it.
+    pub fn set_hdr(&mut self, hdr: bool, images: &mut Assets<Image>) {
+        let format = if hdr {
+            crate::backend::HDR_TEXTURE_FORMAT
+        } else {
+            TextureFormat::Rgba8Unorm
+        };
+        if self.format != format {
+            self.format = format;
+            self.recreate_images(images);
+        }
+    }
+
+    /// Pixel format of the destination and mirror images: `Rgba8Unorm`,
+    /// or `Rgba16Float` after [`Self::set_hdr`].
+    pub fn format(&self) -> TextureFormat {
+        self.format
+    }
+
+    /// Set the backend's viewport and resize ratatui's buffers to the area
+    /// it leaves, so the next draw already sees the new `frame.area()`.
+    fn apply_viewport(&mut self, viewport: Viewport) -> std::io::Result<()> {
+        self.terminal.backend_mut().set_viewport(viewport);
+        self.resize_terminal_to_backend()
+    }
+
+    /// Resize ratatui's buffers to the backend's content area (the grid
+    /// minus any `Viewport::Shrink` inset and chrome border).
+    fn resize_terminal_to_backend(&mut self) -> std::io::Result<()> {
+        let area = self.terminal.backend().content_area();
+        self.terminal.resize(area)
+    }
+
+    /// Set (or with `None` remove) the backend's chrome and rebuild the
+    /// `ratatui::Terminal` around it: a fixed ratatui viewport inside the
+    /// border, since ratatui's full-screen one always spans the whole grid.
+    fn apply_chrome(&mut self, chrome: Option<ChromeConfig>) -> std::io::Result<()> {
+        if self.terminal.backend().chrome() == chrome.as_ref() {
+            return Ok(());
+        }
+        let viewport = if chrome.is_some() {
+            ratatui::Viewport::Fixed(ratatui::layout::Rect::ZERO)
+        } else {
+            ratatui::Viewport::Fullscreen
+        };
+        let stand_in = self.terminal.backend().stand_in();
+        let mut backend = std::mem::replace(self.terminal.backend_mut(), stand_in);
+        backend.set_chrome(chrome);
+        let options = ratatui::TerminalOptions { viewport };
+        self.terminal = ratatui::Terminal::with_options(backend, options)?;
+        // Sizes the fixed viewport, and clears the grid so a removed
+        // border doesn't linger in cells ratatui's diff thinks are blank.
+        self.resize_terminal_to_backend()
+    }
+
+    /// Copy `pixmap` into the cells covered by `area` (in cells), on top of
+    /// the text - for raster graphics ratatui can't draw, like sparkline
+    /// bitmaps, icons or QR codes. The pixmap's top-left corner goes to
+    /// `area`'s; anything beyond `area` (or the grid) is cropped, and a
+    /// smaller pixmap leaves the rest of the area as rendered. Pixels are
+    /// copied, not blended: transparent pixels make the texture
+    /// transparent there.
+    ///
+    /// The copy is made on the GPU right after this terminal's next text
+    /// pass (or on its own, if nothing is redrawn that frame) - it never
+    /// becomes part of the cell content. Any later redraw touching those
+    /// rows (a changed cell, a resize, a lost texture, MSAA resolve)
+    /// paints over it, so re-apply the overlay after every draw that may
+    /// cover it; calling it every frame alongside [`Tui::draw`] is the
+    /// simple way, and cheap for small images.
+    pub fn overlay_pixmap(&mut self, area: ratatui::layout::Rect, pixmap: &tiny_skia::Pixmap) {
+        let area = area.intersection(ratatui::layout::Rect::new(0, 0, self.cols, self.rows));
+        // Offset by the padding; an origin bled off the top/left edge
+        // is clamped to it.
+        let padding = self.terminal.backend().padding();
+        let origin = [
+            (area.x as i64 * self.char_width_px as i64 + padding.left as i64).max(0) as u32,
+            (area.y as i64 * self.char_height_px as i64 + padding.top as i64).max(0) as u32,
+        ];
+        let overlay = crate::backend::PixmapOverlay::from_pixmap(
+            pixmap,
+            origin,
+            area.width as u32 * self.char_width_px,
+            area.height as u32 * self.char_height_px,
+        );
+        self.overlays.extend(overlay);
+    }
+
+    /// Record this terminal's render passes into a caller-owned
+    /// `encoder`, for apps driving wgpu themselves (with or without bevy's
+    /// renderer) that want the terminal in the same submission as their
+    /// own work. Renders whatever [`ratatui::Terminal::draw`] produced
+    /// since the last call - only the dirty rows, like the plugin path -
+    /// plus any queued [`Self::overlay_pixmap`]s, and returns the texture
+    /// it rendered into. Nothing is submitted: the passes run when the
+    /// caller submits `encoder`. Glyph uploads and uniform writes go
+    /// through `queue.write_*`, which wgpu orders before that submission.
+    ///
+    /// The texture is created (and recreated after a resize, padding, MSAA
+    /// or format change) on the first call, on `device`, and is owned by
+    /// this `TerminalTexture`: [`Self::format`], `metrics().texture_width` x
+    /// `texture_height`, usage `RENDER_ATTACHMENT | TEXTURE_BINDING |
+    /// COPY_SRC | COPY_DST`. Sample it, or copy out of it, in passes
+    /// recorded after this call; don't render into it, since partial
+    /// redraws load its previous contents. MSAA counts are used as-is, so
+    /// pick one the device supports for `Rgba8Unorm` (`1` and `4` always
+    /// are).
+    ///
+    /// This path has its own glyph atlas and doesn't go through the
+    /// bevy render world: don't also spawn this terminal as a [`Tui`], and
+    /// give every terminal rendered this way its own [`Fonts`] (not a
+    /// clone of another terminal's `Arc`) - glyph uploads are drained once
+    /// per `Fonts`, so a second atlas fed from the same one misses glyphs.
+    ///
+    /// ```ignore
+    /// texture.terminal.draw(|frame| frame.render_widget(&app, frame.area()))?;
+    /// let mut encoder = device.create_command_encoder(&Default::default());
+    /// let target = texture.record_render(&device, &queue, &mut encoder);
+    /// // ... record passes sampling `target` ...
+    /// queue.submit([encoder.finish()]);
+    /// ```
+    pub fn record_render(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+    ) -> &wgpu::Texture {
+        let (width, height) = (self.width, self.height);
+        let backend = self.terminal.backend_mut();
+        let sample_count = backend.msaa_samples();
+        if self
+            .standalone_gpu
+            .as_ref()
+            .is_none_or(|gpu| !gpu.matches(self.format, width, height, sample_count))
+        {
+            // A fresh texture has no previous contents to load.
+            backend.force_full_redraw();
+            self.standalone_gpu = Some(crate::backend::StandaloneGpuState::new(
+                device,
+                queue,
+                self.format,
+                width,
+                height,
+                sample_count,
+            ));
+        }
+        let draw = backend.take_draw_payload();
+        let uploads = backend.take_shared_glyph_uploads();
+        let overlays = std::mem::take(&mut self.overlays);
+        let gpu = self.standalone_gpu.as_mut().expect("created above");
+        if gpu.record(device, queue, encoder, &uploads, &draw, &overlays) {
+            backend.force_full_redraw();
+        }
+        gpu.texture()
+    }
+
     /// Get the terminal dimensions for entity setup.
     ///
     /// Returns a `TerminalDimensions` component that should be added to
     /// the entity for input coordinate mapping to work correctly.
     pub fn dimensions(&self) -> TerminalDimensions {
-        TerminalDimensions {
+        self.metrics().into()
+    }
+
+    /// Grid size, cell size and texture size in one value - everything
+    /// layout code needs to size a container around this terminal.
+    /// `texture_width`/`texture_height` are the destination `Image`'s
+    /// actual pixel size (`cols * char_width_px` by `rows * char_height_px`
+    /// plus any [`Padding`]), tracking [`Tui::request_resize`].
+    pub fn metrics(&self) -> TerminalMetrics {
+        TerminalMetrics {
             cols: self.cols,
             rows: self.rows,
             char_width_px: self.char_width_px,
             char_height_px: self.char_height_px,
+            texture_width: self.width,
+            texture_height: self.height,
+            padding: self.terminal.backend().padding(),
         }
     }
 
@@ -212,39 +554,375 @@ impl TerminalTexture {
         self.image_handle.clone()
     }
 
-    /// Resize to a new grid size in place. Recreates the destination
-    /// `Image` at the **same handle** (`images.insert`, not a new
-    /// `images.add`) so every `ImageNode`/material already pointing at it
-    /// keeps working with no re-pointing needed. Font metrics are
-    /// unchanged by a grid resize, so pixel dimensions follow directly from
-    /// the stored `char_width_px`/`char_height_px`.
+    /// Resize to a new grid size in place, for callers that own this
+    /// `TerminalTexture` directly (e.g. [`Self::record_render`]); a [`Tui`]
+    /// resizes through [`Tui::request_resize`] instead. Recreates the
+    /// destination `Image` (and mirrors) at the **same handle**
+    /// (`images.insert`, not a new `images.add`) so every
+    /// `ImageNode`/material already pointing at it keeps working with no
+    /// re-pointing needed, resizes the backend's grid and ratatui's
+    /// buffers, and makes the next draw a full one. Fonts and the glyph
+    /// atlas are untouched: font metrics don't change with the grid, so
+    /// pixel dimensions follow directly from the stored
+    /// `char_width_px`/`char_height_px`. The texture `record_render`
+    /// renders into is recreated at the new size on its next call.
     ///
-    /// Does not itself touch ratatui's buffers - `Tui::apply_pending_resize`
-    /// (the only caller) also resizes the backend and calls
-    /// `Terminal::resize` immediately after this returns, so the two stay
-    /// in lockstep.
-    fn resize(&mut self, cols: u16, rows: u16, images: &mut Assets<Image>) {
-        self.width = cols as u32 * self.char_width_px;
-        self.height = rows as u32 * self.char_height_px;
+    /// Whatever was drawn before is gone: draw the new grid before the
+    /// next render. Entities showing the image keep their own size - a
+    /// `TerminalDimensions` component or a container sized from
+    /// [`Self::metrics`] must be updated by the caller. A no-op if
+    /// `(cols, rows)` already matches the current grid size. Unlike
+    /// `request_resize`, the grid isn't clamped to WebGL2's texture limit.
+    pub fn resize(&mut self, cols: u16, rows: u16, images: &mut Assets<Image>) {
+        if (cols, rows) == (self.cols, self.rows) {
+            return;
+        }
         self.cols = cols;
         self.rows = rows;
+        self.recreate_images(images);
+        self.terminal.backend_mut().resize(cols, rows);
+        // The backend's size, not `(cols, rows)`: a `Viewport::Shrink`
+        // inset or chrome border still applies to the new grid.
+        self.resize_terminal_to_backend().ok();
+    }
 
-        let mut image = Image::new_uninit(
-            bevy::render::render_resource::Extent3d {
-                width: self.width,
-                height: self.height,
-                depth_or_array_layers: 1,
-            },
-            bevy::render::render_resource::TextureDimension::D2,
-            bevy::render::render_resource::TextureFormat::Rgba8Unorm,
-            bevy::asset::RenderAssetUsages::RENDER_WORLD,
+    /// Recreate the destination and mirror images, at the same handles,
+    /// for the current grid size and padding.
+    fn recreate_images(&mut self, images: &mut Assets<Image>) {
+        (self.width, self.height) = self.terminal.backend().padding().texture_size(
+            self.cols as u32 * self.char_width_px,
+            self.rows as u32 * self.char_height_px,
         );
-        image.texture_descriptor.usage = bevy::render::render_resource::TextureUsages::RENDER_ATTACHMENT
-            | bevy::render::render_resource::TextureUsages::TEXTURE_BINDING
-            | bevy::render::render_resource::TextureUsages::COPY_SRC;
+        // Positioned for the old grid - the caller re-applies after redrawing.
+        self.overlays.clear();
+        self.images_recreated = true;
+
         images
-            .insert(&self.image_handle, image)
-            .expect("resize: destination image handle must still be valid");
+            .insert(
+                &self.image_handle,
+                destination_image(self.width, self.height, &self.sampler, self.format),
+            )
+            .expect("recreate_images: destination image handle must still be valid");
+        // A mirror whose image was removed from `Assets` simply stays
+        // blank - the render world skips destinations it can't find.
+        for mirror in &self.mirrors {
+            let image = destination_image(self.width, self.height, &self.sampler, self.format);
+            let _ = images.insert(mirror, image);
+        }
+    }
+
+    /// Create another destination image showing this terminal's content -
+    /// e.g. the same HUD on a 2D minimap and on a 3D panel - and return its
+    /// handle for an `ImageNode`/material. Each draw is shaped and
+    /// rasterized once; the render world then renders the same vertex data
+    /// into every destination (one extra render pass per mirror per dirty
+    /// frame, each with its own vertex buffers). Mirrors are always the
+    /// primary image's size and follow resizes at the same handles.
+    ///
+    /// Fresh mirrors start blank: call [`Tui::add_mirror`] instead when the
+    /// terminal is already live, which also re-sends the current content.
+    /// Overlays ([`Self::overlay_pixmap`]) are copied into every mirror
+    /// too. [`Tui::read_back_blocking`] reads the primary image only -
+    /// there is one readback per image, and a mirror holds the same pixels.
+    pub fn add_mirror(&mut self, images: &mut Assets<Image>) -> Handle<Image> {
+        let handle =
+            images.add(destination_image(self.width, self.height, &self.sampler, self.format));
+        self.mirrors.push(handle.clone());
+        handle
+    }
+
+    /// Stop rendering into `mirror`; `false` if it isn't one of this
+    /// terminal's mirrors. The image itself is left as it was.
+    pub fn remove_mirror(&mut self, mirror: &Handle<Image>) -> bool {
+        let before = self.mirrors.len();
+        self.mirrors.retain(|m| m != mirror);
+        self.mirrors.len() != before
+    }
+
+    /// Handles of every mirror added with [`Self::add_mirror`].
+    pub fn mirrors(&self) -> &[Handle<Image>] {
+        &self.mirrors
+    }
+
+    /// Whether the images were recreated since the last call.
+    pub(crate) fn take_images_recreated(&mut self) -> bool {
+        std::mem::take(&mut self.images_recreated)
+    }
+}
+
+/// Largest destination texture side the wasm32 build accepts: WebGL2's
+/// guaranteed `max_texture_dimension_2d` (`wgpu::Limits::
+/// downlevel_webgl2_defaults()`), the same ceiling the glyph atlas is
+/// pinned to (`CACHE_WIDTH` in `backend/mod.rs`). Browsers often allow
+/// more, but not reliably across GPUs - a grid that fits here fits
+/// everywhere.
+#[cfg(any(test, target_arch = "wasm32"))]
+const WEBGL2_MAX_TEXTURE_DIMENSION: u32 = 2048;
+
+/// Render-world-only destination image for a `width` x `height` terminal:
+/// no CPU-side pixel data ever exists for it, and no main-world wgpu
+/// texture exists either - the render world's `TerminalGpuState::render`
+/// (`backend/mod.rs`) renders directly into this asset's own
+/// `GpuImage::texture_view` every dirty frame (see `render_tui_textures` in
+/// `bevy_plugin.rs`). `format` is `Rgba8Unorm`, renderable and
+/// sampleable on every backend, WebGL2 included, or `Rgba16Float` for an
+/// HDR terminal (see [`TerminalTexture::set_hdr`]). `RENDER_ATTACHMENT` makes the direct render
+/// possible; `TEXTURE_BINDING` lets materials sample it; `COPY_DST` takes
+/// `TerminalTexture::overlay_pixmap` copies; `COPY_SRC` backs
+/// `Tui::read_back_blocking`, and is left off on wasm32, where that
+/// readback can't work anyway (see its doc) - WebGL2 validates usage
+/// flags against the format more strictly than native backends, so the
+/// browser build asks for nothing it doesn't use. `sampler` is the
+/// terminal's [`TerminalTexture::sampler`].
+fn destination_image(
+    width: u32,
+    height: u32,
+    sampler: &ImageSampler,
+    format: TextureFormat,
+) -> Image {
+    use bevy::render::render_resource::TextureUsages;
+
+    let mut image = Image::new_uninit(
+        bevy::render::render_resource::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        bevy::render::render_resource::TextureDimension::D2,
+        format,
+        bevy::asset::RenderAssetUsages::RENDER_WORLD,
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST;
+    if !cfg!(target_arch = "wasm32") {
+        image.texture_descriptor.usage |= TextureUsages::COPY_SRC;
+    }
+    image.sampler = sampler.clone();
+    image
+}
+
+/// Largest `(cols, rows)`, no bigger than requested, whose texture stays
+/// within `max_px` per side. Used by `Tui::request_resize` on wasm32.
+#[cfg(any(test, target_arch = "wasm32"))]
+fn clamp_grid_to_texture_limit(
+    cols: u16,
+    rows: u16,
+    char_width_px: u32,
+    char_height_px: u32,
+    max_px: u32,
+) -> (u16, u16) {
+    let max_cols = (max_px / char_width_px.max(1)).min(u16::MAX as u32) as u16;
+    let max_rows = (max_px / char_height_px.max(1)).min(u16::MAX as u32) as u16;
+    (cols.min(max_cols), rows.min(max_rows))
+}
+
+/// Composite RGBA8 pixels over opaque black in place - every alpha becomes
+/// `255` and the colors are scaled by the old alpha. Used by
+/// [`Tui::dump_atlas_png`] so a coverage-only atlas is visible as is.
+#[cfg(feature = "atlas_dump")]
+fn flatten_onto_black(rgba: &mut [u8]) {
+    for pixel in rgba.chunks_exact_mut(4) {
+        let alpha = pixel[3] as u16;
+        for channel in &mut pixel[..3] {
+            *channel = (*channel as u16 * alpha / 255) as u8;
+        }
+        pixel[3] = 255;
+    }
+}
+
+/// Grid and pixel metrics of a [`TerminalTexture`], as returned by
+/// [`TerminalTexture::metrics`]. A superset of [`TerminalDimensions`] -
+/// convert with `.into()` where only the input-mapping subset is needed.
+/// Non-exhaustive: only the terminal builds these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TerminalMetrics {
+    pub cols: u16,
+    pub rows: u16,
+    pub char_width_px: u32,
+    pub char_height_px: u32,
+    pub texture_width: u32,
+    pub texture_height: u32,
+    /// Margin between the grid and the texture's edges - see
+    /// [`TerminalTexture::with_padding`].
+    pub padding: Padding,
+}
+
+impl TerminalMetrics {
+    /// Texture pixel at the center of cell `(col, row)`, or `None` if the
+    /// cell is off the grid or (under negative padding) off the texture.
+    pub fn cell_center_px(&self, col: u16, row: u16) -> Option<UVec2> {
+        if col >= self.cols || row >= self.rows {
+            return None;
+        }
+        let x = self.padding.left as i64
+            + col as i64 * self.char_width_px as i64
+            + self.char_width_px as i64 / 2;
+        let y = self.padding.top as i64
+            + row as i64 * self.char_height_px as i64
+            + self.char_height_px as i64 / 2;
+        let inside = (0..self.texture_width as i64).contains(&x)
+            && (0..self.texture_height as i64).contains(&y);
+        inside.then(|| UVec2::new(x as u32, y as u32))
+    }
+
+    /// The cell under `uv`, a position on the whole texture normalized to
+    /// `0.0..=1.0` (origin top-left, `+Y` down), or `None` if it falls in
+    /// the padding or off the texture. The padding-aware inverse of
+    /// [`Self::cell_center_px`].
+    ///
+    /// This is the mapping to use when the terminal's `Image` is shown by
+    /// something other than this crate's own UI or mesh paths - an egui
+    /// window via `bevy_egui`'s `add_image`, for example - and pointer input
+    /// has to be routed back: with the image drawn into a rect, `uv` is
+    /// `(pointer - rect.min) / rect.size()`. Turn the cell into
+    /// [`InputEvent::Mouse`](crate::input::InputEvent::Mouse) events targeted
+    /// at the `Tui` entity and write them as [`TerminalEvent`](crate::input::TerminalEvent)s.
+    ///
+    /// For egui, add `bevy_egui` (the release matching your bevy version)
+    /// to your own dependencies - this crate has no egui feature - and
+    /// leave [`TerminalInput`](crate::input::TerminalInput) off the
+    /// terminal, so the built-in hit testing doesn't compete. Both use a
+    /// top-left origin with `+Y` down, so no flip is needed. A sketch (the
+    /// `add_image` signature varies between bevy_egui releases):
+    ///
+    /// ```ignore
+    /// // Once per terminal: register its image with egui.
+    /// let texture_id = contexts.add_image(tui.image_handle());
+    ///
+    /// // Each frame, inside an egui window:
+    /// let metrics = tui.metrics();
+    /// let size = egui::vec2(metrics.texture_width as f32, metrics.texture_height as f32);
+    /// let response = ui
+    ///     .image(egui::load::SizedTexture::new(texture_id, size))
+    ///     .interact(egui::Sense::click());
+    /// if let Some(pointer) = response.hover_pos() {
+    ///     let uv = (pointer - response.rect.min) / response.rect.size();
+    ///     if let Some((column, row)) = metrics.cell_at_uv(Vec2::new(uv.x, uv.y)) {
+    ///         let kind = if response.clicked() {
+    ///             MouseEventKind::Down(MouseButton::Left)
+    ///         } else {
+    ///             MouseEventKind::Moved
+    ///         };
+    ///         let mouse = MouseEvent { kind, column, row, modifiers: KeyModifiers::default() };
+    ///         events.write(TerminalEvent::new(tui_entity, InputEvent::Mouse(mouse)));
+    ///     }
+    /// }
+    /// ```
+    pub fn cell_at_uv(&self, uv: Vec2) -> Option<(u16, u16)> {
+        if !(0.0..=1.0).contains(&uv.x) || !(0.0..=1.0).contains(&uv.y) {
+            return None;
+        }
+        let x = uv.x * self.texture_width as f32 - self.padding.left as f32;
+        let y = uv.y * self.texture_height as f32 - self.padding.top as f32;
+        if x < 0.0 || y < 0.0 || self.char_width_px == 0 || self.char_height_px == 0 {
+            return None;
+        }
+        let col = (x / self.char_width_px as f32) as u32;
+        let row = (y / self.char_height_px as f32) as u32;
+        (col < self.cols as u32 && row < self.rows as u32).then_some((col as u16, row as u16))
+    }
+
+    /// The RGBA of cell `(col, row)`'s center pixel in `rgba`, a tightly
+    /// packed readback of this terminal's texture (as returned by
+    /// [`Tui::read_back_blocking`]). `None` if the cell has no center
+    /// pixel (see [`Self::cell_center_px`]) or `rgba` is too short - e.g.
+    /// the empty readback of a wasm32 build.
+    pub fn sample_cell(&self, rgba: &[u8], col: u16, row: u16) -> Option<[u8; 4]> {
+        let center = self.cell_center_px(col, row)?;
+        let at = (center.y as usize * self.texture_width as usize + center.x as usize) * 4;
+        rgba.get(at..at + 4)?.try_into().ok()
+    }
+
+    /// Texture pixels covered by the cells in `area`, after clipping
+    /// `area` to the grid and the result to the texture (padding included,
+    /// so a cell pushed off the texture by negative padding drops out).
+    /// `None` if nothing is left - see [`Tui::read_back_region_blocking`].
+    pub fn cell_rect_px(&self, area: ratatui::layout::Rect) -> Option<URect> {
+        let area = self.clip_to_grid(area);
+        if area.is_empty() {
+            return None;
+        }
+        let x = |col: u16| self.padding.left as i64 + col as i64 * self.char_width_px as i64;
+        let y = |row: u16| self.padding.top as i64 + row as i64 * self.char_height_px as i64;
+        let clamp_x = |px: i64| px.clamp(0, self.texture_width as i64) as u32;
+        let clamp_y = |px: i64| px.clamp(0, self.texture_height as i64) as u32;
+        let rect = URect::new(
+            clamp_x(x(area.left())),
+            clamp_y(y(area.top())),
+            clamp_x(x(area.right())),
+            clamp_y(y(area.bottom())),
+        );
+        (!rect.is_empty()).then_some(rect)
+    }
+
+    fn clip_to_grid(&self, area: ratatui::layout::Rect) -> ratatui::layout::Rect {
+        area.intersection(ratatui::layout::Rect::new(0, 0, self.cols, self.rows))
+    }
+
+    /// Layout for a UI node nested in this terminal's node that covers the
+    /// cells in `area` (clipped to the grid) - a sub-terminal inside a
+    /// bordered panel, say. Absolutely positioned, in percent of the
+    /// parent, so it stays on its cells however the parent node is sized.
+    /// A child terminal `area.width x area.height` cells big, with the same
+    /// font size, lines up cell for cell with the parent shown at its
+    /// native size. See [`TuiRequest::ui_child`].
+    #[cfg(feature = "2d")]
+    pub fn child_node(&self, area: ratatui::layout::Rect) -> Node {
+        let area = self.clip_to_grid(area);
+        let percent_x = |px: f32| Val::Percent(px * 100.0 / self.texture_width.max(1) as f32);
+        let percent_y = |px: f32| Val::Percent(px * 100.0 / self.texture_height.max(1) as f32);
+        let (cw, ch) = (self.char_width_px as f32, self.char_height_px as f32);
+        Node {
+            position_type: PositionType::Absolute,
+            left: percent_x(self.padding.left as f32 + area.x as f32 * cw),
+            top: percent_y(self.padding.top as f32 + area.y as f32 * ch),
+            width: percent_x(area.width as f32 * cw),
+            height: percent_y(area.height as f32 * ch),
+            ..default()
+        }
+    }
+
+    /// Placement of a world quad nested under this terminal's
+    /// [`TuiKind::WorldQuad`] (`parent_height` world units tall) so it
+    /// covers the cells in `area` (clipped to the grid): the child's local
+    /// `Transform` and the `height` to give its own quad. The child sits
+    /// [`CHILD_QUAD_LIFT`] of `parent_height` in front of the parent, so it
+    /// is drawn - and hit-tested - on top. See
+    /// [`TuiRequest::world_quad_child`].
+    #[cfg(feature = "3d")]
+    pub fn child_quad(&self, area: ratatui::layout::Rect, parent_height: f32) -> (Transform, f32) {
+        let area = self.clip_to_grid(area);
+        let width_px = self.texture_width.max(1) as f32;
+        let height_px = self.texture_height.max(1) as f32;
+        let (cw, ch) = (self.char_width_px as f32, self.char_height_px as f32);
+        let world_per_px = parent_height / height_px;
+        let center_x = self.padding.left as f32 + (area.x as f32 + area.width as f32 / 2.0) * cw;
+        let center_y = self.padding.top as f32 + (area.y as f32 + area.height as f32 / 2.0) * ch;
+        let translation = Vec3::new(
+            (center_x - width_px / 2.0) * world_per_px,
+            (height_px / 2.0 - center_y) * world_per_px,
+            CHILD_QUAD_LIFT * parent_height,
+        );
+        (Transform::from_translation(translation), area.height as f32 * ch * world_per_px)
+    }
+}
+
+/// How far [`TerminalMetrics::child_quad`] places a child world quad in
+/// front of its parent, as a fraction of the parent's height: enough to
+/// keep the two from z-fighting, too little to show as a gap.
+#[cfg(feature = "3d")]
+pub const CHILD_QUAD_LIFT: f32 = 0.002;
+
+impl From<TerminalMetrics> for TerminalDimensions {
+    fn from(metrics: TerminalMetrics) -> Self {
+        TerminalDimensions {
+            cols: metrics.cols,
+            rows: metrics.rows,
+            char_width_px: metrics.char_width_px,
+            char_height_px: metrics.char_height_px,
+            padding: metrics.padding,
+        }
     }
 }
 
@@ -352,6 +1030,19 @@ pub struct Tui {
     /// error, so a terminal that keeps failing every frame doesn't spam the
     /// log - one `warn!` per terminal is enough to diagnose it.
     draw_error_logged: bool,
+    /// Counters and overlay text for the `debug_stats` feature's overlay -
+    /// see [`crate::debug_stats`].
+    #[cfg(feature = "debug_stats")]
+    debug_stats: crate::debug_stats::DebugStatsState,
+    /// App time at which a [`TerminalRenderRate`](crate::bevy_plugin::TerminalRenderRate)-capped
+    /// terminal may flush next - see [`Tui::flush_at_most`].
+    next_flush_due: Option<std::time::Duration>,
+    /// See [`Tui::set_strict_bounds`].
+    strict_bounds: bool,
+    /// Set by [`Tui::set_grid`] and [`Tui::set_cell_filter`]: ratatui's
+    /// last frame no longer matches the backend, so the next draw starts
+    /// from a cleared terminal and sends every cell.
+    resend_frame: bool,
 }
 
 impl Tui {
@@ -366,6 +1057,11 @@ impl Tui {
             pending_resize: None,
             hit_regions: HitRegions::default(),
             draw_error_logged: false,
+            #[cfg(feature = "debug_stats")]
+            debug_stats: Default::default(),
+            next_flush_due: None,
+            strict_bounds: false,
+            resend_frame: false,
         }
     }
 
@@ -381,13 +1077,292 @@ impl Tui {
     /// compute `cols`/`rows` yourself, typically from an `InputEvent::
     /// Resize` event's pixel size and `Tui::size_px()`'s per-cell metrics
     /// (see `examples/resize.rs` for the recipe).
+    ///
+    /// On wasm32 the grid is clamped so the texture stays within WebGL2's
+    /// 2048px-per-side limit (with a warning), rather than failing once the
+    /// render world recreates the texture.
     pub fn request_resize(&mut self, cols: u16, rows: u16) {
+        #[cfg(target_arch = "wasm32")]
+        let (cols, rows) = {
+            let metrics = self.texture_state.metrics();
+            let clamped = clamp_grid_to_texture_limit(
+                cols,
+                rows,
+                metrics.char_width_px,
+                metrics.char_height_px,
+                WEBGL2_MAX_TEXTURE_DIMENSION,
+            );
+            if clamped != (cols, rows) {
+                tracing::warn!(
+                    "request_resize({cols}, {rows}) exceeds the WebGL2 texture limit; clamped to {clamped:?}"
+                );
+            }
+            clamped
+        };
         let (current_cols, current_rows) = self.grid_size();
         if (cols, rows) != (current_cols, current_rows) {
             self.pending_resize = Some((cols, rows));
         }
     }
 
+    /// Copy raster graphics into a region of cells, on top of the text -
+    /// see [`TerminalTexture::overlay_pixmap`], including how it interacts
+    /// with later redraws.
+    pub fn overlay_pixmap(&mut self, area: ratatui::layout::Rect, pixmap: &tiny_skia::Pixmap) {
+        self.texture_state.overlay_pixmap(area, pixmap);
+    }
+
+    /// Turn the cell grid overlay on (`Some(color)`) or off (`None`) at
+    /// runtime - see [`TerminalConfig::cell_grid`]. Takes effect on the
+    /// next [`Tui::draw`], which repaints every row even if the drawn
+    /// content is unchanged.
+    pub fn set_cell_grid(&mut self, color: Option<[u8; 3]>) {
+        self.texture_state.terminal.backend_mut().set_cell_grid(color);
+    }
+
+    /// Set or clear the reset-background gradient at runtime - see
+    /// [`TerminalConfig::background_gradient`]. Takes effect on the next
+    /// [`Tui::draw`], which repaints every row.
+    pub fn set_background_gradient(&mut self, gradient: Option<([u8; 3], [u8; 3])>) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .set_background_gradient(gradient);
+    }
+
+    /// Turn the transparency checkerboard on or off for this terminal - see
+    /// [`TerminalBuilder::with_transparency_checkerboard`], and
+    /// [`TerminalDebug`](crate::debug::TerminalDebug) for the app-wide
+    /// switch. Takes effect on the next [`Tui::draw`].
+    pub fn set_transparency_checkerboard(&mut self, enabled: bool) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .set_transparency_checkerboard(enabled);
+    }
+
+    /// Change the glyph scale at runtime - see
+    /// [`TerminalConfig::glyph_scale`]. Takes effect on the next
+    /// [`Tui::draw`], which repaints every row.
+    pub fn set_glyph_scale(&mut self, x: f32, y: f32) {
+        self.texture_state.terminal.backend_mut().set_glyph_scale(x, y);
+    }
+
+    /// Change the cell inset at runtime - see
+    /// [`TerminalConfig::cell_inset`]. Takes effect on the next
+    /// [`Tui::draw`], which repaints every row.
+    pub fn set_cell_inset(&mut self, px: u32) {
+        self.texture_state.terminal.backend_mut().set_cell_inset(px);
+    }
+
+    /// Turn the per-cell fade-in on or off (`Duration::ZERO`) at runtime -
+    /// see [`TerminalConfig::cell_fade`]. Content drawn from then on fades
+    /// in; fades already running end at once.
+    pub fn set_cell_fade(&mut self, duration: std::time::Duration) {
+        self.texture_state.terminal.backend_mut().set_cell_fade(duration);
+    }
+
+    /// Change how long each phase of blinking text lasts - see
+    /// [`TerminalConfig::blink_rates`]. Blinking text is shown
+    /// again right away and both phases restart.
+    pub fn set_blink_rates(&mut self, fast: std::time::Duration, slow: std::time::Duration) {
+        self.texture_state.terminal.backend_mut().set_blink_rates(fast, slow);
+    }
+
+    /// Change the powerline separator geometry at runtime - see
+    /// [`TerminalConfig::powerline_style`]. Takes effect on the next
+    /// [`Tui::draw`], which repaints every row.
+    pub fn set_powerline_style(&mut self, style: PowerlineStyle) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .set_powerline_style(style);
+    }
+
+    /// Change the programmatic glyph supersampling factor at runtime - see
+    /// [`TerminalConfig::programmatic_supersampling`]. Takes effect on the
+    /// next [`Tui::draw`], which repaints every row.
+    pub fn set_programmatic_supersampling(&mut self, factor: u32) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .set_programmatic_supersampling(factor);
+    }
+
+    /// Turn missing-glyph tracking on or off at runtime - see
+    /// [`TerminalConfig::track_missing_glyphs`].
+    pub fn set_missing_glyph_tracking(&mut self, enabled: bool) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .set_missing_glyph_tracking(enabled);
+    }
+
+    /// Characters this terminal could not draw so far (always empty unless
+    /// [`TerminalConfig::track_missing_glyphs`] is on) - e.g. to warn that
+    /// the font lacks N of them, or to pick a better one.
+    pub fn missing_glyphs(&self) -> &std::collections::HashSet<char> {
+        self.texture_state.terminal.backend().missing_glyphs()
+    }
+
+    /// Forget the characters reported by [`Tui::missing_glyphs`].
+    pub fn clear_missing_glyphs(&mut self) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .clear_missing_glyphs();
+    }
+
+    /// Change the block cursor's colors at runtime - see
+    /// [`TerminalConfig::cursor_style`]. Takes effect on the next
+    /// [`Tui::draw`].
+    pub fn set_cursor_style(&mut self, style: CursorStyle) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .set_cursor_style(style);
+    }
+
+    /// Change the tab stop spacing at runtime - see
+    /// [`TerminalConfig::tab_width`]. Takes effect on the next
+    /// [`Tui::draw`], which repaints every row.
+    pub fn set_tab_width(&mut self, tab_width: u16) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .set_tab_width(tab_width);
+    }
+
+    /// Turn control-character pictures on or off at runtime - see
+    /// [`TerminalConfig::control_pictures`]. Applies to cells drawn from
+    /// the next [`Tui::draw`] on; content already drawn keeps its glyph.
+    pub fn set_control_pictures(&mut self, enabled: bool) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .set_control_pictures(enabled);
+    }
+
+    /// Change the modifiers applied to every cell at runtime - see
+    /// [`TerminalConfig::default_modifier`]. Takes effect on the next
+    /// [`Tui::draw`], which repaints every row.
+    pub fn set_default_modifier(&mut self, modifier: ratatui::style::Modifier) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .set_default_modifier(modifier);
+    }
+
+    /// Change the underline and strikethrough colors at runtime - see
+    /// [`TerminalConfig::underline_color`] and
+    /// [`TerminalConfig::strikethrough_color`]. Takes effect on the next
+    /// [`Tui::draw`], which repaints every row.
+    pub fn set_decoration_colors(
+        &mut self,
+        underline_color: Option<[u8; 3]>,
+        strikethrough_color: Option<[u8; 3]>,
+    ) {
+        let backend = self.texture_state.terminal.backend_mut();
+        backend.set_underline_color(underline_color);
+        backend.set_strikethrough_color(strikethrough_color);
+    }
+
+    /// Set or clear (`None`) the palette every color is snapped to at
+    /// runtime - see [`TerminalConfig::color_quantize`]. Takes effect on
+    /// the next [`Tui::draw`], which repaints every row.
+    pub fn set_color_quantize(&mut self, palette: Option<crate::Palette>) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .set_color_quantize(palette);
+    }
+
+    /// Set or clear (`None`) the glyph drawn in blank cells at runtime -
+    /// see [`TerminalConfig::empty_cell`]. Takes effect on the next
+    /// [`Tui::draw`], which repaints every row.
+    pub fn set_empty_cell(&mut self, empty_cell: Option<(char, [u8; 3])>) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .set_empty_cell(empty_cell);
+    }
+
+    /// Switch between color and alpha-mask output at runtime - see
+    /// [`TerminalConfig::output_mode`]. Takes effect on the next
+    /// [`Tui::draw`], which repaints every row.
+    pub fn set_output_mode(&mut self, mode: OutputMode) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .set_output_mode(mode);
+    }
+
+    /// Change the corner radius at runtime - see
+    /// [`TerminalConfig::corner_radius`]. Takes effect on the next
+    /// [`Tui::draw`], which repaints the whole texture.
+    pub fn set_corner_radius(&mut self, px: u32) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .set_corner_radius(px);
+    }
+
+    /// Change the text brightness at runtime - see
+    /// [`TerminalConfig::text_brightness`]. Takes effect on the next
+    /// [`Tui::draw`], which repaints the whole texture.
+    pub fn set_text_brightness(&mut self, factor: f32) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .set_text_brightness(factor);
+    }
+
+    /// Turn ligatures on or off at runtime - see
+    /// [`TerminalConfig::ligatures`]. Takes effect on the next
+    /// [`Tui::draw`], which reshapes every row.
+    pub fn set_ligatures(&mut self, ligatures: bool) {
+        self.texture_state
+            .terminal
+            .backend_mut()
+            .set_ligatures(ligatures);
+    }
+
+    /// Change the area ratatui draws into at runtime - see
+    /// [`TerminalConfig::viewport`]. The texture keeps its size; the next
+    /// [`Tui::draw`] sees the new `frame.area()` and repaints every row,
+    /// with the uncovered margin in the `initial_fill` color.
+    pub fn set_viewport(&mut self, viewport: Viewport) {
+        if let Err(err) = self.texture_state.apply_viewport(viewport) {
+            tracing::warn!("Tui::set_viewport: resizing the terminal failed: {err}");
+        }
+        self.dirty = true;
+    }
+
+    /// Draw a border around the content, or remove it with `None`, at
+    /// runtime - see [`TerminalConfig::chrome`]. The next [`Tui::draw`]
+    /// sees the area inside the border as `frame.area()` and repaints
+    /// every row.
+    pub fn set_chrome(&mut self, chrome: Option<ChromeConfig>) {
+        if let Err(err) = self.texture_state.apply_chrome(chrome) {
+            tracing::warn!("Tui::set_chrome: rebuilding the terminal failed: {err}");
+        }
+        self.dirty = true;
+    }
+
+    /// Push a [`TerminalTheme`](crate::theme::TerminalTheme)'s colors into
+    /// this terminal's backend and rebuild its rows right away, so the next
+    /// flush repaints them even if the terminal is never drawn again (its
+    /// `initial_draw`, say, went out before the theme arrived). Called by
+    /// the plugin's theme system; a no-op when nothing actually changed.
+    pub(crate) fn apply_theme(&mut self, theme: &crate::theme::TerminalTheme) {
+        let backend = self.texture_state.terminal.backend_mut();
+        backend.set_reset_colors(theme.fg, theme.bg);
+        backend.set_palette(theme.palette);
+        if backend.reshape_marked_rows() {
+            self.mark_dirty_if_changed();
+        }
+    }
+
     /// Apply a pending resize, if any: recreate the destination `Image` at
     /// the new pixel size, update the backend's grid dimensions, and resize
     /// ratatui's own buffers immediately (rather than waiting for the next
@@ -402,7 +1377,6 @@ impl Tui {
         let (cols, rows) = self.pending_resize.take()?;
         self.texture_state.resize(cols, rows, images);
         let backend = self.texture_state.terminal.backend_mut();
-        backend.resize(cols, rows);
 
         // Drain whatever the backend currently holds (vertex geometry from
         // the last draw at the OLD grid size) - rendering that geometry
@@ -418,7 +1392,7 @@ impl Tui {
         payload.discard_stale_geometry();
         self.pending_draw = Some(payload);
 
-        // `backend.resize()` (above) already set `full_redraw_needed`, but
+        // `resize()` (above) already set `full_redraw_needed`, but
         // the `take_draw_payload()` call just above consumed and cleared
         // it while building the (now-discarded) payload. Re-arm it so the
         // FIRST REAL DRAW after this resize also comes out full rather
@@ -427,10 +1401,6 @@ impl Tui {
         // preserve yet.
         backend.force_full_redraw();
 
-        self.texture_state
-            .terminal
-            .resize(ratatui::layout::Rect::new(0, 0, cols, rows))
-            .ok();
         // Already extracted a (correctly-sized, geometry-cleared) payload
         // above - `flush`, called right after this by `gpu_flush_system`,
         // must not extract a second one on top of it.
@@ -449,7 +1419,14 @@ impl Tui {
     /// panic - but the first occurrence per `Tui` is logged via `warn!`
     /// (further occurrences are suppressed to avoid per-frame log spam).
     pub fn draw(&mut self, f: impl FnOnce(&mut ratatui::Frame)) {
-        match self.texture_state.terminal.draw(f) {
+        self.resync_frame();
+        #[cfg(feature = "debug_stats")]
+        let debug_stats = &self.debug_stats;
+        match self.texture_state.terminal.draw(|frame| {
+            f(frame);
+            #[cfg(feature = "debug_stats")]
+            debug_stats.render(frame);
+        }) {
             Ok(_) => self.mark_dirty_if_changed(),
             Err(err) => self.log_draw_error(err),
         }
@@ -460,16 +1437,91 @@ impl Tui {
     /// `render_widget` call that draws them. Regions are cleared at the
     /// start of each call - register fresh ones every draw.
     pub fn draw_with_hits(&mut self, f: impl FnOnce(&mut ratatui::Frame, &mut HitRegions)) {
+        self.resync_frame();
         self.hit_regions.clear();
         let hit_regions = &mut self.hit_regions;
-        match self
-            .texture_state
-            .terminal
-            .draw(|frame| f(frame, hit_regions))
-        {
+        #[cfg(feature = "debug_stats")]
+        let debug_stats = &self.debug_stats;
+        let mut area = ratatui::layout::Rect::default();
+        match self.texture_state.terminal.draw(|frame| {
+            area = frame.area();
+            f(frame, hit_regions);
+            #[cfg(feature = "debug_stats")]
+            debug_stats.render(frame);
+        }) {
             Ok(_) => self.mark_dirty_if_changed(),
             Err(err) => self.log_draw_error(err),
         }
+        if self.strict_bounds {
+            self.check_hit_region_bounds(area);
+        }
+    }
+
+    /// Catch layout bugs where a widget is laid out past the frame: in
+    /// [`Tui::draw_with_hits`], every registered hit region must lie inside
+    /// `frame.area()` - the grid size (less any `Viewport::Shrink` inset),
+    /// which is all ratatui ever draws. Content outside it is silently
+    /// clipped, so the region is a stand-in for the widget drawn there.
+    /// Violations panic in debug builds and `warn!` in release builds.
+    /// Default off - see [`TerminalConfig::strict_bounds`].
+    pub fn set_strict_bounds(&mut self, strict: bool) {
+        self.strict_bounds = strict;
+    }
+
+    fn check_hit_region_bounds(&self, area: ratatui::layout::Rect) {
+        for (id, rect) in &self.hit_regions.regions {
+            if rect.is_empty() || area.union(*rect) == area {
+                continue;
+            }
+            let message = format!(
+                "Tui::draw_with_hits: hit region {id} at {rect:?} extends past the \
+                 frame area {area:?}; anything drawn there is clipped"
+            );
+            if cfg!(debug_assertions) {
+                panic!("{message}");
+            }
+            tracing::warn!("{message}");
+        }
+    }
+
+    /// Replace the whole grid from `cells` (row-major, `cols * rows`
+    /// long, `(cols, rows)` the drawable size `frame.area()` would have)
+    /// without going through a ratatui `Frame` - see
+    /// [`BevyTerminalBackend::set_grid`]. Flushes like [`Self::draw`], so
+    /// the new content renders this frame.
+    ///
+    /// ```ignore
+    /// let cells: Vec<Cell> = map.tiles().map(tile_cell).collect();
+    /// tui.set_grid(&cells, MAP_COLS, MAP_ROWS)?;
+    /// ```
+    ///
+    /// ratatui's own record of the last frame no longer matches afterwards,
+    /// so the next [`Self::draw`] clears the terminal and sends its frame in
+    /// full - mixing the two works, it just costs that draw its diffing.
+    pub fn set_grid(&mut self, cells: &[ratatui::buffer::Cell], cols: u16, rows: u16) -> crate::Result<()> {
+        let backend = self.texture_state.terminal.backend_mut();
+        backend.set_grid(cells, cols, rows)?;
+        ratatui::backend::Backend::flush(backend)?;
+        self.mark_dirty_if_changed();
+        self.resend_frame = true;
+        Ok(())
+    }
+
+    /// Set or clear (`None`) a hook that edits every cell on its way into
+    /// the backend - see [`BevyTerminalBackend::set_cell_filter`]. The
+    /// next [`Self::draw`] sends its whole frame, so cells already drawn
+    /// pass through the new filter too.
+    pub fn set_cell_filter(&mut self, filter: Option<crate::CellFilter>) {
+        self.texture_state.terminal.backend_mut().set_cell_filter(filter);
+        self.resend_frame = true;
+    }
+
+    fn resync_frame(&mut self) {
+        if std::mem::take(&mut self.resend_frame) {
+            if let Err(err) = self.texture_state.terminal.clear() {
+                self.log_draw_error(err);
+            }
+        }
     }
 
     fn mark_dirty_if_changed(&mut self) {
@@ -485,44 +1537,298 @@ impl Tui {
         }
     }
 
+    /// `true` while cells are still fading in (see
+    /// [`TerminalBuilder::with_cell_fade`]). Fades advance with the app's
+    /// `Time` by themselves; nothing has to be drawn to keep them going.
+    pub fn is_fading(&self) -> bool {
+        self.texture_state.terminal.backend().is_fading()
+    }
+
+    /// Quad and draw-call counts of this terminal's last rendered frame -
+    /// see [`BevyTerminalBackend::render_stats`]. Updated when
+    /// [`gpu_flush_system`](crate::bevy_plugin::gpu_flush_system) hands a
+    /// drawn frame to the renderer, so right after a [`Tui::draw`] it still
+    /// describes the previous one.
+    pub fn render_stats(&self) -> crate::backend::RenderStats {
+        self.texture_state.terminal.backend().render_stats()
+    }
+
+    /// `(used, capacity)` bytes of this terminal's glyph atlas (shared with
+    /// every terminal on the same fonts) - see
+    /// [`BevyTerminalBackend::atlas_usage`].
+    pub fn atlas_usage(&self) -> (u64, u64) {
+        self.texture_state.terminal.backend().atlas_usage()
+    }
+
     /// The hit regions registered by the most recent [`Tui::draw_with_hits`]
     /// call.
     pub fn hit_regions(&self) -> &HitRegions {
         &self.hit_regions
     }
 
+    /// Grid, cell, texture and padding metrics - see
+    /// [`TerminalTexture::metrics`]. What [`TuiRequest::ui_child`] and
+    /// [`TuiRequest::world_quad_child`] need from a parent terminal.
+    pub fn metrics(&self) -> TerminalMetrics {
+        self.texture_state.metrics()
+    }
+
     /// Actual pixel size of the texture.
     pub fn size_px(&self) -> UVec2 {
         UVec2::new(self.texture_state.width, self.texture_state.height)
     }
 
+    /// Grid, cell and padding metrics for input mapping - see
+    /// [`TerminalTexture::dimensions`].
+    pub(crate) fn dimensions(&self) -> TerminalDimensions {
+        self.texture_state.dimensions()
+    }
+
     /// Terminal grid size (columns, rows).
     pub fn grid_size(&self) -> (u16, u16) {
         let d = self.texture_state.dimensions();
         (d.cols, d.rows)
     }
 
-    /// The `Handle<Image>` this terminal renders into.
+    /// Column the character covering `(col, row)` starts in - see
+    /// [`BevyTerminalBackend::glyph_origin_column`]. What mouse events
+    /// report when [`TerminalInputConfig::snap_wide_glyphs`](crate::input::TerminalInputConfig::snap_wide_glyphs)
+    /// is on; call it directly to snap selectively.
+    pub fn glyph_origin_column(&self, col: u16, row: u16) -> u16 {
+        self.texture_state.terminal.backend().glyph_origin_column(col, row)
+    }
+
+    /// The `Handle<Image>` this terminal renders into. The render world
+    /// draws straight into that image's GPU texture, which materials and
+    /// `ImageNode`s sample as-is; the main-world `Image` asset never holds
+    /// pixels (`data` is `None`, `RenderAssetUsages::RENDER_WORLD`), and no
+    /// frame is ever copied back. When the CPU does need them (screenshots,
+    /// tests), ask explicitly with [`Tui::read_back_blocking`].
     pub fn image_handle(&self) -> &Handle<Image> {
         &self.texture_state.image_handle
     }
 
+    /// Add a mirror destination image - see
+    /// [`TerminalTexture::add_mirror`]. The current content is re-sent in
+    /// full on the next frame, so the mirror never shows up blank.
+    pub fn add_mirror(&mut self, images: &mut Assets<Image>) -> Handle<Image> {
+        let handle = self.texture_state.add_mirror(images);
+        self.recover_lost_texture();
+        handle
+    }
+
+    /// Change how this terminal's images are sampled - see
+    /// [`TerminalTexture::set_sampler`]. The current content is re-sent in
+    /// full on the next frame.
+    pub fn set_sampler(&mut self, sampler: ImageSampler, images: &mut Assets<Image>) {
+        self.texture_state.set_sampler(sampler, images);
+        self.recover_lost_texture();
+    }
+
+    /// Switch this terminal's images between `Rgba8Unorm` and HDR
+    /// `Rgba16Float` - see [`TerminalTexture::set_hdr`]. Unlike
+    /// [`TerminalConfig::hdr`], this doesn't check the adapter. The current
+    /// content is re-sent in full on the next frame.
+    pub fn set_hdr(&mut self, hdr: bool, images: &mut Assets<Image>) {
+        let before = self.texture_state.format();
+        self.texture_state.set_hdr(hdr, images);
+        if self.texture_state.format() != before {
+            self.recover_lost_texture();
+        }
+    }
+
+    /// Stop rendering into `mirror` - see [`TerminalTexture::remove_mirror`].
+    pub fn remove_mirror(&mut self, mirror: &Handle<Image>) -> bool {
+        self.texture_state.remove_mirror(mirror)
+    }
+
+    /// Whether this terminal's images were recreated since the last call,
+    /// for `sync_terminal_materials` in `bevy_plugin.rs`.
+    pub(crate) fn take_images_recreated(&mut self) -> bool {
+        self.texture_state.take_images_recreated()
+    }
+
+    /// Every destination this terminal renders into: the primary image
+    /// first, then its mirrors.
+    pub(crate) fn destinations(&self) -> Vec<AssetId<Image>> {
+        std::iter::once(&self.texture_state.image_handle)
+            .chain(&self.texture_state.mirrors)
+            .map(Handle::id)
+            .collect()
+    }
+
     /// Read this terminal's current pixels back to the CPU, **blocking**
     /// until the render world performs the copy. An explicit opt-in for
     /// screenshots and tests only - the normal per-frame path never touches
     /// the CPU at all; do not call this every frame. Returns tightly-packed
-    /// RGBA8 bytes. Goes through the render world via a request/response
+    /// RGBA8 bytes (RGBA half floats for an HDR terminal - see
+    /// [`TerminalTexture::set_hdr`]). Goes through the render world via a request/response
     /// channel (`TuiReadbackChannel` in `bevy_plugin.rs`) - there is no
     /// main-world texture to read from directly in Phase B.
+    ///
+    /// Always returns an empty `Vec` on wasm32: a browser's main thread
+    /// can't block, and WebGL2 only completes a buffer map from the JS
+    /// event loop, after the calling frame has returned.
     pub fn read_back_blocking(&self, channel: &crate::bevy_plugin::TuiReadbackChannel) -> Vec<u8> {
         channel.request_blocking(self.texture_state.image_handle.id())
     }
 
-    /// Called by [`gpu_flush_system`](crate::bevy_plugin::gpu_flush_system).
-    /// If dirty, extracts the CPU-computed draw payload from the backend and
-    /// stashes it for the render-world extract system to pick up; the
-    /// actual GPU render happens there, not here.
+    /// Read back only the pixels of the cells in `area` - a single widget's
+    /// rendered output, say, or the changed part of the screen for an
+    /// incremental stream - **blocking** like [`Self::read_back_blocking`]
+    /// (same threading rules, empty on wasm32). The GPU copies just that
+    /// rectangle, so the transfer shrinks with the region. Returns
+    /// tightly-packed RGBA8 rows of the
+    /// [`TerminalMetrics::cell_rect_px`] rectangle, or an empty `Vec` if
+    /// `area` covers no visible cell.
+    pub fn read_back_region_blocking(
+        &self,
+        channel: &crate::bevy_plugin::TuiReadbackChannel,
+        area: ratatui::layout::Rect,
+    ) -> Vec<u8> {
+        let image_id = self.texture_state.image_handle.id();
+        match self.texture_state.metrics().cell_rect_px(area) {
+            Some(region) => channel.request_region_blocking(image_id, region),
+            None => Vec::new(),
+        }
+    }
+
+    /// Read this terminal's glyph atlas back to the CPU, **blocking** like
+    /// [`Self::read_back_blocking`] (same threading rules, empty on
+    /// wasm32): the whole `2048 x 2048` RGBA8 texture shared by every
+    /// terminal using the same `Arc<Fonts>`. Empty if the atlas never
+    /// appears, i.e. nothing with these fonts has rendered yet.
+    #[cfg(feature = "atlas_dump")]
+    pub fn read_back_atlas_blocking(&self, channel: &crate::bevy_plugin::TuiReadbackChannel) -> Vec<u8> {
+        let font_key = self.texture_state.terminal.backend().font_key();
+        channel.request_source_blocking(crate::bevy_plugin::ReadbackSource::Atlas(font_key))
+    }
+
+    /// Write this terminal's glyph atlas to a PNG at `path`, for debugging
+    /// glyph packing, eviction and padding. Reads back with
+    /// [`Self::read_back_atlas_blocking`] (same threading rules). The
+    /// image is flattened onto opaque black so glyph coverage shows up
+    /// directly in any viewer; free atlas space stays black.
+    #[cfg(feature = "atlas_dump")]
+    pub fn dump_atlas_png(
+        &self,
+        channel: &crate::bevy_plugin::TuiReadbackChannel,
+        path: impl AsRef<std::path::Path>,
+    ) -> crate::Result<()> {
+        let mut rgba = self.read_back_atlas_blocking(channel);
+        let size = crate::backend::CACHE_WIDTH;
+        if rgba.len() != (size * crate::backend::CACHE_HEIGHT * 4) as usize {
+            return Err(crate::TerminalError::AtlasDump(
+                "no atlas pixels were read back".to_string(),
+            ));
+        }
+        flatten_onto_black(&mut rgba);
+        let pixmap = tiny_skia::Pixmap::from_vec(
+            rgba,
+            tiny_skia::IntSize::from_wh(size, crate::backend::CACHE_HEIGHT).expect("atlas size is non-zero"),
+        )
+        .expect("buffer length matches the atlas size");
+        pixmap
+            .save_png(path)
+            .map_err(|err| crate::TerminalError::AtlasDump(err.to_string()))
+    }
+
+    /// The rendered RGBA at the center of cell `(col, row)` - for tests
+    /// asserting "this cell rendered green", or a color picker. Performs a
+    /// full [`Self::read_back_blocking`] (same threading requirements, and
+    /// `None` on wasm32), so sample many cells from one readback with
+    /// [`TerminalMetrics::sample_cell`] instead of calling this per cell.
+    /// `None` for a cell off the grid or off the texture.
+    pub fn sampled_cell_color(
+        &self,
+        channel: &crate::bevy_plugin::TuiReadbackChannel,
+        col: u16,
+        row: u16,
+    ) -> Option<[u8; 4]> {
+        let metrics = self.texture_state.metrics();
+        metrics.cell_center_px(col, row)?;
+        metrics.sample_cell(&self.read_back_blocking(channel), col, row)
+    }
+
+    /// Whether this terminal's next flush under `rate` (its
+    /// [`TerminalRenderRate`](crate::bevy_plugin::TerminalRenderRate)
+    /// component, if any) at app time `now` would render. Rate-capped
+    /// draw systems check this first and skip rebuilding their widgets on
+    /// frames whose result would only be overwritten before the next
+    /// render - the GPU-side cap alone still leaves that CPU cost, which
+    /// dominates in a browser. Always `true` for an uncapped terminal.
+    ///
+    /// ```ignore
+    /// fn render(time: Res<Time>, mut q: Query<(&mut Tui, Option<&TerminalRenderRate>)>) {
+    ///     for (mut tui, rate) in &mut q {
+    ///         if !tui.render_due(rate, time.elapsed()) {
+    ///             continue;
+    ///         }
+    ///         tui.draw(|frame| { /* expensive widgets */ });
+    ///     }
+    /// }
+    /// ```
+    pub fn render_due(
+        &self,
+        rate: Option<&crate::bevy_plugin::TerminalRenderRate>,
+        now: std::time::Duration,
+    ) -> bool {
+        rate.is_none_or(|rate| rate.0 <= 0.0) || self.next_flush_due.is_none_or(|due| now >= due)
+    }
+
+    /// [`Self::flush`], but at most `fps` times per second of app time
+    /// `now` (`fps <= 0.0`: every call). A dirty terminal that isn't due yet
+    /// simply stays dirty - everything drawn until then goes out with the
+    /// next due flush. Due times advance in fixed `1 / fps` steps, so the
+    /// average rate holds even when frames don't line up with the interval;
+    /// after an idle stretch the schedule restarts from `now` instead of
+    /// flushing a burst to catch up.
+    pub(crate) fn flush_at_most(&mut self, fps: f32, now: std::time::Duration) {
+        self.resume_deferred_glyphs();
+        self.advance_animations(now);
+        if fps > 0.0 && self.dirty {
+            // A rate so low that `1 / fps` doesn't fit a `Duration` flushes
+            // once and is never due again.
+            let interval = std::time::Duration::try_from_secs_f32(1.0 / fps)
+                .unwrap_or(std::time::Duration::MAX);
+            if let Some(due) = self.next_flush_due {
+                if now < due {
+                    return;
+                }
+                let next = if now - due < interval {
+                    due.saturating_add(interval)
+                } else {
+                    now.saturating_add(interval)
+                };
+                self.next_flush_due = Some(next);
+            } else {
+                self.next_flush_due = Some(now.saturating_add(interval));
+            }
+        }
+        self.stash_pending_draw();
+    }
+
+    /// Called by [`gpu_flush_system`](crate::bevy_plugin::gpu_flush_system)
+    /// in apps without a [`Time`](bevy::time::Time) resource. If dirty,
+    /// extracts the CPU-computed draw payload from the backend and stashes
+    /// it for the render-world extract system to pick up; the actual GPU
+    /// render happens there, not here. With no app time to advance them
+    /// by, blinking text holds its phase and fades end at once.
     pub(crate) fn flush(&mut self) {
+        self.resume_deferred_glyphs();
+        let backend = self.texture_state.terminal.backend_mut();
+        if backend.is_fading() {
+            backend.finish_fades();
+            if backend.reshape_marked_rows() {
+                self.dirty = true;
+            }
+        }
+        self.stash_pending_draw();
+    }
+
+    /// The part of a flush after deferred glyphs, blinking and fades have
+    /// had their say: take the backend's payload if anything is dirty.
+    fn stash_pending_draw(&mut self) {
         if self.dirty {
             let backend = self.texture_state.terminal.backend_mut();
             if self.pending_draw.is_some() {
@@ -542,9 +1848,87 @@ impl Tui {
             let draw = backend.take_draw_payload();
             self.pending_draw = Some(draw);
             self.dirty = false;
+            #[cfg(feature = "debug_stats")]
+            self.debug_stats.record_update();
         }
     }
 
+    /// Glyphs a budgeted flush put off (see
+    /// [`TerminalBuilder::with_glyph_budget`]) come in over the following
+    /// frames even when nothing is drawn. Only on frames without a fresh
+    /// draw: that draw's flush already spent this frame's budget.
+    fn resume_deferred_glyphs(&mut self) {
+        if !self.dirty && self.texture_state.terminal.backend_mut().rasterize_deferred_glyphs() {
+            self.dirty = true;
+        }
+    }
+
+    /// Blinking text (see [`TerminalBuilder::with_blink_rates`]) flips
+    /// phase, and fading cells (see [`TerminalBuilder::with_cell_fade`])
+    /// ramp up, on their own schedule of app time `now`, whether or not
+    /// anything is drawn - so both pause and scale with the app's virtual
+    /// clock.
+    fn advance_animations(&mut self, now: std::time::Duration) {
+        let backend = self.texture_state.terminal.backend_mut();
+        let blinked = backend.tick_blink(now);
+        let faded = backend.tick_fades(now);
+        if (blinked || faded) && backend.reshape_marked_rows() {
+            self.dirty = true;
+        }
+    }
+
+    /// Advance this terminal's stats overlay by one frame - see
+    /// [`crate::debug_stats`].
+    #[cfg(feature = "debug_stats")]
+    pub(crate) fn sample_debug_stats(&mut self, delta_secs: f32) {
+        let backend = self.texture_state.terminal.backend();
+        let glyphs = backend.glyphs_rasterized();
+        let atlas = backend.atlas_occupancy();
+        self.debug_stats.sample(delta_secs, glyphs, atlas);
+    }
+
+    #[cfg(feature = "debug_stats")]
+    pub(crate) fn debug_stats_active(&self) -> bool {
+        self.debug_stats.is_active()
+    }
+
+    #[cfg(feature = "debug_stats")]
+    pub(crate) fn clear_debug_stats(&mut self) {
+        self.debug_stats.clear();
+    }
+
+    /// Called by the render-world extract system (`extract_tui_draws` in
+    /// `bevy_plugin.rs`) when this terminal's destination texture was
+    /// recreated underneath it - device loss/reset, suspend/resume, or
+    /// anything else that re-prepared the `GpuImage` - so its content is
+    /// undefined. Immediately stashes a FULL payload rebuilt from the
+    /// backend's cached row geometry (no reshaping: the cells didn't
+    /// change, only the pixels were lost), superseding any pending partial
+    /// one. Without this a static terminal would never redraw and the
+    /// texture would stay garbage forever. Also how a shader hot-reload
+    /// (feature `shader_hot_reload`) gets every terminal re-rendered with
+    /// the new pipelines.
+    pub(crate) fn recover_lost_texture(&mut self) {
+        let backend = self.texture_state.terminal.backend_mut();
+        backend.force_full_redraw();
+        self.pending_draw = Some(backend.take_draw_payload());
+        self.dirty = false;
+    }
+
+    /// Pixmap overlays queued since the last extract - see
+    /// [`TerminalTexture::overlay_pixmap`]. Drained every frame by
+    /// `extract_tui_draws` in `bevy_plugin.rs`, whether or not a draw
+    /// payload goes with them.
+    pub(crate) fn take_overlays(&mut self) -> Vec<crate::backend::PixmapOverlay> {
+        std::mem::take(&mut self.texture_state.overlays)
+    }
+
+    /// Symbol of the cell at column `x`, row `y` as last drawn. Test-only.
+    #[cfg(test)]
+    pub(crate) fn cell_symbol(&self, x: u16, y: u16) -> &str {
+        self.texture_state.terminal.backend().cell_symbol(x, y)
+    }
+
     /// Drain the pending draw payload, if set, returning it alongside the
     /// destination image's asset id. Called once per frame by the
     /// render-world extract system (`extract_tui_draws` in
@@ -600,6 +1984,14 @@ pub struct TerminalConfig {
     pub keyboard: bool,
     /// Whether this terminal can receive mouse input.
     pub mouse: bool,
+    /// Whether Tab focus cycling stops at this terminal - see
+    /// [`TerminalInput::focusable`](crate::input::TerminalInput::focusable).
+    /// Default `true`.
+    pub focusable: bool,
+    /// Whether a mesh terminal takes clicks on its back face too - see
+    /// [`TerminalInput::hit_backfaces`](crate::input::TerminalInput::hit_backfaces).
+    /// Default `false`.
+    pub hit_backfaces: bool,
     /// Drawn once at creation time (before the entity's own draw system
     /// runs), so the very first presented frame already has real content
     /// instead of the create-time fill color. (`Sync` bound because this
@@ -618,6 +2010,123 @@ pub struct TerminalConfig {
     /// Rgb`/`Indexed`/etc., including any of ratatui's named colors) are
     /// unaffected - only `Reset` becomes transparent. Default `false`.
     pub transparent_reset_bg: bool,
+    /// Draw 1px grid lines in this color between every pair of adjacent
+    /// cells (spreadsheet/table style). Default `None` - see
+    /// [`TerminalBuilder::with_cell_grid`].
+    pub cell_grid: Option<[u8; 3]>,
+    /// `(top, bottom)` colors blended row by row behind every
+    /// reset-background cell. Default `None` (flat `reset_bg`) - see
+    /// [`TerminalBuilder::with_background_gradient`].
+    pub background_gradient: Option<([u8; 3], [u8; 3])>,
+    /// `(x, y)` scale of every glyph within its cell, e.g. `(0.8, 1.0)`
+    /// for condensed text. Default `(1.0, 1.0)` - see
+    /// [`TerminalBuilder::with_glyph_scale`].
+    pub glyph_scale: (f32, f32),
+    /// Pixels of room left between every glyph and its cell's edges, for
+    /// less cramped dense text. Default `0` - see
+    /// [`TerminalBuilder::with_cell_inset`].
+    pub cell_inset: u32,
+    /// MSAA sample count for this terminal's render passes. Default `1`
+    /// (off) - see [`TerminalBuilder::with_msaa`].
+    pub msaa_samples: u32,
+    /// How long newly drawn cells take to fade their text in. Default
+    /// `Duration::ZERO` (no fade) - see [`TerminalBuilder::with_cell_fade`].
+    pub cell_fade: std::time::Duration,
+    /// How long each on and off phase of rapid / slow blinking text lasts.
+    /// Default 250 ms and 500 ms - see
+    /// [`TerminalBuilder::with_blink_rates`].
+    pub blink_rates: (std::time::Duration, std::time::Duration),
+    /// Shape, reach and stroke weight of the programmatic powerline
+    /// separators. Default [`PowerlineStyle::default`] - see
+    /// [`TerminalBuilder::with_powerline_style`].
+    pub powerline_style: PowerlineStyle,
+    /// Supersampling factor (`1..=4`) for programmatic glyphs, smoothing
+    /// rounded corners and diagonals. Default `1` (off) - see
+    /// [`TerminalBuilder::with_programmatic_supersampling`].
+    pub programmatic_supersampling: u32,
+    /// Record the characters no font could draw, readable through
+    /// [`Tui::missing_glyphs`]. Default `false` - see
+    /// [`TerminalBuilder::with_missing_glyph_tracking`].
+    pub track_missing_glyphs: bool,
+    /// Area ratatui draws into. Default [`Viewport::Full`];
+    /// `Viewport::Shrink` leaves an undrawn margin on the right and bottom
+    /// edges - see [`TerminalTexture::with_viewport`].
+    pub viewport: Viewport,
+    /// A border (with an optional title) drawn around the content by the
+    /// crate, instead of a `Block::bordered()` in every draw: `frame.area()`
+    /// is the area inside it. Mouse events keep grid coordinates, the same
+    /// ones `frame.area()` is in - see [`ChromeConfig`]. Default `None`.
+    pub chrome: Option<ChromeConfig>,
+    /// Pixel margin between the grid and the texture's edges; negative
+    /// sides bleed the grid off that edge. Default none - see
+    /// [`TerminalTexture::with_padding`].
+    pub padding: Padding,
+    /// Panic (debug builds) or warn (release builds) when a hit region
+    /// registered in [`Tui::draw_with_hits`] reaches past the frame area.
+    /// Default `false` - see [`Tui::set_strict_bounds`].
+    pub strict_bounds: bool,
+    /// Colors of the block cursor shown at `Frame::set_cursor_position`.
+    /// Default [`CursorStyle::default`] (invert the cell) - see
+    /// [`TerminalBuilder::with_cursor_style`].
+    pub cursor_style: CursorStyle,
+    /// Columns between tab stops for cells holding a literal tab. Default
+    /// `8` - see [`TerminalBuilder::with_tab_width`].
+    pub tab_width: u16,
+    /// Show lone control characters as Control Pictures (`␃` for U+0003)
+    /// rather than U+FFFD - for displaying raw PTY streams. Default
+    /// `false` - see [`TerminalBuilder::with_control_pictures`].
+    pub control_pictures: bool,
+    /// Modifiers OR'd into every cell's own (e.g. `Modifier::BOLD` for an
+    /// all-bold theme). Default empty - see
+    /// [`TerminalBuilder::with_default_modifier`].
+    pub default_modifier: ratatui::style::Modifier,
+    /// Color of underlines on cells without their own underline color.
+    /// Default `None` (the text color) - see
+    /// [`TerminalBuilder::with_underline_color`].
+    pub underline_color: Option<[u8; 3]>,
+    /// Color of strikethrough lines. Default `None` (the text color) - see
+    /// [`TerminalBuilder::with_strikethrough_color`].
+    pub strikethrough_color: Option<[u8; 3]>,
+    /// Palette every rendered color is snapped to, for a retro look (e.g.
+    /// [`Palette::green_phosphor`](crate::Palette::green_phosphor)).
+    /// Default `None` (full color) - see
+    /// [`TerminalBuilder::with_color_quantize`].
+    pub color_quantize: Option<crate::Palette>,
+    /// Whether the font's ligatures (`=>`, `!=`, ... in programming fonts)
+    /// are applied. Default `true` - see
+    /// [`TerminalBuilder::with_ligatures`].
+    pub ligatures: bool,
+    /// Glyph and color drawn in blank cells, e.g. `Some(('·', [60, 60,
+    /// 70]))`. Default `None` (blank) - see
+    /// [`TerminalBuilder::with_empty_cell`].
+    pub empty_cell: Option<(char, [u8; 3])>,
+    /// Most new glyphs rasterized per frame, spreading the first display
+    /// of glyph-heavy content over several frames. Default `None` (no
+    /// limit) - see [`TerminalBuilder::with_glyph_budget`].
+    pub glyph_budget: Option<u32>,
+    /// Whether the texture holds the terminal's colors or only its text
+    /// coverage, for use as a mask. Default [`OutputMode::Color`] - see
+    /// [`TerminalBuilder::with_output_mode`].
+    pub output_mode: OutputMode,
+    /// Radius, in pixels, of the texture's rounded corners; outside them
+    /// the texture is transparent. Default `0` (square) - see
+    /// [`TerminalBuilder::with_corner_radius`].
+    pub corner_radius: u32,
+    /// Render into an `Rgba16Float` texture instead of `Rgba8Unorm`, so
+    /// colors past `1.0` survive for bloom. Needs an HDR camera to show -
+    /// see [`TerminalTexture::set_hdr`]. Falls back to `Rgba8Unorm`, with a
+    /// warning, on an adapter that can't render to `Rgba16Float`. Default
+    /// `false`.
+    pub hdr: bool,
+    /// Factor text colors are scaled by, e.g. `4.0` with `hdr: true` for
+    /// text that blooms. Default `1.0` - see
+    /// [`TerminalBuilder::with_text_brightness`].
+    pub text_brightness: f32,
+    /// Sampler materials use on this terminal's image - e.g. an
+    /// anisotropic one to calm the shimmer of a steeply angled 3D
+    /// terminal. Default [`ImageSampler::Default`] - see
+    /// [`TerminalTexture::set_sampler`].
+    pub texture_sampler: ImageSampler,
     /// Alpha/transparency mode for the material [`TuiKind::WorldQuad`]
     /// builds. Default `AlphaMode::Opaque`; combine with
     /// `transparent_reset_bg: true` for a HUD-style see-through screen.
@@ -626,6 +2135,11 @@ pub struct TerminalConfig {
     /// respects its texture's alpha).
     #[cfg(feature = "3d")]
     pub alpha_mode: AlphaMode,
+    /// Makes a [`TuiKind::WorldQuad`] terminal glow: inserts a
+    /// [`TerminalGlow`] with this intensity on the entity. Default `None`
+    /// (the plain unlit material). Ignored by `TuiKind::Ui`/`Headless`.
+    #[cfg(feature = "3d")]
+    pub glow: Option<f32>,
 }
 
 impl Default for TerminalConfig {
@@ -634,11 +2148,47 @@ impl Default for TerminalConfig {
             programmatic_glyphs: true,
             keyboard: true,
             mouse: true,
+            focusable: true,
+            hit_backfaces: false,
             initial_draw: None,
             initial_fill: [0, 0, 0, 255],
             transparent_reset_bg: false,
+            cell_grid: None,
+            background_gradient: None,
+            glyph_scale: (1.0, 1.0),
+            cell_inset: 0,
+            msaa_samples: 1,
+            cell_fade: std::time::Duration::ZERO,
+            blink_rates: (
+                std::time::Duration::from_millis(250),
+                std::time::Duration::from_millis(500),
+            ),
+            powerline_style: PowerlineStyle::default(),
+            programmatic_supersampling: 1,
+            track_missing_glyphs: false,
+            viewport: Viewport::Full,
+            chrome: None,
+            padding: Padding::default(),
+            strict_bounds: false,
+            cursor_style: CursorStyle::default(),
+            tab_width: 8,
+            control_pictures: false,
+            default_modifier: ratatui::style::Modifier::empty(),
+            underline_color: None,
+            strikethrough_color: None,
+            color_quantize: None,
+            ligatures: true,
+            empty_cell: None,
+            glyph_budget: None,
+            output_mode: OutputMode::Color,
+            corner_radius: 0,
+            hdr: false,
+            text_brightness: 1.0,
+            texture_sampler: ImageSampler::Default,
             #[cfg(feature = "3d")]
             alpha_mode: AlphaMode::Opaque,
+            #[cfg(feature = "3d")]
+            glow: None,
         }
     }
 }
@@ -695,6 +2245,43 @@ pub enum TuiKind {
     Headless,
 }
 
+/// Emissive glow for a 3D terminal: put it on an entity whose
+/// `MeshMaterial3d<StandardMaterial>` shows the terminal (a
+/// [`TuiKind::WorldQuad`] - see [`TerminalConfig::glow`] - or an
+/// [`AttachMaterial::standard`] mesh) and the plugin turns that material
+/// into an emissive one: `emissive_texture` is the terminal's image,
+/// `emissive` is white scaled by the intensity. With a camera that has
+/// `Bloom` (and HDR), intensities above `1.0` bleed light around bright
+/// text for a CRT look.
+///
+/// The material stops being `unlit` - emissive light is only added on the
+/// lit path - but its base color is black and non-reflective, so scene
+/// lighting still doesn't show on the screen: `1.0` looks like the plain
+/// unlit terminal, `0.0` is a switched-off screen. Change the value every
+/// frame to animate (flicker, power-on fades); the material is only
+/// rewritten when the component changes. The texture is the same GPU image
+/// the terminal renders into, so the glow follows every draw without any
+/// syncing of its own. Removing the component leaves the material as it
+/// was last set.
+#[cfg(feature = "3d")]
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct TerminalGlow(pub f32);
+
+#[cfg(feature = "3d")]
+impl TerminalGlow {
+    /// Rewrite `material` to glow with `self`'s intensity, taking the
+    /// emissive texture from its `base_color_texture` (the terminal image).
+    pub(crate) fn apply(&self, material: &mut StandardMaterial) {
+        let alpha = material.base_color.alpha();
+        material.base_color = Color::BLACK.with_alpha(alpha);
+        material.unlit = false;
+        material.reflectance = 0.0;
+        material.perceptual_roughness = 1.0;
+        material.emissive_texture = material.base_color_texture.clone();
+        material.emissive = LinearRgba::rgb(self.0, self.0, self.0);
+    }
+}
+
 /// Records a [`TuiKind::WorldQuad`] terminal's configured world-unit height,
 /// inserted at materialization. Read back by the plugin's resize handling
 /// (`gpu_flush_system`) to recompute the mesh's aspect ratio when the grid
@@ -703,6 +2290,80 @@ pub enum TuiKind {
 #[derive(Component, Clone, Copy)]
 pub(crate) struct WorldQuadHeight(pub(crate) f32);
 
+/// One wall of a "terminal room" - a cube lined with [`TuiKind::WorldQuad`]
+/// terminals on its inside, viewed from a camera within it.
+///
+/// Terminals always render into a plain 2D image; there is no way to render
+/// one straight into a face of a cubemap texture. A room is built from six
+/// ordinary world quads instead, which keeps everything else working as
+/// usual - mouse hit-testing, focus, resizing, per-terminal materials. Spawn
+/// a `TuiRequest::world_quad` per face with [`Self::transform`]:
+///
+/// ```ignore
+/// for face in RoomFace::ALL {
+///     commands.spawn((
+///         TuiRequest::world_quad(40, 20, fonts.clone(), 4.0),
+///         face.transform(2.0),
+///     ));
+/// }
+/// ```
+///
+/// Orientation: every face points its front side at the room's center, and
+/// text reads upright for a viewer standing at the center looking at it.
+/// The four walls share `+Y` as "up"; the floor's text top points toward
+/// `-Z` (the front wall) and the ceiling's toward `+Z`, so both read upright
+/// when looked at from the center while facing the front wall. The quads are
+/// `height` tall at the terminal's own aspect ratio - a terminal whose
+/// texture isn't square leaves gaps at the room's edges (or overlaps its
+/// neighbours), so pick the grid, or `height`, to match `2 * half_extent`.
+#[cfg(feature = "3d")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoomFace {
+    /// The wall at `-Z`, facing `+Z`.
+    Front,
+    /// The wall at `+Z`, facing `-Z`.
+    Back,
+    /// The wall at `-X`, facing `+X`.
+    Left,
+    /// The wall at `+X`, facing `-X`.
+    Right,
+    /// The floor at `-Y`, facing `+Y`.
+    Floor,
+    /// The ceiling at `+Y`, facing `-Y`.
+    Ceiling,
+}
+
+#[cfg(feature = "3d")]
+impl RoomFace {
+    /// Every face, walls first.
+    pub const ALL: [RoomFace; 6] = [
+        RoomFace::Front,
+        RoomFace::Back,
+        RoomFace::Left,
+        RoomFace::Right,
+        RoomFace::Floor,
+        RoomFace::Ceiling,
+    ];
+
+    /// Where a world quad sits for this face of a room centered on the
+    /// origin whose walls are `half_extent` from the center. Parent the
+    /// terminals to an entity to move or rotate the room as a whole.
+    pub fn transform(self, half_extent: f32) -> Transform {
+        use std::f32::consts::{FRAC_PI_2, PI};
+
+        // `world_quad`'s mesh faces +Z; turn that toward the center.
+        let (translation, rotation) = match self {
+            RoomFace::Front => (Vec3::NEG_Z, Quat::IDENTITY),
+            RoomFace::Back => (Vec3::Z, Quat::from_rotation_y(PI)),
+            RoomFace::Left => (Vec3::NEG_X, Quat::from_rotation_y(FRAC_PI_2)),
+            RoomFace::Right => (Vec3::X, Quat::from_rotation_y(-FRAC_PI_2)),
+            RoomFace::Floor => (Vec3::NEG_Y, Quat::from_rotation_x(-FRAC_PI_2)),
+            RoomFace::Ceiling => (Vec3::Y, Quat::from_rotation_x(FRAC_PI_2)),
+        };
+        Transform::from_translation(translation * half_extent).with_rotation(rotation)
+    }
+}
+
 /// Declarative terminal request: spawn this component (plus any `Node` /
 /// `Transform` / marker components you want on the terminal entity), and
 /// the plugin's `materialize_tui_requests` system does the rest - **your
@@ -775,6 +2436,66 @@ impl TuiRequest {
         }
     }
 
+    /// A UI terminal nested in `parent`'s terminal node, covering the cells
+    /// in `area` of it: the request (one terminal cell per parent cell, so
+    /// use the parent's font size), its [`TerminalMetrics::child_node`]
+    /// layout and the `ChildOf`. Spawn the tuple, plus a marker:
+    ///
+    /// ```ignore
+    /// let metrics = panel_tui.metrics();
+    /// commands.spawn((
+    ///     TuiRequest::ui_child(panel, &metrics, Rect::new(1, 1, 20, 8), fonts.clone()),
+    ///     LogPane,
+    /// ));
+    /// ```
+    ///
+    /// Mouse input goes to the topmost terminal under the cursor in
+    /// bevy_ui's paint order: a child over its parent; among overlapping
+    /// siblings, the higher `ZIndex`, then the later child of the parent.
+    /// Clicks on the parent's cells outside every child still reach the
+    /// parent. Each terminal is focused and drawn on its own. To change
+    /// the request's config, destructure the tuple first.
+    #[cfg(feature = "2d")]
+    pub fn ui_child(
+        parent: Entity,
+        parent_metrics: &TerminalMetrics,
+        area: ratatui::layout::Rect,
+        fonts: impl Into<TuiFontSource>,
+    ) -> (Self, Node, ChildOf) {
+        let area = parent_metrics.clip_to_grid(area);
+        (
+            Self::ui(area.width, area.height, fonts),
+            parent_metrics.child_node(area),
+            ChildOf(parent),
+        )
+    }
+
+    /// A world quad nested under `parent`'s [`TuiKind::WorldQuad`]
+    /// (`parent_height` world units tall), covering the cells in `area` of
+    /// it: the request, its [`TerminalMetrics::child_quad`] transform and
+    /// the `ChildOf`. It moves, rotates and scales with the parent, and
+    /// mouse rays pick it over the parent since it sits just in front.
+    /// Overlapping siblings sit at the same depth - move the one that
+    /// should win further along its `Transform`'s local `+Z` (by another
+    /// `CHILD_QUAD_LIFT * parent_height`, say), or they z-fight and tie
+    /// for input.
+    #[cfg(feature = "3d")]
+    pub fn world_quad_child(
+        parent: Entity,
+        parent_metrics: &TerminalMetrics,
+        area: ratatui::layout::Rect,
+        fonts: impl Into<TuiFontSource>,
+        parent_height: f32,
+    ) -> (Self, Transform, ChildOf) {
+        let area = parent_metrics.clip_to_grid(area);
+        let (transform, height) = parent_metrics.child_quad(area, parent_height);
+        (
+            Self::world_quad(area.width, area.height, fonts, height),
+            transform,
+            ChildOf(parent),
+        )
+    }
+
     /// Replace the default [`TerminalConfig`].
     pub fn with_config(mut self, config: TerminalConfig) -> Self {
         self.config = config;
@@ -792,6 +2513,25 @@ impl TuiRequest {
 #[require(Node)]
 pub struct TuiUi;
 
+/// `Fonts` built from font assets by [`materialize_tui_requests`], keyed
+/// by asset and size. Weak, so a font no terminal uses any more is freed
+/// along with its glyph atlas.
+#[derive(Default)]
+pub(crate) struct AssetFontsCache(
+    std::collections::HashMap<(AssetId<crate::fonts::TerminalFontAsset>, u32), std::sync::Weak<Fonts>>,
+);
+
+impl AssetFontsCache {
+    fn get(&self, id: AssetId<crate::fonts::TerminalFontAsset>, size_px: u32) -> Option<Arc<Fonts>> {
+        self.0.get(&(id, size_px)).and_then(std::sync::Weak::upgrade)
+    }
+
+    fn insert(&mut self, id: AssetId<crate::fonts::TerminalFontAsset>, size_px: u32, fonts: &Arc<Fonts>) {
+        self.0.retain(|_, fonts| fonts.strong_count() > 0);
+        self.0.insert((id, size_px), Arc::downgrade(fonts));
+    }
+}
+
 /// Plugin system backing [`TuiRequest`]. Registered automatically by
 /// `TerminalPlugin`, scheduled before `TerminalSystemSet::Input` so a
 /// terminal materialized this frame is visible to the same frame's input
@@ -805,12 +2545,24 @@ pub struct TuiUi;
 /// surface components are inserted via `insert_if_new`, so a `Node`,
 /// `Transform`, `TerminalInput`, or (for `WorldQuad`) `Mesh3d`/
 /// `MeshMaterial3d<StandardMaterial>` already on the entity is kept.
+///
+/// Asset-font requests with the same handle and `size_px` get the same
+/// `Arc<Fonts>` while any terminal still holds it, so they share one glyph
+/// atlas exactly like terminals spawned from a cloned
+/// [`TuiFontSource::Ready`]. A modified or removed font asset is dropped
+/// from that cache - later requests build fresh `Fonts` from it, while
+/// terminals already using the old ones keep them.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn materialize_tui_requests(
     mut commands: Commands,
     mut requests: Query<(Entity, &mut TuiRequest), Without<Tui>>,
     asset_server: Res<AssetServer>,
     font_assets: Res<Assets<crate::fonts::TerminalFontAsset>>,
+    mut font_asset_events: MessageReader<AssetEvent<crate::fonts::TerminalFontAsset>>,
+    mut asset_fonts: Local<AssetFontsCache>,
     mut images: ResMut<Assets<Image>>,
+    // Only for validating `TerminalConfig::hdr`; absent without a renderer.
+    render_adapter: Option<Res<bevy::render::renderer::RenderAdapter>>,
     // `Option`: these assets only exist once something registers them
     // (bevy's PbrPlugin, normally) - a headless or UI-only app shouldn't
     // fail this system's parameter validation over resources that only
@@ -818,29 +2570,41 @@ pub(crate) fn materialize_tui_requests(
     #[cfg(feature = "3d")] mut meshes: Option<ResMut<Assets<Mesh>>>,
     #[cfg(feature = "3d")] mut materials: Option<ResMut<Assets<StandardMaterial>>>,
 ) {
+    for event in font_asset_events.read() {
+        if let AssetEvent::Modified { id } | AssetEvent::Removed { id } = event {
+            asset_fonts.0.retain(|(cached, _), _| cached != id);
+        }
+    }
+
     for (entity, mut request) in &mut requests {
         let fonts = match &request.fonts {
             TuiFontSource::Ready(fonts) => fonts.clone(),
-            TuiFontSource::Asset { handle, size_px } => match font_assets.get(handle) {
-                Some(asset) => match Fonts::from_asset(asset, *size_px) {
-                    Ok(fonts) => fonts,
-                    Err(err) => {
-                        tracing::warn!("TuiRequest dropped: font asset failed to parse: {err}");
-                        commands.entity(entity).remove::<TuiRequest>();
-                        continue;
+            TuiFontSource::Asset { handle, size_px } => match asset_fonts.get(handle.id(), *size_px) {
+                Some(fonts) => fonts,
+                None => match font_assets.get(handle) {
+                    Some(asset) => match Fonts::from_asset(asset, *size_px) {
+                        Ok(fonts) => {
+                            asset_fonts.insert(handle.id(), *size_px, &fonts);
+                            fonts
+                        }
+                        Err(err) => {
+                            tracing::warn!("TuiRequest dropped: font asset failed to parse: {err}");
+                            commands.entity(entity).remove::<TuiRequest>();
+                            continue;
+                        }
+                    },
+                    None => {
+                        if asset_server.load_state(handle).is_failed() {
+                            tracing::warn!("TuiRequest dropped: font asset failed to load");
+                            commands.entity(entity).remove::<TuiRequest>();
+                        }
+                        continue; // still loading - retry next frame
                     }
                 },
-                None => {
-                    if asset_server.load_state(handle).is_failed() {
-                        tracing::warn!("TuiRequest dropped: font asset failed to load");
-                        commands.entity(entity).remove::<TuiRequest>();
-                    }
-                    continue; // still loading - retry next frame
-                }
             },
         };
 
-        let texture_state = match TerminalTexture::create(
+        let mut texture_state = match TerminalTexture::create(
             request.cols,
             request.rows,
             fonts,
@@ -857,11 +2621,65 @@ pub(crate) fn materialize_tui_requests(
             }
         };
 
+        let backend = texture_state.terminal.backend_mut();
+        backend.set_cell_grid(request.config.cell_grid);
+        backend.set_background_gradient(request.config.background_gradient);
+        backend.set_glyph_scale(request.config.glyph_scale.0, request.config.glyph_scale.1);
+        backend.set_cell_inset(request.config.cell_inset);
+        backend.set_msaa(request.config.msaa_samples);
+        backend.set_cell_fade(request.config.cell_fade);
+        backend.set_blink_rates(request.config.blink_rates.0, request.config.blink_rates.1);
+        backend.set_powerline_style(request.config.powerline_style);
+        backend.set_programmatic_supersampling(request.config.programmatic_supersampling);
+        backend.set_missing_glyph_tracking(request.config.track_missing_glyphs);
+        backend.set_cursor_style(request.config.cursor_style);
+        backend.set_tab_width(request.config.tab_width);
+        backend.set_control_pictures(request.config.control_pictures);
+        backend.set_default_modifier(request.config.default_modifier);
+        backend.set_underline_color(request.config.underline_color);
+        backend.set_strikethrough_color(request.config.strikethrough_color);
+        backend.set_color_quantize(request.config.color_quantize.clone());
+        backend.set_ligatures(request.config.ligatures);
+        backend.set_empty_cell(request.config.empty_cell);
+        backend.set_glyph_budget(request.config.glyph_budget);
+        backend.set_output_mode(request.config.output_mode);
+        backend.set_corner_radius(request.config.corner_radius);
+        backend.set_text_brightness(request.config.text_brightness);
+        if let Err(err) = texture_state.apply_viewport(request.config.viewport) {
+            tracing::warn!("TuiRequest: applying the viewport failed: {err}");
+        }
+        if let Err(err) = texture_state.apply_chrome(request.config.chrome.take()) {
+            tracing::warn!("TuiRequest: applying the chrome failed: {err}");
+        }
+        if request.config.padding != Padding::default() {
+            texture_state = texture_state.with_padding(request.config.padding, &mut images);
+        }
+        if !matches!(request.config.texture_sampler, ImageSampler::Default) {
+            texture_state =
+                texture_state.with_sampler(request.config.texture_sampler.clone(), &mut images);
+        }
+        if request.config.hdr {
+            // No adapter (a headless app without a renderer) means nothing
+            // renders anyway - keep the requested format.
+            let supported = render_adapter
+                .as_ref()
+                .is_none_or(|adapter| crate::backend::hdr_format_supported(adapter));
+            if supported {
+                texture_state = texture_state.with_hdr(true, &mut images);
+            } else {
+                tracing::warn!(
+                    "TuiRequest: HDR terminal textures are unsupported on this adapter, \
+                     using Rgba8Unorm"
+                );
+            }
+        }
+
         #[cfg(any(feature = "2d", feature = "3d"))]
         let dimensions = texture_state.dimensions();
         #[cfg(any(feature = "2d", feature = "3d"))]
         let image_handle = texture_state.image_handle();
         let mut tui = Tui::from_texture_state(texture_state);
+        tui.set_strict_bounds(request.config.strict_bounds);
         if let Some(initial_draw) = request.config.initial_draw.take() {
             tui.draw(initial_draw);
         }
@@ -869,6 +2687,8 @@ pub(crate) fn materialize_tui_requests(
         let input = TerminalInput {
             keyboard: request.config.keyboard,
             mouse: request.config.mouse,
+            focusable: request.config.focusable,
+            hit_backfaces: request.config.hit_backfaces,
         };
 
         let mut entity_commands = commands.entity(entity);
@@ -895,8 +2715,8 @@ pub(crate) fn materialize_tui_requests(
                     );
                     continue;
                 };
-                let aspect = dimensions.cols as f32 * dimensions.char_width_px as f32
-                    / (dimensions.rows as f32 * dimensions.char_height_px as f32);
+                let size = dimensions.texture_size();
+                let aspect = size.x / size.y;
                 let half_height = height / 2.0;
                 let mesh = meshes.add(Plane3d::new(
                     Vec3::Z,
@@ -915,6 +2735,9 @@ pub(crate) fn materialize_tui_requests(
                 entity_commands
                     .insert((tui, dimensions, WorldQuadHeight(height)))
                     .insert_if_new((Mesh3d(mesh), MeshMaterial3d(material), input));
+                if let Some(intensity) = request.config.glow {
+                    entity_commands.insert_if_new(TerminalGlow(intensity));
+                }
             }
             TuiKind::Headless => {
                 entity_commands.insert(tui);
@@ -1068,10 +2891,7 @@ pub(crate) fn attach_terminal_system(
         };
 
         let image = tui.image_handle().clone();
-        let (cols, rows) = tui.grid_size();
-        let size = tui.size_px();
-        let char_width_px = size.x / (cols.max(1) as u32);
-        let char_height_px = size.y / (rows.max(1) as u32);
+        let dimensions = tui.dimensions();
         let tui_entity = attach.terminal;
         let insert = attach.material.0.clone();
 
@@ -1084,12 +2904,7 @@ pub(crate) fn attach_terminal_system(
         commands.entity(surface_entity).insert((
             TuiSurface { tui: tui_entity },
             TerminalInput::default(),
-            TerminalDimensions {
-                cols,
-                rows,
-                char_width_px,
-                char_height_px,
-            },
+            dimensions,
         ));
     }
 }
@@ -1135,6 +2950,126 @@ mod tui_flush_tests {
         assert!(tui.draw_error_logged);
     }
 
+    /// Lost-texture recovery: even with nothing dirty, the rebuilt payload
+    /// must be full and carry every row's cached geometry.
+    #[test]
+    fn recover_lost_texture_stashes_a_full_payload_of_the_current_content() {
+        let mut images = Assets::<Image>::default();
+        let texture_state = TerminalTexture::create(4, 2, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture");
+        let mut tui = Tui::from_texture_state(texture_state);
+
+        tui.draw(|frame| {
+            frame.buffer_mut()[(0, 0)].set_symbol("a");
+            frame.buffer_mut()[(0, 1)].set_symbol("b");
+        });
+        tui.flush();
+        let (_, first) = tui.take_pending_draw().expect("first draw must produce a payload");
+        assert!(first.is_full());
+
+        tui.recover_lost_texture();
+        let (_, recovered) = tui
+            .take_pending_draw()
+            .expect("recovery must stash a payload even when nothing is dirty");
+        assert!(recovered.is_full());
+        assert_eq!(recovered.text_quad_count(), first.text_quad_count());
+    }
+
+    #[test]
+    fn initial_draw_content_rides_on_the_first_flush() {
+        let mut images = Assets::<Image>::default();
+        let texture_state = TerminalTexture::create(4, 2, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture")
+            .with_initial_draw(|frame| {
+                frame.buffer_mut()[(0, 0)].set_symbol("a");
+                frame.buffer_mut()[(1, 1)].set_symbol("b");
+            })
+            .expect("initial draw failed");
+        let mut tui = Tui::from_texture_state(texture_state);
+
+        tui.flush();
+        let (_, payload) = tui
+            .take_pending_draw()
+            .expect("the initial content must be flushed without a separate draw");
+        assert!(payload.is_full());
+        assert_eq!(payload.text_quad_count(), 2);
+    }
+
+    #[test]
+    fn flush_at_most_caps_the_flush_rate() {
+        use std::time::Duration;
+
+        let mut images = Assets::<Image>::default();
+        let texture_state = TerminalTexture::create(4, 2, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture");
+        let mut tui = Tui::from_texture_state(texture_state);
+        let ms = Duration::from_millis;
+        let draw_and_flush = |tui: &mut Tui, symbol: &str, now: Duration| {
+            tui.draw(|frame| {
+                frame.buffer_mut()[(0, 0)].set_symbol(symbol);
+            });
+            tui.flush_at_most(10.0, now);
+            tui.take_pending_draw().is_some()
+        };
+
+        assert!(draw_and_flush(&mut tui, "a", ms(0)), "the first flush is never held back");
+        assert!(!draw_and_flush(&mut tui, "b", ms(50)), "not due until 100ms");
+        assert!(tui.dirty, "held-back content stays dirty");
+        assert!(draw_and_flush(&mut tui, "b", ms(110)));
+        // Due times step by 100ms from the previous due time (200ms), not
+        // from the late 110ms flush.
+        assert!(draw_and_flush(&mut tui, "c", ms(200)));
+        // After an idle second the schedule restarts instead of bursting.
+        assert!(draw_and_flush(&mut tui, "d", ms(1500)));
+        assert!(!draw_and_flush(&mut tui, "e", ms(1550)));
+
+        tui.flush_at_most(0.0, ms(1560));
+        assert!(tui.take_pending_draw().is_some(), "0 fps means uncapped");
+    }
+
+    #[test]
+    fn flush_at_most_with_a_vanishing_rate_flushes_once() {
+        use std::time::Duration;
+
+        let mut images = Assets::<Image>::default();
+        let texture_state = TerminalTexture::create(4, 2, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture");
+        let mut tui = Tui::from_texture_state(texture_state);
+        for (symbol, flushed) in [("a", true), ("b", false)] {
+            tui.draw(|frame| {
+                frame.buffer_mut()[(0, 0)].set_symbol(symbol);
+            });
+            // `1 / fps` is past `Duration::MAX`: never due again, no panic.
+            tui.flush_at_most(f32::MIN_POSITIVE, Duration::from_secs(3600));
+            assert_eq!(tui.take_pending_draw().is_some(), flushed);
+        }
+    }
+
+    #[test]
+    fn render_due_tracks_the_flush_schedule() {
+        use crate::bevy_plugin::TerminalRenderRate;
+        use std::time::Duration;
+
+        let mut images = Assets::<Image>::default();
+        let texture_state = TerminalTexture::create(4, 2, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture");
+        let mut tui = Tui::from_texture_state(texture_state);
+        let ms = Duration::from_millis;
+        let rate = TerminalRenderRate(10.0);
+
+        assert!(tui.render_due(Some(&rate), ms(0)), "nothing rendered yet");
+        tui.draw(|frame| {
+            frame.buffer_mut()[(0, 0)].set_symbol("a");
+        });
+        tui.flush_at_most(rate.0, ms(0));
+        assert!(tui.take_pending_draw().is_some());
+
+        assert!(!tui.render_due(Some(&rate), ms(50)), "the next flush is due at 100ms");
+        assert!(tui.render_due(Some(&rate), ms(100)));
+        assert!(tui.render_due(None, ms(50)), "uncapped terminals are always due");
+        assert!(tui.render_due(Some(&TerminalRenderRate(0.0)), ms(50)));
+    }
+
     /// No-change skip (design point 7): redrawing byte-identical content
     /// must not re-mark the terminal dirty, so `flush` performs no work on
     /// the second call. Pure CPU - `draw()`'s dirty tracking is ratatui
@@ -1270,6 +3205,9 @@ mod tui_flush_tests {
             has_red_pixel,
             "rendered texture does not contain the drawn red background"
         );
+        let metrics = app.world().get::<Tui>(entity).unwrap().texture_state.metrics();
+        let [r, _, b, _] = metrics.sample_cell(&pixels, 3, 1).expect("cell (3, 1) is on the grid");
+        assert!(r > 200 && b < 60, "the last cell's center is red");
     }
 
     /// Phase 2 partial redraw, GPU-backed regression: draw two rows (red,
@@ -1398,6 +3336,120 @@ mod tui_flush_tests {
             "row 1 must show blue after being redrawn"
         );
     }
+
+    /// A region readback (`Tui::read_back_region_blocking`'s copy with a
+    /// non-zero origin and an unaligned row size) must equal the same
+    /// sub-rectangle cut out of a full readback. Same skip-if-no-GPU
+    /// policy as `flush_renders_drawn_content_synchronously`.
+    #[test]
+    fn region_readback_matches_the_sub_rect_of_a_full_readback() {
+        use crate::bevy_plugin::TuiReadbackChannel;
+        use crate::bevy_plugin::TerminalPlugin;
+        use bevy::render::renderer::RenderDevice;
+
+        let mut app = App::new();
+        app.add_plugins((
+            bevy::app::TaskPoolPlugin::default(),
+            bevy::asset::AssetPlugin::default(),
+            bevy::window::WindowPlugin {
+                primary_window: None,
+                exit_condition: bevy::window::ExitCondition::DontExit,
+                ..default()
+            },
+            bevy::mesh::MeshPlugin,
+            bevy::diagnostic::FrameCountPlugin,
+            bevy::time::TimePlugin,
+            bevy::render::RenderPlugin::default(),
+            bevy::image::ImagePlugin::default(),
+            TerminalPlugin::display_only(),
+        ));
+        app.init_resource::<bevy::camera::ClearColor>();
+        app.finish();
+        app.cleanup();
+
+        let mut ready = false;
+        for _ in 0..200 {
+            app.update();
+            if app.world().get_resource::<RenderDevice>().is_some() {
+                ready = true;
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        if !ready {
+            eprintln!("skipping: no GPU adapter available in this environment");
+            return;
+        }
+
+        // Every cell a different color, with a glyph, so a misplaced
+        // origin can't go unnoticed.
+        let entity = {
+            let mut images = app.world_mut().resource_mut::<Assets<Image>>();
+            let texture_state = TerminalTexture::create(4, 2, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+                .expect("failed to create terminal texture");
+            let mut tui = Tui::from_texture_state(texture_state);
+            tui.draw(|frame| {
+                for (i, color) in [
+                    RatatuiColor::Red,
+                    RatatuiColor::Green,
+                    RatatuiColor::Blue,
+                    RatatuiColor::Yellow,
+                    RatatuiColor::Magenta,
+                    RatatuiColor::Cyan,
+                    RatatuiColor::White,
+                    RatatuiColor::Gray,
+                ]
+                .into_iter()
+                .enumerate()
+                {
+                    let area = ratatui::layout::Rect::new(i as u16 % 4, i as u16 / 4, 1, 1);
+                    frame.render_widget(
+                        ratatui::widgets::Paragraph::new("x").style(Style::default().bg(color)),
+                        area,
+                    );
+                }
+            });
+            app.world_mut().spawn(tui).id()
+        };
+
+        let tui = app.world().get::<Tui>(entity).unwrap();
+        let image_id = tui.image_handle().id();
+        let metrics = tui.texture_state.metrics();
+        let region = metrics
+            .cell_rect_px(ratatui::layout::Rect::new(1, 0, 2, 2))
+            .expect("the cells are on the texture");
+        let channel = app.world().resource::<TuiReadbackChannel>().clone();
+
+        let request_and_wait = |app: &mut App, region: Option<URect>| -> Vec<u8> {
+            let channel = channel.clone();
+            let readback = std::thread::spawn(move || match region {
+                Some(region) => channel.request_region_blocking(image_id, region),
+                None => channel.request_blocking(image_id),
+            });
+            for _ in 0..200 {
+                app.update();
+                if readback.is_finished() {
+                    return readback.join().expect("readback thread panicked");
+                }
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+            panic!("readback never completed - render world stalled");
+        };
+
+        let full = request_and_wait(&mut app, None);
+        let partial = request_and_wait(&mut app, Some(region));
+
+        let row_bytes = region.width() as usize * 4;
+        let expected: Vec<u8> = (region.min.y..region.max.y)
+            .flat_map(|y| {
+                let width = metrics.texture_width as usize;
+                let start = (y as usize * width + region.min.x as usize) * 4;
+                full[start..start + row_bytes].iter().copied()
+            })
+            .collect();
+        assert_eq!(partial.len(), row_bytes * region.height() as usize);
+        assert!(partial == expected, "region readback differs from the full readback's sub-rect");
+    }
 }
 
 // ============================================================================
@@ -1477,6 +3529,64 @@ mod tui_request_tests {
             "TuiRequest must be removed after materialization"
         );
     }
+
+    #[test]
+    fn asset_font_requests_share_one_fonts_per_size() {
+        let mut app = App::new();
+        app.add_plugins((
+            bevy::app::TaskPoolPlugin::default(),
+            bevy::asset::AssetPlugin {
+                file_path: "examples/assets".into(),
+                ..default()
+            },
+            bevy::window::WindowPlugin {
+                primary_window: None,
+                exit_condition: bevy::window::ExitCondition::DontExit,
+                ..default()
+            },
+            bevy::image::ImagePlugin::default(),
+            TerminalPlugin::display_only(),
+        ));
+        app.finish();
+        app.cleanup();
+
+        let handle: Handle<crate::fonts::TerminalFontAsset> = app
+            .world()
+            .resource::<AssetServer>()
+            .load("fonts/Mplus1Code-Regular.ttf");
+        let request = |size_px| {
+            TuiRequest::headless(
+                4,
+                2,
+                TuiFontSource::Asset {
+                    handle: handle.clone(),
+                    size_px,
+                },
+            )
+        };
+        let entities = [
+            app.world_mut().spawn(request(16)).id(),
+            app.world_mut().spawn(request(16)).id(),
+            app.world_mut().spawn(request(24)).id(),
+        ];
+
+        for _ in 0..500 {
+            app.update();
+            if entities.iter().all(|&e| app.world().get::<Tui>(e).is_some()) {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        let font_keys: Vec<usize> = entities
+            .iter()
+            .map(|&e| {
+                let tui = app.world().get::<Tui>(e).expect("terminal never materialized");
+                tui.texture_state.terminal.backend().font_key()
+            })
+            .collect();
+        assert_eq!(font_keys[0], font_keys[1], "same asset and size share one atlas");
+        assert_ne!(font_keys[0], font_keys[2], "another size needs its own Fonts");
+    }
 }
 
 // ============================================================================
@@ -1568,6 +3678,41 @@ mod attach_churn_tests {
              would have advanced if a spurious remove+insert happened"
         );
     }
+
+    #[test]
+    fn glow_makes_the_terminal_image_emissive() {
+        let image = Handle::<Image>::default();
+        let mut material = StandardMaterial {
+            base_color_texture: Some(image.clone()),
+            unlit: true,
+            alpha_mode: AlphaMode::Blend,
+            ..default()
+        };
+        TerminalGlow(2.5).apply(&mut material);
+        assert!(!material.unlit, "emissive light is only added when lit");
+        assert_eq!(material.emissive_texture, Some(image));
+        assert_eq!(material.emissive, LinearRgba::rgb(2.5, 2.5, 2.5));
+        assert_eq!(material.base_color, Color::BLACK);
+        assert_eq!(material.alpha_mode, AlphaMode::Blend);
+    }
+
+    #[test]
+    fn room_faces_point_at_the_center_with_readable_text() {
+        for face in RoomFace::ALL {
+            let transform = face.transform(3.0);
+            assert!((transform.translation.length() - 3.0).abs() < 1e-5, "{face:?}");
+            // The quad's front (its local +Z) looks at the center.
+            let normal = transform.rotation * Vec3::Z;
+            assert!(normal.dot(-transform.translation.normalize()) > 0.999, "{face:?}");
+            let up = transform.rotation * Vec3::Y;
+            let expected_up = match face {
+                RoomFace::Floor => Vec3::NEG_Z,
+                RoomFace::Ceiling => Vec3::Z,
+                _ => Vec3::Y,
+            };
+            assert!(up.dot(expected_up) > 0.999, "{face:?}: text top points {up}");
+        }
+    }
 }
 
 // ============================================================================
@@ -1610,6 +3755,280 @@ mod resize_tests {
         );
     }
 
+    #[test]
+    fn texture_resize_resizes_image_backend_and_frame_in_place() {
+        let mut images = Assets::<Image>::default();
+        let mut texture =
+            TerminalTexture::create(4, 2, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+                .expect("failed to create terminal texture");
+        let handle = texture.image_handle();
+        let metrics = texture.metrics();
+
+        texture.resize(7, 3, &mut images);
+        let resized = texture.metrics();
+        assert_eq!((resized.cols, resized.rows), (7, 3));
+        assert_eq!(resized.texture_width, 7 * metrics.char_width_px);
+        let image = images.get(&handle).expect("same handle, recreated");
+        assert_eq!(image.width(), resized.texture_width);
+        assert_eq!(image.height(), 3 * metrics.char_height_px);
+
+        let mut area = ratatui::layout::Rect::default();
+        texture
+            .terminal
+            .draw(|frame| area = frame.area())
+            .expect("draw failed");
+        assert_eq!(area, ratatui::layout::Rect::new(0, 0, 7, 3), "ratatui sees the new grid");
+        let payload = texture.terminal.backend_mut().take_draw_payload();
+        assert!(payload.is_full(), "the first draw after a resize repaints everything");
+    }
+
+    #[test]
+    fn webgl2_clamp_keeps_the_texture_within_the_limit() {
+        // 10x20px cells: 2048px fits 204 columns and 102 rows.
+        assert_eq!(
+            clamp_grid_to_texture_limit(300, 120, 10, 20, WEBGL2_MAX_TEXTURE_DIMENSION),
+            (204, 102)
+        );
+        assert_eq!(
+            clamp_grid_to_texture_limit(80, 24, 10, 20, WEBGL2_MAX_TEXTURE_DIMENSION),
+            (80, 24),
+            "a grid already within the limit is left alone"
+        );
+    }
+
+    #[test]
+    fn destination_image_is_renderable_and_sampleable() {
+        use bevy::render::render_resource::{TextureFormat, TextureUsages};
+
+        let image = destination_image(64, 32, &ImageSampler::Default, TextureFormat::Rgba8Unorm);
+        let descriptor = &image.texture_descriptor;
+        assert_eq!(descriptor.format, TextureFormat::Rgba8Unorm);
+        assert!(descriptor.usage.contains(
+            TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST
+        ));
+        assert_eq!(
+            descriptor.usage.contains(TextureUsages::COPY_SRC),
+            !cfg!(target_arch = "wasm32"),
+            "COPY_SRC only where blocking readback exists"
+        );
+    }
+
+    #[test]
+    fn sampler_reaches_every_image_and_survives_resize() {
+        let mut images = Assets::<Image>::default();
+        let mut texture = TerminalTexture::create(4, 2, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture")
+            .with_sampler(ImageSampler::nearest(), &mut images);
+        let mirror = texture.add_mirror(&mut images);
+
+        texture.resize(8, 6, &mut images);
+
+        for handle in [&texture.image_handle, &mirror] {
+            let image = images.get(handle).expect("image must exist");
+            assert_eq!(image.width(), texture.width, "the resize recreated the image");
+            assert!(
+                matches!(
+                    &image.sampler,
+                    ImageSampler::Descriptor(d) if d.mag_filter == bevy::image::ImageFilterMode::Nearest
+                ),
+                "the sampler must be carried over to recreated images and mirrors"
+            );
+        }
+    }
+
+    #[test]
+    fn hdr_format_reaches_every_image_and_survives_resize() {
+        let mut images = Assets::<Image>::default();
+        let fill = [0, 0, 0, 255];
+        let mut texture =
+            TerminalTexture::create(4, 2, test_fonts(), false, false, fill, &mut images)
+                .expect("failed to create terminal texture")
+                .with_hdr(true, &mut images);
+        let mirror = texture.add_mirror(&mut images);
+        assert_eq!(texture.format(), TextureFormat::Rgba16Float);
+
+        texture.resize(8, 6, &mut images);
+        for handle in [&texture.image_handle, &mirror] {
+            let image = images.get(handle).expect("image must exist");
+            assert_eq!(image.width(), texture.width, "the resize recreated the image");
+            assert_eq!(image.texture_descriptor.format, TextureFormat::Rgba16Float);
+        }
+
+        texture.set_hdr(false, &mut images);
+        let image = images.get(&texture.image_handle).expect("image must exist");
+        assert_eq!(image.texture_descriptor.format, TextureFormat::Rgba8Unorm);
+    }
+
+    #[test]
+    fn metrics_track_resize_and_agree_with_dimensions() {
+        let mut images = Assets::<Image>::default();
+        let mut texture = TerminalTexture::create(4, 2, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture");
+
+        texture.resize(8, 6, &mut images);
+        let metrics = texture.metrics();
+        assert_eq!((metrics.cols, metrics.rows), (8, 6));
+        assert_eq!(metrics.texture_width, 8 * metrics.char_width_px);
+        assert_eq!(metrics.texture_height, 6 * metrics.char_height_px);
+
+        let dims = texture.dimensions();
+        let from_metrics: TerminalDimensions = metrics.into();
+        assert_eq!(
+            (dims.cols, dims.rows, dims.char_width_px, dims.char_height_px),
+            (
+                from_metrics.cols,
+                from_metrics.rows,
+                from_metrics.char_width_px,
+                from_metrics.char_height_px
+            )
+        );
+    }
+
+    #[test]
+    fn sample_cell_reads_the_cell_center_through_the_padding() {
+        let metrics = TerminalMetrics {
+            cols: 2,
+            rows: 1,
+            char_width_px: 4,
+            char_height_px: 2,
+            texture_width: 10,
+            texture_height: 2,
+            padding: Padding::new(0, 0, 0, 2),
+        };
+        let mut rgba = vec![0u8; 10 * 2 * 4];
+        // Cell (1, 0) spans x 6..10, y 0..2; its center is (8, 1).
+        rgba[(10 + 8) * 4..(10 + 8) * 4 + 4].copy_from_slice(&[0, 255, 0, 255]);
+        assert_eq!(metrics.cell_center_px(1, 0), Some(UVec2::new(8, 1)));
+        assert_eq!(metrics.sample_cell(&rgba, 1, 0), Some([0, 255, 0, 255]));
+        assert_eq!(metrics.sample_cell(&rgba, 0, 0), Some([0, 0, 0, 0]));
+        assert_eq!(metrics.sample_cell(&rgba, 2, 0), None, "off the grid");
+        assert_eq!(metrics.sample_cell(&[], 1, 0), None, "empty readback");
+    }
+
+    #[test]
+    fn cell_rect_px_clips_to_the_grid_and_the_texture() {
+        use ratatui::layout::Rect;
+        let metrics = TerminalMetrics {
+            cols: 4,
+            rows: 2,
+            char_width_px: 4,
+            char_height_px: 2,
+            texture_width: 16,
+            texture_height: 6,
+            padding: Padding::new(2, 0, 0, -2),
+        };
+        // Cells 1..3 x row 1: x 2..10, y 4..6.
+        assert_eq!(metrics.cell_rect_px(Rect::new(1, 1, 2, 1)), Some(URect::new(2, 4, 10, 6)));
+        // Column 0 starts at x = -2: only its visible part remains.
+        assert_eq!(metrics.cell_rect_px(Rect::new(0, 0, 1, 1)), Some(URect::new(0, 2, 2, 4)));
+        // Running past the grid stops at its last cell.
+        assert_eq!(metrics.cell_rect_px(Rect::new(3, 0, 9, 9)), Some(URect::new(10, 2, 14, 6)));
+        assert_eq!(metrics.cell_rect_px(Rect::new(4, 0, 1, 1)), None, "off the grid");
+        assert_eq!(metrics.cell_rect_px(Rect::new(1, 1, 0, 1)), None, "empty");
+    }
+
+    #[cfg(feature = "2d")]
+    #[test]
+    fn child_node_covers_the_area_in_percent_of_the_parent() {
+        use ratatui::layout::Rect;
+        let metrics = TerminalMetrics {
+            cols: 10,
+            rows: 5,
+            char_width_px: 8,
+            char_height_px: 16,
+            texture_width: 100,
+            texture_height: 80,
+            padding: Padding::new(0, 10, 0, 10),
+        };
+        let node = metrics.child_node(Rect::new(1, 2, 5, 2));
+        assert_eq!(node.position_type, PositionType::Absolute);
+        assert_eq!(node.left, Val::Percent(18.0), "(10 + 1 * 8) / 100");
+        assert_eq!(node.top, Val::Percent(40.0));
+        assert_eq!(node.width, Val::Percent(40.0));
+        assert_eq!(node.height, Val::Percent(40.0));
+        // Clipped to the grid: columns 8..10 only.
+        assert_eq!(metrics.child_node(Rect::new(8, 0, 9, 1)).width, Val::Percent(16.0));
+
+        let (request, node, child_of) =
+            TuiRequest::ui_child(Entity::PLACEHOLDER, &metrics, Rect::new(8, 4, 9, 9), test_fonts());
+        assert_eq!((request.cols, request.rows), (2, 1));
+        assert_eq!(node.left, Val::Percent(74.0));
+        assert_eq!(child_of.parent(), Entity::PLACEHOLDER);
+    }
+
+    #[cfg(feature = "3d")]
+    #[test]
+    fn child_quad_sits_over_the_area_just_in_front_of_the_parent() {
+        use ratatui::layout::Rect;
+        let metrics = TerminalMetrics {
+            cols: 4,
+            rows: 2,
+            char_width_px: 10,
+            char_height_px: 20,
+            texture_width: 40,
+            texture_height: 40,
+            padding: Padding::default(),
+        };
+        // A 4 x 2 world-unit parent: one cell is 1 x 1.
+        let (transform, height) = metrics.child_quad(Rect::new(2, 0, 2, 1), 2.0);
+        assert_eq!(height, 1.0);
+        assert_eq!(transform.translation, Vec3::new(1.0, 0.5, CHILD_QUAD_LIFT * 2.0));
+        assert_eq!(transform.rotation, Quat::IDENTITY);
+
+        let (request, _, _) =
+            TuiRequest::world_quad_child(Entity::PLACEHOLDER, &metrics, Rect::new(0, 0, 4, 2), test_fonts(), 2.0);
+        assert_eq!((request.cols, request.rows), (4, 2));
+        assert!(matches!(request.kind, TuiKind::WorldQuad { height } if height == 2.0));
+    }
+
+    #[cfg(feature = "atlas_dump")]
+    #[test]
+    fn atlas_dump_flattens_coverage_onto_black() {
+        let mut rgba = vec![255, 255, 255, 255, 255, 255, 255, 0, 200, 100, 50, 128];
+        flatten_onto_black(&mut rgba);
+        assert_eq!(rgba, [255, 255, 255, 255, 0, 0, 0, 255, 100, 50, 25, 255]);
+    }
+
+    #[test]
+    fn cell_at_uv_skips_the_padding() {
+        let metrics = TerminalMetrics {
+            cols: 2,
+            rows: 1,
+            char_width_px: 4,
+            char_height_px: 2,
+            texture_width: 10,
+            texture_height: 2,
+            padding: Padding::new(0, 0, 0, 2),
+        };
+        assert_eq!(metrics.cell_at_uv(Vec2::new(0.1, 0.5)), None, "left padding");
+        assert_eq!(metrics.cell_at_uv(Vec2::new(0.3, 0.5)), Some((0, 0)));
+        assert_eq!(metrics.cell_at_uv(Vec2::new(0.8, 0.0)), Some((1, 0)));
+        assert_eq!(metrics.cell_at_uv(Vec2::new(1.0, 1.0)), None, "one past the last row");
+        assert_eq!(metrics.cell_at_uv(Vec2::new(-0.1, 0.5)), None);
+        let center = metrics.cell_center_px(1, 0).unwrap().as_vec2() / Vec2::new(10.0, 2.0);
+        assert_eq!(metrics.cell_at_uv(center), Some((1, 0)), "inverse of cell_center_px");
+    }
+
+    #[test]
+    fn padding_grows_the_texture_and_survives_a_resize() {
+        let mut images = Assets::<Image>::default();
+        let texture = TerminalTexture::create(4, 2, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture")
+            .with_padding(Padding::new(1, 2, 3, 4), &mut images);
+        let metrics = texture.metrics();
+        let (cw, ch) = (metrics.char_width_px, metrics.char_height_px);
+        assert_eq!((metrics.texture_width, metrics.texture_height), (4 * cw + 6, 2 * ch + 4));
+        let image = images.get(&texture.image_handle()).unwrap();
+        assert_eq!((image.width(), image.height()), (4 * cw + 6, 2 * ch + 4));
+        assert_eq!(texture.dimensions().padding, Padding::new(1, 2, 3, 4));
+
+        let mut tui = Tui::from_texture_state(texture);
+        tui.request_resize(6, 3);
+        tui.apply_pending_resize(&mut images);
+        assert_eq!(tui.size_px(), UVec2::new(6 * cw + 6, 3 * ch + 4));
+        assert_eq!(tui.dimensions().char_width_px, cw, "cell size ignores the padding");
+    }
+
     #[test]
     fn resize_to_the_current_size_is_a_no_op() {
         let mut images = Assets::<Image>::default();
@@ -1623,6 +4042,191 @@ mod resize_tests {
             "requesting the current grid size must not queue a resize"
         );
     }
+
+    #[test]
+    fn overlay_pixmap_lands_on_cell_pixels_and_is_cropped_to_the_grid() {
+        use ratatui::layout::Rect;
+
+        let mut images = Assets::<Image>::default();
+        let texture = TerminalTexture::create(4, 2, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture");
+        let metrics = texture.metrics();
+        let (cw, ch) = (metrics.char_width_px, metrics.char_height_px);
+        let mut tui = Tui::from_texture_state(texture);
+
+        let big = tiny_skia::Pixmap::new(10 * cw, 10 * ch).unwrap();
+        // Asks for 3x5 cells from (2, 1): only 2x1 cells are on the grid.
+        tui.overlay_pixmap(Rect::new(2, 1, 3, 5), &big);
+        tui.overlay_pixmap(Rect::new(9, 9, 1, 1), &big); // entirely off-grid
+        let overlays = tui.take_overlays();
+        assert_eq!(overlays.len(), 1);
+        assert_eq!(overlays[0].origin, [2 * cw, ch]);
+        assert_eq!((overlays[0].width, overlays[0].height), (2 * cw, ch));
+        assert!(tui.take_overlays().is_empty(), "drained by the first take");
+
+        tui.overlay_pixmap(Rect::new(0, 0, 1, 1), &big);
+        tui.request_resize(8, 6);
+        tui.apply_pending_resize(&mut images);
+        assert!(tui.take_overlays().is_empty(), "a resize drops queued overlays");
+    }
+
+    #[test]
+    fn viewport_shrink_reduces_the_frame_area_and_survives_a_resize() {
+        let mut images = Assets::<Image>::default();
+        let texture = TerminalTexture::create(8, 4, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture");
+        let metrics = texture.metrics();
+        let (cw, ch) = (metrics.char_width_px, metrics.char_height_px);
+        // A partial cell of inset still costs the whole cell.
+        let texture = texture
+            .with_viewport(Viewport::Shrink { width: 2 * cw + 1, height: ch })
+            .expect("failed to apply viewport");
+        assert_eq!(texture.metrics().texture_width, 8 * cw, "the texture keeps its size");
+        let mut tui = Tui::from_texture_state(texture);
+
+        let frame_area = |tui: &mut Tui| {
+            let mut area = ratatui::layout::Rect::default();
+            tui.draw(|frame| area = frame.area());
+            (area.width, area.height)
+        };
+        assert_eq!(frame_area(&mut tui), (5, 3));
+
+        tui.request_resize(12, 6);
+        tui.apply_pending_resize(&mut images);
+        assert_eq!(frame_area(&mut tui), (9, 5), "the inset applies to the new grid");
+
+        tui.set_viewport(Viewport::Full);
+        assert_eq!(frame_area(&mut tui), (12, 6));
+
+        tui.set_viewport(Viewport::Shrink { width: u32::MAX, height: u32::MAX });
+        assert_eq!(frame_area(&mut tui), (0, 0), "an oversized inset leaves nothing");
+    }
+
+    #[test]
+    fn chrome_borders_the_content_and_shrinks_the_frame_area() {
+        use ratatui::layout::Rect;
+
+        let mut images = Assets::<Image>::default();
+        let chrome = ChromeConfig {
+            title: Some("log".into()),
+            ..default()
+        };
+        let texture =
+            TerminalTexture::create(8, 4, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+                .expect("failed to create terminal texture")
+                .with_chrome(chrome)
+                .expect("failed to apply chrome");
+        let mut tui = Tui::from_texture_state(texture);
+
+        let mut area = Rect::default();
+        tui.draw(|frame| {
+            area = frame.area();
+            frame.render_widget(ratatui::widgets::Paragraph::new("xxxxxxxxxx\nyy"), frame.area());
+        });
+        assert_eq!(area, Rect::new(1, 1, 6, 2));
+        let row = |tui: &Tui, y: u16| (0..8).map(|x| tui.cell_symbol(x, y)).collect::<String>();
+        assert_eq!(row(&tui, 0), "┌log───┐");
+        assert_eq!(row(&tui, 1), "│xxxxxx│", "content is clipped to the inside");
+        assert_eq!(row(&tui, 2), "│yy    │");
+        assert_eq!(row(&tui, 3), "└──────┘");
+
+        // The border follows a resize (which clears the grid).
+        tui.request_resize(10, 3);
+        tui.apply_pending_resize(&mut images);
+        tui.draw(|frame| area = frame.area());
+        assert_eq!(area, Rect::new(1, 1, 8, 1));
+        assert_eq!(row(&tui, 2), "└───────");
+
+        // Without it, the whole grid is the frame again, border gone.
+        tui.set_chrome(None);
+        tui.draw(|frame| area = frame.area());
+        assert_eq!(area, Rect::new(0, 0, 10, 3));
+        assert_eq!(row(&tui, 0), "        ");
+    }
+
+    #[test]
+    fn frame_area_is_the_grid_size_whatever_the_padding() {
+        let mut images = Assets::<Image>::default();
+        let texture = TerminalTexture::create(6, 3, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture")
+            .with_padding(Padding::new(5, -3, 7, 11), &mut images);
+        let mut tui = Tui::from_texture_state(texture);
+
+        let mut area = ratatui::layout::Rect::default();
+        tui.draw(|frame| area = frame.area());
+        assert_eq!(area, ratatui::layout::Rect::new(0, 0, 6, 3), "padding is pixels, not cells");
+
+        tui.request_resize(10, 4);
+        tui.apply_pending_resize(&mut images);
+        tui.draw_with_hits(|frame, _| area = frame.area());
+        assert_eq!(area, ratatui::layout::Rect::new(0, 0, 10, 4));
+    }
+
+    #[test]
+    fn strict_bounds_accepts_hit_regions_inside_the_frame() {
+        let mut images = Assets::<Image>::default();
+        let texture = TerminalTexture::create(6, 3, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture");
+        let mut tui = Tui::from_texture_state(texture);
+        tui.set_strict_bounds(true);
+
+        tui.draw_with_hits(|frame, hits| {
+            hits.add(1u64, frame.area());
+            hits.add(2u64, ratatui::layout::Rect::new(5, 2, 1, 1));
+            // Empty rects have no cells to clip.
+            hits.add(3u64, ratatui::layout::Rect::new(40, 40, 0, 0));
+        });
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "extends past the frame area")]
+    fn strict_bounds_panics_on_a_hit_region_past_the_frame_in_debug() {
+        let mut images = Assets::<Image>::default();
+        let texture = TerminalTexture::create(6, 3, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture");
+        let mut tui = Tui::from_texture_state(texture);
+        tui.set_strict_bounds(true);
+
+        // Laid out for an 8-column terminal.
+        tui.draw_with_hits(|_, hits| hits.add(1u64, ratatui::layout::Rect::new(0, 0, 8, 3)));
+    }
+
+    #[test]
+    fn mirrors_share_payloads_and_follow_resizes() {
+        let mut images = Assets::<Image>::default();
+        let texture = TerminalTexture::create(4, 2, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture");
+        let mut tui = Tui::from_texture_state(texture);
+        tui.draw(|_| {});
+        tui.flush();
+        let _ = tui.take_pending_draw();
+
+        let mirror = tui.add_mirror(&mut images);
+        assert_eq!(tui.destinations(), vec![tui.image_handle().id(), mirror.id()]);
+        let payload = tui
+            .take_pending_draw()
+            .map(|(_, draw)| draw)
+            .expect("adding a mirror must re-send the content");
+        assert!(payload.is_full());
+
+        let size = |images: &Assets<Image>, handle: &Handle<Image>| {
+            let image = images.get(handle).expect("image must exist");
+            (image.width(), image.height())
+        };
+        assert_eq!(size(&images, &mirror), size(&images, tui.image_handle()));
+        tui.request_resize(8, 6);
+        tui.apply_pending_resize(&mut images);
+        assert_eq!(
+            size(&images, &mirror),
+            size(&images, tui.image_handle()),
+            "a mirror is resized along with the primary image"
+        );
+
+        assert!(tui.remove_mirror(&mirror));
+        assert!(!tui.remove_mirror(&mirror), "already removed");
+        assert_eq!(tui.destinations(), vec![tui.image_handle().id()]);
+    }
 }
 
 // ============================================================================
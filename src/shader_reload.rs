@@ -0,0 +1,145 @@
+//! Runtime-loaded, hot-reloadable compositor shaders (feature
+//! `shader_hot_reload`, native only).
+//!
+//! By default `composite_bg.wgsl` / `composite_fg.wgsl` are embedded at
+//! compile time. With this feature the plugin also loads them through the
+//! `AssetServer` from the paths in [`TerminalShaderFiles`] (relative to the
+//! app's asset folder), and every time either file is saved - bevy's
+//! `file_watcher` picks the change up - the render world rebuilds the
+//! compositor pipelines and every terminal re-renders in full with the new
+//! code. Start by copying `src/backend/shaders/*.wgsl` into
+//! `assets/shaders/`. A file that is missing (or not loaded yet) falls back
+//! to its embedded copy, so enabling the feature never breaks rendering on
+//! its own.
+//!
+//! The bind group layouts and vertex formats are fixed by the crate - an
+//! edited shader must keep the same entry points (`vs_main`/`fs_main`),
+//! bindings and vertex inputs. A shader that fails to compile is reported
+//! by wgpu like any other validation error.
+
+use bevy::asset::AssetEvent;
+use bevy::prelude::*;
+use bevy::render::extract_resource::ExtractResource;
+use bevy::render::renderer::RenderDevice;
+use bevy::shader::{Shader, Source};
+
+use crate::backend::CompositorShaders;
+use crate::bevy_plugin::{ActiveCompositorShaders, SharedFontGpuStore, TerminalGpuStore};
+use crate::setup::Tui;
+
+/// Asset paths the compositor shaders are loaded (and watched) from - see
+/// the [module docs](self). Insert your own before adding
+/// [`TerminalPlugin`](crate::TerminalPlugin) to use other paths.
+#[derive(Resource, Clone, Debug)]
+pub struct TerminalShaderFiles {
+    /// Background pass (`composite_bg.wgsl`).
+    pub bg: String,
+    /// Foreground/glyph pass (`composite_fg.wgsl`).
+    pub fg: String,
+}
+
+impl Default for TerminalShaderFiles {
+    fn default() -> Self {
+        Self {
+            bg: "shaders/composite_bg.wgsl".to_string(),
+            fg: "shaders/composite_fg.wgsl".to_string(),
+        }
+    }
+}
+
+/// Main-world resource, extracted to the render world: the most recently
+/// loaded shader sources, and a counter bumped each time they change so
+/// the render world knows when to rebuild.
+#[derive(Resource, Clone, Default)]
+pub(crate) struct ReloadedCompositorShaders {
+    shaders: CompositorShaders,
+    generation: u64,
+}
+
+impl ExtractResource for ReloadedCompositorShaders {
+    type Source = Self;
+
+    fn extract_resource(source: &Self::Source) -> Self {
+        source.clone()
+    }
+}
+
+fn wgsl(shaders: &Assets<Shader>, handle: &Handle<Shader>) -> Option<String> {
+    match &shaders.get(handle)?.source {
+        Source::Wgsl(source) => Some(source.to_string()),
+        _ => None,
+    }
+}
+
+/// Loads the files named by [`TerminalShaderFiles`] on first run (and again
+/// whenever that resource changes), then publishes new sources each time
+/// either finishes loading or is modified on disk. Every `Tui` is queued
+/// for a full redraw at the same time, so the new shaders show even on
+/// static terminals.
+pub(crate) fn watch_compositor_shaders(
+    asset_server: Res<AssetServer>,
+    files: Res<TerminalShaderFiles>,
+    mut handles: Local<Option<[Handle<Shader>; 2]>>,
+    mut events: MessageReader<AssetEvent<Shader>>,
+    shaders: Res<Assets<Shader>>,
+    mut reloaded: ResMut<ReloadedCompositorShaders>,
+    mut tuis: Query<&mut Tui>,
+) {
+    if handles.is_none() || files.is_changed() {
+        *handles = Some([asset_server.load(&files.bg), asset_server.load(&files.fg)]);
+    }
+    let Some([bg, fg]) = handles.as_ref() else {
+        return;
+    };
+
+    let touched = events.read().any(|event| match event {
+        AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => {
+            *id == bg.id() || *id == fg.id()
+        }
+        _ => false,
+    });
+    if !touched {
+        return;
+    }
+
+    let embedded = CompositorShaders::default();
+    reloaded.shaders = CompositorShaders {
+        bg: wgsl(&shaders, bg).map_or(embedded.bg, Into::into),
+        fg: wgsl(&shaders, fg).map_or(embedded.fg, Into::into),
+    };
+    reloaded.generation += 1;
+    info!("terminal compositor shaders reloaded");
+
+    for mut tui in &mut tuis {
+        tui.recover_lost_texture();
+    }
+}
+
+/// Render-world half: once per new generation, makes the extracted sources
+/// the ones new fonts build with, rebuilds every existing font's
+/// pipelines in place (atlas contents survive) and drops per-terminal GPU
+/// state, whose bind groups were built against the old pipeline layout -
+/// `render_tui_textures` recreates it lazily. Runs just before
+/// `render_tui_textures`.
+pub(crate) fn apply_compositor_reload(
+    reloaded: Option<Res<ReloadedCompositorShaders>>,
+    mut applied_generation: Local<u64>,
+    mut active: ResMut<ActiveCompositorShaders>,
+    mut font_store: ResMut<SharedFontGpuStore>,
+    mut store: ResMut<TerminalGpuStore>,
+    render_device: Res<RenderDevice>,
+) {
+    let Some(reloaded) = reloaded else {
+        return;
+    };
+    if reloaded.generation == *applied_generation {
+        return;
+    }
+    *applied_generation = reloaded.generation;
+
+    active.0 = reloaded.shaders.clone();
+    for shared in font_store.0.values_mut() {
+        shared.rebuild_compositors(render_device.wgpu_device(), &active.0);
+    }
+    store.0.clear();
+}
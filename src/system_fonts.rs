@@ -0,0 +1,155 @@
+//! OS font discovery for the `system_fonts` feature.
+//!
+//! When a character is missing from every font a [`Fonts`](crate::fonts::Fonts)
+//! holds, [`font_for_char`] asks the operating system's installed fonts
+//! (enumerated once through `fontdb`) for one that covers it, so user
+//! content in arbitrary scripts renders without bundling a font for each.
+//!
+//! Everything is cached process-wide: the first miss builds an index of
+//! every installed face's character coverage, every lookup result (hit or
+//! miss) is remembered per character, and each discovered face is loaded
+//! once into a shared [`Font`] handed out to every `Fonts` that needs it.
+//! Native only - there is no font enumeration on wasm32, where the feature
+//! compiles to nothing.
+
+use crate::fonts::Font;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// The installed faces and what they cover, built once - see
+/// [`Index::build`].
+struct Index {
+    db: fontdb::Database,
+    /// Every face with a Unicode cmap, monospaced ones first: they keep
+    /// the cell grid's proportions.
+    faces: Vec<IndexedFace>,
+}
+
+struct IndexedFace {
+    id: fontdb::ID,
+    /// Code points the face's cmap maps, as sorted, disjoint ranges.
+    coverage: Vec<RangeInclusive<u32>>,
+}
+
+/// Lookup results and loaded faces - see the module docs. Only held for
+/// map accesses, never while parsing or loading a face.
+#[derive(Default)]
+struct Cache {
+    /// Covering face per character, misses included, so the index is
+    /// searched at most once for any character.
+    by_char: HashMap<char, Option<fontdb::ID>>,
+    /// Faces loaded so far.
+    loaded: HashMap<fontdb::ID, Arc<Font>>,
+}
+
+static INDEX: OnceLock<Index> = OnceLock::new();
+static CACHE: OnceLock<Mutex<Cache>> = OnceLock::new();
+
+/// An installed font covering `ch`, or `None` if the OS has none. The
+/// first call enumerates the system fonts and reads every face's cmap,
+/// a one-off stall; after that a new character costs a search of the
+/// in-memory index, plus loading the covering face the first time it is
+/// needed.
+pub(crate) fn font_for_char(ch: char) -> Option<Arc<Font>> {
+    let faces = INDEX.get_or_init(Index::build);
+    let cache = CACHE.get_or_init(Mutex::default);
+    let lock = || cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let cached = lock().by_char.get(&ch).copied();
+    let id = match cached {
+        Some(id) => id?,
+        None => {
+            let id = faces.face_for(ch);
+            lock().by_char.insert(ch, id);
+            id?
+        }
+    };
+    if let Some(font) = lock().loaded.get(&id) {
+        return Some(font.clone());
+    }
+
+    let font = faces
+        .db
+        .with_face_data(id, |data, index| Font::from_vec_face(data.to_vec(), index))
+        .flatten()?;
+    tracing::debug!("system_fonts: loaded a fallback face for U+{:04X}", ch as u32);
+    // Another thread may have loaded the same face meanwhile - keep one.
+    Some(lock().loaded.entry(id).or_insert_with(|| Arc::new(font)).clone())
+}
+
+impl Index {
+    /// Enumerate the installed fonts and record each face's coverage.
+    fn build() -> Self {
+        let mut db = fontdb::Database::new();
+        db.load_system_fonts();
+
+        let mut faces: Vec<(bool, IndexedFace)> = db
+            .faces()
+            .filter_map(|face| {
+                let coverage = db.with_face_data(face.id, face_coverage)?;
+                (!coverage.is_empty())
+                    .then_some((face.monospaced, IndexedFace { id: face.id, coverage }))
+            })
+            .collect();
+        faces.sort_by_key(|(monospaced, _)| !monospaced);
+        tracing::debug!(
+            "system_fonts: {} installed faces found, {} indexed",
+            db.len(),
+            faces.len()
+        );
+        Self {
+            db,
+            faces: faces.into_iter().map(|(_, face)| face).collect(),
+        }
+    }
+
+    fn face_for(&self, ch: char) -> Option<fontdb::ID> {
+        self.faces
+            .iter()
+            .find(|face| face.covers(ch))
+            .map(|face| face.id)
+    }
+}
+
+impl IndexedFace {
+    fn covers(&self, ch: char) -> bool {
+        let cp = ch as u32;
+        self.coverage
+            .binary_search_by(|range| {
+                if *range.end() < cp {
+                    std::cmp::Ordering::Less
+                } else if *range.start() > cp {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+}
+
+/// Code points the Unicode subtables of a face's cmap map, merged into
+/// sorted ranges; empty if the face doesn't parse.
+fn face_coverage(data: &[u8], index: u32) -> Vec<RangeInclusive<u32>> {
+    let Ok(face) = rustybuzz::ttf_parser::Face::parse(data, index) else {
+        return vec![];
+    };
+    let mut code_points = Vec::new();
+    for subtable in face.tables().cmap.iter().flat_map(|cmap| cmap.subtables) {
+        if subtable.is_unicode() {
+            subtable.codepoints(|cp| code_points.push(cp));
+        }
+    }
+    code_points.sort_unstable();
+    code_points.dedup();
+
+    let mut ranges: Vec<RangeInclusive<u32>> = Vec::new();
+    for cp in code_points {
+        match ranges.last_mut() {
+            Some(last) if *last.end() + 1 == cp => *last = *last.start()..=cp,
+            _ => ranges.push(cp..=cp),
+        }
+    }
+    ranges
+}
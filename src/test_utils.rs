@@ -0,0 +1,303 @@
+//! Snapshot testing of rendered terminal output (`test-utils` feature).
+//!
+//! [`render_headless`] renders one frame in a windowless bevy app - the
+//! same `gpu_flush_system` -> `render_tui_textures` path a real app runs -
+//! and reads the destination texture back. [`assert_terminal_matches`]
+//! compares that against a reference PNG within a per-channel tolerance,
+//! so glyph-rendering regressions (box drawing, powerline, braille) fail a
+//! test instead of being spotted by eye.
+//!
+//! Reference images are recorded, not hand-made: with
+//! `BEVY_TUI_TEXTURE_BLESS=1` set, every reference a test touches is
+//! written from the current output. Without it a missing reference fails
+//! the assertion like a mismatch does - nothing is written next to the
+//! references then, the actual output goes to
+//! `<temp dir>/bevy_tui_texture_snapshots/<name>.actual.png` instead.
+//! Review recorded PNGs before committing them.
+//!
+//! Both functions need a GPU adapter. Without one, `render_headless`
+//! returns `None` and `assert_terminal_matches` panics - mark snapshot
+//! tests `#[ignore]` so GPU-less runs report them as skipped rather than
+//! passed, and run them with `cargo test -- --ignored`.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use crate::bevy_plugin::{TerminalPlugin, TuiReadbackChannel};
+use crate::fonts::Fonts;
+use crate::setup::{TerminalTexture, Tui};
+
+/// Environment variable that makes [`assert_terminal_matches`] overwrite
+/// its reference image with the current output instead of comparing.
+pub const BLESS_ENV_VAR: &str = "BEVY_TUI_TEXTURE_BLESS";
+
+/// How many `App::update` rounds to wait for the render device, and then
+/// for the readback, before giving up.
+const MAX_UPDATES: usize = 200;
+
+/// A rendered terminal frame: tightly packed, straight-alpha RGBA8 rows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedImage {
+    pub width: u32,
+    pub height: u32,
+    pub rgba: Vec<u8>,
+}
+
+impl RenderedImage {
+    /// Encode as a PNG file at `path`.
+    pub fn save_png(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        self.to_pixmap()
+            .ok_or_else(|| "image has a zero dimension".to_string())?
+            .save_png(path)
+            .map_err(|err| err.to_string())
+    }
+
+    /// Decode a PNG file written by [`Self::save_png`] (or any 8-bit PNG).
+    pub fn load_png(path: impl AsRef<Path>) -> Result<Self, String> {
+        let pixmap = tiny_skia::Pixmap::load_png(path).map_err(|err| err.to_string())?;
+        Ok(Self::from_pixmap(&pixmap))
+    }
+
+    fn from_pixmap(pixmap: &tiny_skia::Pixmap) -> Self {
+        let rgba = pixmap
+            .pixels()
+            .iter()
+            .flat_map(|px| {
+                let c = px.demultiply();
+                [c.red(), c.green(), c.blue(), c.alpha()]
+            })
+            .collect();
+        Self {
+            width: pixmap.width(),
+            height: pixmap.height(),
+            rgba,
+        }
+    }
+
+    /// Round-trips through tiny-skia's premultiplied storage, so an image
+    /// compares equal to its own saved-and-reloaded PNG even where alpha
+    /// is below 255.
+    fn to_pixmap(&self) -> Option<tiny_skia::Pixmap> {
+        let mut pixmap = tiny_skia::Pixmap::new(self.width, self.height)?;
+        for (dst, src) in pixmap.pixels_mut().iter_mut().zip(self.rgba.chunks_exact(4)) {
+            *dst = tiny_skia::ColorU8::from_rgba(src[0], src[1], src[2], src[3]).premultiply();
+        }
+        Some(pixmap)
+    }
+}
+
+/// Render one frame of a `cols` x `rows` terminal drawn by `draw_fn` and
+/// read it back. `None` if no GPU adapter is available. Blocks until the
+/// render world has produced the frame; expect a few hundred milliseconds
+/// per call, most of it device creation.
+pub fn render_headless(
+    cols: u16,
+    rows: u16,
+    fonts: Arc<Fonts>,
+    draw_fn: impl FnOnce(&mut ratatui::Frame),
+) -> Option<RenderedImage> {
+    use bevy::render::renderer::RenderDevice;
+
+    let mut app = App::new();
+    app.add_plugins((
+        bevy::app::TaskPoolPlugin::default(),
+        bevy::asset::AssetPlugin::default(),
+        // Windowless, but still registers the window messages that
+        // `TerminalPlugin` and bevy_render's extraction systems read.
+        bevy::window::WindowPlugin {
+            primary_window: None,
+            exit_condition: bevy::window::ExitCondition::DontExit,
+            ..default()
+        },
+        bevy::mesh::MeshPlugin,
+        bevy::diagnostic::FrameCountPlugin,
+        bevy::time::TimePlugin,
+        bevy::render::RenderPlugin::default(),
+        bevy::image::ImagePlugin::default(),
+        TerminalPlugin::display_only(),
+    ));
+    // Normally inserted by `CameraPlugin`, which this app leaves out.
+    app.init_resource::<bevy::camera::ClearColor>();
+    app.finish();
+    app.cleanup();
+
+    let device_ready = (0..MAX_UPDATES).any(|_| {
+        app.update();
+        let ready = app.world().get_resource::<RenderDevice>().is_some();
+        if !ready {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        ready
+    });
+    if !device_ready {
+        return None;
+    }
+
+    let texture = {
+        let mut images = app.world_mut().resource_mut::<Assets<Image>>();
+        TerminalTexture::create(cols, rows, fonts, true, false, [0, 0, 0, 255], &mut images)
+            .ok()?
+            .with_initial_draw(draw_fn)
+            .ok()?
+    };
+    let metrics = texture.metrics();
+    let (width, height) = (metrics.texture_width, metrics.texture_height);
+    let tui = Tui::from_texture_state(texture);
+    let image_id = tui.image_handle().id();
+    app.world_mut().spawn(tui);
+
+    // The readback blocks until the render world serves it, so it has to
+    // wait on another thread while this one keeps updating the app.
+    let channel = app.world().resource::<TuiReadbackChannel>().clone();
+    let readback = std::thread::spawn(move || channel.request_blocking(image_id));
+    for _ in 0..MAX_UPDATES {
+        app.update();
+        if readback.is_finished() {
+            let rgba = readback.join().ok()?;
+            return (rgba.len() == width as usize * height as usize * 4).then_some(RenderedImage {
+                width,
+                height,
+                rgba,
+            });
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    None
+}
+
+/// Render `draw_fn` with [`render_headless`] and assert the result matches
+/// the PNG at `reference_png`: same size, and no channel of any pixel off
+/// by more than `tolerance`. See the module docs for how references are
+/// recorded; panics without a GPU adapter.
+///
+/// ```ignore
+/// use bevy_tui_texture::test_utils::assert_terminal_matches;
+///
+/// assert_terminal_matches(8, 2, fonts, |frame| {
+///     frame.render_widget(ratatui::widgets::Paragraph::new("┌──┐"), frame.area());
+/// }, "snapshots/box.png", 8);
+/// ```
+pub fn assert_terminal_matches(
+    cols: u16,
+    rows: u16,
+    fonts: Arc<Fonts>,
+    draw_fn: impl FnOnce(&mut ratatui::Frame),
+    reference_png: impl AsRef<Path>,
+    tolerance: u8,
+) {
+    let reference_png = reference_png.as_ref();
+    let Some(actual) = render_headless(cols, rows, fonts, draw_fn) else {
+        panic!(
+            "cannot check snapshot {}: no GPU adapter available in this environment",
+            reference_png.display()
+        );
+    };
+    // Compare what the PNG will hold, not the raw readback.
+    let actual = match actual.to_pixmap() {
+        Some(pixmap) => RenderedImage::from_pixmap(&pixmap),
+        None => actual,
+    };
+
+    if std::env::var_os(BLESS_ENV_VAR).is_some() {
+        if let Some(dir) = reference_png.parent() {
+            std::fs::create_dir_all(dir).expect("failed to create the snapshot directory");
+        }
+        actual
+            .save_png(reference_png)
+            .expect("failed to write the reference image");
+        tracing::info!("recorded snapshot {}", reference_png.display());
+        return;
+    }
+
+    let mismatch = if !reference_png.exists() {
+        Some(format!("no reference image; rerun with {BLESS_ENV_VAR}=1 to record it"))
+    } else {
+        let expected = RenderedImage::load_png(reference_png)
+            .unwrap_or_else(|err| panic!("failed to read {}: {err}", reference_png.display()));
+        if (expected.width, expected.height) != (actual.width, actual.height) {
+            Some(format!(
+                "size {}x{} differs from the reference's {}x{}",
+                actual.width, actual.height, expected.width, expected.height
+            ))
+        } else {
+            let (count, max_diff) = pixel_differences(&expected.rgba, &actual.rgba, tolerance);
+            (count > 0).then(|| {
+                format!("{count} pixels differ by more than {tolerance} (largest difference {max_diff})")
+            })
+        }
+    };
+    if let Some(mismatch) = mismatch {
+        panic!(
+            "snapshot {} does not match: {mismatch}{}",
+            reference_png.display(),
+            match save_actual(reference_png, &actual) {
+                Some(actual_path) => format!("; actual output written to {}", actual_path.display()),
+                None => String::new(),
+            }
+        );
+    }
+}
+
+/// Save the output a failed comparison against `reference_png` produced
+/// under the temp dir - never next to the reference, which usually sits in
+/// a source tree. `None` if it couldn't be written.
+fn save_actual(reference_png: &Path, actual: &RenderedImage) -> Option<PathBuf> {
+    let dir = std::env::temp_dir().join("bevy_tui_texture_snapshots");
+    std::fs::create_dir_all(&dir).ok()?;
+    let stem = reference_png.file_stem()?.to_string_lossy();
+    let actual_path = dir.join(format!("{stem}.actual.png"));
+    actual.save_png(&actual_path).ok()?;
+    Some(actual_path)
+}
+
+/// Pixels (RGBA quadruples) where any channel differs by more than
+/// `tolerance`, and the largest channel difference seen anywhere.
+fn pixel_differences(expected: &[u8], actual: &[u8], tolerance: u8) -> (usize, u8) {
+    let mut count = 0;
+    let mut max_diff = 0;
+    for (e, a) in expected.chunks_exact(4).zip(actual.chunks_exact(4)) {
+        let diff = e.iter().zip(a).map(|(e, a)| e.abs_diff(*a)).max().unwrap_or(0);
+        max_diff = max_diff.max(diff);
+        if diff > tolerance {
+            count += 1;
+        }
+    }
+    (count, max_diff)
+}
+
+// ============================================================================
+// Test: snapshot comparison and PNG round trip (pure CPU)
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pixel_differences_respect_the_tolerance() {
+        let expected = [10, 20, 30, 255, 0, 0, 0, 255];
+        let actual = [12, 20, 30, 255, 0, 9, 0, 255];
+        assert_eq!(pixel_differences(&expected, &actual, 2), (1, 9));
+        assert_eq!(pixel_differences(&expected, &actual, 9), (0, 9));
+        assert_eq!(pixel_differences(&expected, &expected, 0), (0, 0));
+    }
+
+    #[test]
+    fn png_round_trip_keeps_opaque_pixels_exact() {
+        let image = RenderedImage {
+            width: 2,
+            height: 1,
+            rgba: vec![255, 0, 0, 255, 1, 2, 3, 255],
+        };
+        let path = std::env::temp_dir().join(format!(
+            "bevy_tui_texture_round_trip_{}.png",
+            std::process::id()
+        ));
+        image.save_png(&path).expect("save failed");
+        let loaded = RenderedImage::load_png(&path).expect("load failed");
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(loaded, image);
+    }
+}
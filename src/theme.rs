@@ -0,0 +1,216 @@
+//! Plugin-wide default colors.
+//!
+//! Each backend carries its own `reset_fg`/`reset_bg` (set via
+//! [`TerminalBuilder`](crate::TerminalBuilder) at build time). A
+//! [`TerminalTheme`] resource overrides those for every [`Tui`] at once - and
+//! keeps overriding them: editing or replacing the resource re-applies it to
+//! every terminal, and terminals spawned later pick it up as they appear.
+//!
+//! The resource is optional. Without one, nothing is applied and each
+//! terminal keeps its own per-backend colors. Removing the resource later
+//! leaves terminals with the colors last applied - it does not restore
+//! their build-time colors.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_tui_texture::prelude::*;
+//!
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(TerminalPlugin::default())
+//!     .insert_resource(TerminalTheme::solarized_dark())
+//!     .run();
+//! ```
+
+use bevy::prelude::*;
+
+use crate::setup::Tui;
+
+/// Default colors shared by every terminal in the app - see the
+/// [module docs](self). All colors are `[r, g, b]`.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct TerminalTheme {
+    /// What `Color::Reset` resolves to as a foreground.
+    pub fg: [u8; 3],
+    /// What `Color::Reset` resolves to as a background.
+    pub bg: [u8; 3],
+    /// Optional override for the 16 ANSI colors (ratatui's named colors
+    /// and `Color::Indexed(0..16)`, in xterm slot order: black, red,
+    /// green, yellow, blue, magenta, cyan, gray, then the bright
+    /// variants). `None` keeps the built-in table.
+    pub palette: Option<[[u8; 3]; 16]>,
+}
+
+impl Default for TerminalTheme {
+    /// The same colors a backend uses without a theme: white on black,
+    /// built-in palette.
+    fn default() -> Self {
+        Self {
+            fg: [255, 255, 255],
+            bg: [0, 0, 0],
+            palette: None,
+        }
+    }
+}
+
+impl TerminalTheme {
+    /// Light gray on near-black, built-in palette.
+    pub fn dark() -> Self {
+        Self {
+            fg: [220, 220, 220],
+            bg: [24, 24, 28],
+            palette: None,
+        }
+    }
+
+    /// Near-black on off-white, built-in palette.
+    pub fn light() -> Self {
+        Self {
+            fg: [32, 32, 32],
+            bg: [250, 250, 245],
+            palette: None,
+        }
+    }
+
+    /// Ethan Schoonover's Solarized (dark variant), including its 16-color
+    /// palette.
+    pub fn solarized_dark() -> Self {
+        Self {
+            fg: [0x83, 0x94, 0x96],
+            bg: [0x00, 0x2b, 0x36],
+            palette: Some([
+                [0x07, 0x36, 0x42],
+                [0xdc, 0x32, 0x2f],
+                [0x85, 0x99, 0x00],
+                [0xb5, 0x89, 0x00],
+                [0x26, 0x8b, 0xd2],
+                [0xd3, 0x36, 0x82],
+                [0x2a, 0xa1, 0x98],
+                [0xee, 0xe8, 0xd5],
+                [0x00, 0x2b, 0x36],
+                [0xcb, 0x4b, 0x16],
+                [0x58, 0x6e, 0x75],
+                [0x65, 0x7b, 0x83],
+                [0x83, 0x94, 0x96],
+                [0x6c, 0x71, 0xc4],
+                [0x93, 0xa1, 0xa1],
+                [0xfd, 0xf6, 0xe3],
+            ]),
+        }
+    }
+}
+
+/// Applies [`TerminalTheme`] to every [`Tui`] when the resource is
+/// inserted or changed, and to each newly spawned `Tui` otherwise.
+/// Registered by [`TerminalPlugin`](crate::TerminalPlugin) right after
+/// `materialize_tui_requests`, so a terminal materialized this frame is
+/// themed before the same frame's user draw. Applying repaints the
+/// terminal on its next flush, whether or not it is drawn again - content
+/// from an `initial_draw` picks up the theme too.
+pub(crate) fn apply_terminal_theme(theme: Option<Res<TerminalTheme>>, mut tuis: Query<&mut Tui>) {
+    let Some(theme) = theme else {
+        return;
+    };
+    let theme_changed = theme.is_changed();
+    for mut tui in &mut tuis {
+        if theme_changed || tui.is_added() {
+            tui.apply_theme(&theme);
+        }
+    }
+}
+
+// ============================================================================
+// Test: theme application. Pure CPU - a bare `App` running only
+// `apply_terminal_theme`, checked through the payload the themed terminal
+// produces on its next draw.
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::{Font, Fonts};
+    use crate::setup::TerminalTexture;
+    use std::sync::Arc;
+
+    fn test_fonts() -> Arc<Fonts> {
+        let font_data = include_bytes!("../examples/assets/fonts/Mplus1Code-Regular.ttf");
+        let font = Font::new(font_data).expect("failed to load test font");
+        Arc::new(Fonts::new(font, 16))
+    }
+
+    fn spawn_tui(app: &mut App) -> Entity {
+        let mut images = Assets::<Image>::default();
+        let texture = TerminalTexture::create(2, 1, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture");
+        app.world_mut().spawn(Tui::from_texture_state(texture)).id()
+    }
+
+    /// Draws one default-styled cell and returns the first bg vertex color.
+    fn drawn_reset_bg(app: &mut App, entity: Entity) -> Option<u32> {
+        let mut tui = app.world_mut().get_mut::<Tui>(entity).unwrap();
+        tui.draw(|frame| {
+            frame.buffer_mut()[(0, 0)].set_symbol("a");
+        });
+        tui.flush();
+        let (_, payload) = tui.take_pending_draw()?;
+        payload.bg_quad_colors().first().copied()
+    }
+
+    #[test]
+    fn no_theme_resource_keeps_backend_colors() {
+        let mut app = App::new();
+        app.add_systems(Update, apply_terminal_theme);
+        let entity = spawn_tui(&mut app);
+        app.update();
+
+        // Default reset_bg equals the default initial_fill - no bg quad.
+        assert_eq!(drawn_reset_bg(&mut app, entity), None);
+    }
+
+    #[test]
+    fn theme_applies_to_existing_and_late_spawned_terminals() {
+        let mut app = App::new();
+        app.add_systems(Update, apply_terminal_theme);
+        let early = spawn_tui(&mut app);
+        app.update();
+
+        app.insert_resource(TerminalTheme::light());
+        app.update();
+        let late = spawn_tui(&mut app);
+        app.update();
+
+        let [r, g, b] = TerminalTheme::light().bg;
+        let expected = Some(u32::from_be_bytes([r, g, b, 255]));
+        assert_eq!(drawn_reset_bg(&mut app, early), expected);
+        assert_eq!(drawn_reset_bg(&mut app, late), expected);
+    }
+
+    #[test]
+    fn theme_repaints_content_drawn_before_it_without_a_redraw() {
+        let mut app = App::new();
+        app.add_systems(Update, apply_terminal_theme);
+        let entity = spawn_tui(&mut app);
+        {
+            let mut tui = app.world_mut().get_mut::<Tui>(entity).unwrap();
+            tui.draw(|frame| {
+                frame.buffer_mut()[(0, 0)].set_symbol("a");
+            });
+            tui.flush();
+            let _ = tui.take_pending_draw();
+        }
+
+        app.insert_resource(TerminalTheme::light());
+        app.update();
+
+        let mut tui = app.world_mut().get_mut::<Tui>(entity).unwrap();
+        tui.flush();
+        let (_, payload) = tui
+            .take_pending_draw()
+            .expect("applying the theme must schedule a repaint");
+        let [r, g, b] = TerminalTheme::light().bg;
+        assert_eq!(
+            payload.bg_quad_colors().first().copied(),
+            Some(u32::from_be_bytes([r, g, b, 255]))
+        );
+    }
+}
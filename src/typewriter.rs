@@ -0,0 +1,274 @@
+//! Typewriter reveal for dialogue and cutscene terminals.
+//!
+//! Put a [`TypewriterTerminal`] on a [`Tui`] entity and the plugin draws its
+//! text into the terminal progressively, `chars_per_second` at a time, as a
+//! wrapped `Paragraph` filling the frame. When the last character is shown a
+//! [`TypewriterFinished`] message names the entity; call
+//! [`TypewriterTerminal::set_text`] to start the next line of dialogue, or
+//! [`TypewriterTerminal::skip`] to show the rest at once (the usual "press
+//! a key to skip" behavior).
+//!
+//! ```ignore
+//! commands.spawn((
+//!     TuiRequest::ui(40, 6, fonts),
+//!     Node::default(),
+//!     TypewriterTerminal::new("It was a dark and stormy night...", 20.0),
+//! ));
+//! ```
+//!
+//! The component owns the terminal's content while it is present - don't
+//! also draw into the same `Tui` from your own systems. Characters are
+//! counted as `char`s, so a multi-codepoint grapheme appears over more than
+//! one step. With wrapping on, a word still being typed at a line's end
+//! jumps to the next line once it no longer fits, like in most games.
+
+use bevy::prelude::*;
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Paragraph, Wrap};
+
+use crate::setup::Tui;
+
+/// Reveals [`Self::text`] into this entity's [`Tui`] over time - see the
+/// [module docs](crate::typewriter).
+#[derive(Component, Debug, Clone)]
+pub struct TypewriterTerminal {
+    text: Text<'static>,
+    total_chars: usize,
+    /// Characters revealed per second of (virtual) app time; `0.0` or less
+    /// reveals everything on the next frame.
+    pub chars_per_second: f32,
+    /// Whether the text is word-wrapped to the terminal width. Default
+    /// `true`.
+    pub wrap: bool,
+    /// Fractional progress, in characters.
+    progress: f32,
+    /// Characters shown by the last draw, `None` before the first one.
+    drawn: Option<usize>,
+    finished: bool,
+}
+
+impl TypewriterTerminal {
+    /// Start revealing `text` (plain or styled) at `chars_per_second`.
+    pub fn new(text: impl Into<Text<'static>>, chars_per_second: f32) -> Self {
+        let text = text.into();
+        Self {
+            total_chars: char_count(&text),
+            text,
+            chars_per_second,
+            wrap: true,
+            progress: 0.0,
+            drawn: None,
+            finished: false,
+        }
+    }
+
+    /// Builder form of [`Self::wrap`].
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Replace the text and restart the reveal from its first character.
+    pub fn set_text(&mut self, text: impl Into<Text<'static>>) {
+        *self = Self::new(text, self.chars_per_second).with_wrap(self.wrap);
+    }
+
+    /// Show the remaining text on the next frame.
+    pub fn skip(&mut self) {
+        self.progress = self.total_chars as f32;
+    }
+
+    /// The full text being revealed.
+    pub fn text(&self) -> &Text<'static> {
+        &self.text
+    }
+
+    /// How many characters are currently shown.
+    pub fn revealed_chars(&self) -> usize {
+        (self.progress as usize).min(self.total_chars)
+    }
+
+    /// `true` once every character is shown (and [`TypewriterFinished`]
+    /// was sent).
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// The first `chars` characters of the text, styles kept.
+    fn revealed(&self, chars: usize) -> Text<'static> {
+        let mut budget = chars;
+        let mut lines = Vec::new();
+        for line in &self.text.lines {
+            if budget == 0 {
+                break;
+            }
+            let mut spans = Vec::new();
+            for span in &line.spans {
+                let len = span.content.chars().count();
+                if len <= budget {
+                    spans.push(span.clone());
+                    budget -= len;
+                } else {
+                    let cut: String = span.content.chars().take(budget).collect();
+                    spans.push(Span::styled(cut, span.style));
+                    budget = 0;
+                    break;
+                }
+            }
+            let mut revealed = Line::from(spans).style(line.style);
+            revealed.alignment = line.alignment;
+            lines.push(revealed);
+        }
+        let mut text = Text::from(lines).style(self.text.style);
+        text.alignment = self.text.alignment;
+        text
+    }
+}
+
+/// Sent once when a [`TypewriterTerminal`] has revealed its whole text,
+/// naming the entity it is on.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypewriterFinished(pub Entity);
+
+fn char_count(text: &Text) -> usize {
+    text.lines
+        .iter()
+        .flat_map(|line| &line.spans)
+        .map(|span| span.content.chars().count())
+        .sum()
+}
+
+/// Advances every typewriter by this frame's time and redraws the ones
+/// whose visible prefix grew. Registered by
+/// [`TerminalPlugin`](crate::TerminalPlugin) in `TerminalSystemSet::Render`,
+/// ahead of the flush.
+pub(crate) fn advance_typewriters(
+    time: Res<Time>,
+    mut typewriters: Query<(Entity, &mut TypewriterTerminal, &mut Tui)>,
+    mut finished: MessageWriter<TypewriterFinished>,
+) {
+    for (entity, mut typewriter, mut tui) in &mut typewriters {
+        if typewriter.finished {
+            continue;
+        }
+        if typewriter.chars_per_second > 0.0 {
+            typewriter.progress += typewriter.chars_per_second * time.delta_secs();
+        } else {
+            typewriter.skip();
+        }
+        let shown = typewriter.revealed_chars();
+        if typewriter.drawn != Some(shown) {
+            let mut paragraph = Paragraph::new(typewriter.revealed(shown));
+            if typewriter.wrap {
+                paragraph = paragraph.wrap(Wrap { trim: false });
+            }
+            tui.draw(|frame| frame.render_widget(paragraph, frame.area()));
+            typewriter.drawn = Some(shown);
+        }
+        if shown == typewriter.total_chars {
+            typewriter.finished = true;
+            finished.write(TypewriterFinished(entity));
+        }
+    }
+}
+
+// ============================================================================
+// Test: reveal pacing and the completion message. Pure CPU - a bare `App`
+// running only `advance_typewriters` with a manually stepped clock.
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::{Font, Fonts};
+    use crate::setup::TerminalTexture;
+    use ratatui::style::{Color, Style};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn test_fonts() -> Arc<Fonts> {
+        let font_data = include_bytes!("../examples/assets/fonts/Mplus1Code-Regular.ttf");
+        let font = Font::new(font_data).expect("failed to load test font");
+        Arc::new(Fonts::new(font, 16))
+    }
+
+    fn app_with_typewriter(typewriter: TypewriterTerminal) -> (App, Entity) {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_message::<TypewriterFinished>()
+            .add_systems(Update, advance_typewriters);
+        let mut images = Assets::<Image>::default();
+        let texture = TerminalTexture::create(8, 2, test_fonts(), false, false, [0, 0, 0, 255], &mut images)
+            .expect("failed to create terminal texture");
+        let entity = app
+            .world_mut()
+            .spawn((Tui::from_texture_state(texture), typewriter))
+            .id();
+        (app, entity)
+    }
+
+    fn step(app: &mut App, millis: u64) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(millis));
+        app.update();
+    }
+
+    fn row(app: &App, entity: Entity, y: u16) -> String {
+        let tui = app.world().get::<Tui>(entity).unwrap();
+        (0..8).map(|x| tui.cell_symbol(x, y)).collect()
+    }
+
+    fn finished_count(app: &mut App) -> usize {
+        app.world_mut()
+            .resource_mut::<Messages<TypewriterFinished>>()
+            .drain()
+            .count()
+    }
+
+    #[test]
+    fn reveals_at_the_given_rate_and_reports_completion_once() {
+        let (mut app, entity) = app_with_typewriter(TypewriterTerminal::new("hello", 10.0));
+
+        step(&mut app, 0);
+        assert_eq!(row(&app, entity, 0), "        ");
+        step(&mut app, 250);
+        assert_eq!(row(&app, entity, 0), "he      ", "2.5 chars at 10/s shows 2");
+        assert_eq!(finished_count(&mut app), 0);
+
+        step(&mut app, 300);
+        assert_eq!(row(&app, entity, 0), "hello   ");
+        assert!(app.world().get::<TypewriterTerminal>(entity).unwrap().is_finished());
+        assert_eq!(finished_count(&mut app), 1);
+
+        step(&mut app, 300);
+        assert_eq!(finished_count(&mut app), 0, "completion is reported once");
+    }
+
+    #[test]
+    fn set_text_restarts_and_skip_finishes_immediately() {
+        let styled = Text::from(vec![
+            Line::from(Span::styled("ab", Style::default().fg(Color::Red))),
+            Line::from("cd"),
+        ]);
+        let (mut app, entity) = app_with_typewriter(TypewriterTerminal::new(styled, 1.0));
+
+        step(&mut app, 0);
+        app.world_mut()
+            .get_mut::<TypewriterTerminal>(entity)
+            .unwrap()
+            .skip();
+        step(&mut app, 0);
+        assert_eq!(row(&app, entity, 0), "ab      ");
+        assert_eq!(row(&app, entity, 1), "cd      ", "lines break where the text does");
+        assert_eq!(finished_count(&mut app), 1);
+
+        app.world_mut()
+            .get_mut::<TypewriterTerminal>(entity)
+            .unwrap()
+            .set_text("xyz");
+        step(&mut app, 1000);
+        assert_eq!(row(&app, entity, 0), "x       ", "the new text starts from scratch");
+        assert_eq!(row(&app, entity, 1), "        ");
+    }
+}
@@ -2,17 +2,24 @@ use std::num::NonZeroUsize;
 
 use evictor::Lru;
 use rustybuzz::Direction;
+use rustybuzz::Feature;
 use rustybuzz::Script;
 use rustybuzz::ShapePlan;
 use rustybuzz::UnicodeBuffer;
 
 use crate::Font;
 
+/// The features turned off when ligatures are: standard, contextual and
+/// contextual-alternates ligatures (what programming fonts use for `=>`,
+/// `!=`, ...). Discretionary ligatures (`dlig`) are off by default anyway.
+const LIGATURE_FEATURES: [&[u8; 4]; 3] = [b"liga", b"clig", b"calt"];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Key {
     face_id: u64,
     direction: Direction,
     script: Script,
+    ligatures: bool,
 }
 
 pub(crate) struct PlanCache {
@@ -26,21 +33,37 @@ impl PlanCache {
         }
     }
 
-    pub(crate) fn get(&mut self, font: &Font, buffer: &mut UnicodeBuffer) -> &ShapePlan {
+    /// The plan for shaping `buffer` with `font`, with the font's
+    /// ligatures applied or not.
+    pub(crate) fn get(
+        &mut self,
+        font: &Font,
+        buffer: &mut UnicodeBuffer,
+        ligatures: bool,
+    ) -> &ShapePlan {
         buffer.guess_segment_properties();
         let key = Key {
             face_id: font.id(),
             direction: buffer.direction(),
             script: buffer.script(),
+            ligatures,
         };
 
         self.lru.get_or_insert_with(key, |_| {
+            let features: Vec<Feature> = if ligatures {
+                vec![]
+            } else {
+                LIGATURE_FEATURES
+                    .iter()
+                    .map(|tag| Feature::new(rustybuzz::ttf_parser::Tag::from_bytes(tag), 0, ..))
+                    .collect()
+            };
             ShapePlan::new(
                 font.font(),
                 buffer.direction(),
                 Some(buffer.script()),
                 buffer.language().as_ref(),
-                &[],
+                &features,
             )
         })
     }
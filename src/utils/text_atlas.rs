@@ -2,7 +2,7 @@ use std::num::NonZeroUsize;
 use std::ops::Deref;
 
 use evictor::Lru;
-use tracing::info;
+use tracing::{info, warn};
 use ratatui::style::Modifier;
 
 use crate::Fonts;
@@ -47,6 +47,7 @@ impl Deref for Entry {
 pub(crate) struct Atlas {
     lru: Lru<Key, CacheRect>,
     width: u32,
+    height: u32,
 
     entry_width: u32,
     entry_height: u32,
@@ -60,13 +61,23 @@ pub(crate) struct Atlas {
     /// happened since they were recorded; comparing this counter is how
     /// `BevyTerminalBackend::flush` (the only reader) detects that.
     generation: u64,
+
+    /// Which key currently owns each slot (indexed like `slot_to_rect`),
+    /// so a queued upload - which only knows its rect - can be traced back
+    /// to its glyph by `BevyTerminalBackend::export_atlas`.
+    slot_keys: Vec<Option<Key>>,
+
+    /// `Fonts::max_glyph_px` at creation - see [`Atlas::accepts`].
+    max_glyph_px: u32,
+    /// Whether `accepts` already warned about a rejected glyph.
+    warned_oversized: bool,
 }
 
 impl Atlas {
     pub(crate) fn new(fonts: &Fonts, width: u32, height: u32) -> Self {
         let entry_width = fonts.min_width_px() * 2;
         let entry_height = fonts.height_px();
-        let max_entries = ((width / entry_width) * (height / entry_height)).max(1);
+        let max_entries = ((width / entry_width.max(1)) * (height / entry_height.max(1))).max(1);
         info!(
             "Glyph atlas: {width}x{height}px in use, entries {entry_width}x{entry_height}px, capacity {max_entries}"
         );
@@ -76,11 +87,15 @@ impl Atlas {
                 NonZeroUsize::new(max_entries as usize).expect("Max entries must be non-zero"),
             ),
             width,
+            height,
             entry_width,
             entry_height,
             next_entry: 0,
             max_entries,
             generation: 0,
+            slot_keys: vec![None; max_entries as usize],
+            max_glyph_px: fonts.max_glyph_px(),
+            warned_oversized: false,
         }
     }
 
@@ -89,11 +104,61 @@ impl Atlas {
         self.generation
     }
 
+    /// `(used, capacity)` in slots. Slots are handed out in order and never
+    /// returned (eviction reassigns them), so "used" only ever grows until
+    /// a `clear`.
+    #[cfg(feature = "debug_stats")]
+    pub(crate) fn occupancy(&self) -> (u32, u32) {
+        (self.next_entry, self.max_entries)
+    }
+
+    /// `(used, capacity)` in bytes of RGBA8 texels: the slots handed out
+    /// so far against all the slots the atlas holds (texels past the last
+    /// whole slot column/row are never used, so they don't count). `used`
+    /// reaching `capacity` means every further new glyph evicts the least
+    /// recently used one.
+    pub(crate) fn usage_bytes(&self) -> (u64, u64) {
+        let slot_bytes = self.entry_width as u64 * self.entry_height as u64 * 4;
+        (
+            self.next_entry as u64 * slot_bytes,
+            self.max_entries as u64 * slot_bytes,
+        )
+    }
+
+    /// Whether a `width x height` glyph may be stored: within
+    /// `Fonts::max_glyph_px` on both sides, no wider than one entry, and
+    /// with entries that fit the atlas texture at all (an extreme font
+    /// size makes a single entry larger than the whole atlas). Callers
+    /// check this before [`Atlas::get`], which assumes it. Logs a warning
+    /// the first time it says no.
+    pub(crate) fn accepts(&mut self, width: u32, height: u32) -> bool {
+        let fits = width <= self.entry_width
+            && height <= self.entry_height
+            && width <= self.max_glyph_px
+            && height <= self.max_glyph_px
+            && self.entry_width <= self.width
+            && self.entry_height <= self.height;
+        if !fits && !self.warned_oversized {
+            self.warned_oversized = true;
+            warn!(
+                "Glyph of {width}x{height}px rejected (limit {}px per side, atlas {}x{}px): \
+                 drawing a placeholder box instead - lower the font size or raise \
+                 Fonts::set_max_glyph_px",
+                self.max_glyph_px, self.width, self.height
+            );
+        }
+        fits
+    }
+
     pub(crate) fn try_get(&mut self, key: &Key) -> Option<Entry> {
         self.lru.get(key).copied().map(Entry::Cached)
     }
 
     pub(crate) fn get(&mut self, key: &Key, width: u32, height: u32) -> Entry {
+        debug_assert!(
+            self.entry_width <= self.width && self.entry_height <= self.height,
+            "Atlas entries larger than the atlas - callers must check Atlas::accepts first"
+        );
         debug_assert_eq!(
             self.entry_height, height,
             "Internal height not equal to provided height - entry size is fixed at Atlas::new time"
@@ -117,10 +182,22 @@ impl Atlas {
             };
 
             self.lru.insert(*key, rect);
+            let slot = self.rect_to_slot(&rect);
+            self.slot_keys[slot] = Some(*key);
             Entry::Uncached(rect)
         })
     }
 
+    /// The key whose glyph currently occupies `rect`'s slot.
+    pub(crate) fn key_at(&self, rect: &CacheRect) -> Option<Key> {
+        self.slot_keys.get(self.rect_to_slot(rect)).copied().flatten()
+    }
+
+    fn rect_to_slot(&self, rect: &CacheRect) -> usize {
+        let columns = self.width / self.entry_width;
+        (rect.y / self.entry_height * columns + rect.x / self.entry_width) as usize
+    }
+
     fn slot_to_rect(&self, slot: u32, width: u32) -> CacheRect {
         let x = slot % (self.width / self.entry_width) * self.entry_width;
         let y = slot / (self.width / self.entry_width) * self.entry_height;
@@ -181,4 +258,94 @@ mod tests {
 
         assert_eq!(*last_inserted, *post_insertion);
     }
+
+    #[test]
+    fn oversized_glyphs_are_rejected() {
+        let font = || {
+            Font::new(include_bytes!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/examples/assets/fonts/Mplus1Code-Regular.ttf"
+            )))
+            .unwrap()
+        };
+        // Taller than the whole atlas: not a single entry fits.
+        let huge = Fonts::new(font(), 4096);
+        let mut atlas = Atlas::new(&huge, 2048, 2048);
+        assert!(!atlas.accepts(huge.min_width_px(), huge.height_px()));
+
+        let mut fonts = Fonts::new(font(), 24);
+        fonts.set_max_glyph_px(20);
+        let mut atlas = Atlas::new(&fonts, 2048, 2048);
+        assert!(!atlas.accepts(fonts.min_width_px(), fonts.height_px()), "over the configured limit");
+
+        let fonts = Fonts::new(font(), 24);
+        let mut atlas = Atlas::new(&fonts, 2048, 2048);
+        assert!(atlas.accepts(fonts.min_width_px(), fonts.height_px()));
+        assert!(atlas.accepts(2 * fonts.min_width_px(), fonts.height_px()), "wide glyphs fit an entry");
+        assert!(!atlas.accepts(3 * fonts.min_width_px(), fonts.height_px()));
+    }
+
+    #[test]
+    fn key_at_tracks_slot_reassignment() {
+        let fonts = Fonts::new(
+            Font::new(include_bytes!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/examples/assets/fonts/Mplus1Code-Regular.ttf"
+            )))
+            .unwrap(),
+            24,
+        );
+        // Exactly one slot: every new key evicts the previous one.
+        let mut atlas = Atlas::new(&fonts, fonts.min_width_px() * 2, fonts.height_px());
+        let key = |glyph| Key {
+            style: Modifier::default(),
+            glyph,
+            font: 0,
+        };
+
+        let first = atlas.get(&key(1), fonts.min_width_px(), fonts.height_px());
+        assert_eq!(atlas.key_at(&first), Some(key(1)));
+
+        let second = atlas.get(&key(2), fonts.min_width_px(), fonts.height_px());
+        assert_eq!(*first, *second, "the only slot is reused");
+        assert_eq!(atlas.key_at(&second), Some(key(2)));
+    }
+
+    #[test]
+    fn full_atlas_evicts_the_least_recently_used_glyph() {
+        let fonts = Fonts::new(
+            Font::new(include_bytes!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/examples/assets/fonts/Mplus1Code-Regular.ttf"
+            )))
+            .unwrap(),
+            24,
+        );
+        let (width, height) = (fonts.min_width_px(), fonts.height_px());
+        // Exactly two slots.
+        let mut atlas = Atlas::new(&fonts, width * 4, height);
+        let key = |glyph| Key {
+            style: Modifier::default(),
+            glyph,
+            font: 0,
+        };
+        let slot_bytes = (width * 2 * height * 4) as u64;
+        assert_eq!(atlas.usage_bytes(), (0, 2 * slot_bytes));
+
+        atlas.get(&key(1), width, height);
+        assert_eq!(atlas.usage_bytes(), (slot_bytes, 2 * slot_bytes));
+        atlas.get(&key(2), width, height);
+        // Touch 1, so 2 is now the least recently used.
+        assert!(atlas.get(&key(1), width, height).cached());
+
+        let third = atlas.get(&key(3), width, height);
+        assert!(!third.cached(), "a new glyph gets a fresh rect to rasterize into");
+        assert_eq!(atlas.key_at(&third), Some(key(3)));
+        assert!(atlas.try_get(&key(1)).is_some(), "the recently used glyph stays");
+        assert!(atlas.try_get(&key(2)).is_none(), "the least recently used one is evicted");
+        assert_eq!(atlas.usage_bytes(), (2 * slot_bytes, 2 * slot_bytes));
+
+        // Coming back, the evicted glyph is rasterized again.
+        assert!(!atlas.get(&key(2), width, height).cached());
+    }
 }